@@ -8,13 +8,13 @@
 
 use actix_http::header::{HeaderName, HeaderValue};
 use actix_web::{
-    body::BoxBody,
-    dev::{ServiceFactory, ServiceRequest},
+    body::{BoxBody, EitherBody, MessageBody},
+    dev::{Service, ServiceFactory, ServiceRequest, ServiceResponse},
     http::header,
     web::{Bytes, ServiceConfig},
     *,
 };
-use futures::{Stream, StreamExt};
+use futures::{FutureExt, Stream, StreamExt};
 use http::StatusCode;
 use leptos::{
     leptos_server::{server_fn_by_path, Payload},
@@ -22,15 +22,31 @@ use leptos::{
     ssr::render_to_stream_with_prefix_undisposed_with_context_and_block_replacement,
     *,
 };
-use leptos_integration_utils::{build_async_response, html_parts_separated};
+#[cfg(feature = "image")]
+pub use leptos_integration_utils::{
+    blur_placeholder, ImageError, ImageRequest, MAX_IMAGE_WIDTH,
+};
+use leptos_integration_utils::{
+    build_async_response, build_client_only_response, html_parts_separated,
+    panic_message, static_error_page,
+};
+pub use leptos_integration_utils::{
+    content_etag, etag_matches, CachedPage, ErrorPageInfo, MemoryPageStore,
+    PageCache, PageCacheFuture, PageCacheStore,
+};
+#[cfg(feature = "image")]
+use leptos_integration_utils::{resize_image, IMAGE_CACHE_CONTROL};
 use leptos_meta::*;
 use leptos_router::*;
 use parking_lot::RwLock;
 use regex::Regex;
 use std::{
+    cell::RefCell,
     fmt::{Debug, Display},
     future::Future,
+    panic::AssertUnwindSafe,
     pin::Pin,
+    rc::Rc,
     sync::Arc,
 };
 #[cfg(debug_assertions)]
@@ -720,6 +736,48 @@ where
     }
 }
 
+/// Returns an Actix [struct@Route](actix_web::Route) that listens for a `GET` request and
+/// serves an HTML page without rendering the app on the server, so that the app is only rendered
+/// in the browser. This is what is used for routes with [`SsrMode::Client`].
+///
+/// The page includes the `<head>` and the scripts that load the app, but not the meta tags
+/// injected using [leptos_meta], because the app isn't run.
+///
+/// ## Provided Context Types
+/// This function always provides context values including the following types:
+/// - [ResponseOptions]
+/// - [HttpRequest](actix_web::HttpRequest)
+/// - [MetaContext](leptos_meta::MetaContext)
+/// - [RouterIntegrationContext](leptos_router::RouterIntegrationContext)
+#[tracing::instrument(level = "trace", fields(error), skip_all)]
+pub fn render_app_client_only_with_context(
+    options: LeptosOptions,
+    additional_context: impl Fn() + 'static + Clone + Send,
+    method: Method,
+) -> Route {
+    let handler = move |req: HttpRequest| {
+        let options = options.clone();
+        let additional_context = additional_context.clone();
+
+        async move {
+            let runtime = create_runtime();
+            provide_contexts(&req, ResponseOptions::default());
+            additional_context();
+            let html = build_client_only_response(&options);
+            runtime.dispose();
+
+            HttpResponse::Ok().content_type("text/html").body(html)
+        }
+    };
+    match method {
+        Method::Get => web::get().to(handler),
+        Method::Post => web::post().to(handler),
+        Method::Put => web::put().to(handler),
+        Method::Delete => web::delete().to(handler),
+        Method::Patch => web::patch().to(handler),
+    }
+}
+
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
 fn provide_contexts(req: &HttpRequest, res_options: ResponseOptions) {
     let path = leptos_corrected_path(req);
@@ -727,8 +785,18 @@ fn provide_contexts(req: &HttpRequest, res_options: ResponseOptions) {
     let integration = ServerIntegration { path };
     provide_context(RouterIntegrationContext::new(integration));
     provide_context(MetaContext::new());
+    if let Some(accept_language) = req
+        .headers()
+        .get(header::ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+    {
+        provide_context(AcceptLanguage(accept_language.to_string()));
+    }
     provide_context(res_options);
     provide_context(req.clone());
+    if let Some(render_panic) = req.extensions().get::<RenderPanic>() {
+        *render_panic.0.borrow_mut() = Some(req.clone());
+    }
     provide_server_redirect(redirect);
     #[cfg(feature = "nonce")]
     leptos::nonce::provide_nonce();
@@ -782,6 +850,15 @@ async fn stream_app_in_order(
 
     build_stream_response(options, res_options, stream, runtime).await
 }
+/// Disposes a reactive runtime when it is dropped.
+struct DisposeOnDrop(RuntimeId);
+
+impl Drop for DisposeOnDrop {
+    fn drop(&mut self) {
+        self.0.dispose();
+    }
+}
+
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
 async fn build_stream_response(
     options: &LeptosOptions,
@@ -817,12 +894,15 @@ async fn build_stream_response(
     let (status, headers) = (res_options.status, res_options.headers.clone());
     let status = status.unwrap_or_default();
 
+    // if the client disconnects, Actix drops the stream before it ends: the runtime is
+    // disposed then, which cancels its pending resources
+    let runtime = DisposeOnDrop(runtime);
     let complete_stream =
         futures::stream::iter([first_chunk.unwrap(), second_chunk.unwrap()])
             .chain(stream)
             .chain(
                 futures::stream::once(async move {
-                    runtime.dispose();
+                    drop(runtime);
                     tail.to_string()
                 })
                 .map(|html| Ok(web::Bytes::from(html)) as Result<web::Bytes>),
@@ -901,6 +981,340 @@ where
     generate_route_list_with_exclusions_and_ssg(app_fn, None)
 }
 
+/// Returns an Actix [struct@Route] that renders an error page with the given `status`, like a
+/// custom `404` page for the requests that no route matches.
+///
+/// The error page is rendered asynchronously like any other route, so `leptos_meta` components
+/// like `<Title/>` work in it, and it can read the [`ErrorPageInfo`] from context. If rendering
+/// the error page panics, a minimal static page is sent instead.
+/// ```ignore
+/// #[component]
+/// fn NotFound() -> impl IntoView {
+///     view! {
+///         <Title text="Page not found"/>
+///         <h1>"Page not found"</h1>
+///     }
+/// }
+///
+/// App::new()
+///     .leptos_routes(leptos_options.to_owned(), routes, App)
+///     .default_service(render_error_page(
+///         leptos_options.to_owned(),
+///         StatusCode::NOT_FOUND,
+///         NotFound,
+///     ))
+/// ```
+///
+/// ## Provided Context Types
+/// This function always provides context values including the following types:
+/// - [ErrorPageInfo]
+/// - [ResponseOptions]
+/// - [HttpRequest](actix_web::HttpRequest)
+/// - [MetaContext](leptos_meta::MetaContext)
+/// - [RouterIntegrationContext](leptos_router::RouterIntegrationContext)
+#[tracing::instrument(level = "trace", fields(error), skip_all)]
+pub fn render_error_page<IV>(
+    options: LeptosOptions,
+    status: StatusCode,
+    app_fn: impl Fn() -> IV + Clone + 'static,
+) -> Route
+where
+    IV: IntoView + 'static,
+{
+    web::route().to(move |req: HttpRequest| {
+        error_page_response(
+            options.clone(),
+            app_fn.clone(),
+            req,
+            ErrorPageInfo::new(status.as_u16()),
+        )
+    })
+}
+
+/// Returns an Actix middleware, to be added with [`App::wrap_fn`], that renders an error page
+/// with a `500` status when rendering a route panics before the response has started.
+///
+/// The error page is rendered like in [`render_error_page`]. In debug builds, the message of
+/// the panic is included in the [`ErrorPageInfo`] provided as context, so that it can be shown
+/// on the page. Streaming routes that panic after sending their first chunk can only be cut off,
+/// and panics in handlers that don't render a Leptos app are not caught.
+/// ```ignore
+/// #[component]
+/// fn InternalError() -> impl IntoView {
+///     let info = expect_context::<ErrorPageInfo>();
+///     view! {
+///         <Title text="Something went wrong"/>
+///         <h1>"Something went wrong"</h1>
+///         <pre>{info.panic_message}</pre>
+///     }
+/// }
+///
+/// App::new()
+///     .leptos_routes(leptos_options.to_owned(), routes, App)
+///     .wrap_fn(catch_render_panics(leptos_options.to_owned(), InternalError))
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn catch_render_panics<S, B, IV>(
+    options: LeptosOptions,
+    app_fn: impl Fn() -> IV + Clone + 'static,
+) -> impl Fn(
+    ServiceRequest,
+    &S,
+) -> Pin<
+    Box<dyn Future<Output = Result<ServiceResponse<EitherBody<B>>>>>,
+> + Clone
+       + 'static
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: MessageBody + 'static,
+    IV: IntoView + 'static,
+{
+    move |req: ServiceRequest, srv: &S| {
+        let options = options.clone();
+        let app_fn = app_fn.clone();
+        let render_panic = RenderPanic::default();
+        req.extensions_mut().insert(render_panic.clone());
+        let res = srv.call(req);
+        Box::pin(async move {
+            let res = AssertUnwindSafe(res).catch_unwind().await;
+            // taking the request out of the extensions it is stored in lets it be freed
+            let http_req = render_panic.0.take();
+            match (res, http_req) {
+                (Ok(res), _) => res.map(ServiceResponse::map_into_left_body),
+                (Err(payload), Some(http_req)) => {
+                    let info = ErrorPageInfo::new(
+                        StatusCode::INTERNAL_SERVER_ERROR.as_u16(),
+                    )
+                    .with_panic_message(panic_message(&*payload));
+                    let res = error_page_response(
+                        options,
+                        app_fn,
+                        http_req.clone(),
+                        info,
+                    )
+                    .await;
+                    Ok(ServiceResponse::new(http_req, res)
+                        .map_into_right_body())
+                }
+                // the panic happened before a Leptos handler started rendering
+                (Err(payload), None) => std::panic::resume_unwind(payload),
+            }
+        })
+    }
+}
+
+/// Returns an Actix route that resizes the images under the site root, for the `srcset` of a
+/// [`leptos_meta::Image`] with an [`ImageLoader::query`](leptos_meta::ImageLoader::query)
+/// loader pointing at it.
+///
+/// The image is read from the path in the `url` parameter, resized to the width in `w` and
+/// encoded with the quality in `q`. Only paths under the site root can be read, and images
+/// are never made wider than they are, or than [`MAX_IMAGE_WIDTH`].
+/// ```ignore
+/// provide_image_loader(ImageLoader::query("/_image"));
+///
+/// App::new()
+///     .route("/_image", image_handler(leptos_options.clone()))
+///     .leptos_routes(leptos_options.to_owned(), routes.to_owned(), App)
+/// ```
+#[cfg(feature = "image")]
+pub fn image_handler(options: LeptosOptions) -> Route {
+    web::get().to(move |req: HttpRequest| {
+        let site_root = options.site_root.clone();
+        let query = req.query_string().to_string();
+        async move {
+            let resized = web::block(move || {
+                ImageRequest::from_query(&query)
+                    .and_then(|request| resize_image(&site_root, &request))
+            })
+            .await;
+            match resized {
+                Ok(Ok(image)) => HttpResponse::Ok()
+                    .content_type(image.content_type)
+                    .insert_header((header::CACHE_CONTROL, IMAGE_CACHE_CONTROL))
+                    .body(image.body),
+                Ok(Err(e)) => HttpResponse::build(
+                    StatusCode::from_u16(e.status())
+                        .expect("image errors have valid status codes"),
+                )
+                .body(e.to_string()),
+                Err(_) => HttpResponse::InternalServerError().finish(),
+            }
+        }
+    })
+}
+
+/// Returns an Actix middleware, to be added with [`App::wrap`] and
+/// [`from_fn`](middleware::from_fn), that adds an `ETag` to the pages it renders, computed from
+/// their body, and sends a `304 Not Modified` without a body when the `If-None-Match` header of
+/// the request shows that the browser already has the same page.
+///
+/// When the [`PageCache`] has a store, successful pages are kept in it, by path and query and
+/// by the headers passed to [`PageCache::vary`], and sent again instead of being rendered.
+/// Pages that set cookies, or a `Cache-Control` header with `no-store` or `private` (for
+/// example with [`ResponseOptions`]), are not stored. With the `nonce` feature, no page is
+/// stored, as each one has its own nonce, which must not be sent again.
+///
+/// The whole page is rendered before it is sent, so this is meant for routes that show the
+/// same thing to everyone, like a landing page or a blog post, and not for routes that stream.
+/// Wrap a scope with only those routes:
+/// ```ignore
+/// let cache = PageCache::new()
+///     .with_store(MemoryPageStore::new().with_time_to_live(Duration::from_secs(60)))
+///     .vary("accept-language");
+///
+/// App::new().service(
+///     web::scope("")
+///         .leptos_routes(leptos_options.to_owned(), static_routes, App)
+///         .wrap(middleware::from_fn(cache_pages(cache))),
+/// )
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn cache_pages<B>(
+    cache: PageCache,
+) -> impl Fn(
+    ServiceRequest,
+    middleware::Next<B>,
+) -> Pin<
+    Box<dyn Future<Output = Result<ServiceResponse<EitherBody<B>>>>>,
+> + Clone
+       + 'static
+where
+    B: MessageBody + 'static,
+{
+    move |req: ServiceRequest, next: middleware::Next<B>| {
+        let cache = cache.clone();
+        Box::pin(async move {
+            if !matches!(*req.method(), http::Method::GET | http::Method::HEAD)
+            {
+                return next
+                    .call(req)
+                    .await
+                    .map(ServiceResponse::map_into_left_body);
+            }
+            let if_none_match = req
+                .headers()
+                .get(header::IF_NONE_MATCH)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string);
+            let key = cache.key(
+                req.uri().path_and_query().map_or("/", |path| path.as_str()),
+                |name| req.headers().get(name)?.to_str().ok(),
+            );
+
+            if let Some(page) = cache.cached(&key).await {
+                let res = page_response(page, if_none_match.as_deref());
+                return Ok(req.into_response(res).map_into_right_body());
+            }
+
+            let res = next.call(req).await?;
+            if res.status() != StatusCode::OK {
+                return Ok(res.map_into_left_body());
+            }
+            let (http_req, res) = res.into_parts();
+            let (res, body) = res.into_parts();
+            let body = match body::to_bytes(body).await {
+                Ok(body) => body,
+                Err(e) => {
+                    let e: Box<dyn std::error::Error> = e.into();
+                    tracing::error!("Could not read the page to cache: {e}");
+                    let res = HttpResponse::InternalServerError().finish();
+                    return Ok(ServiceResponse::new(http_req, res)
+                        .map_into_right_body());
+                }
+            };
+            let headers = res
+                .headers()
+                .iter()
+                .filter_map(|(name, value)| {
+                    Some((name.to_string(), value.to_str().ok()?.to_string()))
+                })
+                .collect();
+            // a page rendered with a nonce can't be sent again with the same one
+            let page = cache
+                .rendered(
+                    &key,
+                    res.status().as_u16(),
+                    headers,
+                    body.to_vec(),
+                    !cfg!(feature = "nonce"),
+                )
+                .await;
+            let res = page_response(page, if_none_match.as_deref());
+            Ok(ServiceResponse::new(http_req, res).map_into_right_body())
+        })
+    }
+}
+
+/// Sends a page, or a `304 Not Modified` with its headers if the browser already has it.
+fn page_response(
+    page: CachedPage,
+    if_none_match: Option<&str>,
+) -> HttpResponse {
+    let status = if page.not_modified(if_none_match) {
+        StatusCode::NOT_MODIFIED
+    } else {
+        StatusCode::from_u16(page.status).unwrap_or(StatusCode::OK)
+    };
+    let mut res = HttpResponse::with_body(status, ());
+    for (name, value) in &page.headers {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::try_from(name.as_str()),
+            HeaderValue::try_from(value.as_str()),
+        ) {
+            res.headers_mut().append(name, value);
+        }
+    }
+    if status == StatusCode::NOT_MODIFIED {
+        return res.set_body(BoxBody::new(()));
+    }
+    res.set_body(BoxBody::new(page.body))
+}
+
+/// Holds the request that a Leptos handler is rendering, for the [`catch_render_panics`]
+/// middleware, which cannot keep its own copy because Actix needs to modify the request while
+/// routing it. The middleware adds it to the request extensions, so that the handler can find it.
+#[derive(Clone, Default)]
+struct RenderPanic(Rc<RefCell<Option<HttpRequest>>>);
+
+async fn error_page_response<IV>(
+    options: LeptosOptions,
+    app_fn: impl Fn() -> IV + Clone + 'static,
+    req: HttpRequest,
+    info: ErrorPageInfo,
+) -> HttpResponse
+where
+    IV: IntoView + 'static,
+{
+    let status = StatusCode::from_u16(info.status)
+        .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+    let res_options = ResponseOptions::default();
+    let app = {
+        let res_options = res_options.clone();
+        move || {
+            provide_contexts(&req, res_options);
+            provide_context(info);
+            app_fn().into_view()
+        }
+    };
+    let rendered = render_app_async_helper(&options, app, res_options, || {});
+    let mut res = match AssertUnwindSafe(rendered).catch_unwind().await {
+        Ok(res) => res,
+        Err(_) => {
+            tracing::error!("Rendering the {status} error page panicked.");
+            HttpResponse::Ok().content_type("text/html").body(
+                static_error_page(
+                    status.as_u16(),
+                    status.canonical_reason().unwrap_or_default(),
+                ),
+            )
+        }
+    };
+    *res.status_mut() = status;
+    res
+}
+
 /// Generates a list of all routes defined in Leptos's Router in your app. We can then use this to automatically
 /// create routes in Actix's App without having to use wildcard matching or fallbacks. Takes in your root app Element
 /// as an argument so it can walk you app tree. This version is tailored to generated Actix compatible paths. Adding excluded_routes
@@ -915,6 +1329,93 @@ where
     generate_route_list_with_exclusions_and_ssg(app_fn, excluded_routes).0
 }
 
+/// Returns an Actix [struct@Route] that serves a `sitemap.xml` listing the pages of your app, as
+/// generated by [`generate_sitemap`] from the routes and static data returned by
+/// [`generate_route_list_with_ssg`].
+/// ```ignore
+/// let (routes, static_data_map) = generate_route_list_with_ssg(App);
+/// App::new()
+///     .route(
+///         "/sitemap.xml",
+///         render_sitemap("https://leptos.dev", routes.clone(), static_data_map),
+///     )
+///     .route("/robots.txt", render_robots_txt("https://leptos.dev", vec![]))
+///     .leptos_routes(leptos_options.to_owned(), routes, App)
+/// ```
+pub fn render_sitemap(
+    base_url: impl ToString,
+    routes: Vec<RouteListing>,
+    static_data_map: StaticDataMap,
+) -> Route {
+    let base_url: Arc<str> = base_url.to_string().into();
+    let routes: Arc<[RouteListing]> = routes.into();
+    let static_data_map = Arc::new(static_data_map);
+    web::get().to(move || {
+        let base_url = Arc::clone(&base_url);
+        let routes = Arc::clone(&routes);
+        let static_data_map = Arc::clone(&static_data_map);
+        async move {
+            let sitemap =
+                generate_sitemap(&base_url, &routes, &static_data_map).await;
+            HttpResponse::Ok()
+                .content_type("application/xml")
+                .body(sitemap)
+        }
+    })
+}
+
+/// Returns an Actix [struct@Route] that serves a `robots.txt` which points crawlers to the
+/// `sitemap.xml` served by [`render_sitemap`], as generated by [`generate_robots_txt`].
+pub fn render_robots_txt(
+    base_url: impl ToString,
+    disallow: Vec<String>,
+) -> Route {
+    let robots: Arc<str> =
+        generate_robots_txt(&base_url.to_string(), &disallow).into();
+    web::get().to(move || {
+        let robots = Arc::clone(&robots);
+        async move {
+            HttpResponse::Ok()
+                .content_type("text/plain")
+                .body(robots.to_string())
+        }
+    })
+}
+
+/// Returns an Actix [struct@Route] that serves the [`Feed`](leptos::feed::Feed) returned by
+/// `feed_fn`, rendered in the given `format`.
+/// ```ignore
+/// use leptos::feed::*;
+///
+/// async fn blog_feed() -> Feed {
+///     let posts = list_posts().await;
+///     Feed::new("My Blog", "https://example.com/").entries(posts.into_iter().map(|post| {
+///         FeedEntry::new(post.title, post.url)
+///             .content(move || view! { <PostBody body=post.body/> })
+///     }))
+/// }
+///
+/// App::new()
+///     .route("/feed.xml", render_feed(FeedFormat::Rss, blog_feed))
+///     .leptos_routes(leptos_options.to_owned(), routes, App)
+/// ```
+pub fn render_feed<Fut>(
+    format: leptos::feed::FeedFormat,
+    feed_fn: impl Fn() -> Fut + Clone + 'static,
+) -> Route
+where
+    Fut: Future<Output = leptos::feed::Feed> + 'static,
+{
+    web::get().to(move || {
+        let feed = feed_fn();
+        async move {
+            HttpResponse::Ok()
+                .content_type(format.content_type())
+                .body(feed.await.render(format))
+        }
+    })
+}
+
 /// Generates a list of all routes defined in Leptos's Router in your app. We can then use this to automatically
 /// create routes in Actix's App without having to use wildcard matching or fallbacks. Takes in your root app Element
 /// as an argument so it can walk you app tree. This version is tailored to generated Actix compatible paths. Adding excluded_routes
@@ -1281,6 +1782,13 @@ where
                             app_fn.clone(),
                             method,
                         ),
+                        SsrMode::Client => {
+                            render_app_client_only_with_context(
+                                options.clone(),
+                                additional_context.clone(),
+                                method,
+                            )
+                        }
                     },
                 )
                 };
@@ -1357,6 +1865,13 @@ impl LeptosRoutes for &mut ServiceConfig {
                             app_fn.clone(),
                             method,
                         ),
+                        SsrMode::Client => {
+                            render_app_client_only_with_context(
+                                options.clone(),
+                                additional_context.clone(),
+                                method,
+                            )
+                        }
                     },
                 );
             }
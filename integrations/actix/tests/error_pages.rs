@@ -0,0 +1,86 @@
+use actix_web::{
+    http::StatusCode,
+    test::{call_service, init_service, read_body, TestRequest},
+    App,
+};
+use leptos::*;
+use leptos_actix::{
+    catch_render_panics, render_app_async, render_error_page, ErrorPageInfo,
+};
+use leptos_meta::Title;
+use leptos_router::Method;
+
+#[component]
+fn ErrorPage() -> impl IntoView {
+    let info = expect_context::<ErrorPageInfo>();
+    view! {
+        <Title text="Something went wrong"/>
+        <h1>{info.status}</h1>
+        <pre>{info.panic_message}</pre>
+    }
+}
+
+#[component]
+fn Broken() -> impl IntoView {
+    if true {
+        panic!("the page is broken");
+    }
+}
+
+fn options() -> LeptosOptions {
+    LeptosOptions::builder().output_name("app").build()
+}
+
+#[actix_web::test]
+async fn unmatched_requests_render_the_not_found_page() {
+    let app = init_service(App::new().default_service(render_error_page(
+        options(),
+        StatusCode::NOT_FOUND,
+        ErrorPage,
+    )))
+    .await;
+
+    let res =
+        call_service(&app, TestRequest::get().uri("/missing").to_request())
+            .await;
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    let html = String::from_utf8(read_body(res).await.to_vec()).unwrap();
+    assert!(html.contains("<title>Something went wrong</title>"));
+    assert!(html.contains(">404<"));
+}
+
+#[actix_web::test]
+async fn render_panics_render_the_error_page_with_their_message() {
+    let app = init_service(
+        App::new()
+            .route("/broken", render_app_async(options(), Broken, Method::Get))
+            .wrap_fn(catch_render_panics(options(), ErrorPage)),
+    )
+    .await;
+
+    let res =
+        call_service(&app, TestRequest::get().uri("/broken").to_request())
+            .await;
+    assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    let html = String::from_utf8(read_body(res).await.to_vec()).unwrap();
+    assert!(html.contains("<title>Something went wrong</title>"));
+    assert!(html.contains(">500<"));
+    assert!(html.contains("the page is broken"));
+}
+
+#[actix_web::test]
+async fn a_broken_error_page_falls_back_to_a_static_page() {
+    let app = init_service(App::new().default_service(render_error_page(
+        options(),
+        StatusCode::NOT_FOUND,
+        Broken,
+    )))
+    .await;
+
+    let res =
+        call_service(&app, TestRequest::get().uri("/missing").to_request())
+            .await;
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    let html = String::from_utf8(read_body(res).await.to_vec()).unwrap();
+    assert!(html.contains("<h1>404 Not Found</h1>"));
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "image")]
+
+use actix_web::{
+    http::StatusCode,
+    test::{call_service, init_service, read_body, TestRequest},
+    App,
+};
+use leptos::LeptosOptions;
+use leptos_actix::image_handler;
+
+fn options() -> LeptosOptions {
+    let root = std::env::temp_dir()
+        .join(format!("leptos-actix-images-{}", std::process::id()));
+    std::fs::create_dir_all(&root).unwrap();
+    image::RgbImage::new(200, 100)
+        .save(root.join("cat.png"))
+        .unwrap();
+    LeptosOptions::builder()
+        .output_name("app")
+        .site_root(root.to_str().unwrap())
+        .build()
+}
+
+#[actix_web::test]
+async fn images_are_resized_by_the_handler() {
+    let app =
+        init_service(App::new().route("/_image", image_handler(options())))
+            .await;
+
+    let req = TestRequest::get()
+        .uri("/_image?url=%2Fcat.png&w=50&q=75")
+        .to_request();
+    let res = call_service(&app, req).await;
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers().get("content-type").unwrap(), "image/png");
+    assert!(res.headers().contains_key("cache-control"));
+    let body = read_body(res).await;
+    assert_eq!(image::load_from_memory(&body).unwrap().width(), 50);
+}
+
+#[actix_web::test]
+async fn bad_image_requests_are_rejected() {
+    let app =
+        init_service(App::new().route("/_image", image_handler(options())))
+            .await;
+
+    for (uri, status) in [
+        ("/_image?url=%2Fcat.png&w=0&q=75", StatusCode::BAD_REQUEST),
+        (
+            "/_image?url=%2F..%2Fcat.png&w=50&q=75",
+            StatusCode::NOT_FOUND,
+        ),
+    ] {
+        let res =
+            call_service(&app, TestRequest::get().uri(uri).to_request()).await;
+        assert_eq!(res.status(), status, "{uri}");
+    }
+}
@@ -0,0 +1,68 @@
+//! The cache itself is tested in `leptos_integration_utils`: this checks that the middleware
+//! uses it for Actix requests and responses.
+
+use actix_web::{
+    http::{header, StatusCode},
+    middleware::from_fn,
+    test::{call_service, init_service, read_body, TestRequest},
+    web, App, HttpResponse,
+};
+use leptos_actix::{cache_pages, MemoryPageStore, PageCache};
+use std::{
+    rc::Rc,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+#[actix_web::test]
+async fn stored_pages_are_revalidated_without_rendering() {
+    let renders = Rc::new(AtomicUsize::new(0));
+    let page = {
+        let renders = Rc::clone(&renders);
+        move || {
+            let count = renders.fetch_add(1, Ordering::SeqCst) + 1;
+            async move {
+                HttpResponse::Ok()
+                    .body(format!("<p>Rendered {count} times</p>"))
+            }
+        }
+    };
+    let cache = PageCache::new().with_store(MemoryPageStore::new());
+    let app = init_service(
+        App::new()
+            .route("/", web::get().to(page))
+            .wrap(from_fn(cache_pages(cache))),
+    )
+    .await;
+
+    let res =
+        call_service(&app, TestRequest::get().uri("/").to_request()).await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let etag = res
+        .headers()
+        .get(header::ETAG)
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_string();
+    let body = read_body(res).await;
+    assert_eq!(body, "<p>Rendered 1 times</p>");
+    assert_eq!(etag, leptos_actix::content_etag(&body));
+
+    let req = TestRequest::get()
+        .uri("/")
+        .insert_header((header::IF_NONE_MATCH, etag))
+        .to_request();
+    let res = call_service(&app, req).await;
+    let status = res.status();
+    let body = read_body(res).await;
+    if cfg!(feature = "nonce") {
+        // pages rendered with a nonce are not stored
+        assert_eq!(
+            (status, &*body),
+            (StatusCode::OK, &b"<p>Rendered 2 times</p>"[..])
+        );
+    } else {
+        assert_eq!((status, &*body), (StatusCode::NOT_MODIFIED, &b""[..]));
+        assert_eq!(renders.load(Ordering::SeqCst), 1);
+    }
+}
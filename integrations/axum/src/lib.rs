@@ -44,8 +44,11 @@ use axum::{
     routing::{delete, get, patch, post, put},
 };
 use futures::{
-    channel::mpsc::{Receiver, Sender},
-    Future, SinkExt, Stream, StreamExt,
+    channel::{
+        mpsc::{Receiver, Sender},
+        oneshot,
+    },
+    Future, FutureExt, SinkExt, Stream, StreamExt,
 };
 use http::{
     header, method::Method, request::Parts, uri::Uri, version::Version,
@@ -58,12 +61,28 @@ use leptos::{
     ssr::*,
     *,
 };
-use leptos_integration_utils::{build_async_response, html_parts_separated};
+#[cfg(feature = "image")]
+pub use leptos_integration_utils::{
+    blur_placeholder, ImageError, ImageRequest, MAX_IMAGE_WIDTH,
+};
+use leptos_integration_utils::{
+    build_async_response, build_client_only_response, html_parts_separated,
+    panic_message, static_error_page,
+};
+pub use leptos_integration_utils::{
+    content_etag, etag_matches, CachedPage, ErrorPageInfo, MemoryPageStore,
+    PageCache, PageCacheFuture, PageCacheStore,
+};
+#[cfg(feature = "image")]
+use leptos_integration_utils::{resize_image, IMAGE_CACHE_CONTROL};
 use leptos_meta::{generate_head_metadata_separated, MetaContext};
 use leptos_router::*;
 use once_cell::sync::OnceCell;
 use parking_lot::RwLock;
-use std::{fmt::Debug, io, pin::Pin, sync::Arc, thread::available_parallelism};
+use std::{
+    fmt::Debug, io, panic::AssertUnwindSafe, pin::Pin, sync::Arc,
+    thread::available_parallelism,
+};
 use tokio_util::task::LocalPoolHandle;
 use tracing::Instrument;
 /// A struct to hold the parts of the incoming Request. Since `http::Request` isn't cloneable, we're forced
@@ -678,6 +697,11 @@ where
         app_fn.clone(),
     );
 
+    let client = render_app_client_only_with_context(
+        LeptosOptions::from_ref(&options),
+        additional_context.clone(),
+    );
+
     move |req| {
         // 1. Process route to match the values in routeListing
         let path = req
@@ -701,6 +725,7 @@ where
             SsrMode::PartiallyBlocked => pb(req),
             SsrMode::InOrder => io(req),
             SsrMode::Async => asyn(req),
+            SsrMode::Client => client(req),
         }
     }
 }
@@ -755,7 +780,8 @@ where
             let (tx, rx) = futures::channel::mpsc::channel(8);
 
             let current_span = tracing::Span::current();
-            spawn_task!(async move {
+            let render_panic = RenderPanic::from_request(&req);
+            spawn_task!(catch_render_panic(render_panic, async move {
                 let app = {
                     // Need to get the path and query string of the Request
                     // For reasons that escape me, if the incoming URI protocol is https, it provides the absolute URI
@@ -780,7 +806,7 @@ where
                     forward_stream(&options, res_options2, bundle, tx).await;
 
                     runtime.dispose();
-            }.instrument(current_span));
+            }.instrument(current_span)));
 
             generate_response(res_options3, rx)
         })
@@ -842,12 +868,18 @@ async fn forward_stream(
     let (head, tail) =
         html_parts_separated(options, use_context::<MetaContext>().as_ref());
 
-    _ = tx.send(head).await;
-
-    _ = tx.send(first_app_chunk).await;
+    // if the client disconnects, the response body is dropped and sending fails: stop
+    // rendering, so that the runtime is disposed and its pending resources are cancelled
+    for chunk in [head, first_app_chunk] {
+        if tx.send(chunk).await.is_err() {
+            return;
+        }
+    }
 
     while let Some(fragment) = shell.next().await {
-        _ = tx.send(fragment).await;
+        if tx.send(fragment).await.is_err() {
+            return;
+        }
     }
 
     _ = tx.send(tail.to_string()).await;
@@ -928,7 +960,8 @@ where
 
                 let (tx, rx) = futures::channel::mpsc::channel(8);
                 let current_span = tracing::Span::current();
-                spawn_task!(async move {
+                let render_panic = RenderPanic::from_request(&req);
+                spawn_task!(catch_render_panic(render_panic, async move {
                     let app = {
                         let full_path = full_path.clone();
                         let (req, req_parts) = generate_request_and_parts(req).await;
@@ -948,7 +981,7 @@ where
                     forward_stream(&options, res_options2, bundle, tx).await;
 
                     runtime.dispose();
-                }.instrument(current_span));
+                }.instrument(current_span)));
 
                 generate_response(res_options3, rx).await
             }
@@ -966,6 +999,13 @@ fn provide_contexts(
     let integration = ServerIntegration { path };
     provide_context(RouterIntegrationContext::new(integration));
     provide_context(MetaContext::new());
+    if let Some(accept_language) = req_parts
+        .headers
+        .get(header::ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+    {
+        provide_context(AcceptLanguage(accept_language.to_string()));
+    }
     provide_context(req_parts);
     provide_context(extractor);
     provide_context(default_res_options);
@@ -1105,7 +1145,8 @@ where
                 let full_path = format!("http://leptos.dev{path}");
 
                 let (tx, rx) = futures::channel::oneshot::channel();
-                spawn_task!(async move {
+                let render_panic = RenderPanic::from_request(&req);
+                spawn_task!(catch_render_panic(render_panic, async move {
                     let app = {
                         let full_path = full_path.clone();
                         let (req, req_parts) =
@@ -1140,7 +1181,7 @@ where
                     *writable = new_res_parts;
 
                     _ = tx.send(html);
-                });
+                }));
 
                 let html = rx.await.expect("to complete HTML rendering");
 
@@ -1237,7 +1278,8 @@ where
 
                 let (tx, rx) = futures::channel::oneshot::channel();
 
-                spawn_task!(async move {
+                let render_panic = RenderPanic::from_request(&req);
+                spawn_task!(catch_render_panic(render_panic, async move {
                     let app = {
                         let full_path = full_path.clone();
                         let (req, req_parts) =
@@ -1272,7 +1314,7 @@ where
                     *writable = new_res_parts;
 
                     _ = tx.send(html);
-                });
+                }));
 
                 let html = rx.await.expect("to complete HTML rendering");
 
@@ -1291,6 +1333,422 @@ where
         })
     }
 }
+/// Returns an Axum [Handler](axum::handler::Handler) that listens for a `GET` request and serves
+/// an HTML page without rendering the app on the server, so that the app is only rendered in the
+/// browser. This is what is used for routes with [`SsrMode::Client`].
+///
+/// The page includes the `<head>` and the scripts that load the app, but not the meta tags
+/// injected using [leptos_meta], because the app isn't run.
+///
+/// ## Provided Context Types
+/// This function always provides context values including the following types:
+/// - [RequestParts]
+/// - [ResponseOptions]
+/// - [MetaContext](leptos_meta::MetaContext)
+/// - [RouterIntegrationContext](leptos_router::RouterIntegrationContext)
+#[tracing::instrument(level = "info", fields(error), skip_all)]
+#[allow(clippy::type_complexity)]
+pub fn render_app_client_only_with_context(
+    options: LeptosOptions,
+    additional_context: impl Fn() + 'static + Clone + Send,
+) -> impl Fn(
+    Request<Body>,
+) -> Pin<
+    Box<
+        dyn Future<Output = Response<StreamBody<PinnedHtmlStream>>>
+            + Send
+            + 'static,
+    >,
+> + Clone
+       + Send
+       + 'static {
+    move |req: Request<Body>| {
+        Box::pin({
+            let options = options.clone();
+            let add_context = additional_context.clone();
+
+            async move {
+                let path = req.uri().path_and_query().unwrap().as_str();
+                let full_path = format!("http://leptos.dev{path}");
+                let (req, req_parts) = generate_request_and_parts(req).await;
+
+                let runtime = create_runtime();
+                provide_contexts(
+                    full_path,
+                    req_parts,
+                    req.into(),
+                    ResponseOptions::default(),
+                );
+                add_context();
+                let html = build_client_only_response(&options);
+                runtime.dispose();
+
+                let complete_stream =
+                    futures::stream::iter([Ok(Bytes::from(html))]);
+                let mut res = Response::new(StreamBody::new(Box::pin(
+                    complete_stream,
+                )
+                    as PinnedHtmlStream));
+                res.headers_mut().insert(
+                    http::header::CONTENT_TYPE,
+                    HeaderValue::from_static("text/html; charset=utf-8"),
+                );
+                res
+            }
+        })
+    }
+}
+
+/// Returns an Axum [Handler](axum::handler::Handler) that renders an error page with the given
+/// `status`, like a custom `404` page for the requests that no route matches.
+///
+/// The error page is rendered asynchronously like any other route, so `leptos_meta` components
+/// like `<Title/>` work in it, and it can read the [`ErrorPageInfo`] from context. If rendering
+/// the error page panics, a minimal static page is sent instead.
+/// ```ignore
+/// #[component]
+/// fn NotFound() -> impl IntoView {
+///     view! {
+///         <Title text="Page not found"/>
+///         <h1>"Page not found"</h1>
+///     }
+/// }
+///
+/// let app = Router::new()
+///     .leptos_routes(&leptos_options, routes, App)
+///     .fallback(render_error_page(
+///         leptos_options.clone(),
+///         StatusCode::NOT_FOUND,
+///         NotFound,
+///     ));
+/// ```
+///
+/// ## Provided Context Types
+/// This function always provides context values including the following types:
+/// - [ErrorPageInfo]
+/// - [RequestParts]
+/// - [ResponseOptions]
+/// - [MetaContext](leptos_meta::MetaContext)
+/// - [RouterIntegrationContext](leptos_router::RouterIntegrationContext)
+#[tracing::instrument(level = "info", fields(error), skip_all)]
+pub fn render_error_page<IV>(
+    options: LeptosOptions,
+    status: StatusCode,
+    app_fn: impl Fn() -> IV + Clone + Send + 'static,
+) -> impl Fn(
+    Request<Body>,
+) -> Pin<Box<dyn Future<Output = Response<String>> + Send + 'static>>
+       + Clone
+       + Send
+       + 'static
+where
+    IV: IntoView + 'static,
+{
+    move |req: Request<Body>| {
+        Box::pin(error_page_response(
+            options.clone(),
+            app_fn.clone(),
+            req,
+            ErrorPageInfo::new(status.as_u16()),
+        ))
+    }
+}
+
+/// Returns an Axum middleware that renders an error page with a `500` status when rendering
+/// a route panics before the response has started.
+///
+/// The error page is rendered like in [`render_error_page`]. In debug builds, the message of
+/// the panic is included in the [`ErrorPageInfo`] provided as context, so that it can be shown
+/// on the page. Streaming routes that panic after sending their first chunk can only be cut off.
+/// ```ignore
+/// #[component]
+/// fn InternalError() -> impl IntoView {
+///     let info = expect_context::<ErrorPageInfo>();
+///     view! {
+///         <Title text="Something went wrong"/>
+///         <h1>"Something went wrong"</h1>
+///         <pre>{info.panic_message}</pre>
+///     }
+/// }
+///
+/// let app = Router::new()
+///     .leptos_routes(&leptos_options, routes, App)
+///     .layer(axum::middleware::from_fn(catch_render_panics(
+///         leptos_options.clone(),
+///         InternalError,
+///     )));
+/// ```
+pub fn catch_render_panics<IV>(
+    options: LeptosOptions,
+    app_fn: impl Fn() -> IV + Clone + Send + 'static,
+) -> impl Fn(
+    Request<Body>,
+    axum::middleware::Next<Body>,
+) -> Pin<
+    Box<dyn Future<Output = axum::response::Response> + Send + 'static>,
+> + Clone
+       + Send
+       + 'static
+where
+    IV: IntoView + 'static,
+{
+    move |mut req: Request<Body>, next: axum::middleware::Next<Body>| {
+        let options = options.clone();
+        let app_fn = app_fn.clone();
+        Box::pin(async move {
+            let mut error_req = Request::new(Body::empty());
+            *error_req.method_mut() = req.method().clone();
+            *error_req.uri_mut() = req.uri().clone();
+            *error_req.version_mut() = req.version();
+            *error_req.headers_mut() = req.headers().clone();
+
+            let (render_panic, render_panic_message) = RenderPanic::new();
+            req.extensions_mut().insert(render_panic);
+
+            match AssertUnwindSafe(next.run(req)).catch_unwind().await {
+                Ok(res) => res,
+                Err(payload) => {
+                    // the handler usually panics because the rendering task it was
+                    // waiting for panicked, so that task's message is the useful one. It
+                    // is sent once the task has unwound, and dropped if it didn't panic.
+                    let message = render_panic_message
+                        .await
+                        .unwrap_or_else(|_| panic_message(&*payload));
+                    let info = ErrorPageInfo::new(
+                        StatusCode::INTERNAL_SERVER_ERROR.as_u16(),
+                    )
+                    .with_panic_message(message);
+                    error_page_response(options, app_fn, error_req, info)
+                        .await
+                        .into_response()
+                }
+            }
+        })
+    }
+}
+
+/// Returns an Axum handler that resizes the images under the site root, for the `srcset` of a
+/// [`leptos_meta::Image`] with an [`ImageLoader::query`](leptos_meta::ImageLoader::query)
+/// loader pointing at it.
+///
+/// The image is read from the path in the `url` parameter, resized to the width in `w` and
+/// encoded with the quality in `q`. Only paths under the site root can be read, and images
+/// are never made wider than they are, or than [`MAX_IMAGE_WIDTH`].
+/// ```ignore
+/// provide_image_loader(ImageLoader::query("/_image"));
+///
+/// let app = Router::new()
+///     .route("/_image", get(image_handler(leptos_options.clone())))
+///     .leptos_routes(&leptos_options, routes, App);
+/// ```
+#[cfg(feature = "image")]
+pub fn image_handler(
+    options: LeptosOptions,
+) -> impl Fn(
+    Request<Body>,
+) -> Pin<Box<dyn Future<Output = Response<Body>> + Send + 'static>>
+       + Clone
+       + Send
+       + 'static {
+    move |req: Request<Body>| {
+        let site_root = options.site_root.clone();
+        let query = req.uri().query().unwrap_or_default().to_string();
+        Box::pin(async move {
+            let resized = tokio::task::spawn_blocking(move || {
+                ImageRequest::from_query(&query)
+                    .and_then(|request| resize_image(&site_root, &request))
+            })
+            .await;
+            let res = Response::builder();
+            match resized {
+                Ok(Ok(image)) => res
+                    .header(header::CONTENT_TYPE, image.content_type)
+                    .header(header::CACHE_CONTROL, IMAGE_CACHE_CONTROL)
+                    .body(Body::from(image.body)),
+                Ok(Err(e)) => {
+                    res.status(e.status()).body(Body::from(e.to_string()))
+                }
+                Err(_) => res
+                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                    .body(Body::empty()),
+            }
+            .expect("the image response is valid")
+        })
+    }
+}
+
+/// Returns an Axum middleware that adds an `ETag` to the pages it renders, computed from their
+/// body, and sends a `304 Not Modified` without a body when the `If-None-Match` header of the
+/// request shows that the browser already has the same page.
+///
+/// When the [`PageCache`] has a store, successful pages are kept in it, by path and query and
+/// by the headers passed to [`PageCache::vary`], and sent again instead of being rendered.
+/// Pages that set cookies, or a `Cache-Control` header with `no-store` or `private` (for
+/// example with [`ResponseOptions`]), are not stored. With the `nonce` feature, no page is
+/// stored, as each one has its own nonce, which must not be sent again.
+///
+/// The whole page is rendered before it is sent, so this is meant for routes that show the
+/// same thing to everyone, like a landing page or a blog post, and not for routes that stream.
+/// Use `route_layer` on a router with only those routes:
+/// ```ignore
+/// let cache = PageCache::new()
+///     .with_store(MemoryPageStore::new().with_time_to_live(Duration::from_secs(60)))
+///     .vary("accept-language");
+///
+/// let app = Router::new()
+///     .leptos_routes(&leptos_options, static_routes, App)
+///     .route_layer(axum::middleware::from_fn(cache_pages(cache)));
+/// ```
+pub fn cache_pages(
+    cache: PageCache,
+) -> impl Fn(
+    Request<Body>,
+    axum::middleware::Next<Body>,
+) -> Pin<
+    Box<dyn Future<Output = axum::response::Response> + Send + 'static>,
+> + Clone
+       + Send
+       + 'static {
+    move |req: Request<Body>, next: axum::middleware::Next<Body>| {
+        let cache = cache.clone();
+        Box::pin(async move {
+            if !matches!(*req.method(), Method::GET | Method::HEAD) {
+                return next.run(req).await;
+            }
+            let if_none_match = req
+                .headers()
+                .get(header::IF_NONE_MATCH)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string);
+            let key = cache.key(
+                req.uri().path_and_query().map_or("/", |path| path.as_str()),
+                |name| req.headers().get(name)?.to_str().ok(),
+            );
+
+            if let Some(page) = cache.cached(&key).await {
+                return page_response(page, if_none_match.as_deref());
+            }
+
+            let res = next.run(req).await;
+            if res.status() != StatusCode::OK {
+                return res;
+            }
+            let (parts, body) = res.into_parts();
+            let body = match body::to_bytes(body).await {
+                Ok(body) => body,
+                Err(e) => {
+                    tracing::error!("Could not read the page to cache: {e}");
+                    return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+                }
+            };
+            let headers = parts
+                .headers
+                .iter()
+                .filter_map(|(name, value)| {
+                    Some((name.to_string(), value.to_str().ok()?.to_string()))
+                })
+                .collect();
+            // a page rendered with a nonce can't be sent again with the same one
+            let page = cache
+                .rendered(
+                    &key,
+                    parts.status.as_u16(),
+                    headers,
+                    body.to_vec(),
+                    !cfg!(feature = "nonce"),
+                )
+                .await;
+            page_response(page, if_none_match.as_deref())
+        })
+    }
+}
+
+/// Sends a page, or a `304 Not Modified` with its headers if the browser already has it.
+fn page_response(
+    page: CachedPage,
+    if_none_match: Option<&str>,
+) -> axum::response::Response {
+    let mut headers = HeaderMap::new();
+    for (name, value) in &page.headers {
+        if let (Ok(name), Ok(value)) =
+            (HeaderName::try_from(name), HeaderValue::try_from(value))
+        {
+            headers.append(name, value);
+        }
+    }
+    if page.not_modified(if_none_match) {
+        return (StatusCode::NOT_MODIFIED, headers).into_response();
+    }
+    let status = StatusCode::from_u16(page.status).unwrap_or(StatusCode::OK);
+    (status, headers, page.body).into_response()
+}
+
+async fn error_page_response<IV>(
+    options: LeptosOptions,
+    app_fn: impl Fn() -> IV + Clone + Send + 'static,
+    req: Request<Body>,
+    info: ErrorPageInfo,
+) -> Response<String>
+where
+    IV: IntoView + 'static,
+{
+    let status = StatusCode::from_u16(info.status)
+        .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+    let handler = render_app_async_with_context(
+        options,
+        move || provide_context(info.clone()),
+        app_fn,
+    );
+    let mut res = match AssertUnwindSafe(handler(req)).catch_unwind().await {
+        Ok(res) => res,
+        Err(_) => {
+            tracing::error!("Rendering the {status} error page panicked.");
+            Response::new(static_error_page(
+                status.as_u16(),
+                status.canonical_reason().unwrap_or_default(),
+            ))
+        }
+    };
+    *res.status_mut() = status;
+    if !res.headers().contains_key(header::CONTENT_TYPE) {
+        res.headers_mut().insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("text/html; charset=utf-8"),
+        );
+    }
+    res
+}
+
+/// Sends the message of a panic in a rendering task to the [`catch_render_panics`]
+/// middleware. The middleware adds it to the request extensions, so that the task can find it.
+#[derive(Clone)]
+struct RenderPanic(Arc<parking_lot::Mutex<Option<oneshot::Sender<String>>>>);
+
+impl RenderPanic {
+    fn new() -> (Self, oneshot::Receiver<String>) {
+        let (tx, rx) = oneshot::channel();
+        (Self(Arc::new(parking_lot::Mutex::new(Some(tx)))), rx)
+    }
+
+    fn from_request(req: &Request<Body>) -> Option<Self> {
+        req.extensions().get::<Self>().cloned()
+    }
+}
+
+/// Runs a rendering task, and sends the message of its panic if it panics.
+async fn catch_render_panic(
+    render_panic: Option<RenderPanic>,
+    task: impl Future<Output = ()>,
+) {
+    if let Err(payload) = AssertUnwindSafe(task).catch_unwind().await {
+        if let Some(tx) =
+            render_panic.and_then(|render_panic| render_panic.0.lock().take())
+        {
+            _ = tx.send(panic_message(&*payload));
+        }
+    }
+}
+
 /// Generates a list of all routes defined in Leptos's Router in your app. We can then use this to automatically
 /// create routes in Axum's Router without having to use wildcard matching or fallbacks. Takes in your root app Element
 /// as an argument so it can walk you app tree. This version is tailored to generate Axum compatible paths.
@@ -1355,6 +1813,112 @@ pub async fn build_static_routes<IV>(
     });
 }
 
+/// Returns an Axum [Handler](axum::handler::Handler) that serves a `sitemap.xml` listing the pages
+/// of your app, as generated by [`generate_sitemap`] from the routes and static data returned by
+/// [`generate_route_list_with_ssg`].
+/// ```ignore
+/// let (routes, static_data_map) = generate_route_list_with_ssg(App);
+/// let app = Router::new()
+///     .route(
+///         "/sitemap.xml",
+///         get(render_sitemap("https://leptos.dev", routes.clone(), static_data_map)),
+///     )
+///     .route("/robots.txt", get(render_robots_txt("https://leptos.dev", vec![])))
+///     .leptos_routes(&leptos_options, routes, App);
+/// ```
+pub fn render_sitemap(
+    base_url: impl ToString,
+    routes: Vec<RouteListing>,
+    static_data_map: StaticDataMap,
+) -> impl Fn() -> Pin<Box<dyn Future<Output = Response<String>> + Send + 'static>>
+       + Clone
+       + Send
+       + 'static {
+    let base_url: Arc<str> = base_url.to_string().into();
+    let routes: Arc<[RouteListing]> = routes.into();
+    let static_data_map = Arc::new(static_data_map);
+    move || {
+        let base_url = Arc::clone(&base_url);
+        let routes = Arc::clone(&routes);
+        let static_data_map = Arc::clone(&static_data_map);
+        Box::pin(async move {
+            let sitemap =
+                generate_sitemap(&base_url, &routes, &static_data_map).await;
+            let mut res = Response::new(sitemap);
+            res.headers_mut().insert(
+                header::CONTENT_TYPE,
+                HeaderValue::from_static("application/xml"),
+            );
+            res
+        })
+    }
+}
+
+/// Returns an Axum [Handler](axum::handler::Handler) that serves a `robots.txt` which points
+/// crawlers to the `sitemap.xml` served by [`render_sitemap`], as generated by
+/// [`generate_robots_txt`].
+pub fn render_robots_txt(
+    base_url: impl ToString,
+    disallow: Vec<String>,
+) -> impl Fn() -> Pin<Box<dyn Future<Output = Response<String>> + Send + 'static>>
+       + Clone
+       + Send
+       + 'static {
+    let robots: Arc<str> =
+        generate_robots_txt(&base_url.to_string(), &disallow).into();
+    move || {
+        let robots = Arc::clone(&robots);
+        Box::pin(async move {
+            let mut res = Response::new(robots.to_string());
+            res.headers_mut().insert(
+                header::CONTENT_TYPE,
+                HeaderValue::from_static("text/plain"),
+            );
+            res
+        })
+    }
+}
+
+/// Returns an Axum [Handler](axum::handler::Handler) that serves the [`Feed`](leptos::feed::Feed)
+/// returned by `feed_fn`, rendered in the given `format`.
+/// ```ignore
+/// use leptos::feed::*;
+///
+/// async fn blog_feed() -> Feed {
+///     let posts = list_posts().await;
+///     Feed::new("My Blog", "https://example.com/").entries(posts.into_iter().map(|post| {
+///         FeedEntry::new(post.title, post.url)
+///             .content(move || view! { <PostBody body=post.body/> })
+///     }))
+/// }
+///
+/// let app = Router::new()
+///     .route("/feed.xml", get(render_feed(FeedFormat::Rss, blog_feed)))
+///     .leptos_routes(&leptos_options, routes, App);
+/// ```
+pub fn render_feed<Fut>(
+    format: leptos::feed::FeedFormat,
+    feed_fn: impl Fn() -> Fut + Clone + Send + 'static,
+) -> impl Fn() -> Pin<Box<dyn Future<Output = Response<String>> + Send + 'static>>
+       + Clone
+       + Send
+       + 'static
+where
+    Fut: Future<Output = leptos::feed::Feed> + Send + 'static,
+{
+    move || {
+        let feed = feed_fn();
+        Box::pin(async move {
+            let mut res = Response::new(feed.await.render(format));
+            res.headers_mut().insert(
+                header::CONTENT_TYPE,
+                HeaderValue::from_static(format.content_type()),
+            );
+            res
+        })
+    }
+}
+
 /// Generates a list of all routes defined in Leptos's Router in your app. We can then use this to automatically
 /// create routes in Axum's Router without having to use wildcard matching or fallbacks. Takes in your root app Element
 /// as an argument so it can walk you app tree. This version is tailored to generate Axum compatible paths. Adding excluded_routes
@@ -1567,24 +2131,28 @@ where
 
                     async move {
                         let (tx, rx) = futures::channel::oneshot::channel();
-                        spawn_task!(async move {
-                            let res = incremental_static_route(
-                                tokio::fs::read_to_string(static_file_path(
-                                    &options, &path,
-                                ))
-                                .await,
-                            );
-                            let res = handle_static_response(
-                                path.clone(),
-                                options,
-                                app_fn,
-                                additional_context,
-                                res,
-                            )
-                            .await;
-
-                            let _ = tx.send(res);
-                        });
+                        let render_panic = RenderPanic::from_request(&req);
+                        spawn_task!(catch_render_panic(
+                            render_panic,
+                            async move {
+                                let res = incremental_static_route(
+                                    tokio::fs::read_to_string(
+                                        static_file_path(&options, &path),
+                                    )
+                                    .await,
+                                );
+                                let res = handle_static_response(
+                                    path.clone(),
+                                    options,
+                                    app_fn,
+                                    additional_context,
+                                    res,
+                                )
+                                .await;
+
+                                let _ = tx.send(res);
+                            }
+                        ));
                         rx.await.expect("to complete HTML rendering")
                     }
                 })
@@ -1610,24 +2178,28 @@ where
 
                     async move {
                         let (tx, rx) = futures::channel::oneshot::channel();
-                        spawn_task!(async move {
-                            let res = upfront_static_route(
-                                tokio::fs::read_to_string(static_file_path(
-                                    &options, &path,
-                                ))
-                                .await,
-                            );
-                            let res = handle_static_response(
-                                path.clone(),
-                                options,
-                                app_fn,
-                                additional_context,
-                                res,
-                            )
-                            .await;
-
-                            let _ = tx.send(res);
-                        });
+                        let render_panic = RenderPanic::from_request(&req);
+                        spawn_task!(catch_render_panic(
+                            render_panic,
+                            async move {
+                                let res = upfront_static_route(
+                                    tokio::fs::read_to_string(
+                                        static_file_path(&options, &path),
+                                    )
+                                    .await,
+                                );
+                                let res = handle_static_response(
+                                    path.clone(),
+                                    options,
+                                    app_fn,
+                                    additional_context,
+                                    res,
+                                )
+                                .await;
+
+                                let _ = tx.send(res);
+                            }
+                        ));
                         rx.await.expect("to complete HTML rendering")
                     }
                 })
@@ -1764,6 +2336,19 @@ where
                                 leptos_router::Method::Patch => patch(s),
                             }
                         }
+                        SsrMode::Client => {
+                            let s = render_app_client_only_with_context(
+                                LeptosOptions::from_ref(options),
+                                additional_context.clone(),
+                            );
+                            match method {
+                                leptos_router::Method::Get => get(s),
+                                leptos_router::Method::Post => post(s),
+                                leptos_router::Method::Put => put(s),
+                                leptos_router::Method::Delete => delete(s),
+                                leptos_router::Method::Patch => patch(s),
+                            }
+                        }
                     },
                 )
                 };
@@ -0,0 +1,50 @@
+#![cfg(feature = "image")]
+
+use axum::{body::Body, http::Request, routing::get, Router};
+use hyper::{body::to_bytes, StatusCode};
+use leptos::LeptosOptions;
+use leptos_axum::image_handler;
+use tower::ServiceExt;
+
+fn app() -> Router {
+    let root = std::env::temp_dir()
+        .join(format!("leptos-axum-images-{}", std::process::id()));
+    std::fs::create_dir_all(&root).unwrap();
+    image::RgbImage::new(200, 100)
+        .save(root.join("cat.png"))
+        .unwrap();
+    let options = LeptosOptions::builder()
+        .output_name("app")
+        .site_root(root.to_str().unwrap())
+        .build();
+    Router::new().route("/_image", get(image_handler(options)))
+}
+
+#[tokio::test]
+async fn images_are_resized_by_the_handler() {
+    let req = Request::builder()
+        .uri("/_image?url=%2Fcat.png&w=50&q=75")
+        .body(Body::empty())
+        .unwrap();
+    let res = app().oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers()["content-type"], "image/png");
+    assert!(res.headers().contains_key("cache-control"));
+    let body = to_bytes(res.into_body()).await.unwrap();
+    assert_eq!(image::load_from_memory(&body).unwrap().width(), 50);
+}
+
+#[tokio::test]
+async fn bad_image_requests_are_rejected() {
+    for (uri, status) in [
+        ("/_image?url=%2Fcat.png&w=0&q=75", StatusCode::BAD_REQUEST),
+        (
+            "/_image?url=%2F..%2Fcat.png&w=50&q=75",
+            StatusCode::NOT_FOUND,
+        ),
+    ] {
+        let req = Request::builder().uri(uri).body(Body::empty()).unwrap();
+        let res = app().oneshot(req).await.unwrap();
+        assert_eq!(res.status(), status, "{uri}");
+    }
+}
@@ -0,0 +1,74 @@
+//! The cache itself is tested in `leptos_integration_utils`: this checks that the middleware
+//! uses it for Axum requests and responses.
+
+use axum::{
+    body::Body,
+    http::{header, Request},
+    middleware,
+    response::Html,
+    routing::get,
+    Router,
+};
+use hyper::{body::to_bytes, StatusCode};
+use leptos_axum::{cache_pages, MemoryPageStore, PageCache};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+use tower::ServiceExt;
+
+async fn send(
+    app: &Router,
+    req: Request<Body>,
+) -> (StatusCode, Option<String>, String) {
+    let res = app.clone().oneshot(req).await.unwrap();
+    let status = res.status();
+    let etag = res
+        .headers()
+        .get(header::ETAG)
+        .map(|etag| etag.to_str().unwrap().to_string());
+    let body = to_bytes(res.into_body()).await.unwrap();
+    (status, etag, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[tokio::test]
+async fn stored_pages_are_revalidated_without_rendering() {
+    let renders = Arc::new(AtomicUsize::new(0));
+    let page = {
+        let renders = Arc::clone(&renders);
+        move || async move {
+            let count = renders.fetch_add(1, Ordering::SeqCst) + 1;
+            Html(format!("<p>Rendered {count} times</p>"))
+        }
+    };
+    let cache = PageCache::new().with_store(MemoryPageStore::new());
+    let app = Router::new()
+        .route("/", get(page))
+        .route_layer(middleware::from_fn(cache_pages(cache)));
+
+    let req = Request::builder().uri("/").body(Body::empty()).unwrap();
+    let (status, etag, body) = send(&app, req).await;
+    assert_eq!(
+        (status, body.as_str()),
+        (StatusCode::OK, "<p>Rendered 1 times</p>")
+    );
+    let etag = etag.unwrap();
+    assert_eq!(etag, leptos_axum::content_etag(body.as_bytes()));
+
+    let req = Request::builder()
+        .uri("/")
+        .header(header::IF_NONE_MATCH, &etag)
+        .body(Body::empty())
+        .unwrap();
+    let (status, _, body) = send(&app, req).await;
+    if cfg!(feature = "nonce") {
+        // pages rendered with a nonce are not stored
+        assert_eq!(
+            (status, body.as_str()),
+            (StatusCode::OK, "<p>Rendered 2 times</p>")
+        );
+    } else {
+        assert_eq!((status, body.as_str()), (StatusCode::NOT_MODIFIED, ""));
+        assert_eq!(renders.load(Ordering::SeqCst), 1);
+    }
+}
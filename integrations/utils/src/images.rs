@@ -0,0 +1,246 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use image::{
+    codecs::jpeg::JpegEncoder,
+    imageops::FilterType,
+    io::{Limits, Reader},
+    ImageFormat,
+};
+use std::{
+    fmt,
+    io::Cursor,
+    path::{Component, Path, PathBuf},
+};
+
+/// The widest image the resizing endpoint makes, so that a request can't make the server
+/// allocate an arbitrarily large image.
+pub const MAX_IMAGE_WIDTH: u32 = 3840;
+
+/// The largest file the resizing endpoint reads, in bytes.
+pub const MAX_IMAGE_FILE_SIZE: u64 = 32 * 1024 * 1024;
+
+/// The widest and tallest image the resizing endpoint decodes, in pixels, so that a small file
+/// can't make the server decode an arbitrarily large image.
+pub const MAX_SOURCE_DIMENSION: u32 = 16384;
+
+/// The most memory the decoder can allocate for an image, in bytes.
+const MAX_DECODE_ALLOC: u64 = 512 * 1024 * 1024;
+
+/// The `Cache-Control` header of resized images. Their URL changes with the width and the
+/// quality, but not with the original image, so they are only kept for a day.
+pub const IMAGE_CACHE_CONTROL: &str = "public, max-age=86400";
+
+/// The width of the images made by [`blur_placeholder`].
+pub const PLACEHOLDER_WIDTH: u32 = 16;
+
+/// A request made to the resizing endpoint by `leptos_meta::ImageLoader::query`, like
+/// `?url=%2Fcat.jpg&w=640&q=75`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageRequest {
+    /// The path of the original image, relative to the site root.
+    pub url: String,
+    /// The width to resize the image to, in pixels.
+    pub width: u32,
+    /// The quality of the resized image, from 1 to 100.
+    pub quality: u8,
+}
+
+impl ImageRequest {
+    /// Reads a request from the query string of the URL it was made to.
+    pub fn from_query(query: &str) -> Result<Self, ImageError> {
+        let (mut url, mut width, mut quality) = (None, None, None);
+        for pair in query.split('&') {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let value = decode(value);
+            match key {
+                "url" => url = Some(value),
+                "w" => width = value.parse().ok(),
+                "q" => quality = value.parse().ok(),
+                _ => {}
+            }
+        }
+        let url = url.ok_or(ImageError::BadRequest("missing `url`"))?;
+        let width = width
+            .filter(|width| (1..=MAX_IMAGE_WIDTH).contains(width))
+            .ok_or(ImageError::BadRequest("`w` must be from 1 to 3840"))?;
+        let quality = quality
+            .filter(|quality| (1..=100).contains(quality))
+            .ok_or(ImageError::BadRequest("`q` must be from 1 to 100"))?;
+        Ok(Self {
+            url,
+            width,
+            quality,
+        })
+    }
+}
+
+/// An image made by the resizing endpoint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResizedImage {
+    /// The `Content-Type` of the image.
+    pub content_type: &'static str,
+    /// The encoded image.
+    pub body: Vec<u8>,
+}
+
+/// Why an image could not be resized.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImageError {
+    /// The request is missing a parameter, or has one that is out of range.
+    BadRequest(&'static str),
+    /// There is no image at that path under the site root, or the path leaves it.
+    NotFound,
+    /// The file is not an image that can be decoded.
+    Unsupported,
+    /// The file is larger than [`MAX_IMAGE_FILE_SIZE`], or the image larger than
+    /// [`MAX_SOURCE_DIMENSION`].
+    TooLarge,
+}
+
+impl ImageError {
+    /// The HTTP status code to respond with.
+    pub fn status(&self) -> u16 {
+        match self {
+            ImageError::BadRequest(_) => 400,
+            ImageError::NotFound => 404,
+            ImageError::Unsupported => 415,
+            ImageError::TooLarge => 413,
+        }
+    }
+}
+
+impl fmt::Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImageError::BadRequest(reason) => f.write_str(reason),
+            ImageError::NotFound => f.write_str("image not found"),
+            ImageError::Unsupported => f.write_str("unsupported image"),
+            ImageError::TooLarge => f.write_str("image too large"),
+        }
+    }
+}
+
+impl std::error::Error for ImageError {}
+
+/// Resizes the image at `request.url` under `site_root` to `request.width`, keeping its aspect
+/// ratio. Images are never made wider than they are. JPEG images stay JPEG, with the requested
+/// quality; others become PNG.
+///
+/// This decodes and encodes the whole image, so it should be run on a blocking thread.
+pub fn resize_image(
+    site_root: &str,
+    request: &ImageRequest,
+) -> Result<ResizedImage, ImageError> {
+    let path = image_path(site_root, &request.url)?;
+    let size = std::fs::metadata(&path)
+        .map_err(|_| ImageError::NotFound)?
+        .len();
+    if size > MAX_IMAGE_FILE_SIZE {
+        return Err(ImageError::TooLarge);
+    }
+    let bytes = std::fs::read(path).map_err(|_| ImageError::NotFound)?;
+    let format =
+        image::guess_format(&bytes).map_err(|_| ImageError::Unsupported)?;
+
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_SOURCE_DIMENSION);
+    limits.max_image_height = Some(MAX_SOURCE_DIMENSION);
+    limits.max_alloc = Some(MAX_DECODE_ALLOC);
+    let mut reader = Reader::with_format(Cursor::new(&bytes), format);
+    reader.limits(limits);
+    let image = reader.decode().map_err(|error| match error {
+        image::ImageError::Limits(_) => ImageError::TooLarge,
+        _ => ImageError::Unsupported,
+    })?;
+    let image = if request.width < image.width() {
+        image.resize(request.width, u32::MAX, FilterType::Lanczos3)
+    } else {
+        image
+    };
+
+    let mut body = Vec::new();
+    let content_type = if format == ImageFormat::Jpeg {
+        JpegEncoder::new_with_quality(&mut body, request.quality)
+            .encode_image(&image)
+            .map_err(|_| ImageError::Unsupported)?;
+        "image/jpeg"
+    } else {
+        image
+            .write_to(&mut Cursor::new(&mut body), ImageFormat::Png)
+            .map_err(|_| ImageError::Unsupported)?;
+        "image/png"
+    };
+    Ok(ResizedImage { content_type, body })
+}
+
+/// A tiny version of the image at `url` under `site_root`, as a data URL, to be passed as
+/// the `placeholder` of a `leptos_meta::Image` with `blur` set while the page is rendered.
+pub fn blur_placeholder(
+    site_root: &str,
+    url: &str,
+) -> Result<String, ImageError> {
+    let image = resize_image(
+        site_root,
+        &ImageRequest {
+            url: url.to_string(),
+            width: PLACEHOLDER_WIDTH,
+            quality: 50,
+        },
+    )?;
+    Ok(format!(
+        "data:{};base64,{}",
+        image.content_type,
+        STANDARD.encode(image.body)
+    ))
+}
+
+/// The file for `url`, which must be an absolute path that stays under `site_root`, even
+/// once symlinks are followed.
+fn image_path(site_root: &str, url: &str) -> Result<PathBuf, ImageError> {
+    let relative = url.strip_prefix('/').ok_or(ImageError::NotFound)?;
+    let relative = Path::new(relative);
+    if !relative
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)))
+    {
+        return Err(ImageError::NotFound);
+    }
+    let site_root = Path::new(site_root)
+        .canonicalize()
+        .map_err(|_| ImageError::NotFound)?;
+    let path = site_root
+        .join(relative)
+        .canonicalize()
+        .map_err(|_| ImageError::NotFound)?;
+    if path.starts_with(&site_root) {
+        Ok(path)
+    } else {
+        Err(ImageError::NotFound)
+    }
+}
+
+/// Decodes a percent-encoded query string value.
+fn decode(value: &str) -> String {
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut rest = value.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        rest = tail;
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex = rest
+                    .get(..2)
+                    .and_then(|hex| std::str::from_utf8(hex).ok())
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+                match hex {
+                    Some(decoded) => {
+                        bytes.push(decoded);
+                        rest = &rest[2..];
+                    }
+                    None => bytes.push(b'%'),
+                }
+            }
+            _ => bytes.push(byte),
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
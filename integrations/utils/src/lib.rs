@@ -2,9 +2,17 @@ use futures::{Stream, StreamExt};
 use leptos::{nonce::use_nonce, use_context, RuntimeId};
 use leptos_config::LeptosOptions;
 use leptos_meta::MetaContext;
+use std::any::Any;
 
 extern crate tracing;
 
+#[cfg(feature = "image")]
+mod images;
+#[cfg(feature = "image")]
+pub use images::*;
+mod page_cache;
+pub use page_cache::*;
+
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
 fn autoreload(nonce_str: &str, options: &LeptosOptions) -> String {
     let reload_port = match options.reload_external_port {
@@ -50,6 +58,23 @@ fn autoreload(nonce_str: &str, options: &LeptosOptions) -> String {
     }
 }
 
+/// Returns the HTML before and after the app's `<body>` content, including the scripts that
+/// load the WASM bundle and hydrate the app.
+///
+/// While the app is loading, the scripts report progress so that the server-rendered page
+/// can show a loading indicator:
+/// - a `leptos:wasm-progress` event is dispatched on `window` as the WASM bundle downloads,
+///   with `{ loaded, total }` bytes as its `detail` (`total` is `0` if the server didn't send
+///   a `Content-Length`, or compressed the bundle, since the length is then that of the
+///   compressed bundle), and the `--leptos-wasm-progress` CSS variable on `<html>` is set
+///   to the fraction downloaded so far
+/// - `leptos:wasm-instantiated` is dispatched once the bundle has been compiled and
+///   instantiated
+/// - `leptos:hydrated` is dispatched, and the `data-leptos-hydrated` attribute is added
+///   to `<html>`, once hydration has finished
+///
+/// The progress so far is also kept in `window.__LEPTOS_LOAD_PROGRESS`, which
+/// `leptos::use_load_progress` reads from the app.
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
 pub fn html_parts_separated(
     options: &LeptosOptions,
@@ -93,6 +118,8 @@ pub fn html_parts_separated(
                 let l = e.dataset.component;
                 mod["_island_" + l](e);
             }
+            // the events queued for each island have been replayed to it
+            delete window.__LEPTOS_EVENT_QUEUE;
             mod.hydrate();
         }
         "#
@@ -109,6 +136,16 @@ pub fn html_parts_separated(
                     {head}
                     <link rel="modulepreload" href="/{pkg_path}/{output_name}.js"{nonce}>
                     <link rel="preload" href="/{pkg_path}/{wasm_output_name}.wasm" as="fetch" type="application/wasm" crossorigin=""{nonce}>
+                    <script{nonce}>
+                        (function () {{
+                            let queue = window.__LEPTOS_EVENT_QUEUE = [];
+                            for (let name of ["click", "dblclick", "input", "change", "keydown"]) {{
+                                document.addEventListener(name, (ev) => {{
+                                    if (window.__LEPTOS_EVENT_QUEUE === queue) queue.push(ev);
+                                }}, true);
+                            }}
+                        }})();
+                    </script>
                     <script type="module"{nonce}>
                         function idle(c) {{
                             if ("requestIdleCallback" in window) {{
@@ -117,10 +154,47 @@ pub fn html_parts_separated(
                                 c();
                             }}
                         }}
+                        let state = window.__LEPTOS_LOAD_PROGRESS = {{ loaded: 0, total: 0, instantiated: false, hydrated: false }};
+                        function progress(name, detail) {{
+                            window.dispatchEvent(new CustomEvent("leptos:" + name, {{ detail }}));
+                        }}
+                        function fetchWasm(url) {{
+                            return fetch(url).then(res => {{
+                                if (!res.body) return res;
+                                // the length of a compressed body is not the number of bytes read from it
+                                let encoding = res.headers.get("content-encoding");
+                                let compressed = encoding && encoding !== "identity";
+                                let total = compressed ? 0 : Number(res.headers.get("content-length")) || 0;
+                                state.total = total;
+                                let loaded = 0;
+                                let reader = res.body.getReader();
+                                let body = new ReadableStream({{
+                                    pull(controller) {{
+                                        return reader.read().then(({{ done, value }}) => {{
+                                            if (done) return controller.close();
+                                            loaded += value.byteLength;
+                                            state.loaded = loaded;
+                                            let fraction = total ? Math.min(loaded / total, 1) : 0;
+                                            document.documentElement.style.setProperty("--leptos-wasm-progress", fraction);
+                                            progress("wasm-progress", {{ loaded, total }});
+                                            controller.enqueue(value);
+                                        }});
+                                    }}
+                                }});
+                                return new Response(body, {{ status: res.status, headers: res.headers }});
+                            }});
+                        }}
                         idle(() => {{
                             import('/{pkg_path}/{output_name}.js')
                                 .then(mod => {{
-                                    mod.default('/{pkg_path}/{wasm_output_name}.wasm').then({import_callback});
+                                    mod.default(fetchWasm('/{pkg_path}/{wasm_output_name}.wasm')).then(() => {{
+                                        state.instantiated = true;
+                                        progress("wasm-instantiated");
+                                        ({import_callback})();
+                                        state.hydrated = true;
+                                        document.documentElement.dataset.leptosHydrated = "";
+                                        progress("hydrated");
+                                    }});
                                 }})
                         }});
                     </script>
@@ -157,3 +231,65 @@ pub async fn build_async_response(
 
     format!("{head}<body{body_meta}>{buf}{tail}")
 }
+
+/// Returns the HTML page that is served for a route that is only rendered in the browser, with
+/// `SsrMode::Client`. It contains the same `<head>` and scripts as a server-rendered page, but
+/// none of the app: its `<body>` is empty, and marked with a `data-leptos-client-only`
+/// attribute so that the app is mounted into it rather than hydrated.
+///
+/// This should be called with the same context as the app would be rendered with, so that the
+/// scripts use its nonce.
+#[tracing::instrument(level = "trace", fields(error), skip_all)]
+pub fn build_client_only_response(options: &LeptosOptions) -> String {
+    let (head, tail) = html_parts_separated(options, None);
+    format!("{head}<body data-leptos-client-only>{tail}")
+}
+
+/// Describes the error that an error page is being rendered for. The server integrations provide
+/// it as context to the error page component.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorPageInfo {
+    /// The HTTP status code of the response.
+    pub status: u16,
+    /// The message of the panic that interrupted rendering, if there was one. It is only captured
+    /// in debug builds, so that internal details are not shown to users in production.
+    pub panic_message: Option<String>,
+}
+
+impl ErrorPageInfo {
+    /// Creates the info for an error page with the given status, and no panic message.
+    pub fn new(status: u16) -> Self {
+        Self {
+            status,
+            panic_message: None,
+        }
+    }
+
+    /// Adds the message of the panic that interrupted rendering, in debug builds.
+    pub fn with_panic_message(mut self, message: impl Into<String>) -> Self {
+        if cfg!(debug_assertions) {
+            self.panic_message = Some(message.into());
+        }
+        self
+    }
+}
+
+/// Extracts the message from the payload of a panic, which is a `&str` or a `String` for panics
+/// created with `panic!` and friends.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+/// A minimal HTML page for the given status, used when the error page itself cannot be rendered.
+pub fn static_error_page(status: u16, reason: &str) -> String {
+    format!(
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\"/><title>{status} \
+         {reason}</title></head><body><h1>{status} {reason}</h1></body></html>"
+    )
+}
@@ -0,0 +1,315 @@
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, RwLock},
+    time::{Duration, Instant},
+};
+
+/// The future returned by the methods of a [`PageCacheStore`].
+pub type PageCacheFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// A page that has been rendered, with what is needed to send it again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedPage {
+    /// The HTTP status code of the response.
+    pub status: u16,
+    /// The headers of the response, including its `ETag`.
+    pub headers: Vec<(String, String)>,
+    /// The body of the response.
+    pub body: Vec<u8>,
+    /// The `ETag` of the body.
+    pub etag: String,
+}
+
+impl CachedPage {
+    /// Whether the browser already has this page, given the `If-None-Match` header of the
+    /// request, so that it can be sent a `304 Not Modified` with the headers of the page and
+    /// without its body.
+    pub fn not_modified(&self, if_none_match: Option<&str>) -> bool {
+        if_none_match.is_some_and(|tags| etag_matches(tags, &self.etag))
+    }
+}
+
+/// Where a [`PageCache`] keeps the pages it has rendered. Its methods return boxed futures, so
+/// that it can be implemented for an external store like Redis, shared by several servers.
+///
+/// Keys are the path and query of the request, followed by a line with the name and value of
+/// each header the cache varies by.
+pub trait PageCacheStore: Send + Sync + 'static {
+    /// Returns the page stored under `key`, if there is one.
+    fn get<'a>(
+        &'a self,
+        key: &'a str,
+    ) -> PageCacheFuture<'a, Option<CachedPage>>;
+
+    /// Stores `page` under `key`, replacing any page stored before.
+    fn set<'a>(
+        &'a self,
+        key: &'a str,
+        page: CachedPage,
+    ) -> PageCacheFuture<'a, ()>;
+
+    /// Removes the page stored under `key`, so that it is rendered again.
+    fn remove<'a>(&'a self, key: &'a str) -> PageCacheFuture<'a, ()>;
+}
+
+/// A [`PageCacheStore`] that keeps pages in the memory of the server.
+#[derive(Debug, Default)]
+pub struct MemoryPageStore {
+    pages: RwLock<HashMap<String, (Instant, CachedPage)>>,
+    time_to_live: Option<Duration>,
+}
+
+impl MemoryPageStore {
+    /// Creates an empty store, which keeps pages until they are removed.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forgets pages once they have been stored for longer than `time_to_live`, so that they
+    /// are rendered again.
+    pub fn with_time_to_live(mut self, time_to_live: Duration) -> Self {
+        self.time_to_live = Some(time_to_live);
+        self
+    }
+
+    /// Removes every page.
+    pub fn clear(&self) {
+        if let Ok(mut pages) = self.pages.write() {
+            pages.clear();
+        }
+    }
+}
+
+impl PageCacheStore for MemoryPageStore {
+    fn get<'a>(
+        &'a self,
+        key: &'a str,
+    ) -> PageCacheFuture<'a, Option<CachedPage>> {
+        let page = self.pages.read().ok().and_then(|pages| {
+            let (stored_at, page) = pages.get(key)?;
+            let expired = self
+                .time_to_live
+                .is_some_and(|time_to_live| stored_at.elapsed() > time_to_live);
+            (!expired).then(|| page.clone())
+        });
+        Box::pin(async move { page })
+    }
+
+    fn set<'a>(
+        &'a self,
+        key: &'a str,
+        page: CachedPage,
+    ) -> PageCacheFuture<'a, ()> {
+        if let Ok(mut pages) = self.pages.write() {
+            pages.insert(key.to_string(), (Instant::now(), page));
+        }
+        Box::pin(async {})
+    }
+
+    fn remove<'a>(&'a self, key: &'a str) -> PageCacheFuture<'a, ()> {
+        if let Ok(mut pages) = self.pages.write() {
+            pages.remove(key);
+        }
+        Box::pin(async {})
+    }
+}
+
+/// Configures how the server integrations cache rendered pages: the `ETag` they compute from
+/// the body of each page, the headers the page depends on, and the [`PageCacheStore`] that
+/// pages are kept in, if any.
+///
+/// Without a store, every page is still rendered, but the browser is sent a `304 Not Modified`
+/// without a body when it already has the same page.
+#[derive(Clone)]
+pub struct PageCache {
+    store: Option<Arc<dyn PageCacheStore>>,
+    vary: Vec<String>,
+    etag: Arc<EtagFn>,
+}
+
+type EtagFn = dyn Fn(&[u8]) -> String + Send + Sync;
+
+impl PageCache {
+    /// Creates a configuration that computes `ETag`s with [`content_etag`], and doesn't store
+    /// pages.
+    pub fn new() -> Self {
+        Self {
+            store: None,
+            vary: Vec::new(),
+            etag: Arc::new(content_etag),
+        }
+    }
+
+    /// Keeps rendered pages in `store`, and sends them again instead of rendering them.
+    pub fn with_store(mut self, store: impl PageCacheStore) -> Self {
+        self.store = Some(Arc::new(store));
+        self
+    }
+
+    /// Caches a different page for each value of the request header `name`, like
+    /// `Accept-Language` for a page that is translated. It is added to the `Vary` header of
+    /// the response.
+    pub fn vary(mut self, name: impl Into<String>) -> Self {
+        self.vary.push(name.into().to_ascii_lowercase());
+        self
+    }
+
+    /// Computes the `ETag` of a page from its body with `etag`, instead of [`content_etag`].
+    /// The value must be a quoted string, like `"v1-home"`.
+    pub fn with_etag(
+        mut self,
+        etag: impl Fn(&[u8]) -> String + Send + Sync + 'static,
+    ) -> Self {
+        self.etag = Arc::new(etag);
+        self
+    }
+
+    /// The store that pages are kept in, if any.
+    pub fn store(&self) -> Option<&dyn PageCacheStore> {
+        self.store.as_deref()
+    }
+
+    /// The names of the request headers that pages vary by, in lowercase.
+    pub fn vary_headers(&self) -> &[String] {
+        &self.vary
+    }
+
+    /// Whether a page can be stored, given the names and values of its response headers.
+    /// Pages that set cookies, or a `Cache-Control` header with `no-store` or `private`, are
+    /// only meant for the browser they are sent to. Without a store, nothing is stored.
+    pub fn can_store<'a>(
+        &self,
+        headers: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> bool {
+        self.store.is_some()
+            && headers.into_iter().all(|(name, value)| {
+                if name.eq_ignore_ascii_case("set-cookie") {
+                    false
+                } else if name.eq_ignore_ascii_case("cache-control") {
+                    !value.contains("no-store") && !value.contains("private")
+                } else {
+                    true
+                }
+            })
+    }
+
+    /// The `ETag` of a page with the given body.
+    pub fn etag(&self, body: &[u8]) -> String {
+        (self.etag)(body)
+    }
+
+    /// The page stored under `key`, if pages are stored and it is there.
+    pub async fn cached(&self, key: &str) -> Option<CachedPage> {
+        match &self.store {
+            Some(store) => store.get(key).await,
+            None => None,
+        }
+    }
+
+    /// Completes a page that has just been rendered for the request with `key`: sets its
+    /// `ETag`, unless it has one, adds the headers the cache varies by to its `Vary` header,
+    /// and stores it if it [can be stored](Self::can_store) and `storable` is true.
+    ///
+    /// The `Content-Length` header is left out, because the body is not sent with a
+    /// `304 Not Modified`.
+    pub async fn rendered(
+        &self,
+        key: &str,
+        status: u16,
+        headers: Vec<(String, String)>,
+        body: Vec<u8>,
+        storable: bool,
+    ) -> CachedPage {
+        let mut headers = headers
+            .into_iter()
+            .filter(|(name, _)| !name.eq_ignore_ascii_case("content-length"))
+            .collect::<Vec<_>>();
+        let etag = match headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case("etag"))
+        {
+            Some((_, etag)) => etag.clone(),
+            None => {
+                let etag = self.etag(&body);
+                headers.push(("etag".to_string(), etag.clone()));
+                etag
+            }
+        };
+        for name in &self.vary {
+            headers.push(("vary".to_string(), name.clone()));
+        }
+
+        let page = CachedPage {
+            status,
+            headers,
+            body,
+            etag,
+        };
+        let can_store = storable
+            && self.can_store(
+                page.headers
+                    .iter()
+                    .map(|(name, value)| (name.as_str(), value.as_str())),
+            );
+        if let Some(store) = self.store.as_ref().filter(|_| can_store) {
+            store.set(key, page.clone()).await;
+        }
+        page
+    }
+
+    /// The key that the page for a request is stored under, given its path and query, and a
+    /// function that returns the value of a request header by name.
+    pub fn key<'a>(
+        &self,
+        path_and_query: &str,
+        header: impl Fn(&str) -> Option<&'a str>,
+    ) -> String {
+        let mut key = path_and_query.to_string();
+        for name in &self.vary {
+            key.push('\n');
+            key.push_str(name);
+            key.push_str(": ");
+            key.push_str(header(name).unwrap_or_default());
+        }
+        key
+    }
+}
+
+impl Default for PageCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Debug for PageCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PageCache")
+            .field("store", &self.store.is_some())
+            .field("vary", &self.vary)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A strong `ETag` for a page with the given body: a 64-bit FNV-1a hash of its bytes, which is
+/// the same on every server and in every build.
+pub fn content_etag(body: &[u8]) -> String {
+    let hash = body.iter().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("\"{hash:016x}\"")
+}
+
+/// Whether the value of an `If-None-Match` request header matches `etag`, meaning that the
+/// browser already has the page and can be sent a `304 Not Modified`.
+///
+/// It compares tags weakly, as the header requires: `W/"a"` matches `"a"`.
+pub fn etag_matches(if_none_match: &str, etag: &str) -> bool {
+    let weak = |tag: &str| tag.trim().trim_start_matches("W/").to_string();
+    let etag = weak(etag);
+    if_none_match
+        .split(',')
+        .any(|tag| tag.trim() == "*" || weak(tag) == etag)
+}
@@ -0,0 +1,18 @@
+// islands are hydrated by another script
+#![cfg(not(feature = "experimental-islands"))]
+
+use leptos::create_runtime;
+use leptos_config::LeptosOptions;
+use leptos_integration_utils::html_parts_separated;
+
+#[test]
+fn bootstrap_script() {
+    let runtime = create_runtime();
+    let options = LeptosOptions::builder().output_name("app").build();
+    let (head, _) = html_parts_separated(&options, None);
+    runtime.dispose();
+
+    // the script records the load progress of the app, each step before its event is
+    // dispatched, and ignores the length of a compressed bundle
+    insta::assert_snapshot!(head);
+}
@@ -0,0 +1,143 @@
+#![cfg(feature = "image")]
+
+use image::{GrayImage, ImageFormat, RgbImage};
+use leptos_integration_utils::{
+    blur_placeholder, resize_image, ImageError, ImageRequest,
+    MAX_IMAGE_FILE_SIZE, MAX_SOURCE_DIMENSION,
+};
+use std::path::PathBuf;
+
+/// A site root with a 200x100 `photos/cat.png` and `photos/cat.jpg`.
+fn site_root(name: &str) -> PathBuf {
+    let root = std::env::temp_dir()
+        .join(format!("leptos-images-{name}-{}", std::process::id()));
+    std::fs::create_dir_all(root.join("photos")).unwrap();
+    let image = RgbImage::from_pixel(200, 100, image::Rgb([200, 80, 40]));
+    image.save(root.join("photos/cat.png")).unwrap();
+    image.save(root.join("photos/cat.jpg")).unwrap();
+    std::fs::write(root.join("notes.txt"), "not an image").unwrap();
+    root
+}
+
+fn request(url: &str, width: u32) -> ImageRequest {
+    ImageRequest {
+        url: url.to_string(),
+        width,
+        quality: 75,
+    }
+}
+
+#[test]
+fn requests_are_read_from_the_query_of_the_image_loader() {
+    assert_eq!(
+        ImageRequest::from_query("url=%2Fphotos%2Fcat%201.jpg&w=640&q=75"),
+        Ok(ImageRequest {
+            url: "/photos/cat 1.jpg".to_string(),
+            width: 640,
+            quality: 75,
+        })
+    );
+    assert!(matches!(
+        ImageRequest::from_query("w=640&q=75"),
+        Err(ImageError::BadRequest(_))
+    ));
+    assert!(matches!(
+        ImageRequest::from_query("url=%2Fcat.jpg&w=100000&q=75"),
+        Err(ImageError::BadRequest(_))
+    ));
+    assert!(matches!(
+        ImageRequest::from_query("url=%2Fcat.jpg&w=640&q=0"),
+        Err(ImageError::BadRequest(_))
+    ));
+}
+
+#[test]
+fn images_are_resized_keeping_their_format_and_aspect_ratio() {
+    let root = site_root("resize");
+    let root = root.to_str().unwrap();
+
+    let png = resize_image(root, &request("/photos/cat.png", 50)).unwrap();
+    assert_eq!(png.content_type, "image/png");
+    let decoded =
+        image::load_from_memory_with_format(&png.body, ImageFormat::Png)
+            .unwrap();
+    assert_eq!((decoded.width(), decoded.height()), (50, 25));
+
+    let jpeg = resize_image(root, &request("/photos/cat.jpg", 100)).unwrap();
+    assert_eq!(jpeg.content_type, "image/jpeg");
+    let decoded =
+        image::load_from_memory_with_format(&jpeg.body, ImageFormat::Jpeg)
+            .unwrap();
+    assert_eq!((decoded.width(), decoded.height()), (100, 50));
+
+    // images are never made wider than they are
+    let larger = resize_image(root, &request("/photos/cat.png", 400)).unwrap();
+    let decoded = image::load_from_memory(&larger.body).unwrap();
+    assert_eq!(decoded.width(), 200);
+}
+
+#[test]
+fn only_images_under_the_site_root_are_resized() {
+    let root = site_root("paths");
+    let root = root.to_str().unwrap();
+
+    for url in ["/../cat.png", "/photos/../../cat.png", "photos/cat.png"] {
+        assert_eq!(
+            resize_image(root, &request(url, 50)),
+            Err(ImageError::NotFound),
+            "{url}"
+        );
+    }
+    assert_eq!(
+        resize_image(root, &request("/photos/dog.png", 50)),
+        Err(ImageError::NotFound)
+    );
+    assert_eq!(
+        resize_image(root, &request("/notes.txt", 50)),
+        Err(ImageError::Unsupported)
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn symlinks_out_of_the_site_root_are_not_followed() {
+    let root = site_root("symlinks");
+    let outside = site_root("symlinks-outside");
+    std::os::unix::fs::symlink(outside.join("photos"), root.join("linked"))
+        .unwrap();
+
+    assert_eq!(
+        resize_image(root.to_str().unwrap(), &request("/linked/cat.png", 50)),
+        Err(ImageError::NotFound)
+    );
+}
+
+#[test]
+fn large_files_and_images_are_not_decoded() {
+    let root = site_root("limits");
+    let file = std::fs::File::create(root.join("huge.png")).unwrap();
+    file.set_len(MAX_IMAGE_FILE_SIZE + 1).unwrap();
+    // a tiny file, which would decode to an image too large to resize
+    GrayImage::new(MAX_SOURCE_DIMENSION + 1, 1)
+        .save(root.join("wide.png"))
+        .unwrap();
+    let root = root.to_str().unwrap();
+
+    assert_eq!(
+        resize_image(root, &request("/huge.png", 50)),
+        Err(ImageError::TooLarge)
+    );
+    assert_eq!(
+        resize_image(root, &request("/wide.png", 50)),
+        Err(ImageError::TooLarge)
+    );
+}
+
+#[test]
+fn blur_placeholders_are_tiny_data_urls() {
+    let root = site_root("placeholder");
+    let placeholder =
+        blur_placeholder(root.to_str().unwrap(), "/photos/cat.png").unwrap();
+    assert!(placeholder.starts_with("data:image/png;base64,"));
+    assert!(placeholder.len() < 1000);
+}
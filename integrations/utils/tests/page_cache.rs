@@ -0,0 +1,158 @@
+use futures::executor::block_on;
+use leptos_integration_utils::{
+    content_etag, etag_matches, CachedPage, MemoryPageStore, PageCache,
+    PageCacheStore,
+};
+use std::time::Duration;
+
+fn page(body: &str) -> CachedPage {
+    CachedPage {
+        status: 200,
+        headers: vec![("content-type".into(), "text/html".into())],
+        body: body.as_bytes().to_vec(),
+        etag: content_etag(body.as_bytes()),
+    }
+}
+
+#[test]
+fn content_etags_are_quoted_hashes_of_the_body() {
+    // the FNV-1a offset basis, so that etags are the same on every server
+    assert_eq!(content_etag(b""), r#""cbf29ce484222325""#);
+    assert_eq!(content_etag(b"<p>a</p>"), content_etag(b"<p>a</p>"));
+    assert_ne!(content_etag(b"<p>a</p>"), content_etag(b"<p>b</p>"));
+}
+
+#[test]
+fn if_none_match_is_compared_weakly() {
+    let etag = r#""abc""#;
+    assert!(etag_matches(r#""abc""#, etag));
+    assert!(etag_matches(r#"W/"abc""#, etag));
+    assert!(etag_matches(r#""xyz", W/"abc""#, etag));
+    assert!(etag_matches("*", etag));
+    assert!(!etag_matches(r#""abcd""#, etag));
+    assert!(!etag_matches("", etag));
+}
+
+#[test]
+fn pages_are_keyed_by_path_and_vary_headers() {
+    let cache = PageCache::new().vary("Accept-Language");
+    assert_eq!(cache.vary_headers(), ["accept-language"]);
+
+    let french = cache.key("/blog?page=2", |name| {
+        (name == "accept-language").then_some("fr")
+    });
+    let german = cache.key("/blog?page=2", |name| {
+        (name == "accept-language").then_some("de")
+    });
+    assert_eq!(french, "/blog?page=2\naccept-language: fr");
+    assert_ne!(french, german);
+    assert_eq!(PageCache::new().key("/blog", |_| Some("fr")), "/blog");
+}
+
+#[test]
+fn custom_etags_replace_content_etags() {
+    let cache = PageCache::new().with_etag(|_| r#""v1""#.to_string());
+    assert_eq!(cache.etag(b"anything"), r#""v1""#);
+    assert_eq!(PageCache::new().etag(b"x"), content_etag(b"x"));
+}
+
+#[test]
+fn pages_for_a_single_browser_are_not_stored() {
+    let cache = PageCache::new().with_store(MemoryPageStore::new());
+    assert!(cache.can_store([("content-type", "text/html")]));
+    assert!(cache.can_store([("Cache-Control", "public, max-age=60")]));
+    assert!(!cache.can_store([("Set-Cookie", "session=1")]));
+    assert!(!cache.can_store([("cache-control", "no-store")]));
+    assert!(!cache.can_store([("cache-control", "private, max-age=60")]));
+    // there is nowhere to store them
+    assert!(!PageCache::new().can_store([]));
+}
+
+#[test]
+fn memory_stores_keep_pages_until_they_expire() {
+    let store = MemoryPageStore::new();
+    block_on(store.set("/", page("home")));
+    assert_eq!(block_on(store.get("/")), Some(page("home")));
+    block_on(store.remove("/"));
+    assert_eq!(block_on(store.get("/")), None);
+
+    let store = MemoryPageStore::new().with_time_to_live(Duration::ZERO);
+    block_on(store.set("/", page("home")));
+    std::thread::sleep(Duration::from_millis(1));
+    assert_eq!(block_on(store.get("/")), None);
+}
+
+fn headers(headers: &[(&str, &str)]) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn rendered_pages_get_an_etag_and_vary_headers() {
+    let cache = PageCache::new().vary("Accept-Language");
+    let rendered = block_on(cache.rendered(
+        "/",
+        200,
+        headers(&[("content-type", "text/html"), ("content-length", "4")]),
+        b"home".to_vec(),
+        true,
+    ));
+    assert_eq!(rendered.etag, content_etag(b"home"));
+    assert_eq!(
+        rendered.headers,
+        headers(&[
+            ("content-type", "text/html"),
+            ("etag", &content_etag(b"home")),
+            ("vary", "accept-language"),
+        ])
+    );
+
+    // an etag set by the page is kept
+    let rendered = block_on(cache.rendered(
+        "/",
+        200,
+        headers(&[("ETag", r#""v1""#)]),
+        b"home".to_vec(),
+        true,
+    ));
+    assert_eq!(rendered.etag, r#""v1""#);
+}
+
+#[test]
+fn browsers_with_the_page_are_not_sent_it_again() {
+    let page = page("home");
+    assert!(page.not_modified(Some(&content_etag(b"home"))));
+    assert!(page.not_modified(Some("*")));
+    assert!(!page.not_modified(Some(r#""0000000000000000""#)));
+    assert!(!page.not_modified(None));
+}
+
+#[test]
+fn only_pages_that_can_be_shared_are_stored() {
+    let cache = PageCache::new().with_store(MemoryPageStore::new());
+    let render = |key: &str, page_headers: &[(&str, &str)], storable| {
+        block_on(cache.rendered(
+            key,
+            200,
+            headers(page_headers),
+            key.as_bytes().to_vec(),
+            storable,
+        ))
+    };
+
+    let stored = render("/", &[("content-type", "text/html")], true);
+    assert_eq!(block_on(cache.cached("/")), Some(stored));
+
+    render("/account", &[("set-cookie", "session=1")], true);
+    assert_eq!(block_on(cache.cached("/account")), None);
+    // like a page rendered with a nonce
+    render("/nonce", &[], false);
+    assert_eq!(block_on(cache.cached("/nonce")), None);
+
+    // there is nowhere to store them
+    let cache = PageCache::new();
+    block_on(cache.rendered("/", 200, Vec::new(), Vec::new(), true));
+    assert_eq!(block_on(cache.cached("/")), None);
+}
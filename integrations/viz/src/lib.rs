@@ -7,8 +7,11 @@
 //! directory in the Leptos repository.
 
 use futures::{
-    channel::mpsc::{Receiver, Sender},
-    Future, SinkExt, Stream, StreamExt,
+    channel::{
+        mpsc::{Receiver, Sender},
+        oneshot,
+    },
+    Future, FutureExt, SinkExt, Stream, StreamExt,
 };
 use http::{header, method::Method, uri::Uri, version::Version, StatusCode};
 use hyper::body;
@@ -18,16 +21,29 @@ use leptos::{
     ssr::*,
     *,
 };
-use leptos_integration_utils::{build_async_response, html_parts_separated};
+#[cfg(feature = "image")]
+pub use leptos_integration_utils::{
+    blur_placeholder, ImageError, ImageRequest, MAX_IMAGE_WIDTH,
+};
+use leptos_integration_utils::{
+    build_async_response, build_client_only_response, html_parts_separated,
+    panic_message, static_error_page,
+};
+pub use leptos_integration_utils::{
+    content_etag, etag_matches, CachedPage, ErrorPageInfo, MemoryPageStore,
+    PageCache, PageCacheFuture, PageCacheStore,
+};
+#[cfg(feature = "image")]
+use leptos_integration_utils::{resize_image, IMAGE_CACHE_CONTROL};
 use leptos_meta::{generate_head_metadata_separated, MetaContext};
 use leptos_router::*;
 use parking_lot::RwLock;
-use std::{pin::Pin, sync::Arc};
+use std::{panic::AssertUnwindSafe, pin::Pin, sync::Arc};
 use tokio::task::spawn_blocking;
 use viz::{
     headers::{HeaderMap, HeaderName, HeaderValue},
-    Body, Bytes, Error, Handler, IntoResponse, Request, RequestExt, Response,
-    ResponseExt, Result, Router,
+    Body, BoxHandler, Bytes, Error, Handler, IntoResponse, Request, RequestExt,
+    Response, ResponseExt, Result, Router,
 };
 
 /// A struct to hold the parts of the incoming Request. Since `http::Request` isn't cloneable, we're forced
@@ -573,6 +589,7 @@ where
                 let full_path = format!("http://leptos.dev{path}");
 
                 let (tx, rx) = futures::channel::mpsc::channel(8);
+                let render_panic = RenderPanic::from_request(&req);
 
                 spawn_blocking({
                     let app_fn = app_fn.clone();
@@ -585,7 +602,7 @@ where
                                 let add_context = add_context.clone();
                                 async move {
                                     tokio::task::LocalSet::new()
-                                        .run_until(async {
+                                        .run_until(catch_render_panic(render_panic, async {
                                             let app = {
                                                 let full_path = full_path.clone();
                                                 let req_parts = generate_request_parts(req).await;
@@ -606,7 +623,7 @@ where
                                                 forward_stream(&options, res_options2, bundle, tx).await;
 
                                                 runtime.dispose();
-                                        })
+                                        }))
                                         .await;
                                 }
                             });
@@ -660,12 +677,18 @@ async fn forward_stream(
     let (head, tail) =
         html_parts_separated(options, use_context::<MetaContext>().as_ref());
 
-    _ = tx.send(head).await;
-
-    _ = tx.send(first_app_chunk).await;
+    // if the client disconnects, the response body is dropped and sending fails: stop
+    // rendering, so that the runtime is disposed and its pending resources are cancelled
+    for chunk in [head, first_app_chunk] {
+        if tx.send(chunk).await.is_err() {
+            return;
+        }
+    }
 
     while let Some(fragment) = shell.next().await {
-        _ = tx.send(fragment).await;
+        if tx.send(fragment).await.is_err() {
+            return;
+        }
     }
 
     _ = tx.send(tail.to_string()).await;
@@ -741,6 +764,7 @@ where
                 let full_path = format!("http://leptos.dev{path}");
 
                 let (tx, rx) = futures::channel::mpsc::channel(8);
+                let render_panic = RenderPanic::from_request(&req);
 
                 spawn_blocking({
                     let app_fn = app_fn.clone();
@@ -753,7 +777,7 @@ where
                                 let add_context = add_context.clone();
                                 async move {
                                     tokio::task::LocalSet::new()
-                                        .run_until(async {
+                                        .run_until(catch_render_panic(render_panic, async {
                                             let app = {
                                                 let full_path = full_path.clone();
                                                 let req_parts = generate_request_parts(req).await;
@@ -772,7 +796,7 @@ where
 
                                             forward_stream(&options, res_options2, bundle, tx).await;
                                             runtime.dispose();
-                                        })
+                                        }))
                                         .await;
                                 }
                             });
@@ -793,6 +817,13 @@ fn provide_contexts(
     let integration = ServerIntegration { path };
     provide_context(RouterIntegrationContext::new(integration));
     provide_context(MetaContext::new());
+    if let Some(accept_language) = req_parts
+        .headers
+        .get(header::ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+    {
+        provide_context(AcceptLanguage(accept_language.to_string()));
+    }
     provide_context(req_parts);
     provide_context(default_res_options);
     provide_server_redirect(redirect);
@@ -925,6 +956,7 @@ where
                 let full_path = format!("http://leptos.dev{path}");
 
                 let (tx, rx) = futures::channel::oneshot::channel();
+                let render_panic = RenderPanic::from_request(&req);
 
                 spawn_blocking({
                     let app_fn = app_fn.clone();
@@ -937,7 +969,7 @@ where
                                 let add_context = add_context.clone();
                                 async move {
                                     tokio::task::LocalSet::new()
-                                        .run_until(async {
+                                        .run_until(catch_render_panic(render_panic, async {
                                             let app = {
                                                 let full_path = full_path.clone();
                                                 let req_parts = generate_request_parts(req).await;
@@ -966,7 +998,7 @@ where
                                             *writable = new_res_parts;
 
                                             _ = tx.send(html);
-                                        })
+                                        }))
                                         .await;
                                 }
                             });
@@ -991,6 +1023,54 @@ where
     }
 }
 
+/// Returns a Viz [Handler] that listens for a `GET` request and serves an HTML page without
+/// rendering the app on the server, so that the app is only rendered in the browser. This is
+/// what is used for routes with [`SsrMode::Client`].
+///
+/// The page includes the `<head>` and the scripts that load the app, but not the meta tags
+/// injected using [leptos_meta], because the app isn't run.
+///
+/// ## Provided Context Types
+/// This function always provides context values including the following types:
+/// - [RequestParts]
+/// - [ResponseOptions]
+/// - [MetaContext]
+/// - [RouterIntegrationContext]
+pub fn render_app_client_only_with_context(
+    options: LeptosOptions,
+    additional_context: impl Fn() + 'static + Clone + Send,
+) -> impl Fn(
+    Request,
+) -> Pin<Box<dyn Future<Output = Result<Response>> + Send + 'static>>
+       + Clone
+       + Send
+       + 'static {
+    move |req: Request| {
+        Box::pin({
+            let options = options.clone();
+            let add_context = additional_context.clone();
+
+            async move {
+                let path = req.uri().path_and_query().unwrap().as_str();
+                let full_path = format!("http://leptos.dev{path}");
+                let req_parts = generate_request_parts(req).await;
+
+                let runtime = create_runtime();
+                provide_contexts(
+                    full_path,
+                    req_parts,
+                    ResponseOptions::default(),
+                );
+                add_context();
+                let html = build_client_only_response(&options);
+                runtime.dispose();
+
+                Ok(Response::html(html))
+            }
+        })
+    }
+}
+
 /// Generates a list of all routes defined in Leptos's Router in your app. We can then use this to automatically
 /// create routes in Viz's Router without having to use wildcard matching or fallbacks. Takes in your root app Element
 /// as an argument so it can walk you app tree. This version is tailored to generate Viz compatible paths.
@@ -1027,6 +1107,446 @@ where
 {
     generate_route_list_with_exclusions_and_ssg(app_fn, excluded_routes).0
 }
+/// Returns a Viz [Handler] that serves a `sitemap.xml` listing the pages of your app, as generated
+/// by [`generate_sitemap`] from the routes and static data returned by
+/// [`generate_route_list_with_ssg`].
+/// ```ignore
+/// let (routes, static_data_map) = generate_route_list_with_ssg(App);
+/// let app = Router::new()
+///     .get(
+///         "/sitemap.xml",
+///         render_sitemap("https://leptos.dev", routes.clone(), static_data_map),
+///     )
+///     .get("/robots.txt", render_robots_txt("https://leptos.dev", vec![]))
+///     .leptos_routes(leptos_options, routes, App);
+/// ```
+pub fn render_sitemap(
+    base_url: impl ToString,
+    routes: Vec<RouteListing>,
+    static_data_map: StaticDataMap,
+) -> impl Fn(
+    Request,
+) -> Pin<Box<dyn Future<Output = Result<Response>> + Send + 'static>>
+       + Clone
+       + Send
+       + 'static {
+    let base_url: Arc<str> = base_url.to_string().into();
+    let routes: Arc<[RouteListing]> = routes.into();
+    let static_data_map = Arc::new(static_data_map);
+    move |_req: Request| {
+        let base_url = Arc::clone(&base_url);
+        let routes = Arc::clone(&routes);
+        let static_data_map = Arc::clone(&static_data_map);
+        Box::pin(async move {
+            let sitemap =
+                generate_sitemap(&base_url, &routes, &static_data_map).await;
+            Ok(Response::builder()
+                .header(header::CONTENT_TYPE, "application/xml")
+                .body(Body::from(sitemap))
+                .unwrap())
+        })
+    }
+}
+
+/// Returns a Viz [Handler] that serves a `robots.txt` which points crawlers to the `sitemap.xml`
+/// served by [`render_sitemap`], as generated by [`generate_robots_txt`].
+pub fn render_robots_txt(
+    base_url: impl ToString,
+    disallow: Vec<String>,
+) -> impl Fn(
+    Request,
+) -> Pin<Box<dyn Future<Output = Result<Response>> + Send + 'static>>
+       + Clone
+       + Send
+       + 'static {
+    let robots: Arc<str> =
+        generate_robots_txt(&base_url.to_string(), &disallow).into();
+    move |_req: Request| {
+        let robots = Arc::clone(&robots);
+        Box::pin(async move { Ok(Response::text(robots.to_string())) })
+    }
+}
+
+/// Returns a Viz [Handler] that serves the [`Feed`](leptos::feed::Feed) returned by `feed_fn`,
+/// rendered in the given `format`.
+/// ```ignore
+/// use leptos::feed::*;
+///
+/// async fn blog_feed() -> Feed {
+///     let posts = list_posts().await;
+///     Feed::new("My Blog", "https://example.com/").entries(posts.into_iter().map(|post| {
+///         FeedEntry::new(post.title, post.url)
+///             .content(move || view! { <PostBody body=post.body/> })
+///     }))
+/// }
+///
+/// let app = Router::new()
+///     .get("/feed.xml", render_feed(FeedFormat::Rss, blog_feed))
+///     .leptos_routes(leptos_options, routes, App);
+/// ```
+pub fn render_feed<Fut>(
+    format: leptos::feed::FeedFormat,
+    feed_fn: impl Fn() -> Fut + Clone + Send + 'static,
+) -> impl Fn(
+    Request,
+) -> Pin<Box<dyn Future<Output = Result<Response>> + Send + 'static>>
+       + Clone
+       + Send
+       + 'static
+where
+    Fut: Future<Output = leptos::feed::Feed> + Send + 'static,
+{
+    move |_req: Request| {
+        let feed = feed_fn();
+        Box::pin(async move {
+            Ok(Response::builder()
+                .header(header::CONTENT_TYPE, format.content_type())
+                .body(Body::from(feed.await.render(format)))
+                .unwrap())
+        })
+    }
+}
+
+/// Returns a Viz [Handler] that renders an error page with the given `status`, like a custom
+/// `404` page for the requests that no route matches.
+///
+/// The error page is rendered asynchronously like any other route, so `leptos_meta` components
+/// like `<Title/>` work in it, and it can read the [`ErrorPageInfo`] from context. If rendering
+/// the error page panics, a minimal static page is sent instead.
+/// ```ignore
+/// #[component]
+/// fn NotFound() -> impl IntoView {
+///     view! {
+///         <Title text="Page not found"/>
+///         <h1>"Page not found"</h1>
+///     }
+/// }
+///
+/// let app = Router::new()
+///     .leptos_routes(leptos_options.clone(), routes, App)
+///     .any(
+///         "/*",
+///         render_error_page(leptos_options, StatusCode::NOT_FOUND, NotFound),
+///     );
+/// ```
+///
+/// ## Provided Context Types
+/// This function always provides context values including the following types:
+/// - [ErrorPageInfo]
+/// - [RequestParts]
+/// - [ResponseOptions]
+/// - [MetaContext]
+/// - [RouterIntegrationContext]
+pub fn render_error_page<IV>(
+    options: LeptosOptions,
+    status: StatusCode,
+    app_fn: impl Fn() -> IV + Clone + Send + 'static,
+) -> impl Fn(
+    Request,
+) -> Pin<Box<dyn Future<Output = Result<Response>> + Send + 'static>>
+       + Clone
+       + Send
+       + 'static
+where
+    IV: IntoView + 'static,
+{
+    move |req: Request| {
+        Box::pin(error_page_response(
+            options.clone(),
+            app_fn.clone(),
+            req,
+            ErrorPageInfo::new(status.as_u16()),
+        ))
+    }
+}
+
+/// Returns a Viz handler that resizes the images under the site root, for the `srcset` of a
+/// [`leptos_meta::Image`] with an [`ImageLoader::query`](leptos_meta::ImageLoader::query)
+/// loader pointing at it.
+///
+/// The image is read from the path in the `url` parameter, resized to the width in `w` and
+/// encoded with the quality in `q`. Only paths under the site root can be read, and images
+/// are never made wider than they are, or than [`MAX_IMAGE_WIDTH`].
+/// ```ignore
+/// provide_image_loader(ImageLoader::query("/_image"));
+///
+/// let app = Router::new()
+///     .get("/_image", image_handler(leptos_options.clone()))
+///     .leptos_routes(&leptos_options, routes, App);
+/// ```
+#[cfg(feature = "image")]
+pub fn image_handler(
+    options: LeptosOptions,
+) -> impl Fn(
+    Request,
+) -> Pin<Box<dyn Future<Output = Result<Response>> + Send + 'static>>
+       + Clone
+       + Send
+       + 'static {
+    move |req: Request| {
+        let site_root = options.site_root.clone();
+        let query = req.uri().query().unwrap_or_default().to_string();
+        Box::pin(async move {
+            let resized = tokio::task::spawn_blocking(move || {
+                ImageRequest::from_query(&query)
+                    .and_then(|request| resize_image(&site_root, &request))
+            })
+            .await;
+            let res = Response::builder();
+            let res = match resized {
+                Ok(Ok(image)) => res
+                    .header(header::CONTENT_TYPE, image.content_type)
+                    .header(header::CACHE_CONTROL, IMAGE_CACHE_CONTROL)
+                    .body(Body::from(image.body)),
+                Ok(Err(e)) => {
+                    res.status(e.status()).body(Body::from(e.to_string()))
+                }
+                Err(_) => res
+                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                    .body(Body::empty()),
+            };
+            Ok(res.expect("the image response is valid"))
+        })
+    }
+}
+
+/// Returns a Viz middleware, to be added with [`Router::with_handler`], that renders an error
+/// page with a `500` status when rendering a route panics before the response has started.
+///
+/// The error page is rendered like in [`render_error_page`]. In debug builds, the message of
+/// the panic is included in the [`ErrorPageInfo`] provided as context, so that it can be shown
+/// on the page. Streaming routes that panic after sending their first chunk can only be cut off.
+/// ```ignore
+/// #[component]
+/// fn InternalError() -> impl IntoView {
+///     let info = expect_context::<ErrorPageInfo>();
+///     view! {
+///         <Title text="Something went wrong"/>
+///         <h1>"Something went wrong"</h1>
+///         <pre>{info.panic_message}</pre>
+///     }
+/// }
+///
+/// let app = Router::new()
+///     .leptos_routes(leptos_options.clone(), routes, App)
+///     .with_handler(catch_render_panics(leptos_options, InternalError));
+/// ```
+pub fn catch_render_panics<IV>(
+    options: LeptosOptions,
+    app_fn: impl Fn() -> IV + Clone + Send + 'static,
+) -> impl Fn(
+    (Request, BoxHandler),
+) -> Pin<Box<dyn Future<Output = Result<Response>> + Send + 'static>>
+       + Clone
+       + Send
+       + 'static
+where
+    IV: IntoView + 'static,
+{
+    move |(mut req, next): (Request, BoxHandler)| {
+        let options = options.clone();
+        let app_fn = app_fn.clone();
+        Box::pin(async move {
+            let mut error_req = Request::new(Body::empty());
+            *error_req.method_mut() = req.method().clone();
+            *error_req.uri_mut() = req.uri().clone();
+            *error_req.version_mut() = req.version();
+            *error_req.headers_mut() = req.headers().clone();
+
+            let (render_panic, render_panic_message) = RenderPanic::new();
+            req.extensions_mut().insert(render_panic);
+
+            match AssertUnwindSafe(next.call(req)).catch_unwind().await {
+                Ok(res) => res,
+                Err(payload) => {
+                    // the handler usually panics because the rendering thread it was
+                    // waiting for panicked, so that thread's message is the useful one. It
+                    // is sent once the thread has unwound, and dropped if it didn't panic.
+                    let message = render_panic_message
+                        .await
+                        .unwrap_or_else(|_| panic_message(&*payload));
+                    let info = ErrorPageInfo::new(
+                        StatusCode::INTERNAL_SERVER_ERROR.as_u16(),
+                    )
+                    .with_panic_message(message);
+                    error_page_response(options, app_fn, error_req, info).await
+                }
+            }
+        })
+    }
+}
+
+/// Returns a Viz middleware, to be added with [`Router::with_handler`], that adds an `ETag` to
+/// the pages it renders, computed from their body, and sends a `304 Not Modified` without a
+/// body when the `If-None-Match` header of the request shows that the browser already has the
+/// same page.
+///
+/// When the [`PageCache`] has a store, successful pages are kept in it, by path and query and
+/// by the headers passed to [`PageCache::vary`], and sent again instead of being rendered.
+/// Pages that set cookies, or a `Cache-Control` header with `no-store` or `private` (for
+/// example with [`ResponseOptions`]), are not stored. With the `nonce` feature, no page is
+/// stored, as each one has its own nonce, which must not be sent again.
+///
+/// The whole page is rendered before it is sent, so this is meant for routes that show the
+/// same thing to everyone, like a landing page or a blog post, and not for routes that stream.
+/// Add it to a router with only those routes:
+/// ```ignore
+/// let cache = PageCache::new()
+///     .with_store(MemoryPageStore::new().with_time_to_live(Duration::from_secs(60)))
+///     .vary("accept-language");
+///
+/// let pages = Router::new()
+///     .leptos_routes(leptos_options.clone(), static_routes, App)
+///     .with_handler(cache_pages(cache));
+/// ```
+pub fn cache_pages(
+    cache: PageCache,
+) -> impl Fn(
+    (Request, BoxHandler),
+) -> Pin<Box<dyn Future<Output = Result<Response>> + Send + 'static>>
+       + Clone
+       + Send
+       + 'static {
+    move |(req, next): (Request, BoxHandler)| {
+        let cache = cache.clone();
+        Box::pin(async move {
+            if !matches!(*req.method(), Method::GET | Method::HEAD) {
+                return next.call(req).await;
+            }
+            let if_none_match = req
+                .headers()
+                .get(header::IF_NONE_MATCH)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string);
+            let key = cache.key(
+                req.uri().path_and_query().map_or("/", |path| path.as_str()),
+                |name| req.headers().get(name)?.to_str().ok(),
+            );
+
+            if let Some(page) = cache.cached(&key).await {
+                return Ok(page_response(page, if_none_match.as_deref()));
+            }
+
+            let res = next.call(req).await?;
+            if res.status() != StatusCode::OK {
+                return Ok(res);
+            }
+            let (parts, body) = res.into_parts();
+            let body = match body::to_bytes(body).await {
+                Ok(body) => body,
+                Err(e) => {
+                    tracing::error!("Could not read the page to cache: {e}");
+                    return Ok(
+                        StatusCode::INTERNAL_SERVER_ERROR.into_response()
+                    );
+                }
+            };
+            let headers = parts
+                .headers
+                .iter()
+                .filter_map(|(name, value)| {
+                    Some((name.to_string(), value.to_str().ok()?.to_string()))
+                })
+                .collect();
+            // a page rendered with a nonce can't be sent again with the same one
+            let page = cache
+                .rendered(
+                    &key,
+                    parts.status.as_u16(),
+                    headers,
+                    body.to_vec(),
+                    !cfg!(feature = "nonce"),
+                )
+                .await;
+            Ok(page_response(page, if_none_match.as_deref()))
+        })
+    }
+}
+
+/// Sends a page, or a `304 Not Modified` with its headers if the browser already has it.
+fn page_response(page: CachedPage, if_none_match: Option<&str>) -> Response {
+    let mut headers = HeaderMap::new();
+    for (name, value) in &page.headers {
+        if let (Ok(name), Ok(value)) =
+            (HeaderName::try_from(name), HeaderValue::try_from(value))
+        {
+            headers.append(name, value);
+        }
+    }
+    let (status, body) = if page.not_modified(if_none_match) {
+        (StatusCode::NOT_MODIFIED, Body::empty())
+    } else {
+        (
+            StatusCode::from_u16(page.status).unwrap_or(StatusCode::OK),
+            Body::from(page.body),
+        )
+    };
+    let mut res = Response::new(body);
+    *res.status_mut() = status;
+    *res.headers_mut() = headers;
+    res
+}
+
+async fn error_page_response<IV>(
+    options: LeptosOptions,
+    app_fn: impl Fn() -> IV + Clone + Send + 'static,
+    req: Request,
+    info: ErrorPageInfo,
+) -> Result<Response>
+where
+    IV: IntoView + 'static,
+{
+    let status = StatusCode::from_u16(info.status)
+        .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+    let handler = render_app_async_with_context(
+        options,
+        move || provide_context(info.clone()),
+        app_fn,
+    );
+    let mut res = match AssertUnwindSafe(handler(req)).catch_unwind().await {
+        Ok(res) => res?,
+        Err(_) => {
+            tracing::error!("Rendering the {status} error page panicked.");
+            Response::html(static_error_page(
+                status.as_u16(),
+                status.canonical_reason().unwrap_or_default(),
+            ))
+        }
+    };
+    *res.status_mut() = status;
+    Ok(res)
+}
+
+/// Sends the message of a panic in a rendering thread to the [`catch_render_panics`]
+/// middleware. The middleware adds it to the request extensions, so that the thread can find it.
+#[derive(Clone)]
+struct RenderPanic(Arc<parking_lot::Mutex<Option<oneshot::Sender<String>>>>);
+
+impl RenderPanic {
+    fn new() -> (Self, oneshot::Receiver<String>) {
+        let (tx, rx) = oneshot::channel();
+        (Self(Arc::new(parking_lot::Mutex::new(Some(tx)))), rx)
+    }
+
+    fn from_request(req: &Request) -> Option<Self> {
+        req.extensions().get::<Self>().cloned()
+    }
+}
+
+/// Runs a rendering task, and sends the message of its panic if it panics.
+async fn catch_render_panic(
+    render_panic: Option<RenderPanic>,
+    task: impl Future<Output = ()>,
+) {
+    if let Err(payload) = AssertUnwindSafe(task).catch_unwind().await {
+        if let Some(tx) =
+            render_panic.and_then(|render_panic| render_panic.0.lock().take())
+        {
+            _ = tx.send(panic_message(&*payload));
+        }
+    }
+}
+
 /// Generates a list of all routes defined in Leptos's Router in your app. We can then use this to automatically
 /// create routes in Viz's Router without having to use wildcard matching or fallbacks. Takes in your root app Element
 /// as an argument so it can walk you app tree. This version is tailored to generate Viz compatible paths.
@@ -1192,6 +1712,7 @@ where
             let handler = move |req: Request| {
                 Box::pin({
                     let path = req.path().to_string();
+                    let render_panic = RenderPanic::from_request(&req);
                     let options = options.clone();
                     let app_fn = app_fn.clone();
                     let additional_context = additional_context.clone();
@@ -1212,7 +1733,7 @@ where
                                     let additional_context =
                                         additional_context.clone();
                                     async move {
-                                        tokio::task::LocalSet::new().run_until(async {
+                                        tokio::task::LocalSet::new().run_until(catch_render_panic(render_panic, async {
                                             let res = incremental_static_route(
                                                 tokio::fs::read_to_string(
                                                     static_file_path(
@@ -1232,7 +1753,7 @@ where
                                             .await;
 
                                         let _ = tx.send(res);
-                                        }).await;
+                                        })).await;
                                     }
                                 })
                         });
@@ -1253,6 +1774,7 @@ where
             let handler = move |req: Request| {
                 Box::pin({
                     let path = req.path().to_string();
+                    let render_panic = RenderPanic::from_request(&req);
                     let options = options.clone();
                     let app_fn = app_fn.clone();
                     let additional_context = additional_context.clone();
@@ -1274,7 +1796,7 @@ where
                                         additional_context.clone();
                                     async move {
                                         tokio::task::LocalSet::new()
-                                            .run_until(async {
+                                            .run_until(catch_render_panic(render_panic, async {
                                                 let res = upfront_static_route(
                                                     tokio::fs::read_to_string(
                                                         static_file_path(
@@ -1294,7 +1816,7 @@ where
                                                     .await;
 
                                                 let _ = tx.send(res);
-                                            })
+                                            }))
                                             .await;
                                     }
                                 })
@@ -1486,6 +2008,29 @@ impl LeptosRoutes for Router {
                                 }
                             }
                         }
+                        SsrMode::Client => {
+                            let s = render_app_client_only_with_context(
+                                options.clone(),
+                                additional_context.clone(),
+                            );
+                            match method {
+                                leptos_router::Method::Get => {
+                                    router.get(path, s)
+                                }
+                                leptos_router::Method::Post => {
+                                    router.post(path, s)
+                                }
+                                leptos_router::Method::Put => {
+                                    router.put(path, s)
+                                }
+                                leptos_router::Method::Delete => {
+                                    router.delete(path, s)
+                                }
+                                leptos_router::Method::Patch => {
+                                    router.patch(path, s)
+                                }
+                            }
+                        }
                     }
                 }
             })
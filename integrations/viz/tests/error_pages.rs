@@ -0,0 +1,73 @@
+use hyper::body::to_bytes;
+use leptos::*;
+use leptos_meta::Title;
+use leptos_viz::{
+    catch_render_panics, render_app_async, render_error_page, ErrorPageInfo,
+};
+use viz::{Body, HandlerExt, Request, Response, StatusCode};
+
+#[component]
+fn ErrorPage() -> impl IntoView {
+    let info = expect_context::<ErrorPageInfo>();
+    view! {
+        <Title text="Something went wrong"/>
+        <h1>{info.status}</h1>
+        <pre>{info.panic_message}</pre>
+    }
+}
+
+#[component]
+fn Broken() -> impl IntoView {
+    if true {
+        panic!("the page is broken");
+    }
+}
+
+fn options() -> LeptosOptions {
+    LeptosOptions::builder().output_name("app").build()
+}
+
+fn request(path: &str) -> Request {
+    Request::builder().uri(path).body(Body::empty()).unwrap()
+}
+
+async fn read(res: Response) -> (StatusCode, String) {
+    let status = res.status();
+    let body = to_bytes(res.into_body()).await.unwrap();
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[tokio::test]
+async fn unmatched_requests_render_the_not_found_page() {
+    let handler =
+        render_error_page(options(), StatusCode::NOT_FOUND, ErrorPage);
+
+    let (status, html) =
+        read(handler(request("/missing")).await.unwrap()).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(html.contains("<title>Something went wrong</title>"));
+    assert!(html.contains(">404<"));
+}
+
+#[tokio::test]
+async fn render_panics_render_the_error_page_with_their_message() {
+    let broken = render_app_async(options(), Broken).boxed();
+    let middleware = catch_render_panics(options(), ErrorPage);
+
+    let (status, html) =
+        read(middleware((request("/broken"), broken)).await.unwrap()).await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(html.contains("<title>Something went wrong</title>"));
+    assert!(html.contains(">500<"));
+    assert!(html.contains("the page is broken"));
+}
+
+#[tokio::test]
+async fn a_broken_error_page_falls_back_to_a_static_page() {
+    let handler = render_error_page(options(), StatusCode::NOT_FOUND, Broken);
+
+    let (status, html) =
+        read(handler(request("/missing")).await.unwrap()).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(html.contains("<h1>404 Not Found</h1>"));
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "image")]
+
+use hyper::body::to_bytes;
+use leptos::LeptosOptions;
+use leptos_viz::image_handler;
+use viz::{Body, Request, StatusCode};
+
+fn options() -> LeptosOptions {
+    let root = std::env::temp_dir()
+        .join(format!("leptos-viz-images-{}", std::process::id()));
+    std::fs::create_dir_all(&root).unwrap();
+    image::RgbImage::new(200, 100)
+        .save(root.join("cat.png"))
+        .unwrap();
+    LeptosOptions::builder()
+        .output_name("app")
+        .site_root(root.to_str().unwrap())
+        .build()
+}
+
+fn request(path: &str) -> Request {
+    Request::builder().uri(path).body(Body::empty()).unwrap()
+}
+
+#[tokio::test]
+async fn images_are_resized_by_the_handler() {
+    let handler = image_handler(options());
+
+    let res = handler(request("/_image?url=%2Fcat.png&w=50&q=75"))
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers()["content-type"], "image/png");
+    assert!(res.headers().contains_key("cache-control"));
+    let body = to_bytes(res.into_body()).await.unwrap();
+    assert_eq!(image::load_from_memory(&body).unwrap().width(), 50);
+}
+
+#[tokio::test]
+async fn bad_image_requests_are_rejected() {
+    let handler = image_handler(options());
+
+    for (uri, status) in [
+        ("/_image?url=%2Fcat.png&w=0&q=75", StatusCode::BAD_REQUEST),
+        (
+            "/_image?url=%2F..%2Fcat.png&w=50&q=75",
+            StatusCode::NOT_FOUND,
+        ),
+    ] {
+        let res = handler(request(uri)).await.unwrap();
+        assert_eq!(res.status(), status, "{uri}");
+    }
+}
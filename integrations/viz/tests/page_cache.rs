@@ -0,0 +1,66 @@
+//! The cache itself is tested in `leptos_integration_utils`: this checks that the middleware
+//! uses it for Viz requests and responses.
+
+use hyper::body::to_bytes;
+use leptos_viz::{cache_pages, MemoryPageStore, PageCache};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+use viz::{
+    header, Body, HandlerExt, Request, Response, ResponseExt, StatusCode,
+};
+
+#[tokio::test]
+async fn stored_pages_are_revalidated_without_rendering() {
+    let renders = Arc::new(AtomicUsize::new(0));
+    let middleware =
+        cache_pages(PageCache::new().with_store(MemoryPageStore::new()));
+    let send = |req: Request| {
+        let renders = Arc::clone(&renders);
+        let page = move |_: Request| {
+            let renders = Arc::clone(&renders);
+            async move {
+                let count = renders.fetch_add(1, Ordering::SeqCst) + 1;
+                Ok(Response::html(format!("<p>Rendered {count} times</p>")))
+            }
+        };
+        let res = middleware((req, page.boxed()));
+        async move {
+            let res = res.await.unwrap();
+            let status = res.status();
+            let etag = res
+                .headers()
+                .get(header::ETAG)
+                .map(|etag| etag.to_str().unwrap().to_string());
+            let body = to_bytes(res.into_body()).await.unwrap();
+            (status, etag, String::from_utf8(body.to_vec()).unwrap())
+        }
+    };
+
+    let req = Request::builder().uri("/").body(Body::empty()).unwrap();
+    let (status, etag, body) = send(req).await;
+    assert_eq!(
+        (status, body.as_str()),
+        (StatusCode::OK, "<p>Rendered 1 times</p>")
+    );
+    let etag = etag.unwrap();
+    assert_eq!(etag, leptos_viz::content_etag(body.as_bytes()));
+
+    let req = Request::builder()
+        .uri("/")
+        .header(header::IF_NONE_MATCH, &etag)
+        .body(Body::empty())
+        .unwrap();
+    let (status, _, body) = send(req).await;
+    if cfg!(feature = "nonce") {
+        // pages rendered with a nonce are not stored
+        assert_eq!(
+            (status, body.as_str()),
+            (StatusCode::OK, "<p>Rendered 2 times</p>")
+        );
+    } else {
+        assert_eq!((status, body.as_str()), (StatusCode::NOT_MODIFIED, ""));
+        assert_eq!(renders.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,68 @@
+//! Micro-benchmarks for signal propagation.
+//!
+//! Run with `cargo bench -p leptos --bench reactive`. To check a change for
+//! regressions, save a baseline before making it with
+//! `-- --save-baseline main`, then compare against it with
+//! `-- --baseline main`.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use leptos::*;
+
+const WIDTH: usize = 1_000;
+const DEPTH: usize = 100;
+
+fn create_and_dispose(c: &mut Criterion) {
+    c.bench_function("create_and_dispose_signals", |b| {
+        b.iter(|| {
+            let runtime = create_runtime();
+            for n in 0..WIDTH {
+                let (value, _) = create_signal(n);
+                _ = create_memo(move |_| value.get() * 2);
+            }
+            runtime.dispose();
+        })
+    });
+}
+
+fn deep_memo_chain(c: &mut Criterion) {
+    let runtime = create_runtime();
+    let (source, set_source) = create_signal(0);
+    let mut last = create_memo(move |_| source.get());
+    for _ in 0..DEPTH {
+        let prev = last;
+        last = create_memo(move |_| prev.get() + 1);
+    }
+    create_isomorphic_effect(move |_| last.get());
+
+    c.bench_function("deep_memo_chain", |b| {
+        b.iter(|| set_source.update(|n| *n += 1))
+    });
+    runtime.dispose();
+}
+
+fn wide_fan_out(c: &mut Criterion) {
+    let runtime = create_runtime();
+    let (source, set_source) = create_signal(0);
+    for n in 0..WIDTH {
+        let memo = create_memo(move |_| source.get() + n);
+        create_isomorphic_effect(move |_| memo.get());
+    }
+
+    let mut group = c.benchmark_group("wide_fan_out");
+    group.bench_function("unbatched", |b| {
+        b.iter(|| set_source.update(|n| *n += 1))
+    });
+    group.bench_function("batched", |b| {
+        b.iter(|| {
+            batch(|| {
+                set_source.update(|n| *n += 1);
+                set_source.update(|n| *n += 1);
+            })
+        })
+    });
+    group.finish();
+    runtime.dispose();
+}
+
+criterion_group!(benches, create_and_dispose, deep_memo_chain, wide_fan_out);
+criterion_main!(benches);
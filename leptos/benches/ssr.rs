@@ -0,0 +1,144 @@
+//! Renders representative pages on the server, with both
+//! [`render_to_string`](leptos::ssr::render_to_string) and the streaming
+//! renderers.
+//!
+//! Run with `cargo bench -p leptos --features ssr --bench ssr`. To check a
+//! change for regressions, save a baseline before making it with
+//! `-- --save-baseline main`, then compare against it with
+//! `-- --baseline main`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use futures::{Stream, StreamExt};
+use leptos::*;
+
+const ROWS: usize = 1_000;
+const DEPTH: usize = 64;
+const ELEMENTS: usize = 200;
+
+#[component]
+fn Row(id: usize, label: String) -> impl IntoView {
+    let (selected, _) = create_signal(id % 7 == 1);
+    view! {
+        <tr class:selected=selected>
+            <td class="col-md-1">{id}</td>
+            <td class="col-md-4"><a href=format!("/rows/{id}")>{label}</a></td>
+            <td class="col-md-1"><button aria-label="Remove">"×"</button></td>
+        </tr>
+    }
+}
+
+fn large_list() -> impl IntoView {
+    let (rows, _) = create_signal(
+        (0..ROWS)
+            .map(|id| (id, format!("row number {id}")))
+            .collect::<Vec<_>>(),
+    );
+    view! {
+        <table class="table table-hover">
+            <tbody>
+                <For
+                    each=move || rows.get()
+                    key=|(id, _)| *id
+                    children=|(id, label)| view! { <Row id label/> }
+                />
+            </tbody>
+        </table>
+    }
+}
+
+#[component]
+fn Nested(depth: usize) -> impl IntoView {
+    if depth == 0 {
+        view! { <span>"leaf"</span> }.into_view()
+    } else {
+        view! {
+            <div class="level" data-depth=depth>
+                <Nested depth=depth - 1/>
+            </div>
+        }
+        .into_view()
+    }
+}
+
+fn deep_nesting() -> impl IntoView {
+    view! { <Nested depth=DEPTH/> }
+}
+
+fn many_attributes() -> impl IntoView {
+    let (value, _) = create_signal(String::from("dynamic"));
+    (0..ELEMENTS)
+        .map(|n| {
+            view! {
+                <input
+                    id=format!("input-{n}")
+                    name="field"
+                    type="text"
+                    class="form-control input-lg"
+                    placeholder="Type here"
+                    autocomplete="off"
+                    spellcheck="false"
+                    required
+                    maxlength="120"
+                    data-index=n
+                    data-group="inputs"
+                    aria-label="Field"
+                    aria-describedby="help"
+                    title=move || value.get()
+                    value=move || value.get()
+                />
+            }
+        })
+        .collect_view()
+}
+
+type Page = (&'static str, fn() -> View);
+
+fn pages() -> [Page; 3] {
+    [
+        ("large_list", || large_list().into_view()),
+        ("deep_nesting", || deep_nesting().into_view()),
+        ("many_attributes", || many_attributes().into_view()),
+    ]
+}
+
+// the streaming renderers spawn tasks with `tokio::task::spawn_local`, so
+// they need to start rendering inside a `LocalSet`
+fn collect_stream<S>(render: impl FnOnce() -> S) -> String
+where
+    S: Stream<Item = String>,
+{
+    thread_local! {
+        static RUNTIME: tokio::runtime::Runtime =
+            tokio::runtime::Builder::new_current_thread().build().unwrap();
+    }
+    RUNTIME.with(|rt| {
+        tokio::task::LocalSet::new()
+            .block_on(rt, async { render().collect().await })
+    })
+}
+
+fn render_to_string(c: &mut Criterion) {
+    let mut group = c.benchmark_group("render_to_string");
+    for (name, page) in pages() {
+        group.bench_function(BenchmarkId::from_parameter(name), |b| {
+            b.iter(|| ssr::render_to_string(page))
+        });
+    }
+    group.finish();
+}
+
+fn render_to_stream(c: &mut Criterion) {
+    let mut group = c.benchmark_group("render_to_stream");
+    for (name, page) in pages() {
+        group.bench_function(BenchmarkId::new("out_of_order", name), |b| {
+            b.iter(|| collect_stream(|| ssr::render_to_stream(page)))
+        });
+        group.bench_function(BenchmarkId::new("in_order", name), |b| {
+            b.iter(|| collect_stream(|| ssr::render_to_stream_in_order(page)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, render_to_string, render_to_stream);
+criterion_main!(benches);
@@ -0,0 +1,217 @@
+use core::time::Duration;
+use leptos::component;
+use leptos_dom::{helpers::TimeoutHandle, Each, IntoView};
+use leptos_macro::view;
+use leptos_reactive::{
+    create_render_effect, create_rw_signal, on_cleanup, signal_prelude::*,
+    with_owner, Owner,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+/// Iterates over children and displays them, keyed by the `key` function given, like
+/// [`For`](crate::For). Additionally, each row is wrapped in a `<div>` that can be animated
+/// in and out.
+///
+/// When a row is added, its `<div>` is given the `enter_class`. When it is removed, it is
+/// given the `leave_class` instead, and stays where it was until an animation or transition
+/// of the `<div>` ends, or until the `leave_delay` has passed, whichever comes first. If it
+/// is added back in the meantime, it is kept, and given the `enter_class` again.
+///
+/// ```rust
+/// # use core::time::Duration;
+/// # use leptos::*;
+/// # #[component]
+/// # pub fn App() -> impl IntoView {
+/// let (todos, set_todos) = create_signal(vec!["Write docs", "Ship it"]);
+///
+/// view! {
+///     <AnimatedFor
+///         each=move || todos.get()
+///         key=|todo| *todo
+///         enter_class="slide-in"
+///         leave_class="slide-out"
+///         leave_delay=Duration::from_millis(500)
+///         let:todo
+///     >
+///         <button on:click=move |_| set_todos.update(|todos| todos.retain(|t| *t != todo))>
+///             {todo}
+///         </button>
+///     </AnimatedFor>
+/// }
+/// # }
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "info", skip_all)
+)]
+#[component]
+pub fn AnimatedFor<IF, I, T, EF, N, KF, K>(
+    /// Items over which the component should iterate.
+    each: IF,
+    /// A key function that will be applied to each item.
+    key: KF,
+    /// A function that takes the item, and returns the view that will be displayed for each item.
+    children: EF,
+    /// Optional CSS class to apply to the rows that are shown
+    #[prop(optional)]
+    enter_class: &'static str,
+    /// Optional CSS class to apply to the rows that have been removed, until they are unmounted
+    #[prop(optional)]
+    leave_class: &'static str,
+    /// The timeout after which a removed row will be unmounted, if no animation or
+    /// transition has ended before
+    leave_delay: Duration,
+) -> impl IntoView
+where
+    IF: Fn() -> I + 'static,
+    I: IntoIterator<Item = T>,
+    EF: Fn(T) -> N + 'static,
+    N: IntoView + 'static,
+    KF: Fn(&T) -> K + 'static,
+    K: Eq + Hash + Clone + 'static,
+    T: Clone + 'static,
+{
+    let owner = Owner::current()
+        .expect("<AnimatedFor/> created with no reactive owner");
+    // the class of each row is owned by the component, rather than by the effect below,
+    // so that it outlives the next run of the effect
+    let new_row = move |key: K, item: T| Row {
+        key,
+        item,
+        class: with_owner(owner, || create_rw_signal(enter_class)),
+        leaving: false,
+        timeout: None,
+    };
+
+    let rows = create_rw_signal(
+        each()
+            .into_iter()
+            .map(|item| new_row(key(&item), item))
+            .collect::<Vec<_>>(),
+    );
+
+    let unmount = move |key: &K| {
+        let index = rows.with_untracked(|rows| {
+            rows.iter().position(|row| row.leaving && row.key == *key)
+        });
+        if let Some(index) = index {
+            let mut row = None;
+            rows.update(|rows| row = Some(rows.remove(index)));
+            if let Some(row) = row {
+                if let Some(timeout) = row.timeout {
+                    timeout.clear();
+                }
+                row.class.dispose();
+            }
+        }
+    };
+
+    create_render_effect(move |prev: Option<()>| {
+        let items = each().into_iter().collect::<Vec<_>>();
+        // the rows have already been built from the same items
+        if prev.is_none() {
+            return;
+        }
+
+        let keys = items.iter().map(&key).collect::<HashSet<_>>();
+        let mut kept = HashMap::new();
+        // removed rows stay after the closest row before them that is kept
+        let mut removed = HashMap::<Option<K>, Vec<Row<K, T>>>::new();
+        let mut anchor = None;
+        for mut row in rows
+            .try_update_untracked(std::mem::take)
+            .unwrap_or_default()
+        {
+            if keys.contains(&row.key) {
+                anchor = Some(row.key.clone());
+                kept.insert(row.key.clone(), row);
+            } else {
+                if !row.leaving {
+                    row.leaving = true;
+                    row.class.set(leave_class);
+                    let key = row.key.clone();
+                    row.timeout = Some(
+                        leptos_dom::helpers::set_timeout_with_handle(
+                            move || unmount(&key),
+                            leave_delay,
+                        )
+                        .expect("set timeout in AnimatedFor"),
+                    );
+                }
+                removed.entry(anchor.clone()).or_default().push(row);
+            }
+        }
+
+        let mut next = removed.remove(&None).unwrap_or_default();
+        for item in items {
+            let key = key(&item);
+            let row = match kept.remove(&key) {
+                Some(mut row) => {
+                    if row.leaving {
+                        row.leaving = false;
+                        if let Some(timeout) = row.timeout.take() {
+                            timeout.clear();
+                        }
+                        row.class.set(enter_class);
+                    }
+                    row.item = item;
+                    row
+                }
+                None => new_row(key.clone(), item),
+            };
+            next.push(row);
+            next.extend(removed.remove(&Some(key)).unwrap_or_default());
+        }
+        rows.set(next);
+    });
+
+    on_cleanup(move || {
+        rows.try_with_untracked(|rows| {
+            for timeout in rows.iter().filter_map(|row| row.timeout) {
+                timeout.clear();
+            }
+        });
+    });
+
+    Each::new(
+        move || {
+            rows.with(|rows| {
+                rows.iter()
+                    .map(|row| (row.key.clone(), row.item.clone(), row.class))
+                    .collect::<Vec<_>>()
+            })
+        },
+        |(key, _, _)| key.clone(),
+        move |(key, item, class)| {
+            let on_end = move |ev: &web_sys::Event| {
+                // animations of the children bubble up, but shouldn't unmount the row. The
+                // listeners are not delegated, so that the current target is the row
+                if ev.target() == ev.current_target() {
+                    unmount(&key)
+                }
+            };
+            let on_transition_end = on_end.clone();
+            view! {
+                <div
+                    class=move || class.get()
+                    on:animationend:undelegated=move |ev| on_end(&ev)
+                    on:transitionend:undelegated=move |ev| on_transition_end(&ev)
+                >
+                    {children(item)}
+                </div>
+            }
+        },
+    )
+    .into_view()
+}
+
+struct Row<K, T> {
+    key: K,
+    item: T,
+    class: RwSignal<&'static str>,
+    leaving: bool,
+    timeout: Option<TimeoutHandle>,
+}
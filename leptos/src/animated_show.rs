@@ -10,7 +10,8 @@ use leptos_reactive::{
 
 /// A component that will show its children when the `when` condition is `true`.
 /// Additionally, you need to specify a `hide_delay`. If the `when` condition changes to `false`,
-/// the unmounting of the children will be delayed by the specified Duration.
+/// the unmounting of the children will be delayed by the specified Duration, or until an
+/// animation or transition of the wrapping `<div>` ends, whichever comes first.
 /// If you provide the optional `show_class` and `hide_class`, you can create very easy mount /
 /// unmount animations.
 ///
@@ -60,7 +61,8 @@ pub fn AnimatedShow(
     /// Optional CSS class to apply if `when == false`
     #[prop(optional)]
     hide_class: &'static str,
-    /// The timeout after which the component will be unmounted if `when == false`
+    /// The timeout after which the component will be unmounted if `when == false`, if no
+    /// animation or transition has ended before
     hide_delay: Duration,
 ) -> impl IntoView {
     let handle: StoredValue<Option<TimeoutHandle>> = store_value(None);
@@ -92,6 +94,15 @@ pub fn AnimatedShow(
         }
     });
 
+    let hide_now = move || {
+        if !when.get_untracked() {
+            if let Some(h) = handle.get_value() {
+                h.clear();
+            }
+            show.set(false);
+        }
+    };
+
     on_cleanup(move || {
         if let Some(Some(h)) = handle.try_get_value() {
             h.clear();
@@ -100,7 +111,23 @@ pub fn AnimatedShow(
 
     view! {
         <Show when=move || show.get() fallback=|| ()>
-            <div class=move || cls.get()>{children()}</div>
+            <div
+                class=move || cls.get()
+                // animations of the children bubble up, but shouldn't unmount them. The
+                // listeners are not delegated, so that the current target is the wrapper
+                on:animationend:undelegated=move |ev| {
+                    if ev.target() == ev.current_target() {
+                        hide_now()
+                    }
+                }
+                on:transitionend:undelegated=move |ev| {
+                    if ev.target() == ev.current_target() {
+                        hide_now()
+                    }
+                }
+            >
+                {children()}
+            </div>
         </Show>
     }
 }
@@ -0,0 +1,129 @@
+use crate::Children;
+use leptos_dom::IntoView;
+use leptos_macro::{component, view};
+use leptos_reactive::{
+    create_rw_signal, provide_context, run_as_child, signal_prelude::*,
+    use_context,
+};
+
+/// Hides the live regions visually, while keeping them in the accessibility tree.
+const VISUALLY_HIDDEN: &str = "position:absolute;width:1px;height:1px;margin:\
+                               -1px;padding:0;overflow:hidden;clip:rect(0,0,0,\
+                               0);white-space:nowrap;border:0";
+
+/// How urgently assistive technology should read out an announcement.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Politeness {
+    /// The announcement is read once the user is idle, like a saved draft or the title
+    /// of a new page.
+    #[default]
+    Polite,
+    /// The announcement interrupts whatever is being read, like an error that stops the
+    /// user from going on.
+    Assertive,
+}
+
+/// Announces messages to assistive technology, through the live regions of the closest
+/// [`LiveRegion`]. Returned by [`use_announcer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Announcer {
+    polite: RwSignal<String>,
+    assertive: RwSignal<String>,
+}
+
+impl Announcer {
+    /// Reads `message` out with the given [`Politeness`]. The same message can be
+    /// announced several times in a row.
+    pub fn announce(&self, message: impl Into<String>, politeness: Politeness) {
+        let mut message = message.into();
+        let region = match politeness {
+            Politeness::Polite => self.polite,
+            Politeness::Assertive => self.assertive,
+        };
+        region.update(|current| {
+            // screen readers only read out changes, so a repeated message is told apart
+            // from the previous one
+            if *current == message {
+                message.push('\u{a0}');
+            }
+            *current = message;
+        });
+    }
+}
+
+/// Returns the [`Announcer`] of the closest [`LiveRegion`], to announce async updates
+/// like toasts, validation errors or route changes to assistive technology.
+///
+/// ```rust
+/// # use leptos::*;
+/// #[component]
+/// fn SaveButton() -> impl IntoView {
+///     let announcer = use_announcer();
+///     let save = create_action(|_: &()| async {});
+///     create_effect(move |_| {
+///         if save.version().get() > 0 {
+///             announcer
+///                 .announce("Your changes were saved", Politeness::Polite);
+///         }
+///     });
+///     view! { <button on:click=move |_| save.dispatch(())>"Save"</button> }
+/// }
+/// ```
+///
+/// # Panics
+/// Panics if it is not called inside a [`LiveRegion`].
+#[track_caller]
+pub fn use_announcer() -> Announcer {
+    use_context::<Announcer>()
+        .expect("use_announcer() should be called inside a <LiveRegion/>")
+}
+
+/// Renders a polite and an assertive live region after its children, which can announce
+/// messages to assistive technology with [`use_announcer`].
+///
+/// It should usually wrap the whole app, so that the regions are in the page before
+/// anything is announced: screen readers ignore live regions that are added along with
+/// their content. The regions are hidden visually.
+///
+/// ```rust
+/// # use leptos::*;
+/// # #[component]
+/// # fn Form() -> impl IntoView {}
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///         <LiveRegion>
+///             <Form/>
+///         </LiveRegion>
+///     }
+/// }
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "info", skip_all)
+)]
+#[component]
+pub fn LiveRegion(
+    /// The children can announce messages with [`use_announcer`].
+    children: Children,
+) -> impl IntoView {
+    let announcer = Announcer {
+        polite: create_rw_signal(String::new()),
+        assertive: create_rw_signal(String::new()),
+    };
+    let Announcer { polite, assertive } = announcer;
+    let children = run_as_child(move || {
+        provide_context(announcer);
+        children()
+    });
+
+    view! {
+        {children}
+        <div style=VISUALLY_HIDDEN role="status" aria-live="polite" aria-atomic="true">
+            {move || polite.get()}
+        </div>
+        <div style=VISUALLY_HIDDEN role="alert" aria-live="assertive" aria-atomic="true">
+            {move || assertive.get()}
+        </div>
+    }
+}
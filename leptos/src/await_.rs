@@ -3,8 +3,9 @@ use leptos_dom::IntoView;
 use leptos_macro::{component, view};
 use leptos_reactive::{
     create_blocking_resource, create_local_resource, create_resource,
-    store_value, Serializable,
+    store_value, Owner, ScopedFuture, Serializable, SignalGet,
 };
+use std::{cell::Cell, future::Future};
 
 #[component]
 /// Allows you to inline the data loading for an `async` block or
@@ -120,3 +121,38 @@ where
         </Suspense>
     }
 }
+
+/// Renders the view returned by the body of an `async` [`component`], once it
+/// has resolved.
+///
+/// The body runs in a [local resource](create_local_resource), so it only
+/// runs in the browser, and while it is pending, the component renders
+/// nothing and the nearest `<Suspense/>` shows its fallback. It keeps access
+/// to the context of the component across `.await`s.
+#[doc(hidden)]
+pub fn async_component<V>(
+    body: impl Future<Output = V> + 'static,
+) -> impl IntoView
+where
+    V: IntoView,
+{
+    let owner = Owner::current();
+    let body = Cell::new(Some(body));
+    let view = create_local_resource(
+        || (),
+        move |_| {
+            let body = body.take();
+            async move {
+                let body = async move { Some(body?.await.into_view()) };
+                match owner {
+                    // resolves to `None` if the component has been unmounted
+                    Some(owner) => {
+                        ScopedFuture::new(owner, body).await.flatten()
+                    }
+                    None => body.await,
+                }
+            }
+        },
+    );
+    move || view.get().flatten()
+}
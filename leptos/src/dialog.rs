@@ -0,0 +1,204 @@
+use crate::{AttributeValue, Children};
+use cfg_if::cfg_if;
+use leptos_dom::{create_node_ref, html, IntoView};
+use leptos_macro::{component, view};
+use leptos_reactive::{signal_prelude::*, Oco};
+
+/// A headless dialog, built on the native
+/// [`<dialog>`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/dialog) element.
+///
+/// Setting `open` to `true` shows the dialog with `showModal()`, which blocks the rest of
+/// the page, traps the focus and shows a `::backdrop`. When the user closes it, with the
+/// Escape key or a `<form method="dialog">`, `open` is set back to `false`. Either way, the
+/// focus goes back to the element that had it when the dialog was opened.
+///
+/// During server rendering, a dialog that is open is rendered with the `open` attribute,
+/// so that it is shown, though not as a modal, before the page is hydrated or without
+/// JavaScript.
+///
+/// ```rust
+/// # use leptos::*;
+/// #[component]
+/// fn DeleteButton() -> impl IntoView {
+///     let confirming = create_rw_signal(false);
+///     view! {
+///         <button on:click=move |_| confirming.set(true)>"Delete"</button>
+///         <Dialog open=confirming class="confirm">
+///             <p>"Are you sure?"</p>
+///             <form method="dialog">
+///                 <button>"Cancel"</button>
+///             </form>
+///         </Dialog>
+///     }
+/// }
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "info", skip_all)
+)]
+#[component]
+pub fn Dialog(
+    /// Whether the dialog is shown. It is set to `false` when the user closes the dialog.
+    open: RwSignal<bool>,
+    /// If `true`, the dialog is shown with `show()` rather than `showModal()`, so that the
+    /// rest of the page can still be used. Defaults to `false`.
+    #[prop(optional)]
+    non_modal: bool,
+    /// Sets the `class` attribute of the `<dialog>`.
+    #[prop(optional, into)]
+    class: Option<AttributeValue>,
+    /// The content of the dialog.
+    children: Children,
+) -> impl IntoView {
+    let node_ref = create_node_ref::<html::Dialog>();
+
+    cfg_if! { if #[cfg(all(target_arch = "wasm32", any(feature = "hydrate", feature = "csr")))] {
+        use leptos_dom::document;
+        use leptos_reactive::{create_effect, store_value};
+        use wasm_bindgen::JsCast;
+
+        let previous_focus = store_value(None::<web_sys::Element>);
+        create_effect(move |_| {
+            let Some(dialog) = node_ref.get() else {
+                return;
+            };
+            if open.get() {
+                if dialog.open() {
+                    // a dialog that was rendered open on the server isn't modal yet
+                    if non_modal || dialog.matches(":modal").unwrap_or(true) {
+                        return;
+                    }
+                    _ = dialog.remove_attribute("open");
+                }
+                previous_focus.set_value(document().active_element());
+                if non_modal {
+                    dialog.show();
+                } else if dialog.show_modal().is_err() {
+                    dialog.set_open(true);
+                }
+            } else if dialog.open() {
+                dialog.close();
+            }
+        });
+        let restore_focus = move || {
+            let element = previous_focus.try_update_value(Option::take).flatten();
+            if let Some(element) = element.and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok()) {
+                _ = element.focus();
+            }
+        };
+        let rendered_open = false;
+    } else {
+        let _ = (node_ref, non_modal);
+        let restore_focus = || {};
+        let rendered_open = open.get_untracked();
+    }}
+
+    view! {
+        <dialog
+            node_ref=node_ref
+            class=class
+            open=rendered_open
+            on:close=move |_| {
+                open.set(false);
+                restore_focus();
+            }
+        >
+            {children()}
+        </dialog>
+    }
+}
+
+/// A headless popover, built on the
+/// [Popover API](https://developer.mozilla.org/en-US/docs/Web/API/Popover_API).
+///
+/// The popover is a `<div>` with the `popover` attribute and the given `id`, so that
+/// buttons with a matching `popovertarget` toggle it, even before the page is hydrated.
+/// It is shown above the rest of the page, and closed when the user clicks outside of it
+/// or presses the Escape key, unless it is `manual`.
+///
+/// `open` follows the state of the popover, and setting it shows or hides the popover. In
+/// browsers that don't support the Popover API, the popover is shown inline and hidden
+/// with the `hidden` attribute instead.
+///
+/// ```rust
+/// # use leptos::*;
+/// #[component]
+/// fn Menu() -> impl IntoView {
+///     let open = create_rw_signal(false);
+///     view! {
+///         <button popovertarget="menu">"Menu"</button>
+///         <Popover id="menu" open=open>
+///             <a href="/settings" on:click=move |_| open.set(false)>"Settings"</a>
+///         </Popover>
+///     }
+/// }
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "info", skip_all)
+)]
+#[component]
+pub fn Popover(
+    /// The `id` of the popover, which the `popovertarget` of the buttons that toggle it
+    /// refers to.
+    #[prop(into)]
+    id: Oco<'static, str>,
+    /// Whether the popover is shown.
+    open: RwSignal<bool>,
+    /// If `true`, the popover is only closed by `popovertarget` buttons or by setting `open`,
+    /// and not when the user clicks outside of it. Defaults to `false`.
+    #[prop(optional)]
+    manual: bool,
+    /// Sets the `class` attribute of the popover.
+    #[prop(optional, into)]
+    class: Option<AttributeValue>,
+    /// The content of the popover.
+    children: Children,
+) -> impl IntoView {
+    let node_ref = create_node_ref::<html::Div>();
+
+    cfg_if! { if #[cfg(all(target_arch = "wasm32", any(feature = "hydrate", feature = "csr")))] {
+        use leptos_reactive::create_effect;
+
+        // the `popover` property is only defined by browsers that support the Popover API
+        let is_supported =
+            |popover: &web_sys::HtmlElement| popover.popover().is_some();
+        let is_open = |popover: &web_sys::HtmlElement| {
+            popover.matches(":popover-open").unwrap_or(false)
+        };
+
+        create_effect(move |_| {
+            let Some(popover) = node_ref.get() else {
+                return;
+            };
+            let open = open.get();
+            if !is_supported(&popover) {
+                popover.set_hidden(!open);
+            } else if open && !is_open(&popover) {
+                _ = popover.show_popover();
+            } else if !open && is_open(&popover) {
+                _ = popover.hide_popover();
+            }
+        });
+        let on_toggle = move || {
+            if let Some(popover) = node_ref.get_untracked() {
+                open.set(is_open(&popover));
+            }
+        };
+    } else {
+        let _ = (node_ref, open);
+        let on_toggle = || {};
+    }}
+
+    view! {
+        <div
+            node_ref=node_ref
+            id=id
+            class=class
+            popover=if manual { "manual" } else { "auto" }
+            on:toggle=move |_| on_toggle()
+        >
+            {children()}
+        </div>
+    }
+}
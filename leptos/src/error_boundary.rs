@@ -11,6 +11,11 @@ use leptos_reactive::{
 /// This component lets you define a fallback that should be rendered in that
 /// error case, allowing you to handle errors within a section of the interface.
 ///
+/// Errors are collected in a reactive [`Errors`] map that is passed to the
+/// fallback, and removed again once the `Result` that produced them is `Ok`.
+/// During server rendering, the fallback is rendered in place of the children,
+/// which are still sent, hidden, so that the page can be hydrated.
+///
 /// ```
 /// # use leptos_reactive::*;
 /// # use leptos_macro::*;
@@ -0,0 +1,458 @@
+//! Builds [RSS 2.0](https://www.rssboard.org/rss-specification) and
+//! [Atom](https://www.rfc-editor.org/rfc/rfc4287) feeds.
+//!
+//! The content of each entry can be written as a view, using the same components as the pages
+//! of the site, and is rendered to HTML without any hydration markers. Everything else is a
+//! plain string, which is escaped when the feed is rendered.
+//!
+//! ```
+//! # cfg_if::cfg_if! { if #[cfg(not(any(feature = "csr", feature = "hydrate")))] {
+//! use leptos::{feed::*, *};
+//! use std::time::{Duration, SystemTime};
+//!
+//! #[component]
+//! fn Excerpt(text: &'static str) -> impl IntoView {
+//!     view! { <p class="excerpt">{text}</p> }
+//! }
+//!
+//! let published = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+//! let feed = Feed::new("My Blog", "https://example.com/")
+//!     .description("Posts about Rust & the web")
+//!     .entry(
+//!         FeedEntry::new("Hello", "https://example.com/posts/hello")
+//!             .published(published)
+//!             .content(|| view! { <Excerpt text="Hello, world!"/> }),
+//!     );
+//!
+//! let rss = feed.render(FeedFormat::Rss);
+//! assert!(rss.contains("<title>My Blog</title>"));
+//! assert!(rss.contains("<description>Posts about Rust &amp; the web</description>"));
+//! assert!(rss.contains("<pubDate>Tue, 14 Nov 2023 22:13:20 GMT</pubDate>"));
+//! assert!(rss.contains("&lt;p class=&quot;excerpt&quot;&gt;Hello, world!&lt;/p&gt;"));
+//!
+//! let atom = feed.render(FeedFormat::Atom);
+//! assert!(atom.contains("<published>2023-11-14T22:13:20Z</published>"));
+//! # }}
+//! ```
+
+use crate::{ssr::render_to_string_clean, IntoView};
+use std::{
+    fmt::Write,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// The format a [`Feed`] is rendered in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FeedFormat {
+    /// [RSS 2.0](https://www.rssboard.org/rss-specification)
+    Rss,
+    /// [Atom](https://www.rfc-editor.org/rfc/rfc4287)
+    Atom,
+}
+
+impl FeedFormat {
+    /// The value of the `Content-Type` header to serve the feed with.
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Rss => "application/rss+xml; charset=utf-8",
+            Self::Atom => "application/atom+xml; charset=utf-8",
+        }
+    }
+}
+
+/// A feed of the latest entries of a site, such as a blog.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Feed {
+    title: String,
+    link: String,
+    feed_url: Option<String>,
+    description: Option<String>,
+    language: Option<String>,
+    author: Option<String>,
+    updated: Option<SystemTime>,
+    entries: Vec<FeedEntry>,
+}
+
+impl Feed {
+    /// Creates a feed with the title of the site and the URL of its home page.
+    pub fn new(title: impl Into<String>, link: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            link: link.into(),
+            feed_url: None,
+            description: None,
+            language: None,
+            author: None,
+            updated: None,
+            entries: Vec::new(),
+        }
+    }
+
+    /// The URL the feed itself is served from, which feed readers use to
+    /// find it again.
+    pub fn feed_url(mut self, feed_url: impl Into<String>) -> Self {
+        self.feed_url = Some(feed_url.into());
+        self
+    }
+
+    /// A short description of the site.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// The language of the entries, like `en-us`.
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// The name of the author of the entries.
+    pub fn author(mut self, author: impl Into<String>) -> Self {
+        self.author = Some(author.into());
+        self
+    }
+
+    /// When the feed last changed. Defaults to the latest time any entry was
+    /// updated or published.
+    pub fn updated(mut self, updated: SystemTime) -> Self {
+        self.updated = Some(updated);
+        self
+    }
+
+    /// Adds an entry to the end of the feed.
+    pub fn entry(mut self, entry: FeedEntry) -> Self {
+        self.entries.push(entry);
+        self
+    }
+
+    /// Adds several entries to the end of the feed.
+    pub fn entries(
+        mut self,
+        entries: impl IntoIterator<Item = FeedEntry>,
+    ) -> Self {
+        self.entries.extend(entries);
+        self
+    }
+
+    /// Renders the feed as an XML document.
+    pub fn render(&self, format: FeedFormat) -> String {
+        match format {
+            FeedFormat::Rss => self.to_rss(),
+            FeedFormat::Atom => self.to_atom(),
+        }
+    }
+
+    fn last_updated(&self) -> SystemTime {
+        self.updated
+            .or_else(|| {
+                self.entries
+                    .iter()
+                    .filter_map(FeedEntry::last_updated)
+                    .max()
+            })
+            .unwrap_or_else(SystemTime::now)
+    }
+
+    fn to_rss(&self) -> String {
+        let mut xml = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<rss version=\"2.0\" \
+             xmlns:atom=\"http://www.w3.org/2005/Atom\" \
+             xmlns:dc=\"http://purl.org/dc/elements/1.1/\" \
+             xmlns:content=\"http://purl.org/rss/1.0/modules/content/\">\n\
+             <channel>\n",
+        );
+        element(&mut xml, "title", &self.title);
+        element(&mut xml, "link", &self.link);
+        element(
+            &mut xml,
+            "description",
+            self.description.as_deref().unwrap_or_default(),
+        );
+        if let Some(feed_url) = &self.feed_url {
+            xml.push_str("<atom:link href=\"");
+            escape(&mut xml, feed_url);
+            xml.push_str("\" rel=\"self\" type=\"application/rss+xml\"/>\n");
+        }
+        if let Some(language) = &self.language {
+            element(&mut xml, "language", language);
+        }
+        element(&mut xml, "lastBuildDate", &rfc_2822(self.last_updated()));
+        for entry in &self.entries {
+            xml.push_str("<item>\n");
+            element(&mut xml, "title", &entry.title);
+            element(&mut xml, "link", &entry.link);
+            xml.push_str("<guid isPermaLink=\"");
+            xml.push_str(if entry.id.is_some() { "false" } else { "true" });
+            xml.push_str("\">");
+            escape(&mut xml, entry.guid());
+            xml.push_str("</guid>\n");
+            if let Some(published) = entry.published.or(entry.updated) {
+                element(&mut xml, "pubDate", &rfc_2822(published));
+            }
+            if let Some(author) = entry.author.as_ref().or(self.author.as_ref())
+            {
+                element(&mut xml, "dc:creator", author);
+            }
+            if let Some(summary) =
+                entry.summary.as_ref().or(entry.content.as_ref())
+            {
+                element(&mut xml, "description", summary);
+            }
+            if let Some(content) = &entry.content {
+                element(&mut xml, "content:encoded", content);
+            }
+            xml.push_str("</item>\n");
+        }
+        xml.push_str("</channel>\n</rss>\n");
+        xml
+    }
+
+    fn to_atom(&self) -> String {
+        let mut xml = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<feed \
+             xmlns=\"http://www.w3.org/2005/Atom\"",
+        );
+        if let Some(language) = &self.language {
+            xml.push_str(" xml:lang=\"");
+            escape(&mut xml, language);
+            xml.push('"');
+        }
+        xml.push_str(">\n");
+        element(&mut xml, "title", &self.title);
+        element(&mut xml, "id", self.feed_url.as_ref().unwrap_or(&self.link));
+        link(&mut xml, &self.link, None);
+        if let Some(feed_url) = &self.feed_url {
+            link(&mut xml, feed_url, Some("self"));
+        }
+        if let Some(description) = &self.description {
+            element(&mut xml, "subtitle", description);
+        }
+        element(&mut xml, "updated", &rfc_3339(self.last_updated()));
+        if let Some(author) = &self.author {
+            xml.push_str("<author>");
+            element(&mut xml, "name", author);
+            xml.push_str("</author>\n");
+        }
+        for entry in &self.entries {
+            xml.push_str("<entry>\n");
+            element(&mut xml, "title", &entry.title);
+            element(&mut xml, "id", entry.guid());
+            link(&mut xml, &entry.link, None);
+            let updated =
+                entry.last_updated().unwrap_or_else(|| self.last_updated());
+            element(&mut xml, "updated", &rfc_3339(updated));
+            if let Some(published) = entry.published {
+                element(&mut xml, "published", &rfc_3339(published));
+            }
+            if let Some(author) = &entry.author {
+                xml.push_str("<author>");
+                element(&mut xml, "name", author);
+                xml.push_str("</author>\n");
+            }
+            if let Some(summary) = &entry.summary {
+                element(&mut xml, "summary", summary);
+            }
+            if let Some(content) = &entry.content {
+                xml.push_str("<content type=\"html\">");
+                escape(&mut xml, content);
+                xml.push_str("</content>\n");
+            }
+            xml.push_str("</entry>\n");
+        }
+        xml.push_str("</feed>\n");
+        xml
+    }
+}
+
+/// An entry in a [`Feed`], such as a blog post.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeedEntry {
+    title: String,
+    link: String,
+    id: Option<String>,
+    published: Option<SystemTime>,
+    updated: Option<SystemTime>,
+    author: Option<String>,
+    summary: Option<String>,
+    content: Option<String>,
+}
+
+impl FeedEntry {
+    /// Creates an entry with its title and the URL of its page.
+    pub fn new(title: impl Into<String>, link: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            link: link.into(),
+            id: None,
+            published: None,
+            updated: None,
+            author: None,
+            summary: None,
+            content: None,
+        }
+    }
+
+    /// A unique identifier of the entry, which does not change even if its
+    /// URL does. Defaults to the link.
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// When the entry was first published.
+    pub fn published(mut self, published: SystemTime) -> Self {
+        self.published = Some(published);
+        self
+    }
+
+    /// When the entry last changed.
+    pub fn updated(mut self, updated: SystemTime) -> Self {
+        self.updated = Some(updated);
+        self
+    }
+
+    /// The name of the author of the entry, if it differs from the author of
+    /// the feed.
+    pub fn author(mut self, author: impl Into<String>) -> Self {
+        self.author = Some(author.into());
+        self
+    }
+
+    /// A short plain-text summary of the entry.
+    pub fn summary(mut self, summary: impl Into<String>) -> Self {
+        self.summary = Some(summary.into());
+        self
+    }
+
+    /// The content of the entry, which is rendered to HTML right away, in a
+    /// reactive runtime of its own.
+    pub fn content<F, N>(mut self, content: F) -> Self
+    where
+        F: FnOnce() -> N + 'static,
+        N: IntoView,
+    {
+        self.content = Some(render_to_string_clean(content).into_owned());
+        self
+    }
+
+    fn guid(&self) -> &str {
+        self.id.as_deref().unwrap_or(&self.link)
+    }
+
+    fn last_updated(&self) -> Option<SystemTime> {
+        self.updated.or(self.published)
+    }
+}
+
+fn element(xml: &mut String, name: &str, text: &str) {
+    _ = write!(xml, "<{name}>");
+    escape(xml, text);
+    _ = writeln!(xml, "</{name}>");
+}
+
+fn link(xml: &mut String, href: &str, rel: Option<&str>) {
+    xml.push_str("<link href=\"");
+    escape(xml, href);
+    xml.push('"');
+    if let Some(rel) = rel {
+        _ = write!(xml, " rel=\"{rel}\"");
+    }
+    xml.push_str("/>\n");
+}
+
+fn escape(xml: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => xml.push_str("&amp;"),
+            '<' => xml.push_str("&lt;"),
+            '>' => xml.push_str("&gt;"),
+            '"' => xml.push_str("&quot;"),
+            '\'' => xml.push_str("&apos;"),
+            c => xml.push(c),
+        }
+    }
+}
+
+/// The date and time in UTC, split into its parts.
+struct DateTime {
+    year: i64,
+    month: usize,
+    day: i64,
+    weekday: usize,
+    hour: u64,
+    minute: u64,
+    second: u64,
+}
+
+impl DateTime {
+    fn new(time: SystemTime) -> Self {
+        let secs = time
+            .duration_since(UNIX_EPOCH)
+            .map(|since| since.as_secs())
+            .unwrap_or_default();
+        let days = (secs / 86_400) as i64;
+        let secs_of_day = secs % 86_400;
+
+        // converts days since the epoch into a date of the proleptic Gregorian calendar, see
+        // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + i64::from(month <= 2);
+
+        Self {
+            year,
+            month: month as usize,
+            day,
+            // the epoch was a Thursday
+            weekday: ((days + 4) % 7) as usize,
+            hour: secs_of_day / 3600,
+            minute: secs_of_day % 3600 / 60,
+            second: secs_of_day % 60,
+        }
+    }
+}
+
+/// Formats a time as in RSS, like `Tue, 14 Nov 2023 22:13:20 GMT`.
+fn rfc_2822(time: SystemTime) -> String {
+    const WEEKDAYS: [&str; 7] =
+        ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct",
+        "Nov", "Dec",
+    ];
+    let DateTime {
+        year,
+        month,
+        day,
+        weekday,
+        hour,
+        minute,
+        second,
+    } = DateTime::new(time);
+    format!(
+        "{}, {day:02} {} {year} {hour:02}:{minute:02}:{second:02} GMT",
+        WEEKDAYS[weekday],
+        MONTHS[month - 1]
+    )
+}
+
+/// Formats a time as in Atom, like `2023-11-14T22:13:20Z`.
+fn rfc_3339(time: SystemTime) -> String {
+    let DateTime {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+        ..
+    } = DateTime::new(time);
+    format!("{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}Z")
+}
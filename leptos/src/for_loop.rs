@@ -99,6 +99,11 @@ pub fn For<IF, I, T, EF, N, KF, K>(
     /// # }
     /// ```
     children: EF,
+    /// If `true`, rows rendered on the server are only hydrated once they
+    /// scroll into view, instead of all at once when the page loads. This can
+    /// greatly reduce the time to interactive for very long lists.
+    #[prop(optional)]
+    hydrate_lazily: bool,
 ) -> impl IntoView
 where
     IF: Fn() -> I + 'static,
@@ -109,5 +114,7 @@ where
     K: Eq + Hash + 'static,
     T: 'static,
 {
-    leptos_dom::Each::new(each, key, children).into_view()
+    leptos_dom::Each::new(each, key, children)
+        .hydrate_lazily(hydrate_lazily)
+        .into_view()
 }
@@ -0,0 +1,259 @@
+use cfg_if::cfg_if;
+use leptos_dom::{html::ElementDescriptor, NodeRef};
+use leptos_reactive::{create_rw_signal, Signal};
+
+/// The state of a drag gesture on an element, returned by [`use_drag`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Drag {
+    /// Whether a pointer is being dragged.
+    pub active: Signal<bool>,
+    /// How far the pointer has moved since the drag started, in CSS pixels. It keeps its
+    /// value once the drag has ended, until the next one starts.
+    pub delta: Signal<(f64, f64)>,
+    /// How fast the pointer was moving at its last move, in CSS pixels per millisecond,
+    /// which tells a swipe from a slow drag once the drag has ended.
+    pub velocity: Signal<(f64, f64)>,
+}
+
+/// The state of a pinch gesture on an element, returned by [`use_pinch`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pinch {
+    /// Whether two pointers are pinching.
+    pub active: Signal<bool>,
+    /// The distance between the two pointers, relative to their distance when the pinch
+    /// started. It keeps its value once the pinch has ended, until the next one starts.
+    pub scale: Signal<f64>,
+    /// How fast the scale was changing at the last move, per millisecond.
+    pub velocity: Signal<f64>,
+}
+
+/// Tracks a pointer that is dragged on the element `target` refers to.
+///
+/// A drag starts when a pointer is pressed on the element, which then captures it, so
+/// that the drag goes on when the pointer leaves the element. It ends when the pointer is
+/// released or the browser cancels it. The element should usually have the CSS
+/// `touch-action: none`, so that dragging it with a finger doesn't scroll the page.
+///
+/// The listeners are removed when the current reactive owner is disposed. On the server,
+/// this does nothing.
+///
+/// ```rust
+/// # use leptos::*;
+/// #[component]
+/// fn SwipeToDismiss(children: Children) -> impl IntoView {
+///     let card = create_node_ref::<html::Div>();
+///     let drag = use_drag(card);
+///     let dismissed =
+///         move || !drag.active.get() && drag.velocity.get().0.abs() > 0.5;
+///     view! {
+///         <div
+///             node_ref=card
+///             style:touch-action="none"
+///             style:translate=move || format!("{}px", drag.delta.get().0)
+///             class:dismissed=dismissed
+///         >
+///             {children()}
+///         </div>
+///     }
+/// }
+/// ```
+pub fn use_drag<T>(target: NodeRef<T>) -> Drag
+where
+    T: ElementDescriptor + Clone + 'static,
+{
+    let active = create_rw_signal(false);
+    let delta = create_rw_signal((0.0, 0.0));
+    let velocity = create_rw_signal((0.0, 0.0));
+
+    cfg_if! { if #[cfg(all(target_arch = "wasm32", any(feature = "hydrate", feature = "csr")))] {
+        use leptos_reactive::SignalSet;
+        use std::cell::Cell;
+
+        // the dragged pointer, where it started, and its last position and time
+        let state = Cell::new(None::<(i32, (f64, f64), (f64, f64), f64)>);
+        use_pointer_events(target, move |element, ev| {
+            let id = ev.pointer_id();
+            let position = (ev.client_x() as f64, ev.client_y() as f64);
+            let time = ev.time_stamp();
+            match (ev.type_().as_str(), state.get()) {
+                ("pointerdown", None) => {
+                    _ = element.set_pointer_capture(id);
+                    state.set(Some((id, position, position, time)));
+                    delta.set((0.0, 0.0));
+                    velocity.set((0.0, 0.0));
+                    active.set(true);
+                }
+                ("pointermove", Some((dragged, start, last, last_time))) if dragged == id => {
+                    let elapsed = time - last_time;
+                    if elapsed > 0.0 {
+                        velocity.set((
+                            (position.0 - last.0) / elapsed,
+                            (position.1 - last.1) / elapsed,
+                        ));
+                    }
+                    delta.set((position.0 - start.0, position.1 - start.1));
+                    state.set(Some((id, start, position, time)));
+                }
+                ("pointerup" | "pointercancel", Some((dragged, ..))) if dragged == id => {
+                    state.set(None);
+                    active.set(false);
+                }
+                _ => {}
+            }
+        });
+    } else {
+        let _ = target;
+    }}
+
+    Drag {
+        active: active.into(),
+        delta: delta.into(),
+        velocity: velocity.into(),
+    }
+}
+
+/// Tracks two pointers that pinch the element `target` refers to, like two fingers
+/// zooming an image.
+///
+/// A pinch starts when a second pointer is pressed on the element, and ends when either of
+/// them is released. The element captures the pointers, and should usually have the CSS
+/// `touch-action: none`, so that the browser doesn't zoom the page instead.
+///
+/// The listeners are removed when the current reactive owner is disposed. On the server,
+/// this does nothing.
+///
+/// ```rust
+/// # use leptos::*;
+/// #[component]
+/// fn ZoomableImage(src: &'static str) -> impl IntoView {
+///     let image = create_node_ref::<html::Img>();
+///     let pinch = use_pinch(image);
+///     view! {
+///         <img
+///             node_ref=image
+///             src=src
+///             style:touch-action="none"
+///             style:scale=move || pinch.scale.get().to_string()
+///         />
+///     }
+/// }
+/// ```
+pub fn use_pinch<T>(target: NodeRef<T>) -> Pinch
+where
+    T: ElementDescriptor + Clone + 'static,
+{
+    let active = create_rw_signal(false);
+    let scale = create_rw_signal(1.0);
+    let velocity = create_rw_signal(0.0);
+
+    cfg_if! { if #[cfg(all(target_arch = "wasm32", any(feature = "hydrate", feature = "csr")))] {
+        use leptos_reactive::SignalSet;
+        use std::cell::{Cell, RefCell};
+
+        let pointers = RefCell::new(Vec::<(i32, (f64, f64))>::new());
+        // the distance between the pointers when the pinch started, and the last scale
+        // and time
+        let state = Cell::new(None::<(f64, f64, f64)>);
+        let distance = |pointers: &[(i32, (f64, f64))]| {
+            let [(_, a), (_, b)] = pointers else {
+                return None;
+            };
+            Some((a.0 - b.0).hypot(a.1 - b.1)).filter(|distance| *distance > 0.0)
+        };
+        use_pointer_events(target, move |element, ev| {
+            let id = ev.pointer_id();
+            let position = (ev.client_x() as f64, ev.client_y() as f64);
+            let time = ev.time_stamp();
+            let mut pointers = pointers.borrow_mut();
+            match ev.type_().as_str() {
+                "pointerdown" if pointers.len() < 2 => {
+                    _ = element.set_pointer_capture(id);
+                    pointers.push((id, position));
+                    if let Some(start) = distance(&pointers) {
+                        state.set(Some((start, 1.0, time)));
+                        scale.set(1.0);
+                        velocity.set(0.0);
+                        active.set(true);
+                    }
+                }
+                "pointermove" => {
+                    let Some(pointer) = pointers.iter_mut().find(|(p, _)| *p == id) else {
+                        return;
+                    };
+                    pointer.1 = position;
+                    if let (Some((start, last, last_time)), Some(current)) =
+                        (state.get(), distance(&pointers))
+                    {
+                        let current = current / start;
+                        let elapsed = time - last_time;
+                        if elapsed > 0.0 {
+                            velocity.set((current - last) / elapsed);
+                        }
+                        scale.set(current);
+                        state.set(Some((start, current, time)));
+                    }
+                }
+                "pointerup" | "pointercancel" => {
+                    pointers.retain(|(p, _)| *p != id);
+                    if state.take().is_some() {
+                        active.set(false);
+                    }
+                }
+                _ => {}
+            }
+        });
+    } else {
+        let _ = target;
+    }}
+
+    Pinch {
+        active: active.into(),
+        scale: scale.into(),
+        velocity: velocity.into(),
+    }
+}
+
+/// Calls `on_event` with the pointer events of the element `target` refers to, until the
+/// current reactive owner is disposed.
+#[cfg(all(target_arch = "wasm32", any(feature = "hydrate", feature = "csr")))]
+fn use_pointer_events<T>(
+    target: NodeRef<T>,
+    on_event: impl Fn(&web_sys::HtmlElement, web_sys::PointerEvent) + 'static,
+) where
+    T: ElementDescriptor + Clone + 'static,
+{
+    use leptos_reactive::{create_effect, on_cleanup};
+    use std::rc::Rc;
+    use wasm_bindgen::{closure::Closure, JsCast};
+
+    const EVENTS: [&str; 4] =
+        ["pointerdown", "pointermove", "pointerup", "pointercancel"];
+
+    let on_event = Rc::new(on_event);
+    create_effect(move |_| {
+        let Some(element) = target.get() else {
+            return;
+        };
+        let element = (*element.into_any()).clone();
+        let listener = Closure::<dyn Fn(web_sys::PointerEvent)>::new({
+            let element = element.clone();
+            let on_event = Rc::clone(&on_event);
+            move |ev| on_event(&element, ev)
+        })
+        .into_js_value();
+        for event in EVENTS {
+            _ = element.add_event_listener_with_callback(
+                event,
+                listener.unchecked_ref(),
+            );
+        }
+        on_cleanup(move || {
+            for event in EVENTS {
+                _ = element.remove_event_listener_with_callback(
+                    event,
+                    listener.unchecked_ref(),
+                );
+            }
+        });
+    });
+}
@@ -77,6 +77,8 @@
 //!
 //! # Feature Flags
 //! - `nightly`: On `nightly` Rust, enables the function-call syntax for signal getters and setters.
+//!   On stable, `.get()` and [`.into_fn()`](SignalIntoFn::into_fn) cover the same uses, and they also
+//!   compile with `nightly`, so code can move between the two styles gradually.
 //! - `csr` Client-side rendering: Generate DOM nodes in the browser
 //! - `ssr` Server-side rendering: Generate an HTML string (typically on the server)
 //! - `hydrate` Hydration: use this to add interactivity to an SSRed Leptos app
@@ -88,14 +90,34 @@
 //!   from the server to the client.
 //! - `miniserde` In SSR/hydrate mode, uses [`miniserde`](https://docs.rs/miniserde/latest/miniserde/) to serialize resources and send them
 //!   from the server to the client.
+//! - `bincode` In SSR/hydrate mode, uses [`bincode`](https://docs.rs/bincode/latest/bincode/) to serialize resources into a
+//!   compact, base64-encoded binary payload, which is smaller and faster to decode than JSON for large resources.
+//!   Uses the same `serde` traits as the default `serde` feature.
 //! - `tracing` Adds additional support for [`tracing`](https://docs.rs/tracing/latest/tracing/) to components.
+//! - `alloc-profiling` Counts heap allocations through a wrapping global allocator, and reports how many
+//!   were made each time effects are flushed. See the `alloc_profiling` module.
+//! - `server-fns` (*Default*) Enables [server functions](macro@server), with the `#[server]` macro,
+//!   [`create_server_action`], and [`create_server_multi_action`]. Without it, `#[server]` fails to compile with
+//!   an error naming this feature. Actions created with [`create_action`] are always available.
 //! - `default-tls` Use default native TLS support. (Only applies when using server functions with a non-WASM client like a desktop app.)
 //! - `rustls` Use `rustls`. (Only applies when using server functions with a non-WASM client like a desktop app.)
 //! - `template_macro` Enables the [`template!`](leptos_macro::template) macro, which offers faster DOM node creation for some use cases in `csr`.
+//! - `slim` Removes console logging, including the messages of warnings and errors and the code that formats them,
+//!   to reduce the size of a WASM binary. See [Optimizing WASM Binary Size](https://leptos-rs.github.io/leptos/deployment/binary_size.html).
+//! - `interactive-ssr` Adds [`ssr::render_interactive`], which renders a view on the server that can still respond
+//!   to events, so that states reached through interaction can be tested or rendered to HTML. This makes the
+//!   `view` macro generate more code on the server.
+//! - `compact-hydration-keys` Writes hydration keys and marker comments in a shorter format, to reduce the
+//!   size of server-rendered pages. It must be enabled for both the server and the client, so that the
+//!   client can find the markers the server wrote. [`ssr::hydration_marker_report`] measures how much
+//!   the markers add to a page.
 //!
 //! **Important Note:** You must enable one of `csr`, `hydrate`, or `ssr` to tell Leptos
 //! which mode your app is operating in. You should only enable one of these per build target,
 //! i.e., you should not have both `hydrate` and `ssr` enabled for your server binary, only `ssr`.
+//! Building an app for the browser that mounts with more than one of them, or with neither `csr`
+//! nor `hydrate`, is a compile error. To include code only in one mode, mark it with
+//! [`#[cfg_csr]`](cfg_csr), [`#[cfg_hydrate]`](cfg_hydrate), or [`#[cfg_ssr]`](cfg_ssr).
 //!
 //! # A Simple Counter
 //!
@@ -152,10 +174,19 @@ pub use leptos_config::{self, get_configuration, LeptosOptions};
 )))]
 /// Utilities for server-side rendering HTML.
 pub mod ssr {
+    #[cfg(feature = "interactive-ssr")]
+    pub use leptos_dom::ssr_interactive::{
+        render_interactive, InteractiveView, JsOnlyInteraction,
+    };
     pub use leptos_dom::{ssr::*, ssr_in_order::*};
 }
+#[cfg(not(all(
+    target_arch = "wasm32",
+    any(feature = "csr", feature = "hydrate")
+)))]
+pub mod feed;
 pub use leptos_dom::{
-    self, create_node_ref, document, ev,
+    self, cached_view, create_node_ref, document, ev,
     helpers::{
         event_target, event_target_checked, event_target_value,
         request_animation_frame, request_animation_frame_with_handle,
@@ -163,9 +194,10 @@ pub use leptos_dom::{
         set_interval_with_handle, set_timeout, set_timeout_with_handle,
         window_event_listener, window_event_listener_untyped,
     },
-    html, math, mount_to, mount_to_body, nonce, svg, window, Attribute, Class,
-    CollectView, Errors, Fragment, HtmlElement, IntoAttribute, IntoClass,
-    IntoProperty, IntoStyle, IntoView, NodeRef, Property, View,
+    html, math, merge_classes, merge_styles, mount_to, mount_to_body, nonce,
+    svg, window, Attribute, AttributeMap, Class, ClassProp, CollectView,
+    Errors, Fragment, FragmentCache, HtmlElement, IntoAttribute, IntoClass,
+    IntoProperty, IntoStyle, IntoView, NodeRef, Property, StyleProp, View,
 };
 /// Utilities for simple isomorphic logging to the console or terminal.
 pub mod logging {
@@ -180,21 +212,32 @@ pub mod error {
 pub use leptos_macro::template;
 #[cfg(not(all(target_arch = "wasm32", feature = "template_macro")))]
 pub use leptos_macro::view as template;
-pub use leptos_macro::{component, island, server, slice, slot, view, Params};
+pub use leptos_macro::{
+    cfg_csr, cfg_hydrate, cfg_ssr, component, island, server, slice, slot,
+    view, Params,
+};
 pub use leptos_reactive::*;
 pub use leptos_server::{
-    self, create_action, create_multi_action, create_server_action,
-    create_server_multi_action, Action, MultiAction, ServerFn, ServerFnError,
+    self, create_action, create_multi_action, Action, MultiAction,
+};
+#[cfg(feature = "server-fns")]
+pub use leptos_server::{
+    create_server_action, create_server_multi_action, ServerFn, ServerFnError,
     ServerFnErrorErr,
 };
+#[cfg(feature = "server-fns")]
 pub use server_fn::{self, ServerFn as _};
 mod error_boundary;
 pub use error_boundary::*;
+mod animated_for;
 mod animated_show;
+mod announcer;
 mod for_loop;
 mod provider;
 mod show;
+pub use animated_for::*;
 pub use animated_show::*;
+pub use announcer::*;
 pub use for_loop::*;
 pub use provider::*;
 #[cfg(feature = "experimental-islands")]
@@ -232,10 +275,20 @@ pub use wasm_bindgen; // used in islands
 pub use web_sys; // used in islands
 
 mod children;
+mod dialog;
+mod gesture;
+mod load_progress;
 mod portal;
+mod print;
+mod tween;
 mod view_fn;
 pub use children::*;
+pub use dialog::*;
+pub use gesture::*;
+pub use load_progress::*;
 pub use portal::*;
+pub use print::*;
+pub use tween::*;
 pub use view_fn::*;
 
 extern crate self as leptos;
@@ -266,6 +319,57 @@ pub trait Props {
     fn builder() -> Self::Builder;
 }
 
+/// Describes the props of a component, so that documentation sites and editor
+/// plugins can list them without parsing its source. The [`component`] macro
+/// implements it for the props struct of every component.
+///
+/// ```
+/// use leptos::*;
+///
+/// #[component]
+/// fn Button(
+///     /// The text of the button.
+///     label: String,
+///     #[prop(optional)] disabled: bool,
+/// ) -> impl IntoView {
+///     view! { <button disabled=disabled>{label}</button> }
+/// }
+///
+/// assert_eq!(ButtonProps::COMPONENT, "Button");
+/// let [label, disabled] = ButtonProps::PROPS else {
+///     panic!()
+/// };
+/// assert_eq!(label.docs, "The text of the button.");
+/// assert!(!label.optional);
+/// assert_eq!(disabled.ty, "bool");
+/// assert!(disabled.optional);
+/// ```
+pub trait PropsMetadata {
+    /// The name of the component.
+    const COMPONENT: &'static str;
+    /// The props of the component, in the order they are declared.
+    const PROPS: &'static [PropMetadata];
+}
+
+/// A prop of a component, as described by [`PropsMetadata`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PropMetadata {
+    /// The name of the prop.
+    pub name: &'static str,
+    /// The type of the values that are passed to the prop, as written in the
+    /// component. For `#[prop(optional)]` props of type `Option<T>`, it is `T`.
+    pub ty: &'static str,
+    /// Whether the prop can be left out.
+    pub optional: bool,
+    /// The source of the `#[prop(default = ...)]` expression, if there is one.
+    pub default: Option<&'static str>,
+    /// Whether the prop takes anything that converts into its type, because of
+    /// `#[prop(into)]`.
+    pub into: bool,
+    /// The doc comments of the prop.
+    pub docs: &'static str,
+}
+
 #[doc(hidden)]
 pub trait DynAttrs {
     fn dyn_attrs(self, _args: Vec<(&'static str, Attribute)>) -> Self
@@ -274,6 +378,12 @@ pub trait DynAttrs {
     {
         self
     }
+
+    /// Whether these props take `attr:` bindings with `#[prop(attrs)]`. If
+    /// not, the bindings are added to the root element of the component.
+    fn accepts_dyn_attrs(&self) -> bool {
+        false
+    }
 }
 
 impl DynAttrs for () {}
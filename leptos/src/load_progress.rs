@@ -0,0 +1,91 @@
+use leptos_dom::helpers::window_event_listener_untyped;
+use leptos_reactive::{create_rw_signal, on_cleanup, signal_prelude::*};
+
+/// How far the browser has got with loading the app's WASM bundle and hydrating the
+/// server-rendered page, as reported by the scripts that the server integrations add to
+/// every page.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LoadProgress {
+    /// How many bytes of the WASM bundle have been downloaded.
+    pub loaded: u64,
+    /// The size of the WASM bundle in bytes, or `0` if it is unknown, because the server
+    /// didn't send a `Content-Length` or compressed the bundle.
+    pub total: u64,
+    /// Whether the WASM bundle has been compiled and instantiated.
+    pub instantiated: bool,
+    /// Whether the page has been hydrated.
+    pub hydrated: bool,
+}
+
+impl LoadProgress {
+    /// The fraction of the WASM bundle downloaded so far, from `0.0` to `1.0`, or `None` if
+    /// its size is unknown.
+    pub fn fraction(&self) -> Option<f64> {
+        (self.total > 0)
+            .then(|| (self.loaded as f64 / self.total as f64).min(1.0))
+    }
+}
+
+/// Returns a signal that tracks the [`LoadProgress`] of the app, which changes to
+/// `hydrated` once hydration has finished, so that a loading indicator rendered on the
+/// server can fade out.
+///
+/// Before the WASM bundle has been instantiated, no Rust code runs, so the download itself
+/// can only be shown by the server-rendered page, with the `--leptos-wasm-progress` CSS
+/// variable or the `leptos:wasm-progress` event. On the server, and in apps whose pages are
+/// not rendered by a server integration, the signal stays at its default, with nothing
+/// loaded.
+///
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// let progress = use_load_progress();
+/// let class = move || {
+///     if progress.get().hydrated {
+///         "loading-bar done"
+///     } else {
+///         "loading-bar"
+///     }
+/// };
+/// # assert_eq!(class(), "loading-bar");
+/// # runtime.dispose();
+/// ```
+pub fn use_load_progress() -> Signal<LoadProgress> {
+    let progress = create_rw_signal(reported_progress());
+    for event in ["leptos:wasm-instantiated", "leptos:hydrated"] {
+        let handle = window_event_listener_untyped(event, move |_| {
+            progress.set(reported_progress());
+        });
+        on_cleanup(move || handle.remove());
+    }
+    progress.into()
+}
+
+/// The progress recorded so far by the bootstrap script.
+fn reported_progress() -> LoadProgress {
+    cfg_if::cfg_if! {
+        if #[cfg(all(target_arch = "wasm32", any(feature = "hydrate", feature = "csr")))] {
+            use wasm_bindgen::JsValue;
+            use web_sys::js_sys;
+
+            let Ok(reported) = js_sys::Reflect::get(
+                &leptos_dom::window(),
+                &JsValue::from_str("__LEPTOS_LOAD_PROGRESS"),
+            ) else {
+                return LoadProgress::default();
+            };
+            let field = |name: &str| {
+                js_sys::Reflect::get(&reported, &JsValue::from_str(name))
+                    .unwrap_or(JsValue::UNDEFINED)
+            };
+            LoadProgress {
+                loaded: field("loaded").as_f64().unwrap_or_default() as u64,
+                total: field("total").as_f64().unwrap_or_default() as u64,
+                instantiated: field("instantiated").is_truthy(),
+                hydrated: field("hydrated").is_truthy(),
+            }
+        } else {
+            LoadProgress::default()
+        }
+    }
+}
@@ -2,6 +2,7 @@ use crate::ChildrenFn;
 use cfg_if::cfg_if;
 use leptos_dom::IntoView;
 use leptos_macro::component;
+use leptos_reactive::Callback;
 
 /// Renders components somewhere else in the DOM.
 ///
@@ -9,6 +10,27 @@ use leptos_macro::component;
 /// If no mount point is given, the portal is inserted in `document.body`;
 /// it is wrapped in a `<div>` unless  `is_svg` is `true` in which case it's wrappend in a `<g>`.
 /// Setting `use_shadow` to `true` places the element in a shadow root to isolate styles.
+///
+/// The children are removed from the mount point when the portal is disposed. They are
+/// only rendered in the browser: during server rendering, a portal renders nothing, and its
+/// children are created once the page has been hydrated.
+///
+/// ```rust
+/// # use leptos::*;
+/// #[component]
+/// fn ConfirmDialog(on_confirm: Callback<()>) -> impl IntoView {
+///     view! {
+///         <Portal>
+///             <div class="modal-backdrop">
+///                 <div class="modal" role="dialog">
+///                     <p>"Are you sure?"</p>
+///                     <button on:click=move |_| on_confirm.call(())>"Yes"</button>
+///                 </div>
+///             </div>
+///         </Portal>
+///     }
+/// }
+/// ```
 #[cfg_attr(
     any(debug_assertions, feature = "ssr"),
     tracing::instrument(level = "info", skip_all)
@@ -72,3 +94,111 @@ pub fn Portal(
         let _ = children;
     }}
 }
+
+/// Renders components into the document of another window, like a print preview or a
+/// dashboard opened on another monitor with
+/// [`window.open()`](https://developer.mozilla.org/en-US/docs/Web/API/Window/open).
+///
+/// The children are appended to the other window's `<body>`, wrapped in a `<div>`, and keep
+/// running in this app: they can read its signals and contexts, and their event handlers work
+/// as usual. They are removed when the portal is, and disposed if the user closes the window
+/// first. The other window does not get this document's stylesheets.
+///
+/// ```rust
+/// # use leptos::*;
+/// #[component]
+/// fn PrintPreview(window: web_sys::Window) -> impl IntoView {
+///     let (page, set_page) = create_signal(1);
+///     view! {
+///         <WindowPortal window=window close_on_cleanup=true>
+///             <p>"Page " {page}</p>
+///             <button on:click=move |_| set_page.update(|n| *n += 1)>"Next"</button>
+///         </WindowPortal>
+///     }
+/// }
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "info", skip_all)
+)]
+#[component]
+pub fn WindowPortal(
+    /// The window to render the children in.
+    window: web_sys::Window,
+    /// Whether to close the window when the portal is removed. Defaults to `false`.
+    #[prop(optional)]
+    close_on_cleanup: bool,
+    /// Called when the user closes the window, after the children have been disposed.
+    #[prop(optional, into)]
+    on_close: Option<Callback<()>>,
+    /// The children to render in the window
+    children: ChildrenFn,
+) -> impl IntoView {
+    cfg_if! { if #[cfg(all(target_arch = "wasm32", any(feature = "hydrate", feature = "csr")))] {
+        use leptos_dom::{
+            delegate_events_in, document, stop_delegating_events_in, Mountable,
+        };
+        use leptos_reactive::{
+            as_child_of_current_owner, create_effect, on_cleanup, Callable,
+        };
+        use std::{cell::RefCell, rc::Rc};
+        use wasm_bindgen::{closure::Closure, JsCast};
+
+        create_effect(move |_| {
+            let Some(body) = window.document().and_then(|doc| doc.body()) else {
+                return;
+            };
+            delegate_events_in(&window);
+
+            // nodes are created in this document, and adopted by the other one
+            // when they are inserted into it
+            let container = document()
+                .create_element("div")
+                .expect("element creation to work");
+            let (view, disposer) = as_child_of_current_owner(|children: ChildrenFn| {
+                children().into_view()
+            })(children.clone());
+            let _ = container.append_child(&view.get_mountable_node());
+            let _ = body.append_child(&container);
+
+            let disposer = Rc::new(RefCell::new(Some(disposer)));
+            let on_pagehide = Closure::<dyn Fn()>::new({
+                let disposer = Rc::clone(&disposer);
+                move || {
+                    if let Some(disposer) = disposer.take() {
+                        drop(disposer);
+                        if let Some(on_close) = on_close {
+                            on_close.call(());
+                        }
+                    }
+                }
+            })
+            .into_js_value();
+            let _ = window.add_event_listener_with_callback(
+                "pagehide",
+                on_pagehide.unchecked_ref(),
+            );
+
+            on_cleanup({
+                let window = window.clone();
+                move || {
+                    let _ = window.remove_event_listener_with_callback(
+                        "pagehide",
+                        on_pagehide.unchecked_ref(),
+                    );
+                    stop_delegating_events_in(&window);
+                    container.remove();
+                    drop(disposer.take());
+                    if close_on_cleanup {
+                        let _ = window.close();
+                    }
+                }
+            })
+        });
+    } else {
+        let _ = window;
+        let _ = close_on_cleanup;
+        let _ = on_close;
+        let _ = children;
+    }}
+}
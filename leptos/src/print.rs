@@ -0,0 +1,104 @@
+use crate::{ChildrenFn, Show};
+use leptos_dom::{ev, helpers::window_event_listener, IntoView};
+use leptos_macro::{component, view};
+use leptos_reactive::{create_signal, on_cleanup, signal_prelude::*};
+
+/// Runs `f` when the user starts printing the page, before the browser lays it out
+/// for print. Any DOM changes it makes, directly or through signals, are printed.
+///
+/// The listener is removed when the current reactive owner is disposed. On the server,
+/// this does nothing.
+pub fn use_before_print(f: impl Fn() + 'static) {
+    let handle = window_event_listener(ev::beforeprint, move |_| f());
+    on_cleanup(move || handle.remove());
+}
+
+/// Runs `f` once the page has been printed, or printing has been cancelled.
+///
+/// The listener is removed when the current reactive owner is disposed. On the server,
+/// this does nothing.
+pub fn use_after_print(f: impl Fn() + 'static) {
+    let handle = window_event_listener(ev::afterprint, move |_| f());
+    on_cleanup(move || handle.remove());
+}
+
+/// Returns a signal that is `true` while the page is being printed, between the
+/// `beforeprint` and `afterprint` events. It is always `false` on the server.
+///
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// let printing = use_printing();
+/// let title = move || {
+///     if printing.get() {
+///         "Quarterly report (printed)"
+///     } else {
+///         "Quarterly report"
+///     }
+/// };
+/// # assert_eq!(title(), "Quarterly report");
+/// # runtime.dispose();
+/// ```
+pub fn use_printing() -> Signal<bool> {
+    let (printing, set_printing) = create_signal(false);
+    use_before_print(move || set_printing.set(true));
+    use_after_print(move || set_printing.set(false));
+    printing.into()
+}
+
+/// Renders its children only while the page is being printed, like a letterhead or
+/// the full text of a collapsed section.
+///
+/// Nothing is rendered on the server.
+///
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// view! {
+///   <ScreenOnly>
+///     <button on:click=|_| _ = window().print()>"Print"</button>
+///   </ScreenOnly>
+///   <PrintOnly>
+///     <p>"Printed from example.com"</p>
+///   </PrintOnly>
+/// }
+/// # ;
+/// # runtime.dispose();
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "info", skip_all)
+)]
+#[component]
+pub fn PrintOnly(
+    /// The children will be shown while the page is being printed.
+    children: ChildrenFn,
+) -> impl IntoView {
+    let printing = use_printing();
+    view! {
+        <Show when=move || printing.get()>
+            {children()}
+        </Show>
+    }
+}
+
+/// Renders its children except while the page is being printed, like navigation or
+/// buttons that make no sense on paper.
+///
+/// See [`PrintOnly`] for an example.
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "info", skip_all)
+)]
+#[component]
+pub fn ScreenOnly(
+    /// The children will be shown unless the page is being printed.
+    children: ChildrenFn,
+) -> impl IntoView {
+    let printing = use_printing();
+    view! {
+        <Show when=move || !printing.get()>
+            {children()}
+        </Show>
+    }
+}
@@ -161,6 +161,9 @@ where
                     else {
                         HydrationCtx::continue_from(current_id);
                         let runtime = leptos_reactive::current_runtime();
+                        // the fragments are rendered after the render call has
+                        // returned, so they take its quoting with them
+                        let quoting = leptos_dom::ssr::attribute_quoting();
 
                         SharedContext::register_suspense(
                             context,
@@ -185,14 +188,20 @@ where
                                             HydrationCtx::continue_from(
                                                 current_id,
                                             );
-                                            DynChild::new({
-                                                move || {
-                                                    orig_children().into_view()
-                                                }
-                                            })
-                                            .into_view()
-                                            .render_to_string()
-                                            .to_string()
+                                            leptos_dom::ssr::with_attribute_quoting(
+                                                quoting,
+                                                || {
+                                                    DynChild::new({
+                                                        move || {
+                                                            orig_children()
+                                                                .into_view()
+                                                        }
+                                                    })
+                                                    .into_view()
+                                                    .render_to_string()
+                                                    .to_string()
+                                                },
+                                            )
                                         }
                                     })
                                 }
@@ -217,13 +226,19 @@ where
                                             HydrationCtx::continue_from(
                                                 current_id,
                                             );
-                                            DynChild::new({
-                                                move || {
-                                                    orig_children().into_view()
-                                                }
-                                            })
-                                            .into_view()
-                                            .into_stream_chunks()
+                                            leptos_dom::ssr::with_attribute_quoting(
+                                                quoting,
+                                                || {
+                                                    DynChild::new({
+                                                        move || {
+                                                            orig_children()
+                                                                .into_view()
+                                                        }
+                                                    })
+                                                    .into_view()
+                                                    .into_stream_chunks()
+                                                },
+                                            )
                                         }
                                     })
                                 }
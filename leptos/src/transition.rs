@@ -14,6 +14,8 @@ use std::{
 /// component, it will show the `fallback` while they are loading. Once all are resolved,
 /// it will render the `children`. Unlike [`Suspense`](crate::Suspense), this will not fall
 /// back to the `fallback` state if there are further changes after the initial load.
+/// Instead, it keeps showing the previous `children` while the resources reload, and
+/// calls `set_pending` so that the page can show that it is loading in some other way.
 ///
 /// Note that the `children` will be rendered initially (in order to capture the fact that
 /// those resources are read under the suspense), so you cannot assume that resources have
@@ -0,0 +1,250 @@
+use cfg_if::cfg_if;
+use core::time::Duration;
+use leptos_reactive::{
+    create_rw_signal, signal_prelude::*, IntoSignalSetter, Signal, SignalSetter,
+};
+
+/// Easing functions for [`create_tweened_signal`], which map the progress of a tween,
+/// from `0.0` to `1.0`, to how far its value has moved from the start to the target.
+pub mod easing {
+    /// Moves at a constant speed.
+    pub fn linear(t: f64) -> f64 {
+        t
+    }
+
+    /// Starts slowly, and speeds up until the end.
+    pub fn ease_in_cubic(t: f64) -> f64 {
+        t * t * t
+    }
+
+    /// Starts quickly, and slows down until the end.
+    pub fn ease_out_cubic(t: f64) -> f64 {
+        1.0 - (1.0 - t).powi(3)
+    }
+
+    /// Starts slowly, speeds up, and slows down again until the end.
+    pub fn ease_in_out_cubic(t: f64) -> f64 {
+        if t < 0.5 {
+            4.0 * t * t * t
+        } else {
+            1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+        }
+    }
+}
+
+/// Creates a signal whose value moves toward the last target it was set to over
+/// `duration`, following the `easing` function, like a progress bar that fills up
+/// smoothly. It is updated at every animation frame.
+///
+/// Setting a new target while the value is moving starts a new tween from the current
+/// value. On the server, the value is set to the target right away.
+///
+/// ```rust
+/// # use core::time::Duration;
+/// # use leptos::*;
+/// #[component]
+/// fn ProgressBar(progress: Signal<f64>) -> impl IntoView {
+///     let (width, set_width) = create_tweened_signal(
+///         0.0,
+///         Duration::from_millis(400),
+///         easing::ease_out_cubic,
+///     );
+///     create_effect(move |_| set_width.set(progress.get()));
+///     view! { <div class="bar" style:width=move || format!("{}%", width.get())/> }
+/// }
+/// ```
+pub fn create_tweened_signal(
+    initial: f64,
+    duration: Duration,
+    easing: fn(f64) -> f64,
+) -> (Signal<f64>, SignalSetter<f64>) {
+    let value = create_rw_signal(initial);
+
+    cfg_if! { if #[cfg(all(target_arch = "wasm32", any(feature = "hydrate", feature = "csr")))] {
+        let frames = Frames::new();
+        let set_target = move |target: f64| {
+            let from = value.get_untracked();
+            let start = frames::now();
+            let duration = duration.as_secs_f64() * 1000.0;
+            frames.run(move |now| {
+                let t = if duration > 0.0 {
+                    ((now - start) / duration).clamp(0.0, 1.0)
+                } else {
+                    1.0
+                };
+                value.try_set(from + (target - from) * easing(t)).is_none() && t < 1.0
+            });
+        };
+    } else {
+        let _ = (duration, easing);
+        let set_target = move |target: f64| value.set(target);
+    }}
+
+    (value.into(), set_target.into_signal_setter())
+}
+
+/// How a [spring signal](create_spring_signal) moves toward its target.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpringOptions {
+    /// How strongly the value is pulled toward its target. Higher values make the spring
+    /// faster. Defaults to `0.15`.
+    pub stiffness: f64,
+    /// How much the speed of the value is damped. Lower values make the spring bounce
+    /// around its target. Defaults to `0.8`.
+    pub damping: f64,
+    /// How close to its target, and how slow, the value has to be for the spring to
+    /// settle on its target. Defaults to `0.01`.
+    pub precision: f64,
+}
+
+impl Default for SpringOptions {
+    fn default() -> Self {
+        Self {
+            stiffness: 0.15,
+            damping: 0.8,
+            precision: 0.01,
+        }
+    }
+}
+
+/// Creates a signal whose value is pulled toward the last target it was set to by a
+/// spring, like a chart whose bars overshoot a little before settling. It is updated at
+/// every animation frame.
+///
+/// Unlike a [tweened signal](create_tweened_signal), setting a new target while the value
+/// is moving keeps its speed, so that it changes direction smoothly. On the server, the
+/// value is set to the target right away.
+///
+/// ```rust
+/// # use leptos::*;
+/// #[component]
+/// fn Bar(height: Signal<f64>) -> impl IntoView {
+///     let (sprung, set_sprung) = create_spring_signal(0.0, SpringOptions::default());
+///     create_effect(move |_| set_sprung.set(height.get()));
+///     view! { <div class="bar" style:height=move || format!("{}px", sprung.get())/> }
+/// }
+/// ```
+pub fn create_spring_signal(
+    initial: f64,
+    options: SpringOptions,
+) -> (Signal<f64>, SignalSetter<f64>) {
+    let value = create_rw_signal(initial);
+
+    cfg_if! { if #[cfg(all(target_arch = "wasm32", any(feature = "hydrate", feature = "csr")))] {
+        use leptos_reactive::store_value;
+
+        let SpringOptions { stiffness, damping, precision } = options;
+        let frames = Frames::new();
+        // the target, the speed of the value in units per frame at 60 FPS, and the time
+        // of the last frame
+        let state = store_value((initial, 0.0, 0.0));
+        let set_target = move |target: f64| {
+            let running = frames.is_running();
+            state.update_value(|state| {
+                state.0 = target;
+                if !running {
+                    state.2 = frames::now();
+                }
+            });
+            if running {
+                return;
+            }
+            frames.run(move |now| {
+                let Some(mut current) = value.try_get_untracked() else {
+                    return false;
+                };
+                let settled = state.try_update_value(|(target, velocity, last)| {
+                    // frames that took longer, like in a background tab, are split
+                    // into steps of one frame, so that the spring stays stable
+                    let mut elapsed = ((now - *last) * 0.06).min(60.0);
+                    *last = now;
+                    while elapsed > 0.0 {
+                        let dt = elapsed.min(1.0);
+                        let acceleration =
+                            stiffness * (*target - current) - damping * *velocity;
+                        *velocity += acceleration * dt;
+                        current += *velocity * dt;
+                        elapsed -= dt;
+                    }
+                    let settled = velocity.abs() < precision
+                        && (*target - current).abs() < precision;
+                    if settled {
+                        *velocity = 0.0;
+                        current = *target;
+                    }
+                    settled
+                });
+                value.try_set(current).is_none() && settled == Some(false)
+            });
+        };
+    } else {
+        let _ = options;
+        let set_target = move |target: f64| value.set(target);
+    }}
+
+    (value.into(), set_target.into_signal_setter())
+}
+
+#[cfg(all(target_arch = "wasm32", any(feature = "hydrate", feature = "csr")))]
+mod frames {
+    use leptos_dom::{
+        helpers::{
+            request_animation_frame_with_handle, AnimationFrameRequestHandle,
+        },
+        window,
+    };
+    use leptos_reactive::{on_cleanup, store_value, StoredValue};
+    use std::rc::Rc;
+
+    /// The current time, in milliseconds.
+    pub(super) fn now() -> f64 {
+        window().performance().map(|p| p.now()).unwrap_or_default()
+    }
+
+    /// Runs a step of an animation at each animation frame, until it returns `false`
+    /// or the current reactive owner is disposed.
+    #[derive(Clone, Copy)]
+    pub(super) struct Frames(StoredValue<Option<AnimationFrameRequestHandle>>);
+
+    impl Frames {
+        pub fn new() -> Self {
+            let frame = store_value(None::<AnimationFrameRequestHandle>);
+            on_cleanup(move || {
+                if let Some(Some(frame)) = frame.try_get_value() {
+                    frame.cancel();
+                }
+            });
+            Self(frame)
+        }
+
+        pub fn is_running(&self) -> bool {
+            self.0.try_with_value(Option::is_some).unwrap_or(false)
+        }
+
+        /// Replaces the running animation, if any, with `step`, which is given the
+        /// time of each frame.
+        pub fn run(&self, step: impl Fn(f64) -> bool + 'static) {
+            if let Some(Some(frame)) = self.0.try_get_value() {
+                frame.cancel();
+            }
+            self.schedule(Rc::new(step));
+        }
+
+        fn schedule(self, step: Rc<dyn Fn(f64) -> bool>) {
+            let frame = request_animation_frame_with_handle(move || {
+                if step(now()) {
+                    self.schedule(step);
+                } else {
+                    self.0.try_set_value(None);
+                }
+            })
+            .ok();
+            self.0.try_set_value(frame);
+        }
+    }
+}
+#[cfg(all(
+    target_arch = "wasm32",
+    any(feature = "hydrate", feature = "csr")
+))]
+use frames::Frames;
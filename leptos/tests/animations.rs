@@ -0,0 +1,82 @@
+#![cfg(all(target_arch = "wasm32", feature = "csr"))]
+
+use core::time::Duration;
+use leptos::*;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Dispatches an `animationend` event on `element`, that bubbles up like real ones.
+fn end_animation(element: &web_sys::Element) {
+    let init = web_sys::EventInit::new();
+    init.set_bubbles(true);
+    let ev = web_sys::Event::new_with_event_init_dict("animationend", &init)
+        .unwrap();
+    element.dispatch_event(&ev).unwrap();
+}
+
+fn find(parent: &web_sys::Element, selector: &str) -> Option<web_sys::Element> {
+    parent.query_selector(selector).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn animated_show_unmounts_when_its_animation_ends() {
+    let runtime = create_runtime();
+    let show = create_rw_signal(true);
+    let root = view! {
+        <div>
+            <AnimatedShow when=show hide_class="fade-out" hide_delay=Duration::from_secs(60)>
+                <p>"Here I am"</p>
+            </AnimatedShow>
+        </div>
+    };
+    document().body().unwrap().append_child(&root).unwrap();
+
+    show.set(false);
+    let wrapper = find(&root, ".fade-out").unwrap();
+    // the animations of the children don't unmount them
+    end_animation(&find(&wrapper, "p").unwrap());
+    assert!(find(&root, "p").is_some());
+    // long before the delay has passed
+    end_animation(&wrapper);
+    assert!(find(&root, "p").is_none());
+
+    root.remove();
+    runtime.dispose();
+}
+
+#[wasm_bindgen_test]
+fn animated_for_unmounts_a_removed_row_when_its_animation_ends() {
+    let runtime = create_runtime();
+    let items = create_rw_signal(vec![1, 2]);
+    let root = view! {
+        <ul>
+            <AnimatedFor
+                each=move || items.get()
+                key=|n| *n
+                leave_class="fade-out"
+                leave_delay=Duration::from_secs(60)
+                let:n
+            >
+                <li>{n}</li>
+            </AnimatedFor>
+        </ul>
+    };
+    document().body().unwrap().append_child(&root).unwrap();
+    let rows = || root.query_selector_all("li").unwrap().length();
+
+    items.set(vec![1]);
+    let leaving = find(&root, ".fade-out").unwrap();
+    assert_eq!(rows(), 2);
+    end_animation(&find(&leaving, "li").unwrap());
+    assert_eq!(rows(), 2);
+    end_animation(&leaving);
+    assert_eq!(rows(), 1);
+    assert_eq!(
+        find(&root, "li").unwrap().text_content().as_deref(),
+        Some("1")
+    );
+
+    root.remove();
+    runtime.dispose();
+}
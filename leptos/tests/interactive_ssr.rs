@@ -0,0 +1,209 @@
+#![cfg(all(feature = "ssr", feature = "interactive-ssr"))]
+
+use leptos::{ssr::JsOnlyInteraction, *};
+
+#[component]
+fn Accordion() -> impl IntoView {
+    let (open, set_open) = create_signal(false);
+    view! {
+        <section class:open=move || open.get()>
+            <button on:click=move |_| set_open.update(|open| *open = !*open)>
+                "Details"
+            </button>
+            <Show when=move || open.get()>
+                <p id="details">"Hidden until opened."</p>
+            </Show>
+        </section>
+    }
+}
+
+#[test]
+fn events_update_the_rendered_html() {
+    let runtime = create_runtime();
+    let accordion = ssr::render_interactive(Accordion);
+
+    let closed = accordion.to_html();
+    assert!(!closed.contains("Hidden until opened."));
+    assert!(!closed.contains("open"));
+
+    assert!(accordion.dispatch("button", ev::click));
+    let opened = accordion.to_html();
+    assert!(opened.contains("class=\"open\""));
+    assert!(opened.contains("Hidden until opened."));
+
+    // elements that only exist after an interaction can be found as well
+    assert!(!accordion.dispatch("#missing", ev::click));
+    assert!(accordion.dispatch("#details", ev::click));
+    assert!(accordion.to_html().contains("Hidden until opened."));
+    assert!(accordion.dispatch("button", ev::click));
+    assert!(!accordion.to_html().contains("Hidden until opened."));
+
+    runtime.dispose();
+}
+
+#[test]
+fn list_rows_keep_their_state() {
+    #[component]
+    fn Row(label: &'static str) -> impl IntoView {
+        let (count, set_count) = create_signal(0);
+        view! {
+            <li on:click=move |_| set_count.update(|n| *n += 1)>
+                <span class=label>{label}</span>
+                ": "
+                {move || count.get()}
+            </li>
+        }
+    }
+
+    let runtime = create_runtime();
+    let (rows, set_rows) = create_signal(vec!["a", "b"]);
+    let list = ssr::render_interactive(move || {
+        view! {
+            <ul>
+                <For each=move || rows.get() key=|row| *row let:row>
+                    <Row label=row/>
+                </For>
+            </ul>
+        }
+    });
+
+    // the click bubbles up from the label to the row
+    assert!(list.dispatch(".b", ev::click));
+    set_rows.set(vec!["b", "c"]);
+    let html = without_comments(&list.to_html());
+    assert!(html.contains(">b</span>: 1"));
+    assert!(html.contains(">c</span>: 0"));
+    assert!(!html.contains(">a<"));
+
+    runtime.dispose();
+}
+
+#[test]
+fn show_only_rebuilds_its_views_when_the_condition_flips() {
+    use std::{cell::Cell, rc::Rc};
+
+    let runtime = create_runtime();
+    let (count, set_count) = create_signal(0);
+    let (children_built, fallback_built) =
+        (Rc::new(Cell::new(0)), Rc::new(Cell::new(0)));
+    let page = ssr::render_interactive({
+        let (children_built, fallback_built) =
+            (children_built.clone(), fallback_built.clone());
+        move || {
+            let fallback_built = fallback_built.clone();
+            view! {
+                <Show
+                    when=move || count.get() < 5
+                    fallback=move || {
+                        fallback_built.set(fallback_built.get() + 1);
+                        "Big"
+                    }
+                >
+                    {children_built.set(children_built.get() + 1);}
+                    "Small"
+                </Show>
+            }
+        }
+    });
+    assert_eq!((children_built.get(), fallback_built.get()), (1, 0));
+
+    // the condition doesn't change
+    set_count.set(1);
+    set_count.set(4);
+    assert_eq!((children_built.get(), fallback_built.get()), (1, 0));
+
+    set_count.set(5);
+    set_count.set(6);
+    assert_eq!((children_built.get(), fallback_built.get()), (1, 1));
+    assert!(page.to_html().contains("Big"));
+
+    set_count.set(0);
+    assert_eq!((children_built.get(), fallback_built.get()), (2, 1));
+    assert!(page.to_html().contains("Small"));
+
+    runtime.dispose();
+}
+
+#[test]
+fn listeners_that_use_the_event_are_skipped() {
+    let runtime = create_runtime();
+    let (clicks, set_clicks) = create_signal(0);
+    let (submitted, set_submitted) = create_signal(false);
+    let page = ssr::render_interactive(move || {
+        view! {
+            <div on:click=move |_| set_clicks.update(|n| *n += 1)>
+                <a
+                    href="/next"
+                    on:click=move |ev| {
+                        ev.prevent_default();
+                        set_submitted.set(true);
+                    }
+                >
+                    "Next"
+                </a>
+            </div>
+        }
+    });
+
+    // the link's listener stops at the event, and the click bubbles on
+    assert!(page.dispatch("a", ev::click));
+    assert!(!submitted.get());
+    assert_eq!(clicks.get(), 1);
+
+    runtime.dispose();
+}
+
+fn without_comments(html: &str) -> String {
+    html.split("<!--")
+        .enumerate()
+        .map(|(i, part)| {
+            if i == 0 {
+                part
+            } else {
+                part.split_once("-->").map_or(part, |(_, rest)| rest)
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn other_views_are_not_interactive() {
+    let runtime = create_runtime();
+    let html = Accordion().into_view().render_to_string();
+    assert!(!html.contains("Hidden until opened."));
+    runtime.dispose();
+}
+
+#[test]
+fn js_only_interactions_are_listed() {
+    let runtime = create_runtime();
+    let page = ssr::render_interactive(|| {
+        view! {
+            <Accordion/>
+            <a href="/about" on:click=|_| {}>"About"</a>
+            <form action="/search" on:submit=|_| {}>
+                <button on:click=|_| {}>"Search"</button>
+                <button type="button" class="clear" on:click=|_| {}>"Clear"</button>
+            </form>
+            <form>
+                <button id="save" on:click=|_| {}>"Save"</button>
+            </form>
+        }
+    });
+
+    assert_eq!(
+        page.js_only_interactions(),
+        [
+            JsOnlyInteraction::Click("<button>".into()),
+            JsOnlyInteraction::Click("<button class=\"clear\">".into()),
+            JsOnlyInteraction::FormWithoutAction("<form>".into()),
+            JsOnlyInteraction::Click("<button id=\"save\">".into()),
+        ]
+    );
+    assert_eq!(
+        page.js_only_interactions()[0].to_string(),
+        "<button> only responds to clicks with JavaScript"
+    );
+
+    runtime.dispose();
+}
@@ -168,12 +168,12 @@ fn ssr_with_styles() {
     if cfg!(all(feature = "experimental-islands", feature = "ssr")) {
         assert_eq!(
             rendered.into_view().render_to_string(),
-            "<div class=\" myclass\"><button class=\"btn \
+            "<div class=\"myclass\"><button class=\"btn \
              myclass\">-1</button></div>"
         );
     } else {
         assert!(rendered.into_view().render_to_string().contains(
-            "<div data-hk=\"0-0-0-1\" class=\" myclass\"><button \
+            "<div data-hk=\"0-0-0-1\" class=\"myclass\"><button \
              data-hk=\"0-0-0-2\" class=\"btn myclass\">-1</button></div>"
         ));
     }
@@ -206,3 +206,1092 @@ fn ssr_option() {
 
     runtime.dispose();
 }
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_for_hydrate_lazily() {
+    use leptos::*;
+
+    let runtime = create_runtime();
+    let rendered = view! {
+        <ul>
+            <For each=|| [1, 2] key=|n| *n hydrate_lazily=true let:n>
+                <li>{n}</li>
+            </For>
+        </ul>
+    };
+    let html = rendered.into_view().render_to_string();
+
+    if cfg!(all(feature = "experimental-islands", feature = "ssr")) {
+        assert!(!html.contains("leptos-row"));
+    } else {
+        // each row records the hydration key it starts at, so the client
+        // can skip it and hydrate it later
+        assert!(html.contains("<!--leptos-row=0-0-0-2:0-0-0-2-->"));
+        assert!(html.contains("<!--leptos-row=0-0-0-2:0-0-0-5-->"));
+        assert!(html.contains("<!--leptos-rows-end=0-0-0-2:0-0-0-8-->"));
+    }
+
+    runtime.dispose();
+}
+
+#[cfg(all(
+    not(any(feature = "csr", feature = "hydrate")),
+    feature = "attribute-interpolation"
+))]
+#[test]
+fn ssr_attribute_interpolation() {
+    use leptos::*;
+
+    let runtime = create_runtime();
+    let (id, _) = create_signal(42);
+    let kind = "primary";
+    let rendered = view! {
+        <a href="/users/{id.get()}" class="btn btn-{kind}" data-raw="{{id}}"/>
+    };
+
+    let html = rendered.into_view().render_to_string();
+
+    assert!(html.contains("href=\"/users/42\""));
+    assert!(html.contains("btn btn-primary"));
+    assert!(html.contains("data-raw=\"{id}\""));
+
+    runtime.dispose();
+}
+
+#[cfg(all(
+    not(any(feature = "csr", feature = "hydrate")),
+    not(feature = "attribute-interpolation")
+))]
+#[test]
+fn ssr_attribute_strings_keep_their_braces_by_default() {
+    use leptos::*;
+
+    let runtime = create_runtime();
+    let html = view! {
+        <a href="/users/{id}" data-raw="{{id}}" data-json=r#"{"id":1}"#/>
+    }
+    .into_view()
+    .render_to_string();
+
+    assert!(html.contains("href=\"/users/{id}\""), "{html}");
+    assert!(html.contains("data-raw=\"{{id}}\""), "{html}");
+    assert!(html.contains("data-json=\"{&quot;id&quot;:1}\""), "{html}");
+
+    runtime.dispose();
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_component_attrs_forwarded_to_root() {
+    use leptos::*;
+
+    #[component]
+    fn Button(children: Children) -> impl IntoView {
+        view! { <button class="btn">{children()}</button> }
+    }
+
+    #[component]
+    fn Spread(
+        #[prop(attrs)] attrs: Vec<(&'static str, Attribute)>,
+    ) -> impl IntoView {
+        view! { <input {..attrs}/> }
+    }
+
+    let runtime = create_runtime();
+    let (disabled, _) = create_signal(true);
+    let rendered = view! {
+        <Button attr:disabled=move || disabled.get() attr:title="Save">"Save"</Button>
+        <Spread attr:name="email"/>
+    };
+
+    let html = rendered.into_view().render_to_string();
+
+    assert!(html.contains("<button disabled title=\"Save\""));
+    assert!(html.contains("class=\"btn\""));
+    assert!(html.contains("<input name=\"email\""));
+
+    runtime.dispose();
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_spread_attribute_map() {
+    use leptos::*;
+
+    #[component]
+    fn Card(children: Children) -> impl IntoView {
+        view! { <section>{children()}</section> }
+    }
+
+    let runtime = create_runtime();
+    let attrs = AttributeMap::new()
+        .with("id", "first")
+        .with("hidden", false)
+        .with("id", "main");
+    let forwarded = vec![("role", "region".into_attribute())];
+    let rendered = view! {
+        <div {..attrs} class="box"/>
+        <Card attr:title="Card" {..forwarded}>"Body"</Card>
+    };
+
+    let html = rendered.into_view().render_to_string();
+
+    assert!(html.contains(r#"<div id="main""#));
+    assert!(!html.contains("hidden"));
+    assert!(html.contains(r#"class="box""#));
+    assert!(html.contains(r#"<section title="Card" role="region""#));
+
+    runtime.dispose();
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_event_modifiers_are_not_rendered() {
+    use leptos::*;
+
+    let runtime = create_runtime();
+    let rendered = view! {
+        <div on:scroll:passive=|_| {} on:click:capture:once=|ev| ev.stop_propagation()>
+            <button on:click:undelegated=|_| {}>"Go"</button>
+        </div>
+    };
+
+    let html = rendered.into_view().render_to_string();
+
+    assert!(html.starts_with("<div"));
+    assert!(!html.contains("scroll"));
+    assert!(!html.contains("click"));
+
+    runtime.dispose();
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_renders_the_screen_version() {
+    use leptos::*;
+
+    let runtime = create_runtime();
+    let rendered = view! {
+        <ScreenOnly><nav>"Menu"</nav></ScreenOnly>
+        <PrintOnly><p>"Letterhead"</p></PrintOnly>
+    };
+
+    let html = rendered.into_view().render_to_string();
+
+    assert!(html.contains("Menu"));
+    assert!(!html.contains("Letterhead"));
+
+    runtime.dispose();
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_portals_render_nothing() {
+    use leptos::*;
+
+    let runtime = create_runtime();
+    let rendered = view! {
+        <main>
+            <Portal><div class="modal">"Are you sure?"</div></Portal>
+        </main>
+    };
+
+    let html = rendered.into_view().render_to_string();
+
+    assert!(html.starts_with("<main"));
+    assert!(!html.contains("Are you sure?"));
+
+    runtime.dispose();
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_error_boundary_renders_the_fallback() {
+    use leptos::*;
+
+    let runtime = create_runtime();
+    let (value, _) = create_signal("twelve".parse::<i32>());
+    let rendered = view! {
+        <ErrorBoundary fallback=|errors| view! {
+            <p class="error">{move || errors.with(|errors| errors.iter().count())} " error"</p>
+        }>
+            <p>"Value is: " {move || value.get()}</p>
+        </ErrorBoundary>
+        <ErrorBoundary fallback=|_| view! { <p class="error">"unreachable"</p> }>
+            <p>"Answer: " {Ok::<_, std::num::ParseIntError>(42)}</p>
+        </ErrorBoundary>
+    };
+
+    let html = rendered.into_view().render_to_string();
+
+    assert!(html.contains(r#"class="error""#));
+    if cfg!(feature = "experimental-islands") {
+        // there are no markers between the count and the text
+        assert!(html.contains(">1 error<"));
+    } else {
+        assert!(html.contains(">1<"));
+    }
+    // the children are kept, hidden, so that they can be hydrated
+    let hidden = html
+        .find(r#"style="display: none;""#)
+        .expect("hidden children");
+    assert!(html
+        .find("Value is: ")
+        .is_some_and(|children| children > hidden));
+    assert!(html.contains("Answer: "));
+    assert!(!html.contains("unreachable"));
+
+    runtime.dispose();
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_component_class_and_style_props() {
+    use leptos::*;
+
+    #[component]
+    fn Button(
+        #[prop(optional, into)] class: ClassProp,
+        #[prop(optional, into)] style: StyleProp,
+        children: Children,
+    ) -> impl IntoView {
+        view! {
+            <button
+                class=class.with_defaults("btn btn-sm")
+                style=style.with_defaults("color: black; padding: 4px")
+            >
+                {children()}
+            </button>
+        }
+    }
+
+    let runtime = create_runtime();
+    let (active, _) = create_signal(true);
+    let rendered = view! {
+        <Button class="btn-primary btn" class:active=move || active.get() class:hidden=false style:color="red">
+            "Save"
+        </Button>
+        <Button>"Cancel"</Button>
+    };
+
+    let html = rendered.into_view().render_to_string();
+
+    assert!(html.contains("btn btn-sm btn-primary active\""));
+    assert!(html.contains("padding: 4px; color: red;\""));
+    assert!(html.contains("btn btn-sm\""));
+    assert!(html.contains("color: black; padding: 4px;\""));
+
+    runtime.dispose();
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_clean_has_no_hydration_markers() {
+    use leptos::*;
+
+    #[component]
+    fn Item(label: &'static str) -> impl IntoView {
+        view! { <li>{label}</li> }
+    }
+
+    let html = ssr::render_to_string_clean(|| {
+        view! {
+            <ul>
+                <For each=|| ["a", "b"] key=|label| *label let:label>
+                    <Item label/>
+                </For>
+            </ul>
+            <p>{move || "dynamic"}</p>
+        }
+    });
+
+    assert_eq!(html, "<ul><li>a</li><li>b</li></ul><p>dynamic</p>");
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_clean_turns_markers_back_on_after_a_panic() {
+    use leptos::*;
+
+    let render = || {
+        let runtime = create_runtime();
+        leptos_dom::HydrationCtx::reset_id();
+        let html = view! { <p>{move || "dynamic"}</p> }
+            .into_view()
+            .render_to_string();
+        runtime.dispose();
+        html
+    };
+    let before = render();
+
+    let panicked = std::panic::catch_unwind(|| {
+        ssr::render_to_string_clean(|| -> View { panic!("render failed") })
+    });
+    assert!(panicked.is_err());
+
+    let after = render();
+    assert_eq!(after, before);
+    // with islands, only islands have markers
+    if !cfg!(feature = "experimental-islands") {
+        assert!(after.contains("data-hk"));
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn async_components_render_the_suspense_fallback() {
+    use leptos::*;
+
+    #[component]
+    async fn Greeting(name: &'static str) -> impl IntoView {
+        view! { <p>"Hello, " {name}</p> }
+    }
+
+    let runtime = create_runtime();
+    let html = view! {
+        <Suspense fallback=|| "Loading...">
+            <Greeting name="Leptos"/>
+        </Suspense>
+    }
+    .into_view()
+    .render_to_string();
+
+    // the body only runs in the browser
+    assert!(html.contains("Loading..."));
+    assert!(!html.contains("Hello"));
+
+    runtime.dispose();
+}
+
+// without `ssr`, local resources run, and are resolved synchronously outside the browser
+#[cfg(not(any(feature = "csr", feature = "hydrate", feature = "ssr")))]
+#[test]
+fn async_components_render_their_body_once_resolved() {
+    use leptos::*;
+
+    #[component]
+    async fn Greeting(name: &'static str) -> impl IntoView {
+        let name = async { name }.await;
+        let punctuation = expect_context::<char>();
+        view! { <p>"Hello, " {name} {punctuation}</p> }
+    }
+
+    let runtime = create_runtime();
+    provide_context('!');
+    let html = view! { <Greeting name="Leptos"/> }
+        .into_view()
+        .render_to_string();
+
+    assert!(html.contains("Hello, Leptos!"));
+
+    runtime.dispose();
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn in_order_stream_resolves_sibling_suspense_while_waiting() {
+    use futures::{channel::oneshot, FutureExt, StreamExt};
+    use leptos::*;
+    use std::{cell::RefCell, rc::Rc};
+
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let html = tokio::task::LocalSet::new().block_on(&rt, async {
+        // the first resource only resolves once the second <Suspense/> has been rendered
+        let (tx, rx) = oneshot::channel::<()>();
+        let rx = rx.shared();
+        let tx = Rc::new(RefCell::new(Some(tx)));
+
+        let (stream, runtime) =
+            ssr::render_to_stream_in_order_with_prefix_undisposed_with_context(
+                move || {
+                    let first = create_resource(
+                        || (),
+                        move |_| {
+                            let rx = rx.clone();
+                            async move {
+                                _ = rx.await;
+                                "first".to_string()
+                            }
+                        },
+                    );
+                    let second = create_resource(
+                        || (),
+                        |_| async { "second".to_string() },
+                    );
+                    view! {
+                        <Suspense fallback=|| ()>
+                            <p>{move || first.get()}</p>
+                        </Suspense>
+                        <Suspense fallback=|| ()>
+                            <p>
+                                {
+                                    let tx = Rc::clone(&tx);
+                                    move || {
+                                        second.get().inspect(|_| {
+                                            if let Some(tx) = tx.take() {
+                                                _ = tx.send(());
+                                            }
+                                        })
+                                    }
+                                }
+                            </p>
+                        </Suspense>
+                    }
+                    .into_view()
+                },
+                || "".into(),
+                || {},
+            );
+        let html = stream.collect::<String>().await;
+        runtime.dispose();
+        html
+    });
+
+    let first = html.find(">first<").expect("first suspense rendered");
+    let second = html.find(">second<").expect("second suspense rendered");
+    assert!(first < second);
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn out_of_order_stream_sends_the_shell_before_resources_resolve() {
+    use futures::{channel::oneshot, FutureExt, StreamExt};
+    use leptos::*;
+    use std::{cell::RefCell, rc::Rc};
+
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    tokio::task::LocalSet::new().block_on(&rt, async {
+        // the resource only resolves once the shell has been received
+        let (tx, rx) = oneshot::channel::<()>();
+        let rx = Rc::new(RefCell::new(Some(rx)));
+
+        let (stream, runtime) =
+            ssr::render_to_stream_with_prefix_undisposed_with_context(
+                move || {
+                    let data = create_resource(
+                        || (),
+                        move |_| {
+                            let rx = rx.borrow_mut().take();
+                            async move {
+                                if let Some(rx) = rx {
+                                    _ = rx.await;
+                                }
+                                "loaded".to_string()
+                            }
+                        },
+                    );
+                    view! {
+                        <h1>"Shell"</h1>
+                        <Suspense fallback=|| view! { <p>"loading"</p> }>
+                            <p>{move || data.get()}</p>
+                        </Suspense>
+                    }
+                    .into_view()
+                },
+                || "".into(),
+                || {},
+            );
+        let mut stream = Box::pin(stream);
+
+        let shell = stream.next().now_or_never().flatten().expect("shell");
+        assert!(shell.contains(">Shell<"));
+        assert!(shell.contains(">loading<"));
+        assert!(!shell.contains(">loaded<"));
+
+        _ = tx.send(());
+        let rest = stream.collect::<String>().await;
+        let template = rest.find("<template id=").expect("swapped fragment");
+        let loaded = rest.find(">loaded<").expect("resolved view");
+        assert!(template < loaded);
+        assert!(rest.contains("range.deleteContents()"));
+
+        runtime.dispose();
+    });
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn in_order_stream_needs_no_swap_scripts() {
+    use futures::StreamExt;
+    use leptos::*;
+
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let html = tokio::task::LocalSet::new().block_on(&rt, async {
+        let (stream, runtime) =
+            ssr::render_to_stream_in_order_with_prefix_undisposed_with_context(
+                || {
+                    let data = create_resource(
+                        || (),
+                        |_| async { "loaded".to_string() },
+                    );
+                    view! {
+                        <h1>"Shell"</h1>
+                        <Suspense fallback=|| view! { <p>"loading"</p> }>
+                            <p>{move || data.get()}</p>
+                        </Suspense>
+                        <footer>"Footer"</footer>
+                    }
+                    .into_view()
+                },
+                || "".into(),
+                || {},
+            );
+        let html = stream.collect::<String>().await;
+        runtime.dispose();
+        html
+    });
+
+    let shell = html.find(">Shell<").expect("shell");
+    let loaded = html.find(">loaded<").expect("resolved view");
+    let footer = html.find(">Footer<").expect("footer");
+    assert!(shell < loaded && loaded < footer);
+    assert!(!html.contains("loading"));
+    assert!(!html.contains("<template"));
+}
+
+#[cfg(all(feature = "experimental-islands", feature = "ssr"))]
+mod islands {
+    use leptos::*;
+
+    #[island]
+    fn Counter() -> impl IntoView {
+        let (count, set_count) = create_signal(0);
+        view! {
+            <button on:click=move |_| set_count.update(|n| *n += 1)>{count}</button>
+        }
+    }
+
+    #[component]
+    fn Article() -> impl IntoView {
+        view! {
+            <article>
+                <h1>"Static"</h1>
+                <Counter/>
+            </article>
+        }
+    }
+
+    #[test]
+    fn only_islands_are_rendered_with_hydration_keys() {
+        let runtime = create_runtime();
+        let html = view! { <Article/> }.into_view().render_to_string();
+        runtime.dispose();
+
+        // the server-only component has no markers or keys
+        let (outside, rest) = html.split_once("<leptos-island").unwrap();
+        let (island, after) = rest.split_once("</leptos-island>").unwrap();
+        assert!(outside.starts_with("<article><h1>Static</h1>"));
+        assert_eq!(after, "</article>");
+        for server_only in [outside, after] {
+            assert!(!server_only.contains("data-hk"));
+            assert!(!server_only.contains("<!--"));
+        }
+
+        // the island starts counting its own keys from the key it was given
+        assert!(island.contains(r#"data-component="Counter""#));
+        let (_, island_key) = island.split_once(r#"data-hkc=""#).unwrap();
+        let island_key = &island_key[..island_key.find('"').unwrap()];
+        let (_, button) = island.split_once("<button").unwrap();
+        let (_, button_key) = button.split_once(r#"data-hk=""#).unwrap();
+        let button_key = &button_key[..button_key.find('"').unwrap()];
+        let island_key =
+            island_key.parse::<leptos_dom::HydrationKey>().unwrap();
+        let button_key =
+            button_key.parse::<leptos_dom::HydrationKey>().unwrap();
+        assert_eq!(
+            (island_key.outlet, island_key.fragment, island_key.error),
+            (button_key.outlet, button_key.fragment, button_key.error)
+        );
+        assert!(button_key.id > island_key.id);
+        assert!(button.contains("leptos-dyn-child-start-->0<!"));
+        assert!(button.ends_with("</button>"));
+    }
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn cached_fragments_render_the_same_html_anywhere() {
+    use leptos::*;
+    use std::cell::Cell;
+
+    static CARDS: FragmentCache = FragmentCache::new();
+    thread_local! {
+        static RENDERS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[component]
+    fn Card(cached: bool) -> impl IntoView {
+        let card = || {
+            RENDERS.with(|renders| renders.set(renders.get() + 1));
+            let (count, _) = create_signal(3);
+            // only the keys that are rendered are moved, not the HTML that looks like one
+            let copied = r#"<span data-hk="0-0-0-1"></span>"#;
+            view! {
+                <section>
+                    <h2>"Card"</h2>
+                    <p>"Count: " {count} <Show when=|| true>"!"</Show></p>
+                    <div inner_html=copied></div>
+                    {cached_view(&CARDS, "badge", || view! { <b>{move || "new"}</b> })}
+                </section>
+            }
+        };
+        if cached {
+            cached_view(&CARDS, "card", card)
+        } else {
+            card().into_view()
+        }
+    }
+
+    let page = |cached: bool| {
+        ssr::render_to_string(move || {
+            view! {
+                <main>
+                    <Card cached/>
+                    <div>{move || "between"}</div>
+                    <Card cached/>
+                    <footer>"after"</footer>
+                </main>
+            }
+        })
+    };
+
+    let uncached = page(false);
+    RENDERS.with(|renders| renders.set(0));
+    assert_eq!(page(true), uncached);
+    assert_eq!(page(true), uncached);
+    assert_eq!(
+        uncached
+            .matches(r#"<span data-hk="0-0-0-1"></span>"#)
+            .count(),
+        2
+    );
+    // only the first card of the first page is rendered
+    assert_eq!(RENDERS.with(Cell::get), 1);
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_animated_for_wraps_rows_with_the_enter_class() {
+    use core::time::Duration;
+    use leptos::*;
+
+    let runtime = create_runtime();
+    let html = view! {
+        <ul>
+            <AnimatedFor
+                each=|| ["a", "b"]
+                key=|item| *item
+                enter_class="fade-in"
+                leave_class="fade-out"
+                leave_delay=Duration::from_millis(300)
+                let:item
+            >
+                <li>{item}</li>
+            </AnimatedFor>
+        </ul>
+    }
+    .into_view()
+    .render_to_string();
+
+    let rows = html.matches("class=\"fade-in\"").count();
+    assert_eq!(rows, 2, "{html}");
+    assert!(html.contains(">a</li>") && html.contains(">b</li>"));
+    assert!(!html.contains("fade-out"));
+
+    runtime.dispose();
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn actions_track_their_pending_dispatches() {
+    use futures::channel::oneshot;
+    use leptos::*;
+    use std::{cell::RefCell, rc::Rc};
+
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    tokio::task::LocalSet::new().block_on(&rt, async {
+        let runtime = create_runtime();
+        let senders = Rc::new(RefCell::new(Vec::new()));
+        let save = create_action({
+            let senders = Rc::clone(&senders);
+            move |task: &String| {
+                let (tx, rx) = oneshot::channel::<()>();
+                senders.borrow_mut().push(tx);
+                let task = task.clone();
+                async move {
+                    _ = rx.await;
+                    task.len()
+                }
+            }
+        });
+
+        save.dispatch("write docs".to_string());
+        save.dispatch("ship".to_string());
+        tokio::task::yield_now().await;
+        assert!(save.pending().get());
+        assert_eq!(save.input().get().as_deref(), Some("ship"));
+        assert_eq!(save.value().get(), None);
+        assert_eq!(save.version().get(), 0);
+
+        // the action stays pending until every dispatch has resolved
+        let mut senders = std::mem::take(&mut *senders.borrow_mut());
+        _ = senders.remove(0).send(());
+        tokio::task::yield_now().await;
+        assert!(save.pending().get());
+        assert_eq!(save.value().get(), Some(10));
+        assert_eq!(save.version().get(), 1);
+
+        _ = senders.remove(0).send(());
+        tokio::task::yield_now().await;
+        assert!(!save.pending().get());
+        assert_eq!(save.input().get(), None);
+        assert_eq!(save.value().get(), Some(4));
+        assert_eq!(save.version().get(), 2);
+
+        runtime.dispose();
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_live_region_renders_announcements() {
+    use leptos::*;
+
+    let runtime = create_runtime();
+
+    #[component]
+    fn Form() -> impl IntoView {
+        let announcer = use_announcer();
+        announcer.announce("Saved", Politeness::Polite);
+        announcer.announce("Saved", Politeness::Polite);
+        announcer.announce("Name is required", Politeness::Assertive);
+        view! { <form></form> }
+    }
+
+    let html = view! {
+        <LiveRegion>
+            <Form/>
+        </LiveRegion>
+    }
+    .into_view()
+    .render_to_string();
+
+    let form = html.find("<form").expect("children rendered");
+    let polite = html.find(r#"aria-live="polite""#).expect("polite region");
+    assert!(form < polite, "{html}");
+    assert!(
+        html.contains(r#"role="alert" aria-live="assertive""#),
+        "{html}"
+    );
+    // a repeated message is changed, so that it is read out again
+    assert!(html.contains("Saved\u{a0}<"), "{html}");
+    assert!(html.contains("Name is required"), "{html}");
+
+    runtime.dispose();
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_dialog_and_popover_render_native_elements() {
+    use leptos::*;
+
+    let runtime = create_runtime();
+
+    let render = |open: bool| {
+        let open = create_rw_signal(open);
+        view! {
+            <Dialog open=open class="confirm">
+                <p>"Are you sure?"</p>
+            </Dialog>
+        }
+        .into_view()
+        .render_to_string()
+        .to_string()
+    };
+    let closed = render(false);
+    assert!(closed.contains("<dialog"), "{closed}");
+    assert!(closed.contains("confirm"), "{closed}");
+    assert!(!closed.contains(" open"), "{closed}");
+    assert!(closed.contains("Are you sure?"), "{closed}");
+    // an open dialog is shown before the page is hydrated
+    let open = render(true);
+    assert!(open.contains(" open"), "{open}");
+
+    let html = view! {
+        <button popovertarget="menu">"Menu"</button>
+        <Popover id="menu" open=create_rw_signal(false)>
+            "Settings"
+        </Popover>
+        <Popover id="tip" open=create_rw_signal(false) manual=true>
+            "Tip"
+        </Popover>
+    }
+    .into_view()
+    .render_to_string();
+    assert!(html.contains(r#"id="menu""#), "{html}");
+    assert!(html.contains(r#"popover="auto""#), "{html}");
+    assert!(html.contains(r#"id="tip""#), "{html}");
+    assert!(html.contains(r#"popover="manual""#), "{html}");
+
+    runtime.dispose();
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_erased_signal_props_accept_any_reactive_value() {
+    use leptos::*;
+
+    #[component]
+    fn Badge(
+        #[prop(into)] count: MaybeSignal<i32>,
+        #[prop(into)] label: Signal<String>,
+    ) -> impl IntoView {
+        view! { <b>{move || format!("{}: {}", label.get(), count.get())}</b> }
+    }
+
+    let runtime = create_runtime();
+    let (read, _) = create_signal(1);
+    let rw = create_rw_signal("rw".to_string());
+    let memo = create_memo(move |_| read.get() * 10);
+
+    let html = view! {
+        <Badge count=2 label=rw/>
+        <Badge count=read label=move || format!("read {}", read.get())/>
+        <Badge count=memo label=Signal::derive(|| "memo".to_string())/>
+        <Badge count=rw.get().len() as i32 label=create_memo(|_| "len".into())/>
+    }
+    .into_view()
+    .render_to_string();
+
+    for expected in ["rw: 2", "read 1: 1", "memo: 10", "len: 2"] {
+        assert!(html.contains(expected), "{expected} in {html}");
+    }
+
+    runtime.dispose();
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_gestures_are_inactive_on_the_server() {
+    use leptos::*;
+
+    let runtime = create_runtime();
+    let card = create_node_ref::<html::Div>();
+    let drag = use_drag(card);
+    let pinch = use_pinch(card);
+    let html = view! {
+        <div node_ref=card>{move || format!("{:?}", drag.delta.get())}</div>
+    }
+    .into_view()
+    .render_to_string();
+
+    assert!(html.contains("(0.0, 0.0)"), "{html}");
+    assert!(!drag.active.get() && !pinch.active.get());
+    assert_eq!(drag.velocity.get(), (0.0, 0.0));
+    assert_eq!((pinch.scale.get(), pinch.velocity.get()), (1.0, 0.0));
+
+    runtime.dispose();
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_tweened_and_spring_signals_settle_instantly() {
+    use core::time::Duration;
+    use leptos::*;
+
+    let runtime = create_runtime();
+    let (width, set_width) = create_tweened_signal(
+        0.0,
+        Duration::from_millis(400),
+        easing::ease_out_cubic,
+    );
+    let (height, set_height) =
+        create_spring_signal(10.0, SpringOptions::default());
+    assert_eq!((width.get(), height.get()), (0.0, 10.0));
+
+    set_width.set(75.0);
+    set_height.set(-20.0);
+    let html = view! {
+        <div>{move || format!("{} {}", width.get(), height.get())}</div>
+    }
+    .into_view()
+    .render_to_string();
+    assert!(html.contains("75 -20"), "{html}");
+
+    for ease in [
+        easing::linear,
+        easing::ease_in_cubic,
+        easing::ease_out_cubic,
+        easing::ease_in_out_cubic,
+    ] {
+        assert_eq!((ease(0.0), ease(1.0)), (0.0, 1.0));
+    }
+    assert_eq!(easing::ease_in_out_cubic(0.5), 0.5);
+
+    runtime.dispose();
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_load_progress_has_nothing_loaded_on_the_server() {
+    use leptos::*;
+
+    let runtime = create_runtime();
+    let progress = use_load_progress();
+    assert_eq!(progress.get(), LoadProgress::default());
+    assert_eq!(progress.get().fraction(), None);
+
+    let halfway = LoadProgress {
+        loaded: 512,
+        total: 1024,
+        ..Default::default()
+    };
+    assert_eq!(halfway.fraction(), Some(0.5));
+    // a bundle that is bigger than announced is not more than fully loaded
+    let over = LoadProgress {
+        loaded: 2048,
+        ..halfway
+    };
+    assert_eq!(over.fraction(), Some(1.0));
+
+    runtime.dispose();
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_attributes_are_quoted_as_asked() {
+    use leptos::{
+        ssr::{render_to_string_with_quoting, AttributeQuoting::*},
+        *,
+    };
+
+    fn page() -> impl IntoView {
+        let edge = || r#"a "b" & 'c' <d> e=f `g`"#;
+        let dynamic = || "x.png";
+        view! {
+            <p title=r#"a "b" & 'c' <d> e=f `g`"#>"static"</p>
+            <p title=edge>"dynamic"</p>
+            <input value="plain" disabled/>
+            <br class="x"/>
+            <img src=dynamic alt="it's"/>
+            <div inner_html="<img alt='it&#39;s' src=x.png>"></div>
+        }
+    }
+
+    // by default, values are double-quoted, and `'` is escaped in static values
+    let html = ssr::render_to_string(page);
+    assert!(
+        html.contains(
+            r#"title="a &quot;b&quot; &amp; &#x27;c&#x27; &lt;d&gt; e=f `g`""#
+        ),
+        "{html}"
+    );
+    assert!(
+        html.contains(r#"title="a &quot;b&quot; &amp; 'c' &lt;d&gt; e=f `g`""#),
+        "{html}"
+    );
+    assert!(html.contains(r#"<input value="plain" disabled"#), "{html}");
+    assert_eq!(render_to_string_with_quoting(page, DoubleQuote), html);
+
+    let single = render_to_string_with_quoting(page, SingleQuote);
+    assert!(single.contains("<input value='plain' disabled"), "{single}");
+    assert_eq!(single.matches("&#x27;c&#x27;").count(), 2, "{single}");
+    assert!(single.contains("src='x.png' alt='it&#x27;s'"), "{single}");
+    // HTML set with `inner_html` is kept as it is
+    assert!(
+        single.contains("<img alt='it&#39;s' src=x.png>"),
+        "{single}"
+    );
+
+    let minimal = render_to_string_with_quoting(page, Minimal);
+    assert!(minimal.contains("<input value=plain disabled"), "{minimal}");
+    // a void element's tag ends without the slash, which would be part of
+    // an unquoted value before it
+    assert!(minimal.contains(r#"class=x>"#), "{minimal}");
+    // `'` has to be quoted, and is escaped
+    assert!(
+        minimal.contains(r#"src=x.png alt="it&#x27;s""#),
+        "{minimal}"
+    );
+    assert!(minimal.contains(r#"title="a &quot;b&quot;"#), "{minimal}");
+
+    // the quoting only lasts for its render
+    assert_eq!(ssr::render_to_string(page), html);
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn ssr_streams_rendered_together_keep_their_own_quoting() {
+    use futures::{Stream, StreamExt};
+    use leptos::{ssr::AttributeQuoting, *};
+    use std::pin::Pin;
+
+    fn page() -> View {
+        let data = create_resource(|| (), |_| async { "loaded".to_string() });
+        view! {
+            <p class="shell">"Shell"</p>
+            <Suspense fallback=|| ()>
+                <p class="fragment">{move || data.get()}</p>
+            </Suspense>
+        }
+        .into_view()
+    }
+
+    fn stream(
+        quoting: AttributeQuoting,
+        in_order: bool,
+    ) -> (Pin<Box<dyn Stream<Item = String>>>, RuntimeId) {
+        let context = move || provide_context(quoting);
+        if in_order {
+            let (stream, runtime) =
+                ssr::render_to_stream_in_order_with_prefix_undisposed_with_context(
+                    page,
+                    || "".into(),
+                    context,
+                );
+            (Box::pin(stream), runtime)
+        } else {
+            let (stream, runtime) =
+                ssr::render_to_stream_with_prefix_undisposed_with_context(
+                    page,
+                    || "".into(),
+                    context,
+                );
+            (Box::pin(stream), runtime)
+        }
+    }
+
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    for in_order in [false, true] {
+        let [single, minimal] =
+            tokio::task::LocalSet::new().block_on(&rt, async {
+                let (single, single_runtime) =
+                    stream(AttributeQuoting::SingleQuote, in_order);
+                let (minimal, minimal_runtime) =
+                    stream(AttributeQuoting::Minimal, in_order);
+                let html = futures::join!(
+                    single.collect::<String>(),
+                    minimal.collect::<String>()
+                );
+                single_runtime.dispose();
+                minimal_runtime.dispose();
+                [html.0, html.1]
+            });
+        for class in ["shell", "fragment"] {
+            assert!(single.contains(&format!("class='{class}'")), "{single}");
+            assert!(minimal.contains(&format!("class={class}")), "{minimal}");
+        }
+        assert!(single.contains(">loaded<") && minimal.contains(">loaded<"));
+    }
+}
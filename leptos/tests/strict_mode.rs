@@ -0,0 +1,68 @@
+#![cfg(all(debug_assertions, not(feature = "ssr")))]
+
+use leptos::*;
+use std::{cell::Cell, rc::Rc};
+
+#[component]
+fn Counted(
+    setups: Rc<Cell<usize>>,
+    cleanups: Rc<Cell<usize>>,
+) -> impl IntoView {
+    setups.set(setups.get() + 1);
+    on_cleanup(move || cleanups.set(cleanups.get() + 1));
+}
+
+#[component]
+fn Parent(
+    setups: Rc<Cell<usize>>,
+    child_setups: Rc<Cell<usize>>,
+) -> impl IntoView {
+    setups.set(setups.get() + 1);
+    view! { <Counted setups=child_setups cleanups=Rc::default()/> }
+}
+
+#[component]
+fn WithChildren(setups: Rc<Cell<usize>>, children: Children) -> impl IntoView {
+    setups.set(setups.get() + 1);
+    children()
+}
+
+#[test]
+fn strict_mode_sets_components_up_twice() {
+    let runtime = create_runtime();
+    strict_mode::set_strict_mode(true);
+
+    let setups = Rc::new(Cell::new(0));
+    let cleanups = Rc::new(Cell::new(0));
+    let _view = view! { <Counted setups=Rc::clone(&setups) cleanups=Rc::clone(&cleanups)/> }
+        .into_view();
+    // the first setup is disposed right away
+    assert_eq!((setups.get(), cleanups.get()), (2, 1));
+
+    // components set up by the first setup of their parent are only set up once, so
+    // that nested components are not set up twice as many times at each level
+    let parent_setups = Rc::new(Cell::new(0));
+    let child_setups = Rc::new(Cell::new(0));
+    let _view = view! {
+        <Parent setups=Rc::clone(&parent_setups) child_setups=Rc::clone(&child_setups)/>
+    }
+    .into_view();
+    assert_eq!((parent_setups.get(), child_setups.get()), (2, 3));
+
+    strict_mode::set_strict_mode(false);
+    runtime.dispose();
+}
+
+#[test]
+fn strict_mode_sets_components_with_props_that_cannot_be_cloned_up_once() {
+    let runtime = create_runtime();
+    strict_mode::set_strict_mode(true);
+
+    let setups = Rc::new(Cell::new(0));
+    let _view = view! { <WithChildren setups=Rc::clone(&setups)>"child"</WithChildren> }
+        .into_view();
+    assert_eq!(setups.get(), 1);
+
+    strict_mode::set_strict_mode(false);
+    runtime.dispose();
+}
@@ -14,7 +14,7 @@ pub use dyn_child::*;
 pub use each::*;
 pub use errors::*;
 pub use fragment::*;
-use leptos_reactive::{untrack_with_diagnostics, Oco};
+use leptos_reactive::{untrack_with_diagnostics, with_component_name, Oco};
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 use once_cell::unsync::OnceCell;
 use std::fmt;
@@ -275,13 +275,96 @@ where
             children_fn,
         } = self;
 
-        let mut repr = ComponentRepr::new_with_id(name, id);
+        let mut repr = ComponentRepr::new_with_id(name.clone(), id);
 
         // disposed automatically when the parent scope is disposed
-        let child = untrack_with_diagnostics(|| children_fn().into_view());
+        let child = with_component_name(&name, || {
+            untrack_with_diagnostics(|| children_fn().into_view())
+        });
 
         repr.children.push(child);
 
         repr.into_view()
     }
 }
+
+/// The props of a component, about to be passed to its setup function by the
+/// [`component`](https://docs.rs/leptos/latest/leptos/attr.component.html) macro.
+///
+/// In the browser, in [strict mode](leptos_reactive::strict_mode), a component whose props
+/// can be cloned is set up once and disposed right away before it is set up for real, with
+/// [`StrictComponentSetup`]. Other components fall back to [`ComponentSetupOnce`].
+#[doc(hidden)]
+pub struct ComponentSetup<P> {
+    name: &'static str,
+    props: std::cell::Cell<Option<P>>,
+}
+
+impl<P> ComponentSetup<P> {
+    #[doc(hidden)]
+    pub fn new(name: &'static str, props: P) -> Self {
+        Self {
+            name,
+            props: std::cell::Cell::new(Some(props)),
+        }
+    }
+
+    fn take_props(&self) -> P {
+        self.props
+            .take()
+            .expect("a component should only be set up once")
+    }
+}
+
+/// Sets up a component whose props can be cloned, twice in strict mode.
+#[doc(hidden)]
+pub trait StrictComponentSetup<P> {
+    fn setup<V: IntoView>(&self, setup: impl Fn(P) -> V) -> V;
+}
+
+impl<P: Clone + 'static> StrictComponentSetup<P> for ComponentSetup<P> {
+    fn setup<V: IntoView>(&self, setup: impl Fn(P) -> V) -> V {
+        let props = self.take_props();
+
+        // on the server, and while hydrating, the first setup would render markup or
+        // claim DOM nodes that the real one expects to find
+        #[cfg(not(feature = "ssr"))]
+        if leptos_reactive::strict_mode::should_rehearse()
+            && !HydrationCtx::is_hydrating()
+        {
+            use leptos_reactive::{
+                as_child_of_current_owner, provide_context,
+                GlobalSuspenseContext, SuspenseContext,
+            };
+
+            let id = HydrationCtx::peek_always();
+            let what = format_args!("The component <{}/>", self.name);
+            leptos_reactive::strict_mode::rehearse(&what, || {
+                let (view, disposer) = as_child_of_current_owner(|props| {
+                    // resources read by the first setup are never loaded, so they
+                    // must not hold the suspense contexts of the real one
+                    provide_context(SuspenseContext::new());
+                    provide_context(GlobalSuspenseContext::new());
+                    setup(props).into_view()
+                })(props.clone());
+                drop(view);
+                drop(disposer);
+            });
+            HydrationCtx::continue_from(id);
+        }
+
+        setup(props)
+    }
+}
+
+/// Sets up a component whose props cannot be cloned, once.
+#[doc(hidden)]
+pub trait ComponentSetupOnce<P> {
+    fn setup<V: IntoView>(&self, setup: impl Fn(P) -> V) -> V;
+}
+
+impl<P> ComponentSetupOnce<P> for &ComponentSetup<P> {
+    fn setup<V: IntoView>(&self, setup: impl Fn(P) -> V) -> V {
+        setup(self.take_props())
+    }
+}
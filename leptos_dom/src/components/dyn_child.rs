@@ -380,6 +380,24 @@ where
                 },
             );
 
+            // in an interactive view, the child is swapped out like in the
+            // browser, so that it reflects any later changes
+            #[cfg(all(
+                feature = "interactive-ssr",
+                not(all(target_arch = "wasm32", feature = "web"))
+            ))]
+            if crate::ssr_interactive::is_interactive() {
+                leptos_reactive::create_isomorphic_effect(move |_| {
+                    let new_child = crate::ssr_interactive::interactive(|| {
+                        child_fn().into_view()
+                    });
+
+                    **child.borrow_mut() = Some(new_child);
+                });
+
+                return component;
+            }
+
             #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
             {
                 let new_child = child_fn().into_view();
@@ -1,6 +1,7 @@
-#[cfg(not(all(target_arch = "wasm32", feature = "web")))]
-use crate::hydration::HydrationKey;
-use crate::{hydration::HydrationCtx, Comment, CoreComponent, IntoView, View};
+use crate::{
+    hydration::{HydrationCtx, HydrationKey},
+    Comment, CoreComponent, IntoView, View,
+};
 use leptos_reactive::{as_child_of_current_owner, Disposer};
 use std::{cell::RefCell, fmt, hash::Hash, ops::Deref, rc::Rc};
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
@@ -54,8 +55,11 @@ pub struct EachRepr {
     opening: Comment,
     pub(crate) children: Rc<RefCell<Vec<Option<EachItem>>>>,
     closing: Comment,
-    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
     pub(crate) id: Option<HydrationKey>,
+    /// The hydration key following the last row, when rows are
+    /// hydrated lazily.
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    pub(crate) rows_end: Option<HydrationKey>,
 }
 
 impl fmt::Debug for EachRepr {
@@ -64,10 +68,11 @@ impl fmt::Debug for EachRepr {
 
         f.write_str("<Each>\n")?;
 
-        for child in self.children.borrow().deref() {
+        // rows that are still waiting to be hydrated lazily are `None`
+        for child in self.children.borrow().deref().iter().flatten() {
             let mut pad_adapter = pad_adapter::PadAdapter::new(f);
 
-            writeln!(pad_adapter, "{:#?}", child.as_ref().unwrap())?;
+            writeln!(pad_adapter, "{:#?}", child)?;
         }
 
         f.write_str("</Each>")
@@ -115,8 +120,9 @@ impl Default for EachRepr {
             opening: markers.1,
             children: Default::default(),
             closing: markers.0,
-            #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
             id,
+            #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+            rows_end: None,
         }
     }
 }
@@ -165,6 +171,7 @@ impl Mountable for EachRepr {
 
 /// The internal representation of an [`Each`] item.
 #[derive(PartialEq, Eq)]
+#[cfg_attr(not(all(target_arch = "wasm32", feature = "web")), derive(Clone))]
 pub(crate) struct EachItem {
     #[cfg(all(target_arch = "wasm32", feature = "web"))]
     disposer: Disposer,
@@ -176,6 +183,10 @@ pub(crate) struct EachItem {
     closing: Option<Comment>,
     #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
     pub(crate) id: Option<HydrationKey>,
+    /// The hydration key at which this row started, when rows are
+    /// hydrated lazily.
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    pub(crate) row_start: Option<HydrationKey>,
 }
 
 impl fmt::Debug for EachItem {
@@ -278,6 +289,8 @@ impl EachItem {
             closing: markers.0,
             #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
             id,
+            #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+            row_start: None,
         }
     }
 }
@@ -347,6 +360,7 @@ where
     pub(crate) items_fn: IF,
     pub(crate) each_fn: EF,
     key_fn: KF,
+    hydrate_lazily: bool,
 }
 
 impl<IF, I, T, EF, N, KF, K> Each<IF, I, T, EF, N, KF, K>
@@ -366,8 +380,21 @@ where
             items_fn,
             each_fn,
             key_fn,
+            hydrate_lazily: false,
         }
     }
+
+    /// If `true`, rows that were rendered on the server are only hydrated
+    /// once they scroll into the viewport, rather than all at once when the
+    /// page loads.
+    ///
+    /// Rows that are still waiting to be hydrated are claimed before the
+    /// list is next updated. This has no effect outside of hydration.
+    #[inline(always)]
+    pub fn hydrate_lazily(mut self, hydrate_lazily: bool) -> Self {
+        self.hydrate_lazily = hydrate_lazily;
+        self
+    }
 }
 
 impl<IF, I, T, EF, N, KF, K> IntoView for Each<IF, I, T, EF, N, KF, K>
@@ -389,10 +416,13 @@ where
             items_fn,
             each_fn,
             key_fn,
+            hydrate_lazily,
         } = self;
 
         #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
         let _ = key_fn;
+        #[cfg(all(target_arch = "wasm32", not(feature = "hydrate")))]
+        let _ = hydrate_lazily;
 
         let component = EachRepr::default();
 
@@ -403,11 +433,24 @@ where
         let (children, closing) =
             (component.children.clone(), component.closing.node.clone());
 
-        let each_fn = as_child_of_current_owner(each_fn);
+        #[cfg(all(target_arch = "wasm32", feature = "hydrate"))]
+        let (id, lazy_rows) = (component.id, RefCell::new(None));
+
+        let each_fn = Rc::new(as_child_of_current_owner(each_fn));
 
         #[cfg(all(target_arch = "wasm32", feature = "web"))]
         create_render_effect(
             move |prev_hash_run: Option<HashRun<FxIndexSet<K>>>| {
+                // rows that are still waiting to be hydrated need to be
+                // claimed before the diff can move or remove them
+                #[cfg(feature = "hydrate")]
+                if let Some(lazy_rows) = lazy_rows.take() {
+                    LazyRows::claim_all(lazy_rows, &children, &*each_fn);
+                }
+
+                #[cfg(feature = "hydrate")]
+                let first_run = prev_hash_run.is_none();
+
                 let mut children_borrow = children.borrow_mut();
 
                 #[cfg(all(
@@ -466,7 +509,7 @@ where
                             cmds,
                             &mut children_borrow,
                             items,
-                            &each_fn,
+                            &*each_fn,
                         );
                         return HashRun(hashed_items);
                     }
@@ -474,6 +517,53 @@ where
 
                 // if previous run is empty
                 *children_borrow = Vec::with_capacity(capacity);
+
+                // when hydrating lazily, the rows are already in the DOM
+                // in between the markers written by the server, so they
+                // are claimed in place instead of being moved
+                #[cfg(feature = "hydrate")]
+                if first_run && hydrate_lazily && HydrationCtx::is_hydrating() {
+                    if let Some((markers, rows_end)) =
+                        id.and_then(|id| find_lazy_rows(&id, &closing))
+                    {
+                        let lazy = LazyRows::new(&children, &each_fn);
+                        let mut markers = markers.into_iter();
+
+                        for item in items_iter {
+                            hashed_items.insert(key_fn(&item));
+                            let marker = markers.next();
+                            let target = lazy
+                                .as_ref()
+                                .and(marker.as_ref())
+                                .and_then(|(_, marker)| marker.next_element());
+
+                            match (&lazy, marker, target) {
+                                (Some(lazy), Some(marker), Some(target)) => {
+                                    lazy.observe(item, marker, target);
+                                    children_borrow.push(None);
+                                }
+                                (lazy, marker, _) => {
+                                    if let Some(lazy) = lazy {
+                                        lazy.skip();
+                                    }
+                                    let start =
+                                        marker.map(|(start, marker)| {
+                                            marker.remove();
+                                            start
+                                        });
+                                    children_borrow.push(Some(hydrate_row(
+                                        start, item, &*each_fn,
+                                    )));
+                                }
+                            }
+                        }
+
+                        HydrationCtx::continue_from(rows_end);
+                        *lazy_rows.borrow_mut() = lazy;
+                        return HashRun(hashed_items);
+                    }
+                }
+
                 #[cfg(all(target_arch = "wasm32", feature = "web"))]
                 let fragment = crate::document().create_document_fragment();
 
@@ -501,21 +591,356 @@ where
             },
         );
 
+        #[cfg(all(
+            feature = "interactive-ssr",
+            not(all(target_arch = "wasm32", feature = "web"))
+        ))]
+        if crate::ssr_interactive::is_interactive() {
+            return live_each(component, items_fn, each_fn, key_fn);
+        }
+
         #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
-        {
+        let component = {
+            let mut component = component;
             *component.children.borrow_mut() = (items_fn)()
                 .into_iter()
                 .map(|child| {
+                    // record where each row starts, so that the client can
+                    // skip over rows and hydrate them later
+                    let row_start = if hydrate_lazily {
+                        HydrationCtx::peek()
+                    } else {
+                        None
+                    };
                     let (item, disposer) = each_fn(child);
-                    Some(EachItem::new(disposer, item.into_view()))
+                    let mut each_item =
+                        EachItem::new(disposer, item.into_view());
+                    each_item.row_start = row_start;
+                    Some(each_item)
                 })
                 .collect();
-        }
+            if hydrate_lazily {
+                component.rows_end = HydrationCtx::peek();
+            }
+            component
+        };
 
         View::CoreComponent(CoreComponent::Each(component))
     }
 }
 
+/// Keeps the rows of an interactive view up to date, reusing the rows whose
+/// keys are still present, like the browser does.
+#[cfg(all(
+    feature = "interactive-ssr",
+    not(all(target_arch = "wasm32", feature = "web"))
+))]
+fn live_each<I, T, N, K>(
+    component: EachRepr,
+    items_fn: impl Fn() -> I + 'static,
+    each_fn: Rc<dyn Fn(T) -> (N, Disposer)>,
+    key_fn: impl Fn(&T) -> K + 'static,
+) -> View
+where
+    I: IntoIterator<Item = T>,
+    T: 'static,
+    N: IntoView + 'static,
+    K: Eq + Hash + 'static,
+{
+    use crate::ssr_interactive::interactive;
+    use leptos_reactive::{create_isomorphic_effect, with_owner, Owner};
+    use std::collections::HashMap;
+
+    let children = component.children.clone();
+    // rows belong to the list rather than the effect, so that rows that are
+    // kept are not cleaned up when the effect runs again
+    let owner = Owner::current();
+
+    create_isomorphic_effect(move |prev_keys: Option<Vec<K>>| {
+        let mut prev_rows = prev_keys
+            .unwrap_or_default()
+            .into_iter()
+            .zip(std::mem::take(&mut *children.borrow_mut()))
+            .filter_map(|(key, row)| Some((key, row?)))
+            .collect::<HashMap<_, _>>();
+
+        let mut keys = Vec::new();
+        let rows = interactive(|| {
+            items_fn()
+                .into_iter()
+                .map(|item| {
+                    let key = key_fn(&item);
+                    let row = prev_rows.remove(&key).unwrap_or_else(|| {
+                        let new_row = || {
+                            let (child, disposer) = each_fn(item);
+                            EachItem::new(disposer, child.into_view())
+                        };
+                        match owner {
+                            Some(owner) => with_owner(owner, new_row),
+                            None => new_row(),
+                        }
+                    });
+                    keys.push(key);
+                    Some(row)
+                })
+                .collect()
+        });
+        *children.borrow_mut() = rows;
+
+        keys
+    });
+
+    View::CoreComponent(CoreComponent::Each(component))
+}
+
+/// Writes the marker that precedes a row of a lazily-hydrated [`Each`],
+/// recording the hydration key at which the row starts.
+#[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+pub(crate) fn lazy_row_marker(
+    each: &HydrationKey,
+    row_start: &HydrationKey,
+) -> String {
+    format!("<!--leptos-row={each}:{row_start}-->")
+}
+
+/// Writes the marker that follows the rows of a lazily-hydrated [`Each`],
+/// recording the hydration key at which hydration resumes after them.
+#[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+pub(crate) fn lazy_rows_end_marker(
+    each: &HydrationKey,
+    rows_end: &HydrationKey,
+) -> String {
+    format!("<!--leptos-rows-end={each}:{rows_end}-->")
+}
+
+/// Collects the row markers (and the end marker) written by the server for
+/// the [`Each`] with the given key, by walking back from its closing marker.
+#[cfg(all(target_arch = "wasm32", feature = "hydrate"))]
+fn find_lazy_rows(
+    each: &HydrationKey,
+    closing: &web_sys::Node,
+) -> Option<(Vec<(HydrationKey, LazyRowMarker)>, HydrationKey)> {
+    let row_prefix = format!("leptos-row={each}:");
+    let end_prefix = format!("leptos-rows-end={each}:");
+
+    let mut rows = Vec::new();
+    let mut rows_end = None;
+    let mut sibling = closing.previous_sibling();
+    while let Some(node) = sibling {
+        sibling = node.previous_sibling();
+        if node.node_type() != web_sys::Node::COMMENT_NODE {
+            continue;
+        }
+        let content = node.text_content().unwrap_or_default();
+        if let Some(key) = content.strip_prefix(&row_prefix) {
+            rows.push((
+                key.parse().ok()?,
+                LazyRowMarker {
+                    node,
+                    row_prefix: row_prefix.clone(),
+                    end_prefix: end_prefix.clone(),
+                },
+            ));
+        } else if let Some(key) = content.strip_prefix(&end_prefix) {
+            rows_end = Some(key.parse().ok()?);
+        }
+    }
+    rows.reverse();
+
+    Some((rows, rows_end?))
+}
+
+/// The comment the server wrote before a row of a lazily-hydrated [`Each`].
+#[cfg(all(target_arch = "wasm32", feature = "hydrate"))]
+struct LazyRowMarker {
+    node: web_sys::Node,
+    row_prefix: String,
+    end_prefix: String,
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "hydrate"))]
+impl LazyRowMarker {
+    /// The first element in this row, which is observed to find out when
+    /// the row scrolls into view.
+    fn next_element(&self) -> Option<web_sys::Element> {
+        let mut sibling = self.node.next_sibling();
+        while let Some(node) = sibling {
+            if node.node_type() == web_sys::Node::ELEMENT_NODE {
+                return Some(node.unchecked_into());
+            }
+            if node.node_type() == web_sys::Node::COMMENT_NODE {
+                let content = node.text_content().unwrap_or_default();
+                if content.starts_with(&self.row_prefix)
+                    || content.starts_with(&self.end_prefix)
+                {
+                    return None;
+                }
+            }
+            sibling = node.next_sibling();
+        }
+        None
+    }
+
+    fn remove(&self) {
+        if let Some(parent) = self.node.parent_node() {
+            _ = parent.remove_child(&self.node);
+        }
+    }
+}
+
+/// A row of a lazily-hydrated [`Each`] that has not been claimed yet.
+#[cfg(all(target_arch = "wasm32", feature = "hydrate"))]
+struct PendingRow<T> {
+    item: T,
+    start: HydrationKey,
+    marker: LazyRowMarker,
+    target: web_sys::Element,
+}
+
+/// The rows of a lazily-hydrated [`Each`] that are waiting to scroll into
+/// view, in the same order as its children.
+#[cfg(all(target_arch = "wasm32", feature = "hydrate"))]
+struct LazyRows<T> {
+    rows: Rc<RefCell<Vec<Option<PendingRow<T>>>>>,
+    observer: web_sys::IntersectionObserver,
+    _callback: wasm_bindgen::closure::Closure<dyn FnMut(js_sys::Array)>,
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "hydrate"))]
+impl<T: 'static> LazyRows<T> {
+    /// Creates an `IntersectionObserver` that claims each row once it
+    /// becomes visible. Returns `None` if the observer can't be created.
+    fn new<N: IntoView>(
+        children: &Rc<RefCell<Vec<Option<EachItem>>>>,
+        each_fn: &Rc<impl Fn(T) -> (N, Disposer) + 'static>,
+    ) -> Option<Self> {
+        let rows: Rc<RefCell<Vec<Option<PendingRow<T>>>>> = Default::default();
+        let callback =
+            wasm_bindgen::closure::Closure::<dyn FnMut(js_sys::Array)>::new({
+                let rows = Rc::clone(&rows);
+                let children = Rc::clone(children);
+                let each_fn = Rc::clone(each_fn);
+                move |entries: js_sys::Array| {
+                    for entry in entries.iter() {
+                        let entry = entry
+                        .unchecked_into::<web_sys::IntersectionObserverEntry>();
+                        if !entry.is_intersecting() {
+                            continue;
+                        }
+                        let target = entry.target();
+                        let row = {
+                            let mut rows = rows.borrow_mut();
+                            rows.iter_mut().enumerate().find_map(
+                                |(idx, row)| {
+                                    row.as_ref()
+                                        .is_some_and(|row| row.target == target)
+                                        .then(|| (idx, row.take().unwrap()))
+                                },
+                            )
+                        };
+                        if let Some((idx, row)) = row {
+                            claim_row(&children, idx, row, &*each_fn);
+                        }
+                    }
+                }
+            });
+        let observer = web_sys::IntersectionObserver::new(
+            callback.as_ref().unchecked_ref(),
+        )
+        .ok()?;
+
+        Some(Self {
+            rows,
+            observer,
+            _callback: callback,
+        })
+    }
+
+    /// Adds a row that will be claimed once it scrolls into view.
+    fn observe(
+        &self,
+        item: T,
+        (start, marker): (HydrationKey, LazyRowMarker),
+        target: web_sys::Element,
+    ) {
+        self.observer.observe(&target);
+        self.rows.borrow_mut().push(Some(PendingRow {
+            item,
+            start,
+            marker,
+            target,
+        }));
+    }
+
+    /// Adds a row that has already been claimed.
+    fn skip(&self) {
+        self.rows.borrow_mut().push(None);
+    }
+
+    /// Claims every row that is still pending.
+    fn claim_all<N: IntoView>(
+        self,
+        children: &RefCell<Vec<Option<EachItem>>>,
+        each_fn: &impl Fn(T) -> (N, Disposer),
+    ) {
+        let rows = self.rows.take();
+        for (idx, row) in rows.into_iter().enumerate() {
+            if let Some(row) = row {
+                claim_row(children, idx, row, each_fn);
+            }
+        }
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "hydrate"))]
+impl<T> Drop for LazyRows<T> {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+    }
+}
+
+/// Hydrates a row that was rendered on the server, resuming from the
+/// hydration key at which it started.
+#[cfg(all(target_arch = "wasm32", feature = "hydrate"))]
+fn hydrate_row<T, N: IntoView>(
+    start: Option<HydrationKey>,
+    item: T,
+    each_fn: &impl Fn(T) -> (N, Disposer),
+) -> EachItem {
+    HydrationCtx::with_hydration_on(|| {
+        let prev = HydrationCtx::peek_always();
+        if let Some(start) = start {
+            HydrationCtx::continue_from(start);
+        }
+        let (child, disposer) = each_fn(item);
+        let each_item = EachItem::new(disposer, child.into_view());
+        if start.is_some() {
+            HydrationCtx::continue_from(prev);
+        }
+        each_item
+    })
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "hydrate"))]
+fn claim_row<T, N: IntoView>(
+    children: &RefCell<Vec<Option<EachItem>>>,
+    idx: usize,
+    row: PendingRow<T>,
+    each_fn: &impl Fn(T) -> (N, Disposer),
+) {
+    let PendingRow {
+        item,
+        start,
+        marker,
+        ..
+    } = row;
+    let each_item = hydrate_row(Some(start), item, each_fn);
+    marker.remove();
+    if let Some(slot) = children.borrow_mut().get_mut(idx) {
+        *slot = Some(each_item);
+    }
+}
+
 struct HashRun<T>(T);
 
 impl<T> fmt::Debug for HashRun<T> {
@@ -549,52 +974,46 @@ fn diff<K: Eq + Hash>(from: &FxIndexSet<K>, to: &FxIndexSet<K>) -> Diff {
         };
     }
 
-    let mut removed = vec![];
-    let mut moved = vec![];
-    let mut added = vec![];
-    let max_len = std::cmp::max(from.len(), to.len());
-
-    for index in 0..max_len {
-        let from_item = from.get_index(index);
-        let to_item = to.get_index(index);
-
-        // if they're the same, do nothing
-        if from_item != to_item {
-            // if it's only in old, not new, remove it
-            if from_item.is_some() && !to.contains(from_item.unwrap()) {
-                let op = DiffOpRemove { at: index };
-                removed.push(op);
-            }
-            // if it's only in new, not old, add it
-            if to_item.is_some() && !from.contains(to_item.unwrap()) {
-                let op = DiffOpAdd {
-                    at: index,
-                    mode: DiffOpAddMode::Normal,
-                };
-                added.push(op);
-            }
-            // if it's in both old and new, it can either
-            // 1) be moved (and need to move in the DOM)
-            // 2) be moved (but not need to move in the DOM)
-            //    * this would happen if, for example, 2 items
-            //      have been added before it, and it has moved by 2
-            if let Some(from_item) = from_item {
-                if let Some(to_item) = to.get_full(from_item) {
-                    let moves_forward_by = (to_item.0 as i32) - (index as i32);
-                    let move_in_dom = moves_forward_by
-                        != (added.len() as i32) - (removed.len() as i32);
-
-                    let op = DiffOpMove {
-                        from: index,
-                        len: 1,
-                        to: to_item.0,
-                        move_in_dom,
-                    };
-                    moved.push(op);
-                }
-            }
-        }
-    }
+    let removed = from
+        .iter()
+        .enumerate()
+        .filter(|(_, key)| !to.contains(*key))
+        .map(|(at, _)| DiffOpRemove { at })
+        .collect::<Vec<_>>();
+    let added = to
+        .iter()
+        .enumerate()
+        .filter(|(_, key)| !from.contains(*key))
+        .map(|(at, _)| DiffOpAdd {
+            at,
+            mode: DiffOpAddMode::Normal,
+        })
+        .collect::<Vec<_>>();
+
+    // the rows that are in both old and new, with their index in each, in
+    // their old order. The longest run of them that is already in their new
+    // order stays where it is in the DOM, and the others are moved around it,
+    // which is the fewest moves possible
+    let kept = from
+        .iter()
+        .enumerate()
+        .filter_map(|(from, key)| Some((from, to.get_index_of(key)?)))
+        .collect::<Vec<_>>();
+    let stays = longest_increasing_subsequence(
+        &kept.iter().map(|(_, to)| *to).collect::<Vec<_>>(),
+    );
+    let mut moved = kept
+        .into_iter()
+        .zip(stays)
+        // rows that stay at the same index don't need to move at all
+        .filter(|((from, to), stays)| !(from == to && *stays))
+        .map(|((from, to), stays)| DiffOpMove {
+            from,
+            len: 1,
+            to,
+            move_in_dom: !stays,
+        })
+        .collect::<Vec<_>>();
 
     moved = group_adjacent_moves(moved);
 
@@ -607,6 +1026,34 @@ fn diff<K: Eq + Hash>(from: &FxIndexSet<K>, to: &FxIndexSet<K>) -> Diff {
     }
 }
 
+/// Marks the values that are part of a longest strictly increasing subsequence
+/// of `values`, in `O(n log n)`.
+fn longest_increasing_subsequence(values: &[usize]) -> Vec<bool> {
+    // `tails[len]` is the index of the smallest value that ends an increasing
+    // subsequence of length `len + 1`
+    let mut tails: Vec<usize> = Vec::new();
+    let mut previous = vec![None; values.len()];
+    for (index, value) in values.iter().enumerate() {
+        let len = tails.partition_point(|tail| values[*tail] < *value);
+        if len > 0 {
+            previous[index] = Some(tails[len - 1]);
+        }
+        if len == tails.len() {
+            tails.push(index);
+        } else {
+            tails[len] = index;
+        }
+    }
+
+    let mut in_subsequence = vec![false; values.len()];
+    let mut next = tails.last().copied();
+    while let Some(index) = next {
+        in_subsequence[index] = true;
+        next = previous[index];
+    }
+    in_subsequence
+}
+
 /// Group adjacent items that are being moved as a group.
 /// For example from `[2, 3, 5, 6]` to `[1, 2, 3, 4, 5, 6]` should result
 /// in a move for `2,3` and `5,6` rather than 4 individual moves.
@@ -616,7 +1063,10 @@ fn group_adjacent_moves(moved: Vec<DiffOpMove>) -> Vec<DiffOpMove> {
     for m in moved {
         match prev {
             Some(mut p) => {
-                if (m.from == p.from + p.len) && (m.to == p.to + p.len) {
+                if (m.from == p.from + p.len)
+                    && (m.to == p.to + p.len)
+                    && m.move_in_dom == p.move_in_dom
+                {
                     p.len += 1;
                     prev = Some(p);
                 } else {
@@ -793,32 +1243,61 @@ fn apply_diff<T, EF, V>(
         children[to] = Some(each_item);
     }
 
+    // new rows that go before the same sibling are collected into one
+    // detached fragment, so that each run of additions (like appending a
+    // page of rows) is inserted into the document at once
+    let mut batch: Option<(web_sys::Node, web_sys::DocumentFragment)> = None;
+
     for DiffOpAdd { at, mode } in add_cmds {
         let (item, disposer) = each_fn(items[at].take().unwrap());
         let each_item = EachItem::new(disposer, item.into_view());
 
-        match mode {
-            DiffOpAddMode::Normal => {
-                let sibling_node = children
-                    .get_next_closest_mounted_sibling(at, closing.to_owned());
-
-                mount_child(MountKind::Before(&sibling_node), &each_item);
-            }
-            DiffOpAddMode::Append => {
-                mount_child(MountKind::Before(closing), &each_item);
-            }
+        let sibling_node = match mode {
+            DiffOpAddMode::Normal => children
+                .get_next_closest_mounted_sibling(at, closing.to_owned()),
+            DiffOpAddMode::Append => closing.to_owned(),
             DiffOpAddMode::_Prepend => {
                 todo!("Prepends are not yet implemented")
             }
+        };
+
+        if !matches!(&batch, Some((sibling, _)) if *sibling == sibling_node) {
+            if let Some((sibling, fragment)) = batch.take() {
+                insert_before(&sibling, &fragment);
+            }
+            batch = Some((
+                sibling_node,
+                crate::document().create_document_fragment(),
+            ));
+        }
+        if let Some((_, fragment)) = &batch {
+            fragment
+                .append_child(&each_item.get_mountable_node())
+                .expect("append operation to not err");
         }
 
         children[at] = Some(each_item);
     }
 
+    if let Some((sibling, fragment)) = batch {
+        insert_before(&sibling, &fragment);
+    }
+
     #[allow(unstable_name_collisions)]
     children.drain_filter(|c| c.is_none());
 }
 
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+fn insert_before(
+    sibling: &web_sys::Node,
+    fragment: &web_sys::DocumentFragment,
+) {
+    sibling
+        .unchecked_ref::<web_sys::Element>()
+        .before_with_node_1(fragment)
+        .expect("before to not err");
+}
+
 /// Unpacks adds and moves into a sequence of interleaved
 /// add and move commands. Move commands will always return
 /// with a `len == 1`.
@@ -891,6 +1370,109 @@ fn unpack_moves(diff: &Diff) -> (Vec<DiffOpMove>, Vec<DiffOpAdd>) {
     (moves, adds)
 }
 
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn set(keys: &[usize]) -> FxIndexSet<usize> {
+        keys.iter().copied().collect()
+    }
+
+    /// Returns how many rows the diff from `from` to `to` moves in the DOM,
+    /// after checking that every row ends up at its new index and that the
+    /// rows it doesn't move are already in their new order.
+    fn moved_in_dom(from: &[usize], to: &[usize]) -> usize {
+        let diff = diff(&set(from), &set(to));
+        let moves = diff
+            .moved
+            .iter()
+            .flat_map(|m| {
+                (0..m.len).map(move |i| (m.from + i, m.to + i, m.move_in_dom))
+            })
+            .collect::<Vec<_>>();
+
+        let mut staying = Vec::new();
+        for (index, key) in from.iter().enumerate() {
+            let Some(new_index) = to.iter().position(|k| k == key) else {
+                continue;
+            };
+            match moves.iter().find(|(from, ..)| *from == index) {
+                Some((_, to, move_in_dom)) => {
+                    assert_eq!(*to, new_index);
+                    if !move_in_dom {
+                        staying.push(new_index);
+                    }
+                }
+                None => {
+                    assert_eq!(index, new_index);
+                    staying.push(new_index);
+                }
+            }
+        }
+        assert!(
+            staying.windows(2).all(|pair| pair[0] < pair[1]),
+            "rows left in place are out of order: {staying:?}"
+        );
+
+        moves
+            .iter()
+            .filter(|(.., move_in_dom)| *move_in_dom)
+            .count()
+    }
+
+    #[test]
+    fn reorders_move_the_fewest_rows() {
+        let rows = (0..1000).collect::<Vec<_>>();
+
+        let mut rotated = rows.clone();
+        rotated.rotate_left(1);
+        assert_eq!(moved_in_dom(&rows, &rotated), 1);
+
+        let mut swapped = rows.clone();
+        swapped.swap(1, 998);
+        assert_eq!(moved_in_dom(&rows, &swapped), 2);
+
+        let reversed = rows.iter().rev().copied().collect::<Vec<_>>();
+        assert_eq!(moved_in_dom(&rows, &reversed), 999);
+
+        let mut changed = rotated;
+        changed.remove(10);
+        changed.insert(0, 1000);
+        assert_eq!(moved_in_dom(&rows, &changed), 1);
+    }
+
+    fn rows() -> impl Strategy<Value = Vec<usize>> {
+        prop::sample::subsequence((0..24).collect::<Vec<_>>(), 0..24)
+            .prop_shuffle()
+    }
+
+    proptest! {
+        #[test]
+        fn only_rows_outside_the_longest_ordered_run_move(
+            from in rows(),
+            to in rows(),
+        ) {
+            let kept = from
+                .iter()
+                .filter_map(|key| to.iter().position(|k| k == key))
+                .collect::<Vec<_>>();
+            // the length of the longest increasing subsequence, the slow way
+            let mut longest = vec![1; kept.len()];
+            for i in 0..kept.len() {
+                for j in 0..i {
+                    if kept[j] < kept[i] {
+                        longest[i] = longest[i].max(longest[j] + 1);
+                    }
+                }
+            }
+            let longest = longest.into_iter().max().unwrap_or_default();
+
+            prop_assert_eq!(moved_in_dom(&from, &to), kept.len() - longest);
+        }
+    }
+}
+
 // #[cfg(test)]
 // mod test_utils {
 //     use super::*;
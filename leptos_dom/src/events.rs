@@ -12,6 +12,92 @@ thread_local! {
     pub(crate) static GLOBAL_EVENTS: RefCell<HashSet<Oco<'static, str>>> = RefCell::new(HashSet::new());
 }
 
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+thread_local! {
+    /// The global handler of each delegated event, so that it can be added to other windows.
+    static DELEGATED_HANDLERS: RefCell<Vec<DelegatedHandler>> = Default::default();
+    /// The other windows views are mounted in, with the number of views mounted in each.
+    static DELEGATED_WINDOWS: RefCell<Vec<(web_sys::Window, usize)>> = Default::default();
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+struct DelegatedHandler {
+    event_name: Oco<'static, str>,
+    handler: JsValue,
+    options: Option<web_sys::AddEventListenerOptions>,
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+impl DelegatedHandler {
+    fn add_to(&self, window: &web_sys::Window) {
+        if let Some(options) = &self.options {
+            _ = window
+                .add_event_listener_with_callback_and_add_event_listener_options(
+                    &self.event_name,
+                    self.handler.unchecked_ref(),
+                    options,
+                );
+        } else {
+            _ = window.add_event_listener_with_callback(
+                &self.event_name,
+                self.handler.unchecked_ref(),
+            );
+        }
+    }
+
+    fn remove_from(&self, window: &web_sys::Window) {
+        _ = window.remove_event_listener_with_callback(
+            &self.event_name,
+            self.handler.unchecked_ref(),
+        );
+    }
+}
+
+/// Adds the delegated event handlers to another window, like one opened with
+/// `window.open()`, so that events in the views mounted in its document reach their
+/// handlers. Each call must be matched by a call to [`stop_delegating_events_in`].
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+pub fn delegate_events_in(window: &web_sys::Window) {
+    DELEGATED_WINDOWS.with(|windows| {
+        let mut windows = windows.borrow_mut();
+        if let Some((_, mounted)) =
+            windows.iter_mut().find(|(w, _)| w == window)
+        {
+            *mounted += 1;
+        } else {
+            DELEGATED_HANDLERS.with(|handlers| {
+                for handler in handlers.borrow().iter() {
+                    handler.add_to(window);
+                }
+            });
+            windows.push((window.clone(), 1));
+        }
+    })
+}
+
+/// Removes the delegated event handlers added by [`delegate_events_in`] once no views
+/// are mounted in the window anymore.
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+pub fn stop_delegating_events_in(window: &web_sys::Window) {
+    DELEGATED_WINDOWS.with(|windows| {
+        let mut windows = windows.borrow_mut();
+        let Some(index) = windows.iter().position(|(w, _)| w == window) else {
+            return;
+        };
+        windows[index].1 -= 1;
+        if windows[index].1 == 0 {
+            windows.swap_remove(index);
+            DELEGATED_HANDLERS.with(|handlers| {
+                for handler in handlers.borrow().iter() {
+                    handler.remove_from(window);
+                }
+            });
+        }
+    })
+}
+
 // Used in template macro
 #[doc(hidden)]
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
@@ -112,7 +198,6 @@ pub(crate) fn add_event_listener_undelegated<E>(
     }
 }
 
-// cf eventHandler in ryansolid/dom-expressions
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 pub(crate) fn add_delegated_event_listener(
     key: &str,
@@ -125,50 +210,7 @@ pub(crate) fn add_delegated_event_listener(
             // create global handler
             let key = JsValue::from_str(&key);
             let handler = move |ev: web_sys::Event| {
-                let target = ev.target();
-                let node = ev.composed_path().get(0);
-                let mut node = if node.is_undefined() || node.is_null() {
-                    JsValue::from(target)
-                } else {
-                    node
-                };
-
-                // TODO reverse Shadow DOM retargetting
-
-                // TODO simulate currentTarget
-
-                while !node.is_null() {
-                    let node_is_disabled = js_sys::Reflect::get(
-                        &node,
-                        &JsValue::from_str("disabled"),
-                    )
-                    .unwrap_throw()
-                    .is_truthy();
-                    if !node_is_disabled {
-                        let maybe_handler =
-                            js_sys::Reflect::get(&node, &key).unwrap_throw();
-                        if !maybe_handler.is_undefined() {
-                            let f = maybe_handler
-                                .unchecked_ref::<js_sys::Function>();
-                            let _ = f.call1(&node, &ev);
-
-                            if ev.cancel_bubble() {
-                                return;
-                            }
-                        }
-                    }
-
-                    // navigate up tree
-                    if let Some(parent) =
-                        node.unchecked_ref::<web_sys::Node>().parent_node()
-                    {
-                        node = parent.into()
-                    } else if let Some(root) = node.dyn_ref::<web_sys::ShadowRoot>() {
-                        node = root.host().unchecked_into();
-                    } else  {
-                        node = JsValue::null()
-                    }
-                }
+                dispatch_delegated_event(&key, &ev);
             };
 
             cfg_if::cfg_if! {
@@ -182,23 +224,204 @@ pub(crate) fn add_delegated_event_listener(
             }
 
             let handler = Box::new(handler) as Box<dyn FnMut(web_sys::Event)>;
-            let handler = Closure::wrap(handler).into_js_value();
-            if let Some(options) = options {
-                _ = crate::window().add_event_listener_with_callback_and_add_event_listener_options(
-                    &event_name,
-                    handler.unchecked_ref(),
-                    options,
-                );
-            } else {
-                _ = crate::window().add_event_listener_with_callback(
-                    &event_name,
-                    handler.unchecked_ref(),
-                );
-
-            }
+            let handler = DelegatedHandler {
+                event_name: event_name.clone(),
+                handler: Closure::wrap(handler).into_js_value(),
+                options: options.clone(),
+            };
+            handler.add_to(&crate::window());
+            DELEGATED_WINDOWS.with(|windows| {
+                for (window, _) in windows.borrow().iter() {
+                    handler.add_to(window);
+                }
+            });
+            DELEGATED_HANDLERS
+                .with(|handlers| handlers.borrow_mut().push(handler));
 
             // register that we've created handler
             events.insert(event_name);
         }
     })
 }
+
+// cf eventHandler in ryansolid/dom-expressions
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+fn dispatch_delegated_event(key: &JsValue, ev: &web_sys::Event) {
+    let target = ev.target();
+    let node = ev.composed_path().get(0);
+    let mut node = if node.is_undefined() || node.is_null() {
+        JsValue::from(target)
+    } else {
+        node
+    };
+
+    // TODO reverse Shadow DOM retargetting
+
+    // TODO simulate currentTarget
+
+    while !node.is_null() {
+        let node_is_disabled =
+            js_sys::Reflect::get(&node, &JsValue::from_str("disabled"))
+                .unwrap_throw()
+                .is_truthy();
+        if !node_is_disabled {
+            let maybe_handler = js_sys::Reflect::get(&node, key).unwrap_throw();
+            if !maybe_handler.is_undefined() {
+                let f = maybe_handler.unchecked_ref::<js_sys::Function>();
+                let _ = f.call1(&node, ev);
+
+                if ev.cancel_bubble() {
+                    return;
+                }
+            }
+        }
+
+        // navigate up tree
+        if let Some(parent) =
+            node.unchecked_ref::<web_sys::Node>().parent_node()
+        {
+            node = parent.into()
+        } else if let Some(root) = node.dyn_ref::<web_sys::ShadowRoot>() {
+            node = root.host().unchecked_into();
+        } else {
+            node = JsValue::null()
+        }
+    }
+}
+
+/// The global that the inline script emitted by the server integrations uses to queue
+/// events that happen before hydration has attached their handlers.
+#[cfg(all(target_arch = "wasm32", feature = "web", feature = "hydrate"))]
+const EVENT_QUEUE: &str = "__LEPTOS_EVENT_QUEUE";
+
+/// Replays events that were queued before hydration to the delegated event handlers that
+/// hydration has since attached, and stops queueing new events.
+///
+/// When an `island` is given, only the events inside it, and not inside an island nested
+/// in it, are replayed and taken off the queue, which goes on queueing events for the
+/// islands that have yet to hydrate.
+///
+/// Events have already been dispatched to the browser by the time they are replayed, so
+/// their default action has already happened and can't be prevented by the handler.
+#[cfg(all(target_arch = "wasm32", feature = "web", feature = "hydrate"))]
+pub(crate) fn replay_queued_events(island: Option<&web_sys::Element>) {
+    let window = crate::window();
+    let queue_key = JsValue::from_str(EVENT_QUEUE);
+    let Some(queue) = js_sys::Reflect::get(&window, &queue_key)
+        .ok()
+        .and_then(|q| q.dyn_into::<js_sys::Array>().ok())
+    else {
+        return;
+    };
+
+    // collect first, because handlers may register new global events or queue new events
+    let (replayed, left): (Vec<_>, Vec<_>) = queue
+        .iter()
+        .map(|ev| ev.unchecked_into::<web_sys::Event>())
+        .partition(|ev| island.map_or(true, |island| in_island(ev, island)));
+    if island.is_some() {
+        // the inline script only queues events while this is still the same array
+        queue.set_length(0);
+        for ev in &left {
+            queue.push(ev);
+        }
+    } else {
+        _ = js_sys::Reflect::delete_property(&window, &queue_key);
+    }
+
+    for ev in replayed {
+        let delegated = GLOBAL_EVENTS
+            .with(|events| events.borrow().contains(ev.type_().as_str()));
+        if delegated {
+            let key = JsValue::from_str(&format!("$$${}", ev.type_()));
+            dispatch_delegated_event(&key, &ev);
+        }
+    }
+}
+
+/// Whether the target of `ev` is inside `island`, and not inside an island nested in it.
+#[cfg(all(target_arch = "wasm32", feature = "web", feature = "hydrate"))]
+fn in_island(ev: &web_sys::Event, island: &web_sys::Element) -> bool {
+    let Some(target) = ev
+        .target()
+        .and_then(|target| target.dyn_into::<web_sys::Node>().ok())
+    else {
+        return false;
+    };
+    let element = match target.dyn_into::<web_sys::Element>() {
+        Ok(element) => Some(element),
+        Err(node) => node.parent_element(),
+    };
+    element
+        .and_then(|element| element.closest("leptos-island").ok().flatten())
+        .is_some_and(|closest| &closest == island)
+}
+
+#[cfg(all(test, target_arch = "wasm32", feature = "hydrate"))]
+mod tests {
+    use super::*;
+    use std::{cell::Cell, rc::Rc};
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn island_with_button(clicks: &Rc<Cell<usize>>) -> web_sys::Element {
+        let document = crate::document();
+        let island = document.create_element("leptos-island").unwrap();
+        let button = document.create_element("button").unwrap();
+        island.append_child(&button).unwrap();
+        document.body().unwrap().append_child(&island).unwrap();
+
+        let clicks = Rc::clone(clicks);
+        add_event_listener::<web_sys::Event>(
+            &button,
+            "$$$click".into(),
+            "click".into(),
+            Box::new(move |_| clicks.set(clicks.get() + 1)),
+            &None,
+        );
+        island
+    }
+
+    fn click(island: &web_sys::Element) {
+        let button = island.first_element_child().unwrap();
+        button.unchecked_into::<web_sys::HtmlElement>().click();
+    }
+
+    #[wasm_bindgen_test]
+    fn events_queued_for_an_island_are_replayed_when_it_hydrates() {
+        // the inline script emitted by the server integrations
+        js_sys::eval(
+            r#"(function () {
+                let queue = window.__LEPTOS_EVENT_QUEUE = [];
+                document.addEventListener("click", (ev) => {
+                    if (window.__LEPTOS_EVENT_QUEUE === queue) queue.push(ev);
+                }, true);
+            })()"#,
+        )
+        .unwrap();
+
+        let first_clicks = Rc::new(Cell::new(0));
+        let second_clicks = Rc::new(Cell::new(0));
+        let first = island_with_button(&first_clicks);
+        let second = island_with_button(&second_clicks);
+        // handled once, and queued once
+        click(&first);
+        click(&second);
+        assert_eq!((first_clicks.get(), second_clicks.get()), (1, 1));
+
+        replay_queued_events(Some(&first));
+        assert_eq!((first_clicks.get(), second_clicks.get()), (2, 1));
+        replay_queued_events(Some(&first));
+        assert_eq!(first_clicks.get(), 2);
+
+        replay_queued_events(None);
+        assert_eq!((first_clicks.get(), second_clicks.get()), (2, 2));
+        let queue = js_sys::Reflect::get(
+            &crate::window(),
+            &JsValue::from_str(EVENT_QUEUE),
+        )
+        .unwrap();
+        assert!(queue.is_undefined());
+    }
+}
@@ -2,12 +2,12 @@
 
 use leptos_reactive::Oco;
 use std::marker::PhantomData;
-use wasm_bindgen::convert::FromWasmAbi;
+use wasm_bindgen::{convert::FromWasmAbi, JsCast};
 
 /// A trait for converting types into [web_sys events](web_sys).
 pub trait EventDescriptor: Clone {
     /// The [`web_sys`] event type, such as [`web_sys::MouseEvent`].
-    type EventType: FromWasmAbi;
+    type EventType: FromWasmAbi + JsCast;
 
     /// Indicates if this event bubbles. For example, `click` bubbles,
     /// but `focus` does not.
@@ -52,15 +52,94 @@ impl<Ev: EventDescriptor> EventDescriptor for undelegated<Ev> {
     const BUBBLES: bool = false;
 }
 
-/// A custom event.
+/// The flags of an event listener that is attached directly to its element, rather than
+/// delegated. Used with [`WithOptions`], [`HtmlElement::on_with_options`](crate::HtmlElement::on_with_options),
+/// or the `on:event:capture`, `on:event:passive` and `on:event:once` modifiers in the `view` macro.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct EventOptions {
+    /// Whether the listener runs in the capture phase, before listeners on the
+    /// element's descendants.
+    pub capture: bool,
+    /// Whether the listener promises not to call `preventDefault()`, so that the browser
+    /// can scroll without waiting for it.
+    pub passive: bool,
+    /// Whether the listener is removed after it runs once.
+    pub once: bool,
+}
+
+/// Attaches the listener for `Ev` directly to the element with the given [`EventOptions`].
+/// Like [`undelegated`], it is never delegated, so `stopPropagation()` in handlers further
+/// up the tree works as it does with `addEventListener`.
+#[derive(Clone, Debug)]
+pub struct WithOptions<Ev: EventDescriptor> {
+    event: Ev,
+    options: Option<web_sys::AddEventListenerOptions>,
+}
+
+impl<Ev: EventDescriptor> WithOptions<Ev> {
+    /// Wraps `event` so that its listener is added with `options`.
+    pub fn new(event: Ev, options: EventOptions) -> Self {
+        // the options are a JS object, which only exists in the browser
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        let options = {
+            let js_options = web_sys::AddEventListenerOptions::new();
+            js_options.set_capture(options.capture);
+            js_options.set_passive(options.passive);
+            js_options.set_once(options.once);
+            Some(js_options)
+        };
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        let options = {
+            _ = options;
+            None
+        };
+        Self { event, options }
+    }
+}
+
+impl<Ev: EventDescriptor> EventDescriptor for WithOptions<Ev> {
+    type EventType = Ev::EventType;
+
+    #[inline(always)]
+    fn name(&self) -> Oco<'static, str> {
+        self.event.name()
+    }
+
+    #[inline(always)]
+    fn event_delegation_key(&self) -> Oco<'static, str> {
+        self.event.event_delegation_key()
+    }
+
+    const BUBBLES: bool = false;
+
+    #[inline(always)]
+    fn options(&self) -> &Option<web_sys::AddEventListenerOptions> {
+        &self.options
+    }
+}
+
+/// A custom event, with the [`web_sys`] type its handlers receive.
+///
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// # if false {
+/// let item_selected =
+///     ev::Custom::<web_sys::CustomEvent>::new("item-selected");
+/// view! { <ul/> }.on(item_selected, |ev| {
+///     logging::log!("selected {:?}", ev.detail());
+/// });
+/// # }
+/// # runtime.dispose();
+/// ```
 #[derive(Debug)]
-pub struct Custom<E: FromWasmAbi = web_sys::Event> {
+pub struct Custom<E: FromWasmAbi + JsCast = web_sys::Event> {
     name: Oco<'static, str>,
     options: Option<web_sys::AddEventListenerOptions>,
     _event_type: PhantomData<E>,
 }
 
-impl<E: FromWasmAbi> Clone for Custom<E> {
+impl<E: FromWasmAbi + JsCast> Clone for Custom<E> {
     fn clone(&self) -> Self {
         Self {
             name: self.name.clone(),
@@ -70,7 +149,7 @@ impl<E: FromWasmAbi> Clone for Custom<E> {
     }
 }
 
-impl<E: FromWasmAbi> EventDescriptor for Custom<E> {
+impl<E: FromWasmAbi + JsCast> EventDescriptor for Custom<E> {
     type EventType = E;
 
     fn name(&self) -> Oco<'static, str> {
@@ -89,7 +168,7 @@ impl<E: FromWasmAbi> EventDescriptor for Custom<E> {
     }
 }
 
-impl<E: FromWasmAbi> Custom<E> {
+impl<E: FromWasmAbi + JsCast> Custom<E> {
     /// Creates a custom event type that can be used within
     /// [`HtmlElement::on`](crate::HtmlElement::on), for events
     /// which are not covered in the [`ev`](crate::ev) module.
@@ -0,0 +1,350 @@
+#[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+use crate::HydrationKey;
+use crate::{IntoView, View};
+#[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    sync::{Arc, RwLock},
+};
+
+/// Caches the HTML rendered on the server for parts of a page that are the same for every
+/// request, like a header, a footer, or a product card, so that they are only rendered once.
+///
+/// Use it with [`cached_view`]. It is shared by all requests and threads, so it is usually
+/// kept in a `static`.
+pub struct FragmentCache {
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    fragments: RwLock<BTreeMap<String, Arc<CachedFragment>>>,
+}
+
+impl FragmentCache {
+    /// Creates an empty cache.
+    pub const fn new() -> Self {
+        Self {
+            #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+            fragments: RwLock::new(BTreeMap::new()),
+        }
+    }
+
+    /// Removes the fragment cached under `key`, so that it is rendered again the next
+    /// time it is used, after the data it shows has changed.
+    pub fn invalidate(&self, key: &str) {
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        if let Ok(mut fragments) = self.fragments.write() {
+            fragments.remove(key);
+        }
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        let _ = key;
+    }
+
+    /// Removes every cached fragment.
+    pub fn clear(&self) {
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        if let Ok(mut fragments) = self.fragments.write() {
+            fragments.clear();
+        }
+    }
+
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    fn get(&self, key: &str) -> Option<Arc<CachedFragment>> {
+        self.fragments.read().ok()?.get(key).cloned()
+    }
+
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    fn insert(&self, key: String, fragment: Arc<CachedFragment>) {
+        if let Ok(mut fragments) = self.fragments.write() {
+            fragments.insert(key, fragment);
+        }
+    }
+}
+
+impl Default for FragmentCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for FragmentCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FragmentCache").finish_non_exhaustive()
+    }
+}
+
+/// Renders `view` on the server the first time it is used with `key`, and the HTML cached
+/// in `cache` after that. In the browser, `view` is always built, and hydrates the cached
+/// HTML like any other.
+///
+/// The hydration keys in the HTML depend on where the fragment is in the page, so they are
+/// moved to the position of each use, and the keys that follow are the same as when the
+/// view is rendered: a cached fragment can be used anywhere, any number of times.
+///
+/// ```
+/// # cfg_if::cfg_if! { if #[cfg(not(any(feature = "csr", feature = "hydrate")))] {
+/// use leptos::*;
+///
+/// static FOOTER: FragmentCache = FragmentCache::new();
+///
+/// #[component]
+/// fn Footer() -> impl IntoView {
+///     cached_view(&FOOTER, "footer", || {
+///         view! { <footer>"Built with Leptos"</footer> }
+///     })
+/// }
+///
+/// let first = leptos::ssr::render_to_string(|| view! { <Footer/> });
+/// // not rendered again, but the same HTML
+/// let second = leptos::ssr::render_to_string(|| view! { <Footer/> });
+/// assert_eq!(first, second);
+/// # }}
+/// ```
+///
+/// Only cache views that render the same HTML for every request, and that render
+/// synchronously: the HTML of a `<Suspense/>` and the data of resources are not cached. The
+/// side effects of the view only happen when it renders, like setting the title of the
+/// page with `leptos_meta`.
+pub fn cached_view<V: IntoView>(
+    cache: &FragmentCache,
+    key: impl Into<String>,
+    view: impl FnOnce() -> V,
+) -> View {
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    {
+        let _ = (cache, key.into());
+        view().into_view()
+    }
+
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    {
+        use crate::{
+            html::{AnyElement, HtmlElement, StringOrView},
+            HydrationCtx,
+        };
+
+        let key = key.into();
+        let start = HydrationCtx::peek_always();
+        let markers = HydrationCtx::peek().is_some();
+        let html =
+            match cache.get(&key).filter(|cached| cached.markers == markers) {
+                Some(cached) => {
+                    HydrationCtx::continue_from(relocate(
+                        cached.end,
+                        cached.start,
+                        start,
+                    ));
+                    cached.html_at(start)
+                }
+                None => {
+                    let fragment = CachedFragment::render(view, start, markers);
+                    let html = fragment.html_at(start);
+                    cache.insert(key, Arc::new(fragment));
+                    html
+                }
+            };
+
+        let el = AnyElement {
+            name: "".into(),
+            is_void: false,
+            id: None,
+        };
+        HtmlElement::from_chunks(el, [StringOrView::String(html.into())])
+            .into_view()
+    }
+}
+
+/// The HTML of a fragment, split around its hydration keys.
+#[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+struct CachedFragment {
+    /// Each key, and the HTML before it.
+    keys: Vec<(String, HydrationKey)>,
+    /// The HTML after the last key.
+    tail: String,
+    /// The next key before and after it was rendered.
+    start: HydrationKey,
+    end: HydrationKey,
+    /// Whether it was rendered with hydration markers.
+    markers: bool,
+}
+
+#[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+thread_local! {
+    /// The keys written by [`HydrationKey::to_html`] while a fragment is rendered.
+    static RECORDING: RefCell<Option<Recording>> = const { RefCell::new(None) };
+}
+
+#[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+struct Recording {
+    /// Starts each placeholder, and can't be in the HTML otherwise.
+    prefix: String,
+    keys: Vec<HydrationKey>,
+}
+
+/// Records a key written while a fragment is rendered, returning the placeholder to write
+/// instead of it.
+#[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+pub(crate) fn record_key(key: HydrationKey) -> Option<String> {
+    RECORDING.with(|recording| {
+        let mut recording = recording.borrow_mut();
+        let recording = recording.as_mut()?;
+        recording.keys.push(key);
+        Some(format!(
+            "{}{}\u{2}",
+            recording.prefix,
+            recording.keys.len() - 1
+        ))
+    })
+}
+
+#[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+impl CachedFragment {
+    /// Renders a view that starts at `start`, keeping track of the keys it writes.
+    fn render<V: IntoView>(
+        view: impl FnOnce() -> V,
+        start: HydrationKey,
+        markers: bool,
+    ) -> Self {
+        use std::hash::{BuildHasher, Hasher};
+
+        // the random part makes the placeholders impossible to write by accident
+        let random = std::collections::hash_map::RandomState::new()
+            .build_hasher()
+            .finish();
+        let prefix = format!("\u{1}leptos-hk-{random:x}-");
+        // a fragment rendered inside another one is recorded on its own, and the outer
+        // recording is put back even if rendering panics
+        struct RestoreOuter(Option<Recording>);
+        impl Drop for RestoreOuter {
+            fn drop(&mut self) {
+                let outer = self.0.take();
+                RECORDING.with(|recording| *recording.borrow_mut() = outer);
+            }
+        }
+        let _restore = RestoreOuter(RECORDING.with(|recording| {
+            recording.replace(Some(Recording {
+                prefix: prefix.clone(),
+                keys: Vec::new(),
+            }))
+        }));
+        let html = view().into_view().render_to_string();
+        let recorded = RECORDING
+            .with(|recording| recording.take())
+            .map(|recording| recording.keys)
+            .unwrap_or_default();
+        let end = crate::HydrationCtx::peek_always();
+
+        let mut pieces = html.split(prefix.as_str());
+        let mut before = pieces.next().unwrap_or_default().to_string();
+        let mut keys = Vec::new();
+        for piece in pieces {
+            let (index, after) = piece
+                .split_once('\u{2}')
+                .expect("placeholders to be closed");
+            let index = index.parse::<usize>().expect("a placeholder index");
+            keys.push((std::mem::take(&mut before), recorded[index]));
+            before = after.to_string();
+        }
+        Self {
+            keys,
+            tail: before,
+            start,
+            end,
+            markers,
+        }
+    }
+
+    /// The HTML, with its keys moved to a fragment that starts at `start`.
+    fn html_at(&self, start: HydrationKey) -> String {
+        let mut html =
+            String::with_capacity(self.tail.len() + self.keys.len() * 24);
+        for (before, key) in &self.keys {
+            html.push_str(before);
+            // written like any other key, in case this is inside a fragment being cached
+            html.push_str(&relocate(*key, self.start, start).to_html());
+        }
+        html.push_str(&self.tail);
+        html
+    }
+}
+
+/// Moves a key given out in a fragment that started at `from` to the same place in a
+/// fragment that starts at `to`.
+///
+/// Inside a fragment, the key only ever moves forward: the outlet, fragment and error parts
+/// are incremented, and the last part is reset to `0` when any of them is. So a key keeps
+/// its offset from the start in each part, and the offset of its last part only counts
+/// while the others haven't changed.
+#[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+fn relocate(
+    key: HydrationKey,
+    from: HydrationKey,
+    to: HydrationKey,
+) -> HydrationKey {
+    let moved = |part: usize, from: usize, to: usize| {
+        part.wrapping_sub(from).wrapping_add(to)
+    };
+    let same_run = (key.outlet, key.fragment, key.error)
+        == (from.outlet, from.fragment, from.error);
+    HydrationKey {
+        outlet: moved(key.outlet, from.outlet, to.outlet),
+        fragment: moved(key.fragment, from.fragment, to.fragment),
+        error: moved(key.error, from.error, to.error),
+        id: if same_run {
+            moved(key.id, from.id, to.id)
+        } else {
+            key.id
+        },
+    }
+}
+
+#[cfg(all(test, not(all(target_arch = "wasm32", feature = "web"))))]
+mod tests {
+    use super::*;
+    use crate::{html, Component, HydrationCtx};
+
+    #[test]
+    fn cached_fragments_move_the_keys_they_render() {
+        let runtime = leptos_reactive::create_runtime();
+        let cache = FragmentCache::new();
+        let fragment = || {
+            html::p()
+                .child(Component::new("Badge", || html::b().child("new")))
+                .child(html::span().child("card"))
+        };
+        let render = |start: HydrationKey, cached: bool| {
+            HydrationCtx::continue_from(start);
+            let view = if cached {
+                cached_view(&cache, "card", fragment)
+            } else {
+                fragment().into_view()
+            };
+            (
+                view.render_to_string().into_owned(),
+                HydrationCtx::peek_always(),
+            )
+        };
+
+        let hk = |outlet, fragment, error, id| HydrationKey {
+            outlet,
+            fragment,
+            error,
+            id,
+        };
+        for start in [hk(0, 0, 0, 0), hk(0, 2, 0, 1295), hk(1, 0, 3, 40)] {
+            let (html, end) = render(start, true);
+            assert_eq!((html.clone(), end), render(start, false));
+            // the key after the one it continues from
+            let first = HydrationKey {
+                id: start.id + 1,
+                ..start
+            };
+            assert!(html.starts_with(&format!(r#"<p data-hk="{first}">"#)));
+            assert!(!html.contains('\u{1}'));
+        }
+        #[cfg(feature = "compact-hydration-keys")]
+        assert!(render(hk(0, 2, 0, 1295), true)
+            .0
+            .starts_with(r#"<p data-hk="2.0.100">"#));
+
+        runtime.dispose();
+    }
+}
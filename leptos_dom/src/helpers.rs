@@ -321,6 +321,7 @@ impl IntervalHandle {
     /// Cancels the repeating event to which this refers.
     /// See [`clearInterval()`](https://developer.mozilla.org/en-US/docs/Web/API/clearInterval)
     pub fn clear(&self) {
+        leptos_reactive::strict_mode::release("interval", self.0 as u64);
         window().clear_interval_with_handle(self.0);
     }
 }
@@ -332,6 +333,7 @@ impl IntervalHandle {
   any(debug_assertions, feature = "ssr"),
   instrument(level = "trace", skip_all, fields(duration = ?duration))
 )]
+#[track_caller]
 pub fn set_interval(cb: impl Fn() + 'static, duration: Duration) {
     _ = set_interval_with_handle(cb, duration);
 }
@@ -344,6 +346,7 @@ pub fn set_interval(cb: impl Fn() + 'static, duration: Duration) {
   instrument(level = "trace", skip_all, fields(duration = ?duration))
 )]
 #[inline(always)]
+#[track_caller]
 pub fn set_interval_with_handle(
     cb: impl Fn() + 'static,
     duration: Duration,
@@ -375,7 +378,11 @@ pub fn set_interval_with_handle(
             .map(IntervalHandle)
     }
 
-    si(Box::new(cb), duration)
+    let handle = si(Box::new(cb), duration);
+    if let Ok(IntervalHandle(id)) = handle {
+        leptos_reactive::strict_mode::acquire("interval", id as u64);
+    }
+    handle
 }
 
 /// Adds an event listener to the `Window`, typed as a generic `Event`,
@@ -385,6 +392,7 @@ pub fn set_interval_with_handle(
   instrument(level = "trace", skip_all, fields(event_name = %event_name))
 )]
 #[inline(always)]
+#[track_caller]
 pub fn window_event_listener_untyped(
     event_name: &str,
     cb: impl Fn(web_sys::Event) + 'static,
@@ -406,6 +414,7 @@ pub fn window_event_listener_untyped(
         fn wel(
             cb: Box<dyn FnMut(web_sys::Event)>,
             event_name: &str,
+            key: u64,
         ) -> WindowListenerHandle {
             let cb = Closure::wrap(cb).into_js_value();
             _ = window().add_event_listener_with_callback(
@@ -414,6 +423,10 @@ pub fn window_event_listener_untyped(
             );
             let event_name = event_name.to_string();
             WindowListenerHandle(Box::new(move || {
+                leptos_reactive::strict_mode::release(
+                    "window event listener",
+                    key,
+                );
                 _ = window().remove_event_listener_with_callback(
                     &event_name,
                     cb.unchecked_ref(),
@@ -421,7 +434,11 @@ pub fn window_event_listener_untyped(
             }))
         }
 
-        wel(Box::new(cb), event_name)
+        static NEXT_KEY: std::sync::atomic::AtomicU64 =
+            std::sync::atomic::AtomicU64::new(0);
+        let key = NEXT_KEY.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        leptos_reactive::strict_mode::acquire("window event listener", key);
+        wel(Box::new(cb), event_name, key)
     } else {
         WindowListenerHandle(Box::new(|| ()))
     }
@@ -443,6 +460,7 @@ pub fn window_event_listener_untyped(
 ///     on_cleanup(move || handle.remove());
 /// }
 /// ```
+#[track_caller]
 pub fn window_event_listener<E: ev::EventDescriptor + 'static>(
     event: E,
     cb: impl Fn(E::EventType) + 'static,
@@ -204,7 +204,7 @@ impl Custom {
             #[allow(unused)]
             let id = id.unwrap();
             #[cfg(feature = "hydrate")]
-            if let Some(el) = crate::hydration::get_element(&id.to_string()) {
+            if let Some(el) = crate::hydration::get_element(&id) {
                 #[cfg(debug_assertions)]
                 assert_eq!(
                     el.node_name().to_ascii_uppercase(),
@@ -298,6 +298,8 @@ cfg_if! {
         pub(crate) element: El,
         pub(crate) attrs: SmallVec<[(Oco<'static, str>, Oco<'static, str>); 4]>,
         pub(crate) children: ElementChildren,
+        #[cfg(feature = "interactive-ssr")]
+        pub(crate) live: Option<crate::ssr_interactive::LiveElement>,
         #[cfg(debug_assertions)]
         pub(crate) view_marker: Option<String>
     }
@@ -374,6 +376,8 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
               attrs: smallvec![],
               children: Default::default(),
               element,
+              #[cfg(feature = "interactive-ssr")]
+              live: crate::ssr_interactive::LiveElement::new(),
               #[cfg(debug_assertions)]
               view_marker: None
             }
@@ -391,6 +395,8 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
             attrs: smallvec![],
             children: ElementChildren::Chunks(chunks.into_iter().collect()),
             element,
+            #[cfg(feature = "interactive-ssr")]
+            live: crate::ssr_interactive::LiveElement::new(),
             #[cfg(debug_assertions)]
             view_marker: None,
         }
@@ -433,6 +439,8 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
               attrs,
               children,
               element,
+              #[cfg(feature = "interactive-ssr")]
+              live,
               #[cfg(debug_assertions)]
               view_marker
             } = self;
@@ -441,6 +449,8 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
 
               attrs,
               children,
+              #[cfg(feature = "interactive-ssr")]
+              live,
               element: AnyElement {
                 name: element.name(),
                 is_void: element.is_void(),
@@ -618,11 +628,13 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
         {
             let mut this = self;
 
-            let mut attr = attr.into_attribute();
-            while let Attribute::Fn(f) = attr {
-                attr = f();
+            let attr = attr.into_attribute();
+            #[cfg(feature = "interactive-ssr")]
+            if let (Some(live), Attribute::Fn(_)) = (&this.live, &attr) {
+                live.add_attr(name, attr);
+                return this;
             }
-            match attr {
+            match attr.resolve() {
                 Attribute::String(value) => {
                     this.attrs.push((name, value));
                 }
@@ -706,6 +718,11 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
 
             let include = match class {
                 Class::Value(include) => include,
+                #[cfg(feature = "interactive-ssr")]
+                Class::Fn(f) if this.live.is_some() => {
+                    this.live.as_ref().unwrap().add_class(name, f);
+                    return this;
+                }
                 Class::Fn(f) => f(),
             };
 
@@ -910,6 +927,12 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
 
             let style = style.into_style();
 
+            #[cfg(feature = "interactive-ssr")]
+            if let (Some(live), Style::Fn(_)) = (&this.live, &style) {
+                live.add_style(name, style);
+                return this;
+            }
+
             let include = match style {
                 Style::Value(value) => Some(value),
                 Style::Option(value) => value,
@@ -1016,6 +1039,12 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
 
         #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
         {
+            #[cfg(feature = "interactive-ssr")]
+            if let Some(live) = &self.live {
+                live.add_listener(event, event_handler);
+                return self;
+            }
+
             _ = event;
             _ = event_handler;
 
@@ -1023,6 +1052,33 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
         }
     }
 
+    /// Adds an event listener directly to this element, with the given
+    /// [`EventOptions`](crate::ev::EventOptions), rather than delegating it.
+    ///
+    /// ```rust
+    /// # use leptos::*;
+    /// # let runtime = create_runtime();
+    /// view! { <div/> }.on_with_options(
+    ///     ev::touchmove,
+    ///     ev::EventOptions {
+    ///         passive: true,
+    ///         ..Default::default()
+    ///     },
+    ///     |_| { /* can't call `prevent_default()` */ },
+    /// );
+    /// # runtime.dispose();
+    /// ```
+    #[track_caller]
+    #[inline(always)]
+    pub fn on_with_options<E: EventDescriptor + 'static>(
+        self,
+        event: E,
+        options: crate::ev::EventOptions,
+        event_handler: impl FnMut(E::EventType) + 'static,
+    ) -> Self {
+        self.on(crate::ev::WithOptions::new(event, options), event_handler)
+    }
+
     /// Optionally adds an event listener to this element.
     ///
     /// ## Example
@@ -1182,6 +1238,8 @@ impl<El: ElementDescriptor> IntoView for HtmlElement<El> {
                 element,
                 mut attrs,
                 children,
+                #[cfg(feature = "interactive-ssr")]
+                live,
                 #[cfg(debug_assertions)]
                 view_marker,
                 ..
@@ -1192,11 +1250,15 @@ impl<El: ElementDescriptor> IntoView for HtmlElement<El> {
             let mut element = Element::new(element);
 
             if let Some(id) = id {
-                attrs.push(("data-hk".into(), id.to_string().into()));
+                attrs.push(("data-hk".into(), id.to_html().into()));
             }
 
             element.attrs = attrs;
             element.children = children;
+            #[cfg(feature = "interactive-ssr")]
+            {
+                element.live = live;
+            }
 
             #[cfg(debug_assertions)]
             {
@@ -1236,6 +1298,189 @@ pub fn text(text: impl Into<Oco<'static, str>>) -> Text {
     Text::new(text.into())
 }
 
+/// The HTML of the static parts of a view, parsed once into a `<template>` element. Created
+/// by the `view` macro for [`from_template`].
+#[doc(hidden)]
+pub struct StaticTemplate {
+    /// `None` if the browser parsed the HTML into other elements than the view has, for
+    /// example because it does not allow them to be nested like this.
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    template: Option<web_sys::HtmlTemplateElement>,
+}
+
+impl StaticTemplate {
+    /// Parses `html`, which must have a single root element, and checks that the browser
+    /// parsed it into the nodes described by `shape`: each element is written as its tag
+    /// name followed by its children in parentheses, each text node as `#`, and each
+    /// comment as `!`.
+    pub fn new(html: &'static str, shape: &'static str) -> Self {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            let template = crate::document()
+                .create_element("template")
+                .unwrap()
+                .unchecked_into::<web_sys::HtmlTemplateElement>();
+            template.set_inner_html(html);
+            let mut parsed = String::new();
+            write_shape(&template.content(), &mut parsed);
+            if parsed != shape {
+                crate::debug_warn!(
+                    "The browser does not parse the view {html:?} into the \
+                     same elements, so it is created one element at a time."
+                );
+                return Self { template: None };
+            }
+            Self {
+                template: Some(template),
+            }
+        }
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        {
+            let _ = (html, shape);
+            Self {}
+        }
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+fn write_shape(parent: &web_sys::Node, shape: &mut String) {
+    let mut child = parent.first_child();
+    while let Some(node) = child {
+        match node.node_type() {
+            web_sys::Node::ELEMENT_NODE => {
+                shape.push_str(
+                    &node.unchecked_ref::<web_sys::Element>().local_name(),
+                );
+                shape.push('(');
+                write_shape(&node, shape);
+                shape.push(')');
+            }
+            web_sys::Node::TEXT_NODE => shape.push('#'),
+            _ => shape.push('!'),
+        }
+        child = node.next_sibling();
+    }
+}
+
+/// A deep clone of a [`StaticTemplate`], whose elements and holes are numbered in the order
+/// in which they appear in its HTML. The holes are the comments that it contains.
+#[doc(hidden)]
+pub struct TemplateClone {
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    elements: Vec<web_sys::Element>,
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    holes: Vec<web_sys::Node>,
+}
+
+impl TemplateClone {
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    fn new(root: web_sys::Element) -> Self {
+        fn collect(node: &web_sys::Node, clone: &mut TemplateClone) {
+            let mut child = node.first_child();
+            while let Some(node) = child {
+                match node.node_type() {
+                    web_sys::Node::ELEMENT_NODE => {
+                        clone.elements.push(node.clone().unchecked_into());
+                        collect(&node, clone);
+                    }
+                    web_sys::Node::COMMENT_NODE => {
+                        clone.holes.push(node.clone())
+                    }
+                    _ => {}
+                }
+                child = node.next_sibling();
+            }
+        }
+
+        let mut clone = Self {
+            elements: vec![root.clone()],
+            holes: Vec::new(),
+        };
+        collect(&root, &mut clone);
+        clone
+    }
+
+    /// The element at `index`, to add the dynamic attributes and event listeners of the view
+    /// to.
+    pub fn element(&self, index: usize) -> HtmlElement<AnyElement> {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            self.elements[index].clone().to_leptos_element()
+        }
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        {
+            let _ = index;
+            unreachable!("templates are only cloned in the browser")
+        }
+    }
+
+    /// Replaces the hole at `index` with a dynamic child of the view.
+    pub fn hole(&self, index: usize, child: impl IntoView) {
+        let child = child.into_view();
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            let hole = &self.holes[index];
+            mount_child(MountKind::Before(hole), &child);
+            if let Some(parent) = hole.parent_node() {
+                _ = parent.remove_child(hole);
+            }
+        }
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        {
+            let _ = (index, child);
+            unreachable!("templates are only cloned in the browser")
+        }
+    }
+}
+
+impl IntoView for TemplateClone {
+    fn into_view(self) -> View {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            self.elements[0].clone().to_leptos_element().into_view()
+        }
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        {
+            unreachable!("templates are only cloned in the browser")
+        }
+    }
+}
+
+/// Creates a part of a view by deep-cloning the element parsed in `template`, which takes a
+/// single call into the DOM for all of its static elements, rather than one per element and
+/// attribute. `view` is given the clone to fill in its dynamic parts.
+///
+/// While hydrating, outside of the browser, and if the browser did not parse the template as
+/// expected, `view` is given `None` instead, and must create the same elements itself.
+#[doc(hidden)]
+pub fn from_template(
+    template: &'static std::thread::LocalKey<StaticTemplate>,
+    view: impl FnOnce(Option<TemplateClone>) -> View,
+) -> View {
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    if !HydrationCtx::is_hydrating() {
+        let root = template.with(|template| {
+            Some(
+                template
+                    .template
+                    .as_ref()?
+                    .content()
+                    .first_element_child()?
+                    .clone_node_with_deep(true)
+                    .unwrap()
+                    .unchecked_into::<web_sys::Element>(),
+            )
+        });
+        if let Some(root) = root {
+            return view(Some(TemplateClone::new(root)));
+        }
+    }
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    let _ = template;
+
+    view(None)
+}
+
 macro_rules! generate_html_tags {
   ($(
     #[$meta:meta]
@@ -1354,6 +1599,17 @@ macro_rules! generate_html_tags {
           HtmlElement::new( [<$tag:camel $($trailing_)?>]::default())
         }
       )*
+
+      /// Returns the static name of an HTML element, if `name` is one, so
+      /// that the name of an element read back from the DOM does not need to
+      /// be kept as a new string.
+      #[cfg(all(target_arch = "wasm32", feature = "web"))]
+      pub(crate) fn static_tag_name(name: &str) -> Option<&'static str> {
+        match name {
+          $(stringify!($tag) => Some(stringify!($tag)),)*
+          _ => None,
+        }
+      }
     }
   };
   (@void) => {};
@@ -1379,7 +1635,7 @@ fn create_leptos_element(
     #[cfg(feature = "hydrate")]
     if HydrationCtx::is_hydrating() && id.is_some() {
         let id = id.unwrap();
-        if let Some(el) = crate::hydration::get_element(&id.to_string()) {
+        if let Some(el) = crate::hydration::get_element(&id) {
             #[cfg(debug_assertions)]
             assert_eq!(
                 &el.node_name().to_ascii_uppercase(),
@@ -7,6 +7,7 @@ use std::{cell::RefCell, fmt::Display};
 
 #[cfg(feature = "hydrate")]
 mod hydrate_only {
+    use super::{parse_marker_key, HydrationKey, MarkerKey};
     use once_cell::unsync::Lazy as LazyCell;
     use std::{cell::Cell, collections::HashMap};
     use wasm_bindgen::JsCast;
@@ -16,7 +17,7 @@ mod hydrate_only {
     const FILTER_SHOW_COMMENT: u32 = 0b10000000;
 
     thread_local! {
-      pub static HYDRATION_COMMENTS: LazyCell<HashMap<String, web_sys::Comment>> = LazyCell::new(|| {
+      pub static HYDRATION_COMMENTS: LazyCell<HashMap<MarkerKey, web_sys::Comment>> = LazyCell::new(|| {
         let document = crate::document();
         let body = document.body().unwrap();
         let walker = document
@@ -25,9 +26,9 @@ mod hydrate_only {
         let mut map = HashMap::new();
         while let Ok(Some(node)) = walker.next_node() {
           if let Some(content) = node.text_content() {
-            if let Some(hk) = content.strip_prefix("hk=") {
-              if let Some(hk) = hk.split('|').next() {
-                map.insert(hk.into(), node.unchecked_into());
+            if let Some(hk) = content.strip_prefix(super::MARKER_PREFIX) {
+              if let Some(key) = hk.split('|').next().and_then(parse_marker_key) {
+                map.insert(key, node.unchecked_into());
               }
             }
           }
@@ -35,7 +36,7 @@ mod hydrate_only {
         map
       });
 
-      pub static HYDRATION_ELEMENTS: LazyCell<HashMap<String, web_sys::HtmlElement>> = LazyCell::new(|| {
+      pub static HYDRATION_ELEMENTS: LazyCell<HashMap<HydrationKey, web_sys::HtmlElement>> = LazyCell::new(|| {
         let document = crate::document();
         let els = document.query_selector_all("[data-hk]");
         if let Ok(list) = els {
@@ -45,7 +46,9 @@ mod hydrate_only {
                 let el = list.item(idx).unwrap().unchecked_into::<web_sys::HtmlElement>();
                 let dataset = el.dataset();
                 let hk = dataset.get(wasm_bindgen::intern("hk")).unwrap();
-                map.insert(hk, el);
+                if let Ok(hk) = hk.parse() {
+                    map.insert(hk, el);
+                }
             }
             map
         } else {
@@ -57,12 +60,12 @@ mod hydrate_only {
     }
 
     #[allow(unused)]
-    pub fn get_marker(id: &str) -> Option<web_sys::Comment> {
-        HYDRATION_COMMENTS.with(|comments| comments.get(id).cloned())
+    pub fn get_marker(key: &MarkerKey) -> Option<web_sys::Comment> {
+        HYDRATION_COMMENTS.with(|comments| comments.get(key).cloned())
     }
 
     #[allow(unused)]
-    pub fn get_element(hk: &str) -> Option<web_sys::HtmlElement> {
+    pub fn get_element(hk: &HydrationKey) -> Option<web_sys::HtmlElement> {
         HYDRATION_ELEMENTS.with(|els| els.get(hk).cloned())
     }
 }
@@ -83,20 +86,131 @@ pub struct HydrationKey {
     pub id: usize,
 }
 
+/// The text that starts the hydration marker comments, before the key.
+#[allow(unused)] // used in SSR and hydration
+pub(crate) const MARKER_PREFIX: &str =
+    if cfg!(feature = "compact-hydration-keys") {
+        "#"
+    } else {
+        "hk="
+    };
+
 impl Display for HydrationKey {
+    /// Writes the key as `outlet-fragment-error-id`, like `0-0-0-42`.
+    ///
+    /// With the `compact-hydration-keys` feature, each part is written in base 36 and the
+    /// leading parts that are zero are left out, separated by `.`: `0-0-0-42` is `16`, and
+    /// `0-0-1-42` is `1.16`.
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(
-            f,
-            "{}-{}-{}-{}",
-            self.outlet, self.fragment, self.error, self.id
-        )
+        if cfg!(feature = "compact-hydration-keys") {
+            let parts = [self.outlet, self.fragment, self.error, self.id];
+            let first = parts.iter().position(|part| *part != 0).unwrap_or(3);
+            for (index, part) in parts[first..].iter().enumerate() {
+                if index > 0 {
+                    f.write_str(".")?;
+                }
+                write_base36(f, *part)?;
+            }
+            Ok(())
+        } else {
+            write!(
+                f,
+                "{}-{}-{}-{}",
+                self.outlet, self.fragment, self.error, self.id
+            )
+        }
+    }
+}
+
+fn write_base36(
+    f: &mut core::fmt::Formatter<'_>,
+    mut n: usize,
+) -> core::fmt::Result {
+    const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+    let mut buf = [0; 16];
+    let mut start = buf.len();
+    loop {
+        start -= 1;
+        buf[start] = DIGITS[n % 36];
+        n /= 36;
+        if n == 0 {
+            break;
+        }
+    }
+    // the digits are all ASCII
+    f.write_str(std::str::from_utf8(&buf[start..]).unwrap_or_default())
+}
+
+/// Identifies a hydration marker comment.
+///
+/// In debug builds, components have both an opening and a closing marker, so
+/// markers are keyed by whether they are the closing one as well.
+#[cfg(all(debug_assertions, any(feature = "hydrate", test)))]
+pub(crate) type MarkerKey = (HydrationKey, bool);
+
+/// Identifies a hydration marker comment.
+#[cfg(all(not(debug_assertions), any(feature = "hydrate", test)))]
+pub(crate) type MarkerKey = HydrationKey;
+
+/// Parses the key from the text of a marker comment, after the [`MARKER_PREFIX`] and
+/// before any `|`: `0-0-0-1o` or `0-0-0-1c` in debug builds, and `0-0-0-1`
+/// in release.
+#[cfg(any(feature = "hydrate", test))]
+pub(crate) fn parse_marker_key(hk: &str) -> Option<MarkerKey> {
+    #[cfg(debug_assertions)]
+    {
+        let (hk, closing) = match hk.strip_suffix('c') {
+            Some(hk) => (hk, true),
+            None => (hk.strip_suffix('o')?, false),
+        };
+        Some((hk.parse().ok()?, closing))
+    }
+
+    #[cfg(not(debug_assertions))]
+    {
+        hk.parse().ok()
+    }
+}
+
+impl HydrationKey {
+    /// Writes the key into HTML rendered on the server, like [`Display`] does, except
+    /// while [`cached_view`](crate::cached_view) renders a fragment, when the key is
+    /// written so that it can be moved each time the fragment is used.
+    #[doc(hidden)]
+    pub fn to_html(&self) -> String {
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        if let Some(placeholder) = crate::fragment_cache::record_key(*self) {
+            return placeholder;
+        }
+        self.to_string()
     }
 }
 
 impl std::str::FromStr for HydrationKey {
     type Err = (); // TODO better error
 
+    /// Parses a key in either format, so that keys written with or without the
+    /// `compact-hydration-keys` feature can be read.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if !s.contains('-') {
+            let mut parts = [0; 4];
+            let pieces = s.split('.').collect::<Vec<_>>();
+            if pieces.is_empty() || pieces.len() > 4 {
+                return Err(());
+            }
+            let offset = 4 - pieces.len();
+            for (part, piece) in parts[offset..].iter_mut().zip(pieces) {
+                *part = usize::from_str_radix(piece, 36).map_err(|_| ())?;
+            }
+            let [outlet, fragment, error, id] = parts;
+            return Ok(HydrationKey {
+                outlet,
+                fragment,
+                error,
+                id,
+            });
+        }
+
         let mut pieces = s.splitn(4, '-');
         let first = pieces.next().ok_or(())?;
         let second = pieces.next().ok_or(())?;
@@ -131,10 +245,68 @@ mod tests {
             })
         )
     }
+
+    #[test]
+    fn parse_compact_hydration_key() {
+        use crate::HydrationKey;
+        use std::str::FromStr;
+        let hk = |outlet, fragment, error, id| HydrationKey {
+            outlet,
+            fragment,
+            error,
+            id,
+        };
+        assert_eq!(HydrationKey::from_str("16"), Ok(hk(0, 0, 0, 42)));
+        assert_eq!(HydrationKey::from_str("1.16"), Ok(hk(0, 0, 1, 42)));
+        assert_eq!(HydrationKey::from_str("1.0.0.0"), Ok(hk(1, 0, 0, 0)));
+        assert_eq!(HydrationKey::from_str("1.0.0.0.0"), Err(()));
+        assert_eq!(HydrationKey::from_str("1_2"), Err(()));
+        assert_eq!(HydrationKey::from_str(""), Err(()));
+    }
+
+    #[cfg(feature = "compact-hydration-keys")]
+    #[test]
+    fn display_compact_hydration_key() {
+        use crate::HydrationKey;
+        let hk = |outlet, fragment, error, id| HydrationKey {
+            outlet,
+            fragment,
+            error,
+            id,
+        };
+        for key in [hk(0, 0, 0, 0), hk(0, 0, 0, 42), hk(0, 3, 0, 1295)] {
+            assert_eq!(key.to_string().parse(), Ok(key));
+        }
+        assert_eq!(hk(0, 0, 0, 0).to_string(), "0");
+        assert_eq!(hk(0, 3, 0, 1295).to_string(), "3.0.zz");
+    }
+
+    #[test]
+    fn parse_marker_key() {
+        use crate::{hydration::parse_marker_key, HydrationKey};
+        let hk = HydrationKey {
+            outlet: 0,
+            fragment: 1,
+            error: 2,
+            id: 3,
+        };
+        #[cfg(debug_assertions)]
+        {
+            assert_eq!(parse_marker_key("0-1-2-3o"), Some((hk, false)));
+            assert_eq!(parse_marker_key("0-1-2-3c"), Some((hk, true)));
+            assert_eq!(parse_marker_key("0-1-2-3"), None);
+        }
+        #[cfg(not(debug_assertions))]
+        assert_eq!(parse_marker_key("0-1-2-3"), Some(hk));
+        assert_eq!(parse_marker_key("island"), None);
+    }
 }
 
 thread_local!(static ID: RefCell<HydrationKey> = RefCell::new(HydrationKey { outlet: 0, fragment: 0, error: 0, id: 0 }));
 
+#[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+thread_local!(static WITHOUT_MARKERS: std::cell::Cell<bool> = const { std::cell::Cell::new(false) });
+
 /// Control and utility methods for hydration.
 pub struct HydrationCtx;
 
@@ -151,7 +323,7 @@ impl HydrationCtx {
             any(feature = "hydrate", feature = "ssr")
         )))]
         let no_hydrate = false;
-        if no_hydrate {
+        if no_hydrate || Self::without_markers() {
             None
         } else {
             Some(ID.with(|id| *id.borrow()))
@@ -176,7 +348,7 @@ impl HydrationCtx {
         )))]
         let no_hydrate = false;
 
-        if no_hydrate {
+        if no_hydrate || Self::without_markers() {
             None
         } else {
             Some(ID.with(|id| {
@@ -285,6 +457,40 @@ impl HydrationCtx {
         value
     }
 
+    /// Runs `f` without giving out hydration keys, so that the views it creates
+    /// and renders have no hydration markers or `data-hk` attributes.
+    #[doc(hidden)]
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    pub fn with_markers_off<T>(f: impl FnOnce() -> T) -> T {
+        // restores the previous state even if `f` panics, so that a caught
+        // panic doesn't leave markers off for the rest of the thread
+        struct Restore(bool);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                WITHOUT_MARKERS.with(|without| without.set(self.0));
+            }
+        }
+
+        let _restore =
+            Restore(WITHOUT_MARKERS.with(|without| without.replace(true)));
+        f()
+    }
+
+    /// Whether the views that are being created are rendered without
+    /// hydration markers.
+    #[inline(always)]
+    pub(crate) fn without_markers() -> bool {
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        {
+            WITHOUT_MARKERS.with(|without| without.get())
+        }
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            false
+        }
+    }
+
     /// Whether the UI is currently in the process of hydrating from the server-sent HTML.
     #[inline(always)]
     pub fn is_hydrating() -> bool {
@@ -300,13 +506,13 @@ impl HydrationCtx {
 
     #[cfg(feature = "hydrate")]
     #[allow(unused)]
-    pub(crate) fn to_string(id: &HydrationKey, closing: bool) -> String {
+    pub(crate) fn marker_key(id: &HydrationKey, closing: bool) -> MarkerKey {
         #[cfg(debug_assertions)]
-        return format!("{id}{}", if closing { 'c' } else { 'o' });
+        return (*id, closing);
 
         #[cfg(not(debug_assertions))]
         {
-            id.to_string()
+            *id
         }
     }
 }
@@ -12,6 +12,7 @@ pub extern crate tracing;
 mod components;
 mod directive;
 mod events;
+mod fragment_cache;
 pub mod helpers;
 pub mod html;
 mod hydration;
@@ -22,8 +23,10 @@ pub mod math;
 mod node_ref;
 /// Utilities for exporting nonces to be used for a Content Security Policy.
 pub mod nonce;
+pub mod renderer;
 pub mod ssr;
 pub mod ssr_in_order;
+pub mod ssr_interactive;
 pub mod svg;
 mod transparent;
 
@@ -31,10 +34,13 @@ use cfg_if::cfg_if;
 pub use components::*;
 pub use directive::*;
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
-pub use events::add_event_helper;
+pub use events::{
+    add_event_helper, delegate_events_in, stop_delegating_events_in,
+};
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 use events::{add_event_listener, add_event_listener_undelegated};
 pub use events::{typed as ev, typed::EventHandler};
+pub use fragment_cache::*;
 pub use html::HtmlElement;
 use html::{AnyElement, ElementDescriptor};
 pub use hydration::{HydrationCtx, HydrationKey};
@@ -287,6 +293,8 @@ cfg_if! {
       attrs: SmallVec<[(Oco<'static, str>, Oco<'static, str>); 4]>,
       children: ElementChildren,
       id: Option<HydrationKey>,
+      #[cfg(feature = "interactive-ssr")]
+      live: Option<ssr_interactive::LiveElement>,
       #[cfg(debug_assertions)]
       /// Optional marker for the view macro source, in debug mode.
       pub view_marker: Option<String>
@@ -336,10 +344,15 @@ impl Element {
                 ..
             } = self;
 
-            let name = element.node_name().to_ascii_lowercase();
+            let mut name = element.node_name();
+            name.make_ascii_lowercase();
+            let name = match html::static_tag_name(&name) {
+                Some(tag) => Oco::Borrowed(tag),
+                None => name.into(),
+            };
 
             let element = AnyElement {
-                name: name.into(),
+                name,
                 element,
                 is_void: false,
             };
@@ -361,6 +374,8 @@ impl Element {
                 attrs,
                 children,
                 id,
+                #[cfg(feature = "interactive-ssr")]
+                live,
                 #[cfg(debug_assertions)]
                 view_marker,
             } = self;
@@ -371,6 +386,8 @@ impl Element {
                 element,
                 attrs,
                 children,
+                #[cfg(feature = "interactive-ssr")]
+                live,
                 #[cfg(debug_assertions)]
                 view_marker,
             }
@@ -405,12 +422,62 @@ impl Element {
               attrs: Default::default(),
               children: Default::default(),
               id: *el.hydration_id(),
+              #[cfg(feature = "interactive-ssr")]
+              live: None,
               #[cfg(debug_assertions)]
               view_marker: None
             }
           }
         }
     }
+
+    #[track_caller]
+    fn add_attrs(self, attrs: Vec<(&'static str, Attribute)>) -> Self {
+        cfg_if! {
+          if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
+            for (name, value) in attrs {
+              attribute_helper(self.element.as_ref(), name.into(), value);
+            }
+            self
+          } else {
+            let mut this = self;
+            let mut rendered = String::new();
+
+            for (name, value) in attrs {
+              let value = match value.resolve() {
+                Attribute::String(value) => Some(value),
+                Attribute::Bool(include) => include.then(Oco::default),
+                Attribute::Option(maybe) => maybe,
+                _ => unreachable!(),
+              };
+              let Some(value) = value else { continue };
+
+              if matches!(this.children, ElementChildren::Chunks(_)) {
+                if value.is_empty() {
+                  rendered.push_str(&format!(" {name}"));
+                } else {
+                  rendered.push(' ');
+                  ssr::write_attribute(&mut rendered, name, &value);
+                }
+              } else {
+                this.attrs.push((name.into(), value));
+              }
+            }
+
+            // elements created by the `view` macro during SSR have already
+            // rendered their opening tag into the first chunk
+            if let ElementChildren::Chunks(chunks) = &mut this.children {
+              if let Some(html::StringOrView::String(open)) = chunks.first_mut() {
+                if let Some(rest) = open.strip_prefix(&format!("<{}", this.name)) {
+                  *open = format!("<{}{rendered}{rest}", this.name).into();
+                }
+              }
+            }
+
+            this
+          }
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -456,9 +523,9 @@ impl Comment {
                 #[cfg(feature = "hydrate")]
                 if HydrationCtx::is_hydrating() && id.is_some() {
                     let id = id.as_ref().unwrap();
-                    let id = HydrationCtx::to_string(id, closing);
+                    let key = HydrationCtx::marker_key(id, closing);
 
-                    if let Some(marker) = hydration::get_marker(&id) {
+                    if let Some(marker) = hydration::get_marker(&key) {
                         marker.before_with_node_1(&node).unwrap();
 
                         marker.remove();
@@ -842,6 +909,29 @@ impl View {
 
         self
     }
+
+    /// Adds attributes to the root element of this view, analogous to
+    /// [`HtmlElement::attrs`].
+    ///
+    /// If this is a component, the attributes are added to its first child
+    /// that is an element (or a component with one). This is how `attr:`
+    /// bindings are forwarded to components that do not take
+    /// `#[prop(attrs)]`.
+    #[track_caller]
+    pub fn attrs(self, attrs: Vec<(&'static str, Attribute)>) -> Self {
+        match self {
+            Self::Element(el) => Self::Element(el.add_attrs(attrs)),
+            Self::Component(mut c) => {
+                if let Some(root) = c.children.iter_mut().find(|child| {
+                    matches!(child, Self::Element(_) | Self::Component(_))
+                }) {
+                    *root = std::mem::take(root).attrs(attrs);
+                }
+                Self::Component(c)
+            }
+            _ => self,
+        }
+    }
 }
 
 #[cfg_attr(debug_assertions, instrument)]
@@ -929,17 +1019,12 @@ where
     F: Fn() -> N + 'static,
     N: IntoView,
 {
-    #[cfg(all(feature = "web", feature = "ssr"))]
-    crate::logging::console_warn(
-        "You have both `csr` and `ssr` or `hydrate` and `ssr` enabled as \
-         features, which may cause issues like <Suspense/>` failing to work \
-         silently.",
-    );
-
     cfg_if! {
       if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
         mount_to(crate::document().body().expect("body element to exist"), f)
       } else {
+        #[cfg(target_arch = "wasm32")]
+        let () = RequireOneMode::<F>::CHECKED;
         _ = f;
         crate::warn!("`mount_to_body` should not be called outside the browser.");
       }
@@ -964,13 +1049,31 @@ pub fn mount_to_with_stop_hydrating<F, N>(
     F: FnOnce() -> N + 'static,
     N: IntoView,
 {
+    #[cfg(target_arch = "wasm32")]
+    let () = RequireOneMode::<F>::CHECKED;
+
     cfg_if! {
       if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
+            // the server sends an empty page for routes that are only rendered
+            // in the browser, so there is nothing to hydrate
+            let client_only = cfg!(feature = "hydrate")
+                && document()
+                    .body()
+                    .is_some_and(|body| body.has_attribute("data-leptos-client-only"));
+            if client_only {
+                HydrationCtx::stop_hydrating();
+            }
             let node = f().into_view();
             if stop_hydrating {
                 HydrationCtx::stop_hydrating();
+                #[cfg(feature = "hydrate")]
+                events::replay_queued_events(None);
+            } else {
+                // an island, whose events are replayed as soon as it has hydrated
+                #[cfg(feature = "hydrate")]
+                events::replay_queued_events(Some(&parent));
             }
-            if cfg!(feature = "csr") {
+            if cfg!(feature = "csr") || client_only {
                 parent.append_child(&node.get_mountable_node()).unwrap();
             }
             std::mem::forget(node);
@@ -983,6 +1086,39 @@ pub fn mount_to_with_stop_hydrating<F, N>(
     }
 }
 
+// Mounting in the browser without the `csr` or `hydrate` feature renders
+// nothing, and mounting with more than one mode renders in whichever one wins,
+// so building an app that does either fails instead. The check is a generic
+// constant so that it is only evaluated where `mount_to` is actually called:
+// libraries can still be built for `wasm32` without a mode, and native builds,
+// like the tests of a workspace whose features are combined, are not checked.
+#[cfg(target_arch = "wasm32")]
+struct RequireOneMode<F>(std::marker::PhantomData<F>);
+
+#[cfg(target_arch = "wasm32")]
+impl<F> RequireOneMode<F> {
+    #[cfg(not(feature = "web"))]
+    const CHECKED: () = panic!(
+        "mounting a Leptos app in the browser requires a rendering mode: \
+         enable the `csr` or `hydrate` feature of `leptos`"
+    );
+    #[cfg(all(
+        feature = "web",
+        any(feature = "ssr", all(feature = "csr", feature = "hydrate"))
+    ))]
+    const CHECKED: () = panic!(
+        "more than one of the `csr`, `hydrate`, and `ssr` features of \
+         `leptos` is enabled for a browser build, but an app can only be \
+         built in one mode at a time. Enable the mode in the crate you are \
+         building rather than in a shared dependency."
+    );
+    #[cfg(all(
+        feature = "web",
+        not(any(feature = "ssr", all(feature = "csr", feature = "hydrate")))
+    ))]
+    const CHECKED: () = ();
+}
+
 thread_local! {
     pub(crate) static WINDOW: web_sys::Window = web_sys::window().unwrap_throw();
 
@@ -1,26 +1,29 @@
+#[cfg(not(feature = "slim"))]
 use crate::is_server;
 use cfg_if::cfg_if;
+use std::fmt;
+#[cfg(not(feature = "slim"))]
 use wasm_bindgen::JsValue;
 
 /// Uses `println!()`-style formatting to log something to the console (in the browser)
 /// or via `println!()` (if not in the browser).
 #[macro_export]
 macro_rules! log {
-    ($($t:tt)*) => ($crate::logging::console_log(&format_args!($($t)*).to_string()))
+    ($($t:tt)*) => ($crate::logging::console_log_fmt(format_args!($($t)*)))
 }
 
 /// Uses `println!()`-style formatting to log warnings to the console (in the browser)
 /// or via `eprintln!()` (if not in the browser).
 #[macro_export]
 macro_rules! warn {
-    ($($t:tt)*) => ($crate::logging::console_warn(&format_args!($($t)*).to_string()))
+    ($($t:tt)*) => ($crate::logging::console_warn_fmt(format_args!($($t)*)))
 }
 
 /// Uses `println!()`-style formatting to log errors to the console (in the browser)
 /// or via `eprintln!()` (if not in the browser).
 #[macro_export]
 macro_rules! error {
-    ($($t:tt)*) => ($crate::logging::console_error(&format_args!($($t)*).to_string()))
+    ($($t:tt)*) => ($crate::logging::console_error_fmt(format_args!($($t)*)))
 }
 
 /// Uses `println!()`-style formatting to log warnings to the console (in the browser)
@@ -43,31 +46,92 @@ macro_rules! debug_warn {
 
 /// Log a string to the console (in the browser)
 /// or via `println!()` (if not in the browser).
+///
+/// With the `slim` feature, this does nothing.
 pub fn console_log(s: &str) {
-    if is_server() {
-        println!("{s}");
-    } else {
-        web_sys::console::log_1(&JsValue::from_str(s));
+    cfg_if! {
+        if #[cfg(feature = "slim")] {
+            let _ = s;
+        } else {
+            if is_server() {
+                println!("{s}");
+            } else {
+                web_sys::console::log_1(&JsValue::from_str(s));
+            }
+        }
     }
 }
 
 /// Log a warning to the console (in the browser)
 /// or via `println!()` (if not in the browser).
+///
+/// With the `slim` feature, this does nothing.
 pub fn console_warn(s: &str) {
-    if is_server() {
-        eprintln!("{s}");
-    } else {
-        web_sys::console::warn_1(&JsValue::from_str(s));
+    cfg_if! {
+        if #[cfg(feature = "slim")] {
+            let _ = s;
+        } else {
+            if is_server() {
+                eprintln!("{s}");
+            } else {
+                web_sys::console::warn_1(&JsValue::from_str(s));
+            }
+        }
     }
 }
 
 /// Log an error to the console (in the browser)
 /// or via `println!()` (if not in the browser).
+///
+/// With the `slim` feature, this does nothing.
 pub fn console_error(s: &str) {
-    if is_server() {
-        eprintln!("{s}");
-    } else {
-        web_sys::console::error_1(&JsValue::from_str(s));
+    cfg_if! {
+        if #[cfg(feature = "slim")] {
+            let _ = s;
+        } else {
+            if is_server() {
+                eprintln!("{s}");
+            } else {
+                web_sys::console::error_1(&JsValue::from_str(s));
+            }
+        }
+    }
+}
+
+// The logging macros pass their arguments through these, rather than
+// formatting a string at each call site, so that with the `slim` feature the
+// messages and the code that formats them can be removed from the binary.
+
+#[doc(hidden)]
+pub fn console_log_fmt(args: fmt::Arguments<'_>) {
+    cfg_if! {
+        if #[cfg(feature = "slim")] {
+            let _ = args;
+        } else {
+            console_log(&args.to_string());
+        }
+    }
+}
+
+#[doc(hidden)]
+pub fn console_warn_fmt(args: fmt::Arguments<'_>) {
+    cfg_if! {
+        if #[cfg(feature = "slim")] {
+            let _ = args;
+        } else {
+            console_warn(&args.to_string());
+        }
+    }
+}
+
+#[doc(hidden)]
+pub fn console_error_fmt(args: fmt::Arguments<'_>) {
+    cfg_if! {
+        if #[cfg(feature = "slim")] {
+            let _ = args;
+        } else {
+            console_error(&args.to_string());
+        }
     }
 }
 
@@ -75,7 +139,7 @@ pub fn console_error(s: &str) {
 /// or via `println!()` (if not in the browser), but only in a debug build.
 pub fn console_debug_warn(s: &str) {
     cfg_if! {
-        if #[cfg(debug_assertions)] {
+        if #[cfg(all(debug_assertions, not(feature = "slim")))] {
             if is_server() {
                 eprintln!("{s}");
             } else {
@@ -0,0 +1,139 @@
+use crate::{Attribute, IntoAttribute};
+
+/// A set of attributes built at runtime, which can be spread onto an element
+/// or a component with `{..attrs}` in the `view` macro.
+///
+/// Attributes keep the order they were first inserted in, and inserting an
+/// attribute that is already in the map replaces its value. A component can
+/// also take the attributes its caller passes with `attr:` as
+/// `#[prop(attrs)] attrs: AttributeMap` and forward them to its root element.
+///
+/// ```
+/// # use leptos::*;
+/// #[component]
+/// fn TextInput(#[prop(attrs)] attrs: AttributeMap) -> impl IntoView {
+///     let attrs = attrs.with("type", "text");
+///     view! { <input {..attrs}/> }
+/// }
+///
+/// # let runtime = create_runtime();
+/// let (disabled, _) = create_signal(true);
+/// let extra = AttributeMap::new().with("required", true);
+/// let view = view! {
+///     <TextInput attr:name="email" attr:disabled=move || disabled.get() {..extra}/>
+/// };
+/// # if cfg!(feature = "ssr") {
+/// let html = view.into_view().render_to_string();
+/// assert!(html.contains(r#"name="email""#));
+/// assert!(html.contains(r#"type="text""#));
+/// assert!(html.contains("required"));
+/// # }
+/// # runtime.dispose();
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AttributeMap(Vec<(&'static str, Attribute)>);
+
+impl AttributeMap {
+    /// Creates an empty map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an attribute, replacing any previous value with the same name.
+    pub fn with(
+        mut self,
+        name: &'static str,
+        value: impl IntoAttribute,
+    ) -> Self {
+        self.insert(name, value);
+        self
+    }
+
+    /// Adds an attribute, returning the previous value with the same name.
+    pub fn insert(
+        &mut self,
+        name: &'static str,
+        value: impl IntoAttribute,
+    ) -> Option<Attribute> {
+        let value = value.into_attribute();
+        match self.0.iter_mut().find(|(existing, _)| *existing == name) {
+            Some((_, existing)) => Some(std::mem::replace(existing, value)),
+            None => {
+                self.0.push((name, value));
+                None
+            }
+        }
+    }
+
+    /// Returns the value of the attribute with the given name.
+    pub fn get(&self, name: &str) -> Option<&Attribute> {
+        self.0
+            .iter()
+            .find(|(existing, _)| *existing == name)
+            .map(|(_, value)| value)
+    }
+
+    /// Removes the attribute with the given name, returning its value.
+    pub fn remove(&mut self, name: &str) -> Option<Attribute> {
+        let index =
+            self.0.iter().position(|(existing, _)| *existing == name)?;
+        Some(self.0.remove(index).1)
+    }
+
+    /// Whether the map has an attribute with the given name.
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    /// The number of attributes in the map.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether the map has no attributes.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates over the attributes, in the order they were first inserted.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &Attribute)> {
+        self.0.iter().map(|(name, value)| (*name, value))
+    }
+}
+
+impl IntoIterator for AttributeMap {
+    type Item = (&'static str, Attribute);
+    type IntoIter = std::vec::IntoIter<(&'static str, Attribute)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<A: IntoAttribute> Extend<(&'static str, A)> for AttributeMap {
+    fn extend<T: IntoIterator<Item = (&'static str, A)>>(&mut self, iter: T) {
+        for (name, value) in iter {
+            self.insert(name, value);
+        }
+    }
+}
+
+impl<A: IntoAttribute> FromIterator<(&'static str, A)> for AttributeMap {
+    fn from_iter<T: IntoIterator<Item = (&'static str, A)>>(iter: T) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl From<Vec<(&'static str, Attribute)>> for AttributeMap {
+    fn from(attrs: Vec<(&'static str, Attribute)>) -> Self {
+        attrs.into_iter().collect()
+    }
+}
+
+impl From<AttributeMap> for Vec<(&'static str, Attribute)> {
+    fn from(map: AttributeMap) -> Self {
+        map.0
+    }
+}
@@ -0,0 +1,91 @@
+//! Reactive attribute, class and style updates are not written to the DOM as each
+//! effect runs. They are queued, grouped by element, and applied together
+//! once every effect triggered by the same update has run, so that an update
+//! that changes several attributes of an element touches it once.
+//!
+//! The first value of each attribute is still written as soon as the element
+//! is built, while it is detached: elements are only mounted once all their
+//! attributes, classes, styles and children have been added.
+
+use super::{
+    into_attribute::attribute_expression, into_class::class_expression,
+    into_style::style_expression,
+};
+use crate::Attribute;
+use leptos_reactive::{queue_after_effects, Oco};
+use std::cell::RefCell;
+use wasm_bindgen::JsCast;
+
+pub(crate) enum Write {
+    Attribute {
+        name: Oco<'static, str>,
+        value: Attribute,
+        #[cfg(debug_assertions)]
+        called_at: &'static std::panic::Location<'static>,
+    },
+    Class {
+        name: Oco<'static, str>,
+        value: bool,
+    },
+    Style {
+        name: Oco<'static, str>,
+        value: Option<Oco<'static, str>>,
+    },
+}
+
+thread_local! {
+    static PENDING_WRITES: RefCell<Vec<(web_sys::Element, Vec<Write>)>> =
+        Default::default();
+}
+
+/// Queues a write to `el`, to be applied once the effects that are running
+/// have finished.
+pub(crate) fn queue_write(el: &web_sys::Element, write: Write) {
+    let is_first = PENDING_WRITES.with(|pending| {
+        let mut pending = pending.borrow_mut();
+        let is_first = pending.is_empty();
+        // the effects of an element are created one after the other, so they
+        // also run one after the other and only the last entry needs checking
+        match pending.last_mut() {
+            Some((last, writes)) if last == el => writes.push(write),
+            _ => pending.push((el.clone(), vec![write])),
+        }
+        is_first
+    });
+
+    if is_first {
+        queue_after_effects(apply_writes);
+    }
+}
+
+fn apply_writes() {
+    let pending = PENDING_WRITES.with(|pending| pending.take());
+    for (el, writes) in pending {
+        for write in writes {
+            match write {
+                Write::Attribute {
+                    name,
+                    value,
+                    #[cfg(debug_assertions)]
+                    called_at,
+                } => attribute_expression(
+                    &el,
+                    &name,
+                    value,
+                    true,
+                    #[cfg(debug_assertions)]
+                    called_at,
+                ),
+                Write::Class { name, value } => {
+                    class_expression(&el.class_list(), &name, value, true)
+                }
+                Write::Style { name, value } => style_expression(
+                    &el.unchecked_ref::<web_sys::HtmlElement>().style(),
+                    &name,
+                    value.as_deref(),
+                    true,
+                ),
+            }
+        }
+    }
+}
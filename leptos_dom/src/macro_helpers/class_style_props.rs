@@ -0,0 +1,285 @@
+use crate::{Attribute, IntoAttribute, IntoClass, IntoStyle, Style};
+use leptos_reactive::{Oco, TextProp};
+use std::{fmt, rc::Rc};
+
+/// The classes a caller passes to a component, which the component can
+/// combine with its own default classes with [`ClassProp::with_defaults`].
+///
+/// By convention, components that accept classes take them as
+/// `#[prop(optional, into)] class: ClassProp`. The `view` macro then collects
+/// both `class="..."` and `class:name=value` on the component tag into this
+/// one prop.
+///
+/// ```
+/// # use leptos::*;
+/// #[component]
+/// fn Button(
+///     #[prop(optional, into)] class: ClassProp,
+///     children: Children,
+/// ) -> impl IntoView {
+///     view! { <button class=class.with_defaults("btn")>{children()}</button> }
+/// }
+///
+/// # let runtime = create_runtime();
+/// let (active, _) = create_signal(true);
+/// let view = view! {
+///     <Button class="btn-primary" class:active=move || active.get()>"Save"</Button>
+/// };
+/// # if cfg!(feature = "ssr") {
+/// assert!(view
+///     .into_view()
+///     .render_to_string()
+///     .contains("btn btn-primary active"));
+/// # }
+/// # runtime.dispose();
+/// ```
+#[derive(Clone, Default)]
+pub struct ClassProp {
+    list: Option<TextProp>,
+    toggles: Vec<(Oco<'static, str>, ClassToggle)>,
+}
+
+type ClassToggle = Rc<dyn Fn() -> bool>;
+
+impl ClassProp {
+    /// Returns the current space-separated list of classes.
+    pub fn get(&self) -> String {
+        let list = self.list.as_ref().map(TextProp::get).unwrap_or_default();
+        let toggled = self
+            .toggles
+            .iter()
+            .filter(|(_, include)| include())
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+        merge_classes(&list, &toggled)
+    }
+
+    /// Whether no classes have been set at all.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_none() && self.toggles.is_empty()
+    }
+
+    /// Adds a class that is toggled by `value`, like `class:name=value` on an
+    /// element.
+    pub fn with_class(
+        mut self,
+        name: impl Into<Oco<'static, str>>,
+        value: impl IntoClass,
+    ) -> Self {
+        let include: ClassToggle = match value.into_class() {
+            crate::Class::Value(value) => Rc::new(move || value),
+            crate::Class::Fn(f) => Rc::from(f),
+        };
+        self.toggles.push((name.into(), include));
+        self
+    }
+
+    /// Combines the component's `defaults` with these classes, following
+    /// [`merge_classes`].
+    pub fn with_defaults(self, defaults: impl Into<ClassProp>) -> Self {
+        let defaults = defaults.into();
+        if self.is_empty() {
+            return defaults;
+        }
+        let merged = move || merge_classes(&defaults.get(), &self.get());
+        Self {
+            list: Some(merged.into()),
+            toggles: Vec::new(),
+        }
+    }
+}
+
+impl fmt::Debug for ClassProp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ClassProp").finish()
+    }
+}
+
+impl<T> From<T> for ClassProp
+where
+    T: Into<TextProp>,
+{
+    fn from(value: T) -> Self {
+        Self {
+            list: Some(value.into()),
+            toggles: Vec::new(),
+        }
+    }
+}
+
+impl IntoAttribute for ClassProp {
+    fn into_attribute(self) -> Attribute {
+        Attribute::Fn(Rc::new(move || {
+            let classes = self.get();
+            Attribute::Option((!classes.is_empty()).then(|| classes.into()))
+        }))
+    }
+
+    #[inline(always)]
+    fn into_attribute_boxed(self: Box<Self>) -> Attribute {
+        self.into_attribute()
+    }
+}
+
+/// The inline styles a caller passes to a component, which the component can
+/// combine with its own default styles with [`StyleProp::with_defaults`].
+///
+/// By convention, components that accept styles take them as
+/// `#[prop(optional, into)] style: StyleProp`. The `view` macro then collects
+/// both `style="..."` and `style:name=value` on the component tag into this
+/// one prop.
+#[derive(Clone, Default)]
+pub struct StyleProp {
+    list: Option<TextProp>,
+    properties: Vec<(Oco<'static, str>, StyleValue)>,
+}
+
+type StyleValue = Rc<dyn Fn() -> Option<Oco<'static, str>>>;
+
+impl StyleProp {
+    /// Returns the current list of declarations, like `color: red; margin: 0`.
+    pub fn get(&self) -> String {
+        let list = self.list.as_ref().map(TextProp::get).unwrap_or_default();
+        let properties = self
+            .properties
+            .iter()
+            .filter_map(|(name, value)| {
+                value().map(|value| format!("{name}: {value}"))
+            })
+            .collect::<Vec<_>>()
+            .join("; ");
+        merge_styles(&list, &properties)
+    }
+
+    /// Whether no styles have been set at all.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_none() && self.properties.is_empty()
+    }
+
+    /// Sets a single style property, like `style:name=value` on an element.
+    pub fn with_style(
+        mut self,
+        name: impl Into<Oco<'static, str>>,
+        value: impl IntoStyle,
+    ) -> Self {
+        let style = value.into_style();
+        let value = move || {
+            let mut style = style.clone();
+            while let Style::Fn(f) = style {
+                style = f();
+            }
+            match style {
+                Style::Value(value) => Some(value),
+                Style::Option(value) => value,
+                Style::Fn(_) => unreachable!(),
+            }
+        };
+        self.properties.push((name.into(), Rc::new(value)));
+        self
+    }
+
+    /// Combines the component's `defaults` with these styles, following
+    /// [`merge_styles`].
+    pub fn with_defaults(self, defaults: impl Into<StyleProp>) -> Self {
+        let defaults = defaults.into();
+        if self.is_empty() {
+            return defaults;
+        }
+        let merged = move || merge_styles(&defaults.get(), &self.get());
+        Self {
+            list: Some(merged.into()),
+            properties: Vec::new(),
+        }
+    }
+}
+
+impl fmt::Debug for StyleProp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("StyleProp").finish()
+    }
+}
+
+impl<T> From<T> for StyleProp
+where
+    T: Into<TextProp>,
+{
+    fn from(value: T) -> Self {
+        Self {
+            list: Some(value.into()),
+            properties: Vec::new(),
+        }
+    }
+}
+
+impl IntoAttribute for StyleProp {
+    fn into_attribute(self) -> Attribute {
+        Attribute::Fn(Rc::new(move || {
+            let styles = self.get();
+            Attribute::Option((!styles.is_empty()).then(|| styles.into()))
+        }))
+    }
+
+    #[inline(always)]
+    fn into_attribute_boxed(self: Box<Self>) -> Attribute {
+        self.into_attribute()
+    }
+}
+
+/// Combines a component's default classes with the classes its caller
+/// passed in.
+///
+/// Classes are appended: the defaults come first, in order, followed by any
+/// of the caller's classes that are not already present.
+///
+/// ```
+/// # use leptos_dom::merge_classes;
+/// assert_eq!(
+///     merge_classes("btn  btn-sm", "btn active"),
+///     "btn btn-sm active"
+/// );
+/// ```
+pub fn merge_classes(defaults: &str, caller: &str) -> String {
+    let mut classes: Vec<&str> = Vec::new();
+    for class in defaults.split_whitespace().chain(caller.split_whitespace()) {
+        if !classes.contains(&class) {
+            classes.push(class);
+        }
+    }
+    classes.join(" ")
+}
+
+/// Combines a component's default inline styles with the styles its caller
+/// passed in.
+///
+/// The caller wins: any property the caller sets replaces the default for
+/// that property. The remaining defaults come first, in order, followed by
+/// the caller's declarations.
+///
+/// ```
+/// # use leptos_dom::merge_styles;
+/// assert_eq!(
+///     merge_styles("color: red; padding: 4px", "Color: blue;"),
+///     "padding: 4px; Color: blue"
+/// );
+/// ```
+pub fn merge_styles(defaults: &str, caller: &str) -> String {
+    fn declarations(styles: &str) -> impl Iterator<Item = (&str, &str)> {
+        styles.split(';').filter_map(|declaration| {
+            let (name, value) = declaration.split_once(':')?;
+            Some((name.trim(), value.trim()))
+        })
+    }
+
+    let caller = declarations(caller).collect::<Vec<_>>();
+    declarations(defaults)
+        .filter(|(name, _)| {
+            !caller
+                .iter()
+                .any(|(caller_name, _)| caller_name.eq_ignore_ascii_case(name))
+        })
+        .chain(caller.iter().copied())
+        .map(|(name, value)| format!("{name}: {value}"))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
@@ -25,6 +25,16 @@ pub enum Attribute {
 }
 
 impl Attribute {
+    /// Calls a dynamic attribute, and whatever functions it returns, until it
+    /// gets a value that can be compared with the previous one or rendered.
+    pub(crate) fn resolve(self) -> Attribute {
+        let mut value = self;
+        while let Attribute::Fn(f) = value {
+            value = f();
+        }
+        value
+    }
+
     /// Converts the attribute to its HTML value at that moment, including the attribute name,
     /// so it can be rendered on the server.
     pub fn as_value_string(
@@ -32,19 +42,11 @@ impl Attribute {
         attr_name: &'static str,
     ) -> Oco<'static, str> {
         match self {
-            Attribute::String(value) => {
-                format!("{attr_name}=\"{value}\"").into()
-            }
-            Attribute::Fn(f) => {
-                let mut value = f();
-                while let Attribute::Fn(f) = value {
-                    value = f();
-                }
-                value.as_value_string(attr_name)
-            }
+            Attribute::String(value) => name_and_value(attr_name, value),
+            Attribute::Fn(f) => f().resolve().as_value_string(attr_name),
             Attribute::Option(value) => value
                 .as_ref()
-                .map(|value| format!("{attr_name}=\"{value}\"").into())
+                .map(|value| name_and_value(attr_name, value))
                 .unwrap_or_default(),
             Attribute::Bool(include) => {
                 Oco::Borrowed(if *include { attr_name } else { "" })
@@ -57,13 +59,7 @@ impl Attribute {
     pub fn as_nameless_value_string(&self) -> Option<Oco<'static, str>> {
         match self {
             Attribute::String(value) => Some(value.clone()),
-            Attribute::Fn(f) => {
-                let mut value = f();
-                while let Attribute::Fn(f) = value {
-                    value = f();
-                }
-                value.as_nameless_value_string()
-            }
+            Attribute::Fn(f) => f().resolve().as_nameless_value_string(),
             Attribute::Option(value) => value.as_ref().cloned(),
             Attribute::Bool(include) => {
                 if *include {
@@ -76,11 +72,37 @@ impl Attribute {
     }
 }
 
+// built without `format!`, which would pull in the formatting machinery for
+// something that is only ever a concatenation
+fn name_and_value(name: &str, value: &str) -> Oco<'static, str> {
+    // the value is not escaped, so it keeps double quotes if it contains a
+    // single quote
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    let quote = if value.contains('\'') {
+        "\""
+    } else {
+        crate::ssr::attribute_quote(!crate::ssr::attribute_needs_quotes(value))
+    };
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    let quote = "\"";
+
+    let mut buf = String::with_capacity(name.len() + value.len() + 3);
+    buf.push_str(name);
+    buf.push('=');
+    buf.push_str(quote);
+    buf.push_str(value);
+    buf.push_str(quote);
+    buf.into()
+}
+
 impl PartialEq for Attribute {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
-            (Self::String(l0), Self::String(r0)) => l0 == r0,
+            (Self::String(l0), Self::String(r0)) => l0.ptr_eq(r0) || l0 == r0,
             (Self::Fn(_), Self::Fn(_)) => false,
+            (Self::Option(Some(l0)), Self::Option(Some(r0))) => {
+                l0.ptr_eq(r0) || l0 == r0
+            }
             (Self::Option(l0), Self::Option(r0)) => l0 == r0,
             (Self::Bool(l0), Self::Bool(r0)) => l0 == r0,
             _ => false,
@@ -252,8 +274,7 @@ where
     U: IntoAttribute,
 {
     fn into_attribute(self) -> Attribute {
-        let modified_fn = Rc::new(move || (self)().into_attribute());
-        Attribute::Fn(modified_fn)
+        Attribute::Fn(Rc::new(move || (self)().into_attribute()))
     }
 
     impl_into_attr_boxed! {}
@@ -332,8 +353,7 @@ macro_rules! attr_signal_type {
             T: IntoAttribute + Clone,
         {
             fn into_attribute(self) -> Attribute {
-                let modified_fn = Rc::new(move || self.get().into_attribute());
-                Attribute::Fn(modified_fn)
+                Attribute::Fn(Rc::new(move || self.get().into_attribute()))
             }
 
             impl_into_attr_boxed! {}
@@ -350,8 +370,7 @@ macro_rules! attr_signal_type_optional {
             Option<T>: IntoAttribute,
         {
             fn into_attribute(self) -> Attribute {
-                let modified_fn = Rc::new(move || self.get().into_attribute());
-                Attribute::Fn(modified_fn)
+                Attribute::Fn(Rc::new(move || self.get().into_attribute()))
             }
 
             impl_into_attr_boxed! {}
@@ -394,21 +413,34 @@ pub fn attribute_helper(
 ) {
     #[cfg(debug_assertions)]
     let called_at = std::panic::Location::caller();
+    use super::batched_writes::{queue_write, Write};
     use leptos_reactive::create_render_effect;
     match value {
         Attribute::Fn(f) => {
             let el = el.clone();
             create_render_effect(move |old| {
-                let new = f();
-                if old.as_ref() != Some(&new) {
-                    attribute_expression(
+                // a closure that returns another closure is resolved here, so
+                // that its value can still be compared with the last one
+                let new = f().resolve();
+                match old {
+                    None => attribute_expression(
                         &el,
                         &name,
                         new.clone(),
                         true,
                         #[cfg(debug_assertions)]
                         called_at,
-                    );
+                    ),
+                    Some(old) if old != new => queue_write(
+                        &el,
+                        Write::Attribute {
+                            name: name.clone(),
+                            value: new.clone(),
+                            #[cfg(debug_assertions)]
+                            called_at,
+                        },
+                    ),
+                    _ => {}
                 }
                 new
             });
@@ -493,3 +525,46 @@ pub(crate) fn attribute_expression(
         }
     }
 }
+
+#[cfg(all(test, target_arch = "wasm32", feature = "web"))]
+mod tests {
+    use super::*;
+    use leptos_reactive::{create_runtime, create_signal, SignalSet};
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn dynamic_attributes_are_only_written_when_they_change() {
+        let runtime = create_runtime();
+        let (count, set_count) = create_signal(1);
+
+        let input = crate::document().create_element("input").unwrap();
+        attribute_helper(
+            &input,
+            "placeholder".into(),
+            (move || move || if count.get() > 0 { "items" } else { "empty" })
+                .into_attribute(),
+        );
+        assert_eq!(
+            input.get_attribute("placeholder").as_deref(),
+            Some("items")
+        );
+
+        // the closure returns a new closure each time, which is resolved to
+        // compare its value with the last one
+        input.set_attribute("placeholder", "edited").unwrap();
+        set_count.set(2);
+        assert_eq!(
+            input.get_attribute("placeholder").as_deref(),
+            Some("edited")
+        );
+        set_count.set(0);
+        assert_eq!(
+            input.get_attribute("placeholder").as_deref(),
+            Some("empty")
+        );
+
+        runtime.dispose();
+    }
+}
@@ -86,21 +86,32 @@ pub fn class_helper(
     name: Oco<'static, str>,
     value: Class,
 ) {
+    use super::batched_writes::{queue_write, Write};
     use leptos_reactive::create_render_effect;
 
-    let class_list = el.class_list();
     match value {
         Class::Fn(f) => {
+            let el = el.clone();
             create_render_effect(move |old| {
                 let new = f();
-                if old.as_ref() != Some(&new) && (old.is_some() || new) {
-                    class_expression(&class_list, &name, new, true)
+                match old {
+                    None if new => {
+                        class_expression(&el.class_list(), &name, new, true)
+                    }
+                    Some(old) if old != new => queue_write(
+                        &el,
+                        Write::Class {
+                            name: name.clone(),
+                            value: new,
+                        },
+                    ),
+                    _ => {}
                 }
                 new
             });
         }
         Class::Value(value) => {
-            class_expression(&class_list, &name, value, false)
+            class_expression(&el.class_list(), &name, value, false)
         }
     };
 }
@@ -118,14 +129,8 @@ pub(crate) fn class_expression(
     if force || !HydrationCtx::is_hydrating() {
         let class_name = wasm_bindgen::intern(class_name);
 
-        if value {
-            if let Err(e) = class_list.add_1(class_name) {
-                crate::error!("[HtmlElement::class()] {e:?}");
-            }
-        } else {
-            if let Err(e) = class_list.remove_1(class_name) {
-                crate::error!("[HtmlElement::class()] {e:?}");
-            }
+        if let Err(e) = class_list.toggle_with_force(class_name, value) {
+            crate::error!("[HtmlElement::class()] {e:?}");
         }
     }
 }
@@ -9,6 +9,11 @@ use wasm_bindgen::UnwrapThrowExt;
 /// Represents the different possible values an element property could have,
 /// allowing you to do fine-grained updates to single fields.
 ///
+/// Properties hold the live state of an element, while some attributes only set its initial
+/// state: once the user has typed into an `<input>`, changing its `value` attribute no longer
+/// changes what it shows, but `prop:value=move || text.get()` does. The same goes for `checked`
+/// and `selected`.
+///
 /// This mostly exists for the [`view`](https://docs.rs/leptos_macro/latest/leptos_macro/macro.view.html)
 /// macro’s use. You usually won't need to interact with it directly, but it can be useful for defining
 /// permissive APIs for certain components.
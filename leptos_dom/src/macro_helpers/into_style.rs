@@ -5,7 +5,14 @@ use leptos_reactive::{
 };
 use std::{borrow::Cow, rc::Rc};
 
-/// todo docs
+/// Represents the different possible values a single CSS property of an element could have,
+/// allowing you to do fine-grained updates to single properties with
+/// [`CSSStyleDeclaration.setProperty`](https://developer.mozilla.org/en-US/docs/Web/API/CSSStyleDeclaration/setProperty)
+/// instead of replacing the whole `style` attribute.
+///
+/// This mostly exists for the [`view`](https://docs.rs/leptos_macro/latest/leptos_macro/macro.view.html)
+/// macro’s use, as in `style:color=move || color.get()`. You usually won't need to interact with it
+/// directly, but it can be useful for defining permissive APIs for certain components.
 #[derive(Clone)]
 pub enum Style {
     /// A plain string value.
@@ -19,8 +26,11 @@ pub enum Style {
 impl PartialEq for Style {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
-            (Self::Value(l0), Self::Value(r0)) => l0 == r0,
+            (Self::Value(l0), Self::Value(r0)) => l0.ptr_eq(r0) || l0 == r0,
             (Self::Fn(_), Self::Fn(_)) => false,
+            (Self::Option(Some(l0)), Self::Option(Some(r0))) => {
+                l0.ptr_eq(r0) || l0 == r0
+            }
             (Self::Option(l0), Self::Option(r0)) => l0 == r0,
             _ => false,
         }
@@ -172,6 +182,17 @@ where
     }
 }
 
+// built without `format!`, which would pull in the formatting machinery for
+// something that is only ever a concatenation
+fn declaration(name: &str, value: &str) -> Oco<'static, str> {
+    let mut buf = String::with_capacity(name.len() + value.len() + 3);
+    buf.push_str(name);
+    buf.push_str(": ");
+    buf.push_str(value);
+    buf.push(';');
+    buf.into()
+}
+
 impl Style {
     /// Converts the style to its HTML value at that moment so it can be rendered on the server.
     pub fn as_value_string(
@@ -179,12 +200,10 @@ impl Style {
         style_name: &'static str,
     ) -> Option<Oco<'static, str>> {
         match self {
-            Style::Value(value) => {
-                Some(format!("{style_name}: {value};").into())
+            Style::Value(value) => Some(declaration(style_name, value)),
+            Style::Option(value) => {
+                value.as_ref().map(|value| declaration(style_name, value))
             }
-            Style::Option(value) => value
-                .as_ref()
-                .map(|value| format!("{style_name}: {value};").into()),
             Style::Fn(f) => {
                 let mut value = f();
                 while let Style::Fn(f) = value {
@@ -204,14 +223,15 @@ pub fn style_helper(
     name: Oco<'static, str>,
     value: Style,
 ) {
+    use super::batched_writes::{queue_write, Write};
     use leptos_reactive::create_render_effect;
     use std::ops::Deref;
     use wasm_bindgen::JsCast;
 
-    let el = el.unchecked_ref::<web_sys::HtmlElement>();
-    let style_list = el.style();
+    let style_list = el.unchecked_ref::<web_sys::HtmlElement>().style();
     match value {
         Style::Fn(f) => {
+            let el = el.clone();
             create_render_effect(move |old| {
                 let mut new = f();
                 while let Style::Fn(f) = new {
@@ -222,8 +242,21 @@ pub fn style_helper(
                     Style::Option(value) => value,
                     _ => unreachable!(),
                 };
-                if old.as_ref() != Some(&new) {
-                    style_expression(&style_list, &name, new.as_deref(), true)
+                match &old {
+                    None => style_expression(
+                        &style_list,
+                        &name,
+                        new.as_deref(),
+                        true,
+                    ),
+                    Some(old) if *old != new => queue_write(
+                        &el,
+                        Write::Style {
+                            name: name.clone(),
+                            value: new.clone(),
+                        },
+                    ),
+                    _ => {}
                 }
                 new
             });
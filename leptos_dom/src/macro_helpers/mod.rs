@@ -1,3 +1,7 @@
+mod attribute_map;
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+mod batched_writes;
+mod class_style_props;
 mod into_attribute;
 mod into_class;
 mod into_property;
@@ -5,6 +9,8 @@ mod into_style;
 #[cfg(feature = "trace-component-props")]
 #[doc(hidden)]
 pub mod tracing_property;
+pub use attribute_map::*;
+pub use class_style_props::*;
 pub use into_attribute::*;
 pub use into_class::*;
 pub use into_property::*;
@@ -26,7 +26,7 @@ use std::cell::Cell;
 ///     view! {
 ///       <div>
 ///       // `node_ref` loads the input
-///       <input _ref=input_ref type="text"/>
+///       <input node_ref=input_ref type="text"/>
 ///       // the button consumes it
 ///       <button on:click=on_click>"Click me"</button>
 ///       </div>
@@ -60,7 +60,7 @@ pub struct NodeRef<T: ElementDescriptor + 'static>(
 ///     view! {
 ///       <div>
 ///           // `node_ref` loads the input
-///           <input _ref=input_ref type="text"/>
+///           <input node_ref=input_ref type="text"/>
 ///           // the button consumes it
 ///           <button on:click=on_click>"Click me"</button>
 ///       </div>
@@ -98,7 +98,7 @@ impl<T: ElementDescriptor + 'static> NodeRef<T> {
     ///     view! {
     ///       <div>
     ///           // `node_ref` loads the input
-    ///           <input _ref=input_ref type="text"/>
+    ///           <input node_ref=input_ref type="text"/>
     ///           // the button consumes it
     ///           <button on:click=on_click>"Click me"</button>
     ///       </div>
@@ -161,6 +161,11 @@ impl<T: ElementDescriptor + 'static> NodeRef<T> {
 
     /// Runs the provided closure when the `NodeRef` has been connected
     /// with it's [`HtmlElement`].
+    ///
+    /// This happens as soon as the element has been created. When hydrating,
+    /// it is the element that was rendered on the server; otherwise, it may not
+    /// have been added to the document yet. Use [`NodeRef::on_mount`] for work
+    /// that needs the element to be in the page, like focusing it or measuring it.
     #[inline(always)]
     pub fn on_load<F>(self, f: F)
     where
@@ -177,6 +182,37 @@ impl<T: ElementDescriptor + 'static> NodeRef<T> {
     }
 }
 
+impl<T: ElementDescriptor + Clone + 'static> NodeRef<T> {
+    /// Runs the provided closure once the element has been loaded into the
+    /// `NodeRef` and mounted to the document, whether it was created in the
+    /// browser or hydrated from the server.
+    ///
+    /// On the server, the closure is never called.
+    ///
+    /// ```
+    /// # use leptos::*;
+    /// use leptos::html::Input;
+    ///
+    /// #[component]
+    /// pub fn SearchBox() -> impl IntoView {
+    ///     let input_ref = create_node_ref::<Input>();
+    ///     input_ref.on_mount(|input| {
+    ///         _ = input.focus();
+    ///     });
+    ///
+    ///     view! { <input node_ref=input_ref type="search"/> }
+    /// }
+    /// ```
+    pub fn on_mount<F>(self, f: F)
+    where
+        F: FnOnce(HtmlElement<T>) + 'static,
+    {
+        self.on_load(move |el| {
+            _ = el.on_mount(f);
+        });
+    }
+}
+
 impl<T: ElementDescriptor> Clone for NodeRef<T> {
     fn clone(&self) -> Self {
         *self
@@ -0,0 +1,263 @@
+//! An integration point for rendering to something other than the browser DOM.
+//!
+//! The [`View`](crate::View) tree and the `view` macro target the browser
+//! through `web_sys`. The [`Renderer`] trait describes the small set of
+//! operations that rendering actually needs (creating nodes, setting
+//! attributes, inserting and removing nodes, and listening for events), so
+//! that the same reactive logic can drive another backend: a native toolkit,
+//! a terminal UI, or an in-memory DOM for tests.
+//!
+//! The helpers in this module, like [`attribute`] and [`text`], connect
+//! reactive values to any renderer in the same way that the `view` macro
+//! connects them to the DOM.
+//!
+//! **This module is experimental.** [`View`](crate::View),
+//! [`HtmlElement`](crate::HtmlElement) and the `view` macro do not go through
+//! the trait yet, so a custom renderer can only be driven with the helpers in
+//! this module, and the trait may change in any release while that is worked
+//! out. `Dom` is the implementation backed by `web_sys`, and [`TestDom`] is an
+//! in-memory implementation for tests.
+
+mod test_dom;
+
+use crate::{Attribute, Class};
+use leptos_reactive::{create_isomorphic_effect, TextProp};
+pub use test_dom::*;
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+use wasm_bindgen::{JsCast, UnwrapThrowExt};
+
+/// The operations needed to build and update a UI tree.
+///
+/// Methods are associated functions rather than taking `&self`, because a
+/// renderer is a backend, not an object: nodes carry whatever handle they need
+/// to reach their tree.
+pub trait Renderer: Sized + 'static {
+    /// Any node in the tree, including elements, text, and placeholders.
+    type Node: Clone + 'static;
+
+    /// An element, which can have attributes, classes, children, and event
+    /// listeners.
+    type Element: Clone + AsRef<Self::Node> + 'static;
+
+    /// The event passed to event listeners.
+    type Event: 'static;
+
+    /// Creates an element with the given tag name.
+    fn create_element(tag: &str) -> Self::Element;
+
+    /// Creates a text node.
+    fn create_text_node(text: &str) -> Self::Node;
+
+    /// Creates an empty node that marks a position in the tree, like an HTML
+    /// comment in the DOM.
+    fn create_placeholder() -> Self::Node;
+
+    /// Replaces the text of a text node.
+    fn set_text(node: &Self::Node, text: &str);
+
+    /// Sets an attribute on an element.
+    fn set_attribute(el: &Self::Element, name: &str, value: &str);
+
+    /// Removes an attribute from an element.
+    fn remove_attribute(el: &Self::Element, name: &str);
+
+    /// Adds a class to an element's class list.
+    fn add_class(el: &Self::Element, name: &str);
+
+    /// Removes a class from an element's class list.
+    fn remove_class(el: &Self::Element, name: &str);
+
+    /// Inserts `node` as a child of `parent`, before `marker` if it is given,
+    /// or as the last child otherwise.
+    fn insert_node(
+        parent: &Self::Element,
+        node: &Self::Node,
+        marker: Option<&Self::Node>,
+    );
+
+    /// Removes a node from its parent, if it has one.
+    fn remove_node(node: &Self::Node);
+
+    /// Returns the parent element of a node.
+    fn get_parent(node: &Self::Node) -> Option<Self::Element>;
+
+    /// Returns the first child of a node.
+    fn first_child(node: &Self::Node) -> Option<Self::Node>;
+
+    /// Returns the next sibling of a node.
+    fn next_sibling(node: &Self::Node) -> Option<Self::Node>;
+
+    /// Adds a listener for the event with the given name. The listener lives
+    /// as long as the element does.
+    fn add_event_listener(
+        el: &Self::Element,
+        name: &str,
+        handler: Box<dyn FnMut(Self::Event)>,
+    );
+}
+
+// These use isomorphic effects, rather than render effects, because they
+// render to a live tree even when the `ssr` feature is enabled, as it is when
+// testing with `TestDom`.
+
+/// Sets an attribute on an element, updating it whenever `value` changes if
+/// it is reactive.
+///
+/// This follows the same rules as attributes in the `view` macro: `false` and
+/// `None` remove the attribute, and `true` sets it with an empty value.
+pub fn attribute<R: Renderer>(el: &R::Element, name: &str, value: Attribute) {
+    match value {
+        Attribute::Fn(f) => {
+            let el = el.clone();
+            let name = name.to_owned();
+            create_isomorphic_effect(move |old: Option<Attribute>| {
+                let new = f().resolve();
+                if old.as_ref() != Some(&new) {
+                    attribute_expression::<R>(&el, &name, new.clone());
+                }
+                new
+            });
+        }
+        _ => attribute_expression::<R>(el, name, value),
+    }
+}
+
+fn attribute_expression<R: Renderer>(
+    el: &R::Element,
+    name: &str,
+    value: Attribute,
+) {
+    match value {
+        Attribute::String(value) => R::set_attribute(el, name, &value),
+        Attribute::Option(Some(value)) => R::set_attribute(el, name, &value),
+        Attribute::Bool(true) => R::set_attribute(el, name, ""),
+        Attribute::Option(None) | Attribute::Bool(false) => {
+            R::remove_attribute(el, name)
+        }
+        Attribute::Fn(f) => attribute_expression::<R>(el, name, f().resolve()),
+    }
+}
+
+/// Toggles a class on an element, updating it whenever `value` changes if it
+/// is reactive, like `class:name=value` in the `view` macro.
+pub fn class<R: Renderer>(el: &R::Element, name: &str, value: Class) {
+    match value {
+        Class::Fn(f) => {
+            let el = el.clone();
+            let name = name.to_owned();
+            create_isomorphic_effect(move |old| {
+                let new = f();
+                if old != Some(new) && (old.is_some() || new) {
+                    class_expression::<R>(&el, &name, new);
+                }
+                new
+            });
+        }
+        Class::Value(value) => class_expression::<R>(el, name, value),
+    }
+}
+
+fn class_expression<R: Renderer>(el: &R::Element, name: &str, value: bool) {
+    if value {
+        R::add_class(el, name);
+    } else {
+        R::remove_class(el, name);
+    }
+}
+
+/// Creates a text node whose text is updated whenever `text` changes.
+pub fn text<R: Renderer>(text: impl Into<TextProp>) -> R::Node {
+    let text = text.into();
+    let node = R::create_text_node("");
+    create_isomorphic_effect({
+        let node = node.clone();
+        move |_| R::set_text(&node, &text.get())
+    });
+    node
+}
+
+/// Renders to the browser DOM through `web_sys`.
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Dom;
+
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+impl Renderer for Dom {
+    type Node = web_sys::Node;
+    type Element = web_sys::Element;
+    type Event = web_sys::Event;
+
+    fn create_element(tag: &str) -> Self::Element {
+        crate::document()
+            .create_element(wasm_bindgen::intern(tag))
+            .unwrap_throw()
+    }
+
+    fn create_text_node(text: &str) -> Self::Node {
+        crate::document().create_text_node(text).unchecked_into()
+    }
+
+    fn create_placeholder() -> Self::Node {
+        crate::document().create_comment("").unchecked_into()
+    }
+
+    fn set_text(node: &Self::Node, text: &str) {
+        node.set_text_content(Some(text));
+    }
+
+    fn set_attribute(el: &Self::Element, name: &str, value: &str) {
+        el.set_attribute(wasm_bindgen::intern(name), value)
+            .unwrap_throw();
+    }
+
+    fn remove_attribute(el: &Self::Element, name: &str) {
+        el.remove_attribute(wasm_bindgen::intern(name))
+            .unwrap_throw();
+    }
+
+    fn add_class(el: &Self::Element, name: &str) {
+        if let Err(e) = el.class_list().add_1(wasm_bindgen::intern(name)) {
+            crate::error!("[Dom::add_class()] {e:?}");
+        }
+    }
+
+    fn remove_class(el: &Self::Element, name: &str) {
+        if let Err(e) = el.class_list().remove_1(wasm_bindgen::intern(name)) {
+            crate::error!("[Dom::remove_class()] {e:?}");
+        }
+    }
+
+    fn insert_node(
+        parent: &Self::Element,
+        node: &Self::Node,
+        marker: Option<&Self::Node>,
+    ) {
+        parent.insert_before(node, marker).unwrap_throw();
+    }
+
+    fn remove_node(node: &Self::Node) {
+        if let Some(parent) = node.parent_node() {
+            _ = parent.remove_child(node);
+        }
+    }
+
+    fn get_parent(node: &Self::Node) -> Option<Self::Element> {
+        node.parent_element()
+    }
+
+    fn first_child(node: &Self::Node) -> Option<Self::Node> {
+        node.first_child()
+    }
+
+    fn next_sibling(node: &Self::Node) -> Option<Self::Node> {
+        node.next_sibling()
+    }
+
+    fn add_event_listener(
+        el: &Self::Element,
+        name: &str,
+        handler: Box<dyn FnMut(Self::Event)>,
+    ) {
+        crate::events::add_event_listener_undelegated(el, name, handler, &None);
+    }
+}
@@ -0,0 +1,378 @@
+use super::Renderer;
+use std::{
+    cell::RefCell,
+    fmt,
+    rc::{Rc, Weak},
+};
+
+/// A headless, in-memory [`Renderer`] for testing.
+///
+/// It runs on any target, so components built with the helpers in
+/// [`renderer`](crate::renderer) can be tested with a plain `cargo test`,
+/// without a browser or `wasm-pack`. Events can be dispatched with
+/// [`TestNode::dispatch`], and the tree can be inspected directly or
+/// serialized with [`TestNode::to_html`].
+///
+/// ```
+/// use leptos::{create_runtime, create_signal, SignalGet, SignalUpdate};
+/// use leptos_dom::renderer::{self, Renderer, TestDom};
+///
+/// let runtime = create_runtime();
+/// let (count, set_count) = create_signal(0);
+///
+/// let button = TestDom::create_element("button");
+/// TestDom::add_event_listener(
+///     &button,
+///     "click",
+///     Box::new(move |_| set_count.update(|n| *n += 1)),
+/// );
+/// let label = renderer::text::<TestDom>(move || count.get().to_string());
+/// TestDom::insert_node(&button, &label, None);
+///
+/// button.dispatch("click");
+/// button.dispatch("click");
+/// assert_eq!(button.to_html(), "<button>2</button>");
+/// # runtime.dispose();
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TestDom;
+
+/// A node in a [`TestDom`] tree.
+///
+/// Clones refer to the same node.
+#[derive(Clone)]
+pub struct TestNode(Rc<RefCell<NodeData>>);
+
+struct NodeData {
+    kind: NodeKind,
+    parent: Option<Weak<RefCell<NodeData>>>,
+    children: Vec<TestNode>,
+}
+
+enum NodeKind {
+    Element {
+        tag: String,
+        attributes: Vec<(String, String)>,
+        listeners: Vec<(String, Listener)>,
+    },
+    Text(String),
+    Placeholder,
+}
+
+type Listener = Rc<RefCell<Box<dyn FnMut(TestEvent)>>>;
+
+/// An event dispatched in a [`TestDom`] tree.
+#[derive(Clone, Debug)]
+pub struct TestEvent {
+    name: String,
+    target: TestNode,
+}
+
+impl TestEvent {
+    /// The name of the event, like `"click"`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The node the event was dispatched on.
+    pub fn target(&self) -> &TestNode {
+        &self.target
+    }
+}
+
+impl TestNode {
+    fn new(kind: NodeKind) -> Self {
+        Self(Rc::new(RefCell::new(NodeData {
+            kind,
+            parent: None,
+            children: Vec::new(),
+        })))
+    }
+
+    /// The tag name, if this is an element.
+    pub fn tag(&self) -> Option<String> {
+        match &self.0.borrow().kind {
+            NodeKind::Element { tag, .. } => Some(tag.clone()),
+            _ => None,
+        }
+    }
+
+    /// Whether this is a placeholder created with
+    /// [`Renderer::create_placeholder`].
+    pub fn is_placeholder(&self) -> bool {
+        matches!(self.0.borrow().kind, NodeKind::Placeholder)
+    }
+
+    /// The value of an attribute, if this is an element that has it.
+    pub fn attribute(&self, name: &str) -> Option<String> {
+        match &self.0.borrow().kind {
+            NodeKind::Element { attributes, .. } => attributes
+                .iter()
+                .find(|(attr, _)| attr == name)
+                .map(|(_, value)| value.clone()),
+            _ => None,
+        }
+    }
+
+    /// Whether the element's class list includes `name`.
+    pub fn has_class(&self, name: &str) -> bool {
+        self.attribute("class").is_some_and(|classes| {
+            classes.split_whitespace().any(|c| c == name)
+        })
+    }
+
+    /// The text of this node and all of its descendants.
+    pub fn text_content(&self) -> String {
+        let data = self.0.borrow();
+        match &data.kind {
+            NodeKind::Text(text) => text.clone(),
+            NodeKind::Placeholder => String::new(),
+            NodeKind::Element { .. } => {
+                data.children.iter().map(TestNode::text_content).collect()
+            }
+        }
+    }
+
+    /// The children of this node.
+    pub fn children(&self) -> Vec<TestNode> {
+        self.0.borrow().children.clone()
+    }
+
+    /// The parent of this node, if it has been inserted into another node.
+    pub fn parent(&self) -> Option<TestNode> {
+        self.0
+            .borrow()
+            .parent
+            .as_ref()
+            .and_then(Weak::upgrade)
+            .map(TestNode)
+    }
+
+    /// Returns the first descendant (or this node itself) that matches a
+    /// simple selector: a tag name like `button`, an id like `#save`, or a
+    /// class like `.primary`.
+    pub fn query_selector(&self, selector: &str) -> Option<TestNode> {
+        let matches = |node: &TestNode| {
+            if let Some(id) = selector.strip_prefix('#') {
+                node.attribute("id").as_deref() == Some(id)
+            } else if let Some(class) = selector.strip_prefix('.') {
+                node.has_class(class)
+            } else {
+                node.tag().as_deref() == Some(selector)
+            }
+        };
+        if matches(self) {
+            return Some(self.clone());
+        }
+        self.children()
+            .iter()
+            .find_map(|child| child.query_selector(selector))
+    }
+
+    /// Dispatches an event on this node. Like most DOM events, it bubbles:
+    /// listeners on this node run first, then those on each ancestor.
+    pub fn dispatch(&self, name: &str) {
+        let event = TestEvent {
+            name: name.to_owned(),
+            target: self.clone(),
+        };
+        let mut node = Some(self.clone());
+        while let Some(current) = node {
+            // clone the listeners out, so that they can modify the tree
+            let listeners = match &current.0.borrow().kind {
+                NodeKind::Element { listeners, .. } => listeners
+                    .iter()
+                    .filter(|(listener, _)| listener == name)
+                    .map(|(_, listener)| Rc::clone(listener))
+                    .collect::<Vec<_>>(),
+                _ => Vec::new(),
+            };
+            for listener in listeners {
+                (listener.borrow_mut())(event.clone());
+            }
+            node = current.parent();
+        }
+    }
+
+    /// Serializes this node and its descendants as HTML. Placeholders are
+    /// serialized as empty comments.
+    pub fn to_html(&self) -> String {
+        let data = self.0.borrow();
+        match &data.kind {
+            NodeKind::Text(text) => html_escape::encode_text(text).into_owned(),
+            NodeKind::Placeholder => "<!---->".to_owned(),
+            NodeKind::Element {
+                tag, attributes, ..
+            } => {
+                let attributes = attributes
+                    .iter()
+                    .map(|(name, value)| {
+                        if value.is_empty() {
+                            format!(" {name}")
+                        } else {
+                            format!(
+                                " {name}=\"{}\"",
+                                html_escape::encode_double_quoted_attribute(
+                                    value
+                                )
+                            )
+                        }
+                    })
+                    .collect::<String>();
+                let children = data
+                    .children
+                    .iter()
+                    .map(TestNode::to_html)
+                    .collect::<String>();
+                format!("<{tag}{attributes}>{children}</{tag}>")
+            }
+        }
+    }
+
+    fn with_attributes(&self, f: impl FnOnce(&mut Vec<(String, String)>)) {
+        if let NodeKind::Element { attributes, .. } =
+            &mut self.0.borrow_mut().kind
+        {
+            f(attributes);
+        }
+    }
+}
+
+impl AsRef<TestNode> for TestNode {
+    fn as_ref(&self) -> &TestNode {
+        self
+    }
+}
+
+impl PartialEq for TestNode {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for TestNode {}
+
+impl fmt::Debug for TestNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_html())
+    }
+}
+
+impl Renderer for TestDom {
+    type Node = TestNode;
+    type Element = TestNode;
+    type Event = TestEvent;
+
+    fn create_element(tag: &str) -> Self::Element {
+        TestNode::new(NodeKind::Element {
+            tag: tag.to_owned(),
+            attributes: Vec::new(),
+            listeners: Vec::new(),
+        })
+    }
+
+    fn create_text_node(text: &str) -> Self::Node {
+        TestNode::new(NodeKind::Text(text.to_owned()))
+    }
+
+    fn create_placeholder() -> Self::Node {
+        TestNode::new(NodeKind::Placeholder)
+    }
+
+    fn set_text(node: &Self::Node, text: &str) {
+        if let NodeKind::Text(old) = &mut node.0.borrow_mut().kind {
+            text.clone_into(old);
+        }
+    }
+
+    fn set_attribute(el: &Self::Element, name: &str, value: &str) {
+        el.with_attributes(|attributes| {
+            match attributes.iter_mut().find(|(attr, _)| attr == name) {
+                Some((_, old)) => value.clone_into(old),
+                None => attributes.push((name.to_owned(), value.to_owned())),
+            }
+        });
+    }
+
+    fn remove_attribute(el: &Self::Element, name: &str) {
+        el.with_attributes(|attributes| {
+            attributes.retain(|(attr, _)| attr != name)
+        });
+    }
+
+    fn add_class(el: &Self::Element, name: &str) {
+        if !el.has_class(name) {
+            let classes = match el.attribute("class") {
+                Some(classes) if !classes.trim().is_empty() => {
+                    format!("{} {name}", classes.trim())
+                }
+                _ => name.to_owned(),
+            };
+            Self::set_attribute(el, "class", &classes);
+        }
+    }
+
+    fn remove_class(el: &Self::Element, name: &str) {
+        if let Some(classes) = el.attribute("class") {
+            let classes = classes
+                .split_whitespace()
+                .filter(|class| *class != name)
+                .collect::<Vec<_>>()
+                .join(" ");
+            if classes.is_empty() {
+                Self::remove_attribute(el, "class");
+            } else {
+                Self::set_attribute(el, "class", &classes);
+            }
+        }
+    }
+
+    fn insert_node(
+        parent: &Self::Element,
+        node: &Self::Node,
+        marker: Option<&Self::Node>,
+    ) {
+        Self::remove_node(node);
+        let mut data = parent.0.borrow_mut();
+        let index = marker
+            .and_then(|marker| {
+                data.children.iter().position(|child| child == marker)
+            })
+            .unwrap_or(data.children.len());
+        data.children.insert(index, node.clone());
+        node.0.borrow_mut().parent = Some(Rc::downgrade(&parent.0));
+    }
+
+    fn remove_node(node: &Self::Node) {
+        if let Some(parent) = node.parent() {
+            parent.0.borrow_mut().children.retain(|child| child != node);
+        }
+        node.0.borrow_mut().parent = None;
+    }
+
+    fn get_parent(node: &Self::Node) -> Option<Self::Element> {
+        node.parent()
+    }
+
+    fn first_child(node: &Self::Node) -> Option<Self::Node> {
+        node.0.borrow().children.first().cloned()
+    }
+
+    fn next_sibling(node: &Self::Node) -> Option<Self::Node> {
+        let parent = node.parent()?;
+        let children = parent.0.borrow();
+        let index = children.children.iter().position(|child| child == node)?;
+        children.children.get(index + 1).cloned()
+    }
+
+    fn add_event_listener(
+        el: &Self::Element,
+        name: &str,
+        handler: Box<dyn FnMut(Self::Event)>,
+    ) {
+        if let NodeKind::Element { listeners, .. } = &mut el.0.borrow_mut().kind
+        {
+            listeners.push((name.to_owned(), Rc::new(RefCell::new(handler))));
+        }
+    }
+}
@@ -3,14 +3,15 @@
 //! Server-side HTML rendering utilities.
 
 use crate::{
+    components::{lazy_row_marker, lazy_rows_end_marker},
     html::{ElementChildren, StringOrView},
+    hydration::MARKER_PREFIX,
     CoreComponent, HydrationCtx, HydrationKey, IntoView, View,
 };
 use cfg_if::cfg_if;
 use futures::{stream::FuturesUnordered, Future, Stream, StreamExt};
-use itertools::Itertools;
 use leptos_reactive::{Oco, *};
-use std::pin::Pin;
+use std::{cell::Cell, fmt::Write, pin::Pin};
 
 type PinnedFuture<T> = Pin<Box<dyn Future<Output = T>>>;
 
@@ -31,6 +32,23 @@ type PinnedFuture<T> = Pin<Box<dyn Future<Output = T>>>;
     instrument(level = "info", skip_all,)
 )]
 pub fn render_to_string<F, N>(f: F) -> Oco<'static, str>
+where
+    F: FnOnce() -> N + 'static,
+    N: IntoView,
+{
+    render_to_string_with_quoting(f, AttributeQuoting::default())
+}
+
+/// Renders the given function to a static HTML string, with the values of attributes
+/// quoted as given.
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    instrument(level = "info", skip_all,)
+)]
+pub fn render_to_string_with_quoting<F, N>(
+    f: F,
+    quoting: AttributeQuoting,
+) -> Oco<'static, str>
 where
     F: FnOnce() -> N + 'static,
     N: IntoView,
@@ -38,13 +56,231 @@ where
     HydrationCtx::reset_id();
     let runtime = leptos_reactive::create_runtime();
 
-    let html = f().into_view().render_to_string();
+    let html =
+        with_attribute_quoting(quoting, || f().into_view().render_to_string());
 
     runtime.dispose();
 
     html
 }
 
+/// Renders a function to an HTML string without any hydration markers or
+/// `data-hk` attributes, for HTML that will never be hydrated, like the body of
+/// an email or the content of a feed entry.
+///
+/// ```
+/// # cfg_if::cfg_if! { if #[cfg(not(any(feature = "csr", feature = "hydrate")))] {
+/// # use leptos::*;
+/// let html = leptos::ssr::render_to_string_clean(|| view! {
+///     <p>"Hello, " {move || "world"} "!"</p>
+/// });
+/// assert_eq!(html, "<p>Hello, world!</p>");
+/// # }}
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    instrument(level = "info", skip_all,)
+)]
+pub fn render_to_string_clean<F, N>(f: F) -> Oco<'static, str>
+where
+    F: FnOnce() -> N + 'static,
+    N: IntoView,
+{
+    let runtime = leptos_reactive::create_runtime();
+
+    let html =
+        HydrationCtx::with_markers_off(|| f().into_view().render_to_string());
+
+    runtime.dispose();
+
+    html
+}
+
+/// How many bytes of a rendered page are taken up by hydration markers, as measured by
+/// [`hydration_marker_report`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HydrationMarkerReport {
+    /// The size of the whole page.
+    pub total_bytes: usize,
+    /// The number of hydration marker comments.
+    pub comments: usize,
+    /// The size of the hydration marker comments.
+    pub comment_bytes: usize,
+    /// The number of `data-hk` attributes.
+    pub attributes: usize,
+    /// The size of the `data-hk` attributes, including the space before them.
+    pub attribute_bytes: usize,
+}
+
+impl HydrationMarkerReport {
+    /// The share of the page taken up by hydration markers, between `0.0` and `1.0`.
+    pub fn overhead(&self) -> f64 {
+        if self.total_bytes == 0 {
+            0.0
+        } else {
+            (self.comment_bytes + self.attribute_bytes) as f64
+                / self.total_bytes as f64
+        }
+    }
+}
+
+/// Measures the hydration markers in server-rendered HTML, to see how much they add to the
+/// size of a page, for example with and without the `compact-hydration-keys` feature.
+///
+/// ```
+/// # cfg_if::cfg_if! { if #[cfg(not(any(feature = "csr", feature = "hydrate")))] {
+/// # use leptos::*;
+/// let html = leptos::ssr::render_to_string(|| view! { <p>"Hello, world!"</p> });
+/// let report = leptos::ssr::hydration_marker_report(&html);
+/// assert_eq!(report.attributes, 1);
+/// assert!(report.overhead() > 0.0);
+/// # }}
+/// ```
+pub fn hydration_marker_report(html: &str) -> HydrationMarkerReport {
+    let mut report = HydrationMarkerReport {
+        total_bytes: html.len(),
+        ..Default::default()
+    };
+
+    let comment_start = format!("<!--{MARKER_PREFIX}");
+    let mut rest = html;
+    while let Some(start) = rest.find(&comment_start) {
+        let Some(len) = rest[start..].find("-->").map(|end| end + 3) else {
+            break;
+        };
+        report.comments += 1;
+        report.comment_bytes += len;
+        rest = &rest[start + len..];
+    }
+
+    let mut rest = html;
+    while let Some(start) = rest.find(" data-hk=\"") {
+        let value = &rest[start + 10..];
+        let Some(len) = value.find('"').map(|end| end + 11) else {
+            break;
+        };
+        report.attributes += 1;
+        report.attribute_bytes += len;
+        rest = &rest[start + len..];
+    }
+
+    report
+}
+
+/// How the values of attributes are quoted in server-rendered HTML.
+///
+/// Pass it to [`render_to_string_with_quoting`], or provide it as context in the
+/// `additional_context` of a stream, which is also how the server integrations take
+/// context for each request, for tools that process the HTML and require a certain style.
+///
+/// ```
+/// # cfg_if::cfg_if! { if #[cfg(not(any(feature = "csr", feature = "hydrate")))] {
+/// # use leptos::*;
+/// use leptos::ssr::{render_to_string_with_quoting, AttributeQuoting};
+///
+/// let html = render_to_string_with_quoting(
+///     || view! { <a href="/about" title="Bob's page">"About"</a> },
+///     AttributeQuoting::SingleQuote,
+/// );
+/// assert!(html.contains("<a href='/about' title='Bob&#x27;s page'"));
+/// # }}
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum AttributeQuoting {
+    /// `name="value"`, which is how attributes are rendered by default.
+    #[default]
+    DoubleQuote,
+    /// `name='value'`.
+    SingleQuote,
+    /// `name=value` when the value can be written without quotes, and `name="value"`
+    /// otherwise, which makes the page smaller.
+    Minimal,
+}
+
+thread_local! {
+    static ATTRIBUTE_QUOTING: Cell<AttributeQuoting> = const {
+        Cell::new(AttributeQuoting::DoubleQuote)
+    };
+}
+
+/// Runs `f` with the attributes of the views it creates and renders quoted as given.
+///
+/// The setting only lasts while `f` runs, so renders that are streamed at the same time
+/// on one thread don't see each other's quoting.
+#[doc(hidden)]
+pub fn with_attribute_quoting<T>(
+    quoting: AttributeQuoting,
+    f: impl FnOnce() -> T,
+) -> T {
+    // restores the previous quoting even if `f` panics
+    struct Restore(AttributeQuoting);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            ATTRIBUTE_QUOTING.with(|quoting| quoting.set(self.0));
+        }
+    }
+
+    let _restore =
+        Restore(ATTRIBUTE_QUOTING.with(|current| current.replace(quoting)));
+    f()
+}
+
+/// How the attributes of the views that are being rendered are quoted.
+pub fn attribute_quoting() -> AttributeQuoting {
+    ATTRIBUTE_QUOTING.with(Cell::get)
+}
+
+/// The quote to write around an attribute value, which is empty with
+/// [`AttributeQuoting::Minimal`] if `can_omit` is true.
+#[doc(hidden)]
+pub fn attribute_quote(can_omit: bool) -> &'static str {
+    match attribute_quoting() {
+        AttributeQuoting::SingleQuote => "'",
+        AttributeQuoting::Minimal if can_omit => "",
+        _ => "\"",
+    }
+}
+
+/// How a void element's start tag ends: an unquoted value right before `/>`
+/// would take the slash with it, so [`AttributeQuoting::Minimal`] leaves it out.
+#[doc(hidden)]
+pub fn void_tag_end() -> &'static str {
+    match attribute_quoting() {
+        AttributeQuoting::Minimal => ">",
+        _ => "/>",
+    }
+}
+
+/// Whether an attribute value can't be written without quotes.
+#[doc(hidden)]
+pub fn attribute_needs_quotes(value: &str) -> bool {
+    value.is_empty()
+        || value.contains(|c: char| {
+            c.is_ascii_whitespace()
+                || matches!(c, '"' | '\'' | '=' | '<' | '>' | '`')
+        })
+}
+
+/// Writes `name=value`, with the value escaped and quoted as the current render requires.
+#[doc(hidden)]
+pub fn write_attribute(buf: &mut String, name: &str, value: &str) {
+    let quote = attribute_quote(!attribute_needs_quotes(value));
+    buf.push_str(name);
+    buf.push('=');
+    buf.push_str(quote);
+    buf.push_str(&escape_attr(&value));
+    buf.push_str(quote);
+}
+
+/// Renders `name=value`, with the value escaped and quoted as the current render requires.
+#[doc(hidden)]
+pub fn attribute_to_string(name: &str, value: &str) -> String {
+    let mut buf = String::with_capacity(name.len() + value.len() + 3);
+    write_attribute(&mut buf, name, value);
+    buf
+}
+
 /// Renders a function to a stream of HTML strings.
 ///
 /// This renders:
@@ -190,10 +426,11 @@ pub fn render_to_stream_with_prefix_undisposed_with_context_and_block_replacemen
 
     // Add additional context items
     additional_context();
+    let quoting = use_context::<AttributeQuoting>().unwrap_or_default();
 
     // the actual app body/template code
     // this does NOT contain any of the data being loaded asynchronously in resources
-    let shell = view().render_to_string();
+    let shell = with_attribute_quoting(quoting, || view().render_to_string());
 
     let resources = SharedContext::pending_resources();
     let pending_resources = serde_json::to_string(&resources).unwrap();
@@ -242,7 +479,7 @@ pub fn render_to_stream_with_prefix_undisposed_with_context_and_block_replacemen
                         blocks.push((blocked_id, blocked_fragment));
                     }
 
-                    let prefix = prefix();
+                    let prefix = with_attribute_quoting(quoting, prefix);
 
                     let mut shell = shell;
 
@@ -270,7 +507,7 @@ pub fn render_to_stream_with_prefix_undisposed_with_context_and_block_replacemen
                     while let Some(fragment) = blocking_fragments.next().await {
                         blocking.push_str(&fragment);
                     }
-                    let prefix = prefix();
+                    let prefix = with_attribute_quoting(quoting, prefix);
                     format!("{prefix}{shell}{resolvers}{blocking}")
                 }
             }
@@ -293,9 +530,9 @@ fn ooo_body_stream_recurse(
     let resources = render_serializers(nonce_str.clone(), serializers);
 
     Box::pin(
-        // TODO these should be combined again in a way that chains them appropriately
-        // such that individual resources can resolve before all fragments are done
-        fragments.chain(resources).chain(
+        // send each fragment and resource as soon as it resolves, rather than waiting for
+        // all the fragments before sending any resources
+        futures::stream::select(fragments, resources).chain(
             futures::stream::once(async move {
                 let pending = SharedContext::pending_fragments();
 
@@ -386,274 +623,245 @@ impl View {
         self,
         dont_escape_text: bool,
     ) -> Oco<'static, str> {
+        let mut buf = String::new();
+        self.render_to_buffer(&mut buf, dont_escape_text);
+        buf.into()
+    }
+
+    /// Renders the view into the end of `buf`, so that the whole page can be
+    /// built in a single buffer rather than one string per node.
+    #[cfg_attr(
+        any(debug_assertions, feature = "ssr"),
+        instrument(level = "trace", skip_all,)
+    )]
+    pub(crate) fn render_to_buffer(
+        self,
+        buf: &mut String,
+        dont_escape_text: bool,
+    ) {
         match self {
             View::Text(node) => {
                 if dont_escape_text {
-                    node.content
+                    buf.push_str(&node.content);
                 } else {
-                    html_escape::encode_safe(&node.content).to_string().into()
+                    html_escape::encode_safe_to_string(&node.content, buf);
                 }
             }
             View::Component(node) => {
-                let content = || {
-                    node.children
-                        .into_iter()
-                        .map(|node| {
-                            node.render_to_string_helper(dont_escape_text)
-                        })
-                        .join("")
-                };
                 cfg_if! {
                   if #[cfg(debug_assertions)] {
                     let name = to_kebab_case(&node.name);
-                    let content = format!(r#"{}{}{}"#,
-                      node.id.to_marker(false, &name),
-                      content(),
-                      node.id.to_marker(true, &name),
-                    );
-                    if let Some(id) = node.view_marker {
-                        format!("<!--leptos-view|{id}|open-->{content}<!--leptos-view|{id}|close-->").into()
-                    } else {
-                        content.into()
+                    if let Some(id) = view_marker(&node.view_marker) {
+                      _ = write!(buf, "<!--leptos-view|{id}|open-->");
+                    }
+                    node.id.write_marker(buf, false, &name);
+                    for child in node.children {
+                      child.render_to_buffer(buf, dont_escape_text);
+                    }
+                    node.id.write_marker(buf, true, &name);
+                    if let Some(id) = view_marker(&node.view_marker) {
+                      _ = write!(buf, "<!--leptos-view|{id}|close-->");
                     }
                   } else {
-                    format!(
-                      r#"{}{}"#,
-                      content(),
-                      node.id.to_marker(true)
-                    ).into()
+                    for child in node.children {
+                      child.render_to_buffer(buf, dont_escape_text);
+                    }
+                    node.id.write_marker(buf, true);
                   }
                 }
             }
-            View::Suspense(id, node) => format!(
-                "<!--suspense-open-{id}-->{}<!--suspense-close-{id}-->",
+            View::Suspense(id, node) => {
+                let markers = !HydrationCtx::without_markers();
+                if markers {
+                    _ = write!(buf, "<!--suspense-open-{id}-->");
+                }
                 View::CoreComponent(node)
-                    .render_to_string_helper(dont_escape_text)
-            )
-            .into(),
-            View::CoreComponent(node) => {
-                let (id, name, wrap, content) = match node {
-                    CoreComponent::Unit(u) => (
-                        u.id,
-                        "",
+                    .render_to_buffer(buf, dont_escape_text);
+                if markers {
+                    _ = write!(buf, "<!--suspense-close-{id}-->");
+                }
+            }
+            View::CoreComponent(node) => match node {
+                CoreComponent::Unit(u) => u.id.write_marker(
+                    buf,
+                    true,
+                    #[cfg(debug_assertions)]
+                    "unit",
+                ),
+                CoreComponent::DynChild(node) => {
+                    node.id.write_marker(
+                        buf,
                         false,
-                        Box::new(move || {
-                            u.id.to_marker(
+                        #[cfg(debug_assertions)]
+                        "dyn-child",
+                    );
+                    match *node.child.take() {
+                        Some(View::Text(t)) => {
+                            // On debug builds, `DynChild` has two marker nodes,
+                            // so there is no way for the text to be merged with
+                            // surrounding text when the browser parses the HTML,
+                            // but in release, `DynChild` only has a trailing marker,
+                            // and the browser automatically merges the dynamic text
+                            // into one single node, so we need to artificially make the
+                            // browser create the dynamic text as it's own text node
+                            if !cfg!(debug_assertions) && node.id.is_some() {
+                                buf.push_str("<!>");
+                            }
+                            // if we don't check if the string is empty,
+                            // the HTML is an empty string; but an empty string
+                            // is not a text node in HTML, so can't be updated
+                            // in the future. so we put a one-space text node instead
+                            if t.content.is_empty() {
+                                buf.push(' ');
+                            } else if dont_escape_text {
+                                // don't escape content in a <script> or <style>
+                                buf.push_str(&t.content);
+                            } else {
+                                html_escape::encode_safe_to_string(
+                                    &t.content, buf,
+                                );
+                            }
+                        }
+                        Some(child) => {
+                            child.render_to_buffer(buf, dont_escape_text)
+                        }
+                        None => {}
+                    }
+                    node.id.write_marker(
+                        buf,
+                        true,
+                        #[cfg(debug_assertions)]
+                        "dyn-child",
+                    );
+                }
+                CoreComponent::Each(node) => {
+                    let each_id = node.id;
+                    each_id.write_marker(
+                        buf,
+                        false,
+                        #[cfg(debug_assertions)]
+                        "each",
+                    );
+                    for node in node.children.take().into_iter().flatten() {
+                        if let Some((each, start)) = each_id.zip(node.row_start)
+                        {
+                            buf.push_str(&lazy_row_marker(&each, &start));
+                        }
+                        if matches!(node.child, View::Element(_)) {
+                            node.child.render_to_buffer(buf, dont_escape_text);
+                        } else {
+                            node.id.write_marker(
+                                buf,
+                                false,
+                                #[cfg(debug_assertions)]
+                                "each-item",
+                            );
+                            node.child.render_to_buffer(buf, dont_escape_text);
+                            node.id.write_marker(
+                                buf,
                                 true,
                                 #[cfg(debug_assertions)]
-                                "unit",
-                            )
-                        })
-                            as Box<dyn FnOnce() -> Oco<'static, str>>,
-                    ),
-                    CoreComponent::DynChild(node) => {
-                        let child = node.child.take();
-                        (
-                            node.id,
-                            "dyn-child",
-                            true,
-                            Box::new(move || {
-                                if let Some(child) = *child {
-                                    if let View::Text(t) = child {
-                                        // if we don't check if the string is empty,
-                                        // the HTML is an empty string; but an empty string
-                                        // is not a text node in HTML, so can't be updated
-                                        // in the future. so we put a one-space text node instead
-                                        let was_empty = t.content.is_empty();
-                                        let content = if was_empty {
-                                            " ".into()
-                                        } else {
-                                            t.content
-                                        };
-                                        // escape content unless we're in a <script> or <style>
-                                        let content = if dont_escape_text {
-                                            content
-                                        } else {
-                                            html_escape::encode_safe(&content)
-                                                .to_string()
-                                                .into()
-                                        };
-                                        // On debug builds, `DynChild` has two marker nodes,
-                                        // so there is no way for the text to be merged with
-                                        // surrounding text when the browser parses the HTML,
-                                        // but in release, `DynChild` only has a trailing marker,
-                                        // and the browser automatically merges the dynamic text
-                                        // into one single node, so we need to artificially make the
-                                        // browser create the dynamic text as it's own text node
-                                        if !cfg!(debug_assertions) {
-                                            format!("<!>{content}",).into()
-                                        } else {
-                                            content
-                                        }
-                                    } else {
-                                        child.render_to_string_helper(
-                                            dont_escape_text,
-                                        )
-                                    }
-                                } else {
-                                    "".into()
-                                }
-                            })
-                                as Box<dyn FnOnce() -> Oco<'static, str>>,
-                        )
+                                "each-item",
+                            );
+                        }
                     }
-                    CoreComponent::Each(node) => {
-                        let children = node.children.take();
-                        (
-                            node.id,
-                            "each",
-                            true,
-                            Box::new(move || {
-                                children
-                                    .into_iter()
-                                    .flatten()
-                                    .map(|node| {
-                                        let id = node.id;
-                                        let is_el = matches!(
-                                            node.child,
-                                            View::Element(_)
-                                        );
-
-                                        let content = || {
-                                            node.child.render_to_string_helper(
-                                                dont_escape_text,
-                                            )
-                                        };
-
-                                        if is_el {
-                                            content()
-                                        } else {
-                                            format!(
-                                                "{}{}{}",
-                                                id.to_marker(
-                                                    false,
-                                                    #[cfg(debug_assertions)]
-                                                    "each-item",
-                                                ),
-                                                content(),
-                                                id.to_marker(
-                                                    true,
-                                                    #[cfg(debug_assertions)]
-                                                    "each-item",
-                                                )
-                                            )
-                                            .into()
-                                        }
-                                    })
-                                    .join("")
-                                    .into()
-                            })
-                                as Box<dyn FnOnce() -> Oco<'static, str>>,
-                        )
+                    if let Some((each, end)) = each_id.zip(node.rows_end) {
+                        buf.push_str(&lazy_rows_end_marker(&each, &end));
                     }
-                };
-
-                if wrap {
-                    format!(
-                        r#"{}{}{}"#,
-                        id.to_marker(
-                            false,
-                            #[cfg(debug_assertions)]
-                            name,
-                        ),
-                        content(),
-                        id.to_marker(
-                            true,
-                            #[cfg(debug_assertions)]
-                            name,
-                        ),
-                    )
-                    .into()
-                } else {
-                    content()
+                    each_id.write_marker(
+                        buf,
+                        true,
+                        #[cfg(debug_assertions)]
+                        "each",
+                    );
                 }
-            }
+            },
             View::Element(el) => {
+                #[cfg(feature = "interactive-ssr")]
+                let el = el.with_live_attrs();
+
+                #[cfg(debug_assertions)]
+                if let Some(id) = view_marker(&el.view_marker) {
+                    _ = write!(buf, "<!--leptos-view|{id}|open-->");
+                }
+
                 let is_script_or_style =
                     el.name == "script" || el.name == "style";
-                let el_html = if let ElementChildren::Chunks(chunks) =
-                    el.children
-                {
-                    chunks
-                        .into_iter()
-                        .map(|chunk| match chunk {
-                            StringOrView::String(string) => string,
-                            StringOrView::View(view) => view()
-                                .render_to_string_helper(is_script_or_style),
-                        })
-                        .join("")
-                        .into()
+                if let ElementChildren::Chunks(chunks) = el.children {
+                    for chunk in chunks {
+                        match chunk {
+                            StringOrView::String(string) => {
+                                buf.push_str(&string)
+                            }
+                            StringOrView::View(view) => {
+                                view().render_to_buffer(buf, is_script_or_style)
+                            }
+                        }
+                    }
                 } else {
-                    let tag_name: Oco<'_, str> = el.name;
-
+                    let tag_name = &el.name;
                     let mut inner_html: Option<Oco<'_, str>> = None;
 
-                    let attrs = el
-                        .attrs
-                        .into_iter()
-                        .filter_map(
-                            |(name, value)| -> Option<Oco<'static, str>> {
-                                if value.is_empty() {
-                                    Some(format!(" {name}").into())
-                                } else if name == "inner_html" {
-                                    inner_html = Some(value);
-                                    None
-                                } else {
-                                    Some(
-                                        format!(
-                                            " {name}=\"{}\"",
-                                            html_escape::encode_double_quoted_attribute(&value)
-                                        )
-                                        .into(),
-                                    )
-                                }
-                            },
-                        )
-                        .join("");
+                    buf.push('<');
+                    buf.push_str(tag_name);
+                    for (name, value) in el.attrs {
+                        if value.is_empty() {
+                            buf.push(' ');
+                            buf.push_str(&name);
+                        } else if name == "inner_html" {
+                            inner_html = Some(value);
+                        } else {
+                            buf.push(' ');
+                            write_attribute(buf, &name, &value);
+                        }
+                    }
 
                     if el.is_void {
-                        format!("<{tag_name}{attrs}/>").into()
-                    } else if let Some(inner_html) = inner_html {
-                        format!("<{tag_name}{attrs}>{inner_html}</{tag_name}>")
-                            .into()
+                        buf.push_str(void_tag_end());
                     } else {
-                        let children = match el.children {
-                            ElementChildren::Empty => "".into(),
-                            ElementChildren::Children(c) => c
-                                .into_iter()
-                                .map(|v| {
-                                    v.render_to_string_helper(
-                                        is_script_or_style,
-                                    )
-                                })
-                                .join("")
-                                .into(),
-                            ElementChildren::InnerHtml(h) => h,
-                            // already handled this case above
-                            ElementChildren::Chunks(_) => unreachable!(),
-                        };
-
-                        format!("<{tag_name}{attrs}>{children}</{tag_name}>")
-                            .into()
-                    }
-                };
-                cfg_if! {
-                    if #[cfg(debug_assertions)] {
-                        if let Some(id) = el.view_marker {
-                            format!("<!--leptos-view|{id}|open-->{el_html}<!--leptos-view|{id}|close-->").into()
+                        buf.push('>');
+                        if let Some(inner_html) = inner_html {
+                            buf.push_str(&inner_html);
                         } else {
-                            el_html
+                            match el.children {
+                                ElementChildren::Empty => {}
+                                ElementChildren::Children(c) => {
+                                    for child in c {
+                                        child.render_to_buffer(
+                                            buf,
+                                            is_script_or_style,
+                                        );
+                                    }
+                                }
+                                ElementChildren::InnerHtml(h) => {
+                                    buf.push_str(&h)
+                                }
+                                // already handled this case above
+                                ElementChildren::Chunks(_) => unreachable!(),
+                            }
                         }
-                    } else {
-                        el_html
+                        buf.push_str("</");
+                        buf.push_str(tag_name);
+                        buf.push('>');
                     }
                 }
+
+                #[cfg(debug_assertions)]
+                if let Some(id) = view_marker(&el.view_marker) {
+                    _ = write!(buf, "<!--leptos-view|{id}|close-->");
+                }
             }
-            View::Transparent(_) => Default::default(),
+            View::Transparent(_) => {}
         }
     }
 }
 
+/// The hot-reloading marker of a view, unless it is rendered without markers.
+#[cfg(debug_assertions)]
+fn view_marker(marker: &Option<String>) -> Option<&String> {
+    marker.as_ref().filter(|_| !HydrationCtx::without_markers())
+}
+
 #[cfg(debug_assertions)]
 pub(crate) fn to_kebab_case(name: &str) -> String {
     if name.is_empty() {
@@ -719,26 +927,50 @@ pub fn escape_attr<T>(value: &T) -> Oco<'_, str>
 where
     T: AsRef<str>,
 {
-    html_escape::encode_double_quoted_attribute(value).into()
+    // values in single quotes need `'` escaped, and escaping both quotes also keeps a
+    // value safe inside either kind of quote
+    if attribute_quoting() == AttributeQuoting::DoubleQuote {
+        html_escape::encode_double_quoted_attribute(value).into()
+    } else {
+        html_escape::encode_quoted_attribute(value).into()
+    }
 }
 
 pub(crate) trait ToMarker {
+    fn write_marker(
+        &self,
+        buf: &mut String,
+        closing: bool,
+        #[cfg(debug_assertions)] component_name: &str,
+    );
+
     fn to_marker(
         &self,
         closing: bool,
         #[cfg(debug_assertions)] component_name: &str,
-    ) -> Oco<'static, str>;
+    ) -> Oco<'static, str> {
+        let mut buf = String::new();
+        self.write_marker(
+            &mut buf,
+            closing,
+            #[cfg(debug_assertions)]
+            component_name,
+        );
+        buf.into()
+    }
 }
 
 impl ToMarker for HydrationKey {
     #[inline(always)]
-    fn to_marker(
+    fn write_marker(
         &self,
+        buf: &mut String,
         closing: bool,
         #[cfg(debug_assertions)] mut component_name: &str,
-    ) -> Oco<'static, str> {
+    ) {
         #[cfg(debug_assertions)]
         {
+            let key = self.to_html();
             if component_name.is_empty() {
                 // NOTE:
                 // If the name is left empty, this will lead to invalid comments,
@@ -746,18 +978,22 @@ impl ToMarker for HydrationKey {
                 component_name = "<>";
             }
             if closing || component_name == "unit" {
-                format!("<!--hk={self}c|leptos-{component_name}-end-->").into()
+                _ = write!(
+                    buf,
+                    "<!--{MARKER_PREFIX}{key}c|leptos-{component_name}-end-->"
+                );
             } else {
-                format!("<!--hk={self}o|leptos-{component_name}-start-->")
-                    .into()
+                _ = write!(
+                    buf,
+                    "<!--{MARKER_PREFIX}{key}o|leptos-{component_name}-start-->"
+                );
             }
         }
         #[cfg(not(debug_assertions))]
         {
             if closing {
-                format!("<!--hk={self}-->").into()
-            } else {
-                "".into()
+                let key = self.to_html();
+                _ = write!(buf, "<!--{MARKER_PREFIX}{key}-->");
             }
         }
     }
@@ -765,18 +1001,19 @@ impl ToMarker for HydrationKey {
 
 impl ToMarker for Option<HydrationKey> {
     #[inline(always)]
-    fn to_marker(
+    fn write_marker(
         &self,
+        buf: &mut String,
         closing: bool,
         #[cfg(debug_assertions)] component_name: &str,
-    ) -> Oco<'static, str> {
-        self.map(|key| {
-            key.to_marker(
+    ) {
+        if let Some(key) = self {
+            key.write_marker(
+                buf,
                 closing,
                 #[cfg(debug_assertions)]
                 component_name,
-            )
-        })
-        .unwrap_or("".into())
+            );
+        }
     }
 }
@@ -3,17 +3,24 @@
 //! Server-side HTML rendering utilities for in-order streaming and async rendering.
 
 use crate::{
+    components::{lazy_row_marker, lazy_rows_end_marker},
     html::{ElementChildren, StringOrView},
-    ssr::{render_serializers, ToMarker},
+    ssr::{
+        render_serializers, void_tag_end, with_attribute_quoting,
+        write_attribute, AttributeQuoting, ToMarker,
+    },
     CoreComponent, HydrationCtx, View,
 };
 use async_recursion::async_recursion;
-use futures::{channel::mpsc::UnboundedSender, Stream, StreamExt};
+use futures::{
+    channel::mpsc::Sender, future::Either, SinkExt, Stream, StreamExt,
+};
 use itertools::Itertools;
 use leptos_reactive::{
-    create_runtime, suspense::StreamChunk, Oco, RuntimeId, SharedContext,
+    create_runtime, suspense::StreamChunk, use_context, Oco, RuntimeId,
+    SharedContext,
 };
-use std::collections::VecDeque;
+use std::{collections::VecDeque, pin::Pin};
 
 /// Renders a view to HTML, waiting to return until all `async` [Resource](leptos_reactive::Resource)s
 /// loaded in `<Suspense/>` elements have finished loading.
@@ -40,6 +47,10 @@ pub async fn render_to_string_async(
 /// in order:
 /// 1. HTML from the `view` in order, pausing to wait for each `<Suspense/>`
 /// 2. any serialized [Resource](leptos_reactive::Resource)s
+///
+/// Each `<Suspense/>` is sent once its resources have resolved, in document order and without
+/// its fallback, so the page needs no script to be complete. This suits crawlers and clients
+/// that don't run JavaScript, at the cost of a slower first byte than [`render_to_stream`](crate::ssr::render_to_stream).
 #[tracing::instrument(level = "info", skip_all)]
 pub fn render_to_stream_in_order(
     view: impl FnOnce() -> View + 'static,
@@ -98,25 +109,32 @@ pub fn render_to_stream_in_order_with_prefix_undisposed_with_context(
 
     // add additional context
     additional_context();
+    let quoting = use_context::<AttributeQuoting>().unwrap_or_default();
 
     // render view and return chunks
-    let view = view();
+    let view = with_attribute_quoting(quoting, view);
 
     let blocking_fragments_ready = SharedContext::blocking_fragments_ready();
-    let chunks = view.into_stream_chunks();
+    let chunks = with_attribute_quoting(quoting, || view.into_stream_chunks());
     let pending_resources =
         serde_json::to_string(&SharedContext::pending_resources()).unwrap();
 
-    let (tx, rx) = futures::channel::mpsc::unbounded();
+    // the HTML is only rendered as fast as the stream is read, and rendering stops if the
+    // stream is dropped, for example because the client disconnected
+    let (tx, rx) = futures::channel::mpsc::channel(MAX_BUFFERED_CHUNKS);
     let (prefix_tx, prefix_rx) = futures::channel::oneshot::channel();
     leptos_reactive::spawn_local(async move {
         blocking_fragments_ready.await;
 
-        let remaining_chunks = handle_blocking_chunks(tx.clone(), chunks).await;
+        // the blocking chunks are sent after the prefix, so they are buffered until then
+        let mut blocking = String::new();
+        let remaining_chunks =
+            handle_blocking_chunks(&mut blocking, chunks).await;
 
-        let prefix = prefix();
-        prefix_tx.send(prefix).expect("to send prefix");
-        handle_chunks(tx, remaining_chunks).await;
+        let prefix = with_attribute_quoting(quoting, prefix);
+        if prefix_tx.send(prefix).is_ok() {
+            handle_chunks(tx, blocking, remaining_chunks).await;
+        }
     });
 
     let nonce = crate::nonce::use_nonce();
@@ -160,10 +178,9 @@ pub fn render_to_stream_in_order_with_prefix_undisposed_with_context(
 #[tracing::instrument(level = "trace", skip_all)]
 #[async_recursion(?Send)]
 async fn handle_blocking_chunks(
-    tx: UnboundedSender<String>,
+    buffer: &mut String,
     mut queued_chunks: VecDeque<StreamChunk>,
 ) -> VecDeque<StreamChunk> {
-    let mut buffer = String::new();
     while let Some(chunk) = queued_chunks.pop_front() {
         match chunk {
             StreamChunk::Sync(sync) => buffer.push_str(&sync),
@@ -172,13 +189,8 @@ async fn handle_blocking_chunks(
                 should_block,
             } => {
                 if should_block {
-                    // add static HTML before the Suspense and stream it down
-                    tx.unbounded_send(std::mem::take(&mut buffer))
-                        .expect("failed to send async HTML chunk");
-
-                    // send the inner stream
                     let suspended = chunks.await;
-                    handle_blocking_chunks(tx.clone(), suspended).await;
+                    handle_blocking_chunks(buffer, suspended).await;
                 } else {
                     // TODO: should probably first check if there are any *other* blocking chunks
                     queued_chunks.push_front(StreamChunk::Async {
@@ -191,39 +203,65 @@ async fn handle_blocking_chunks(
         }
     }
 
-    // send final sync chunk
-    tx.unbounded_send(std::mem::take(&mut buffer))
-        .expect("failed to send final HTML chunk");
-
     queued_chunks
 }
 
+/// The number of `<Suspense/>` chunks at the same level of the tree that are resolved ahead
+/// of the one being streamed. Their HTML is buffered until everything before it has been sent.
+const MAX_BUFFERED_CHUNKS: usize = 16;
+
 #[tracing::instrument(level = "trace", skip_all)]
-#[async_recursion(?Send)]
 async fn handle_chunks(
-    tx: UnboundedSender<String>,
+    mut tx: Sender<String>,
+    blocking: String,
     chunks: VecDeque<StreamChunk>,
 ) {
+    let mut html =
+        futures::stream::iter([blocking]).chain(chunks_to_stream(chunks));
+    while let Some(html) = html.next().await {
+        if tx.send(html).await.is_err() {
+            break;
+        }
+    }
+}
+
+/// Streams the HTML of the chunks in order, resolving the `<Suspense/>` chunks that follow the
+/// one being streamed at the same time, so that a slow subtree doesn't delay rendering its
+/// siblings, which are sent as soon as it is done.
+fn chunks_to_stream(
+    chunks: VecDeque<StreamChunk>,
+) -> Pin<Box<dyn Stream<Item = String>>> {
+    // merge the sync chunks between each suspense, so they are sent together
+    let mut pieces = Vec::with_capacity(chunks.len());
     let mut buffer = String::new();
     for chunk in chunks {
         match chunk {
             StreamChunk::Sync(sync) => buffer.push_str(&sync),
             StreamChunk::Async { chunks, .. } => {
-                // add static HTML before the Suspense and stream it down
-                tx.unbounded_send(std::mem::take(&mut buffer))
-                    .expect("failed to send async HTML chunk");
-
-                // send the inner stream
-
-                let suspended = chunks.await;
-
-                handle_chunks(tx.clone(), suspended).await;
+                if !buffer.is_empty() {
+                    pieces.push(Either::Left(std::mem::take(&mut buffer)));
+                }
+                pieces.push(Either::Right(chunks));
             }
         }
     }
-    // send final sync chunk
-    tx.unbounded_send(std::mem::take(&mut buffer))
-        .expect("failed to send final HTML chunk");
+    if !buffer.is_empty() {
+        pieces.push(Either::Left(buffer));
+    }
+
+    Box::pin(
+        futures::stream::iter(pieces)
+            .map(|piece| async move {
+                match piece {
+                    Either::Left(html) => {
+                        futures::stream::once(async move { html }).boxed_local()
+                    }
+                    Either::Right(chunks) => chunks_to_stream(chunks.await),
+                }
+            })
+            .buffered(MAX_BUFFERED_CHUNKS)
+            .flatten(),
+    )
 }
 
 impl View {
@@ -279,6 +317,9 @@ impl View {
                 )));
             }
             View::Element(el) => {
+                #[cfg(feature = "interactive-ssr")]
+                let el = el.with_live_attrs();
+
                 let is_script_or_style =
                     el.name == "script" || el.name == "style";
 
@@ -317,13 +358,9 @@ impl View {
                                     inner_html = Some(value);
                                     None
                                 } else {
-                                    Some(
-                                        format!(
-                                            " {name}=\"{}\"",
-                                            html_escape::encode_double_quoted_attribute(&value)
-                                        )
-                                        .into(),
-                                    )
+                                    let mut attr = String::from(" ");
+                                    write_attribute(&mut attr, &name, &value);
+                                    Some(attr.into())
                                 }
                             },
                         )
@@ -331,7 +368,8 @@ impl View {
 
                     if el.is_void {
                         chunks.push_back(StreamChunk::Sync(
-                            format!("<{tag_name}{attrs}/>").into(),
+                            format!("<{tag_name}{attrs}{}", void_tag_end())
+                                .into(),
                         ));
                     } else if let Some(inner_html) = inner_html {
                         chunks.push_back(StreamChunk::Sync(
@@ -461,6 +499,8 @@ impl View {
                     }
                     CoreComponent::Each(node) => {
                         let children = node.children.take();
+                        let each_id = node.id;
+                        let rows_end = node.rows_end;
                         (
                             node.id,
                             "each",
@@ -469,6 +509,18 @@ impl View {
                                 move |chunks: &mut VecDeque<StreamChunk>| {
                                     for node in children.into_iter().flatten() {
                                         let id = node.id;
+                                        if let Some((each, start)) =
+                                            each_id.zip(node.row_start)
+                                        {
+                                            chunks.push_back(
+                                                StreamChunk::Sync(
+                                                    lazy_row_marker(
+                                                        &each, &start,
+                                                    )
+                                                    .into(),
+                                                ),
+                                            );
+                                        }
                                         let is_el = matches!(
                                             node.child,
                                             View::Element(_)
@@ -502,6 +554,14 @@ impl View {
                                             );
                                         }
                                     }
+                                    if let Some((each, end)) =
+                                        each_id.zip(rows_end)
+                                    {
+                                        chunks.push_back(StreamChunk::Sync(
+                                            lazy_rows_end_marker(&each, &end)
+                                                .into(),
+                                        ));
+                                    }
                                 },
                             )
                                 as Box<dyn FnOnce(&mut VecDeque<StreamChunk>)>,
@@ -0,0 +1,548 @@
+#![cfg(all(
+    feature = "interactive-ssr",
+    not(all(target_arch = "wasm32", feature = "web"))
+))]
+
+//! Server-side rendering of views that stay interactive after the first
+//! render, so that states only reachable through interaction (an opened
+//! accordion, a filled-in form) can be tested or rendered to HTML.
+//!
+//! Normally, the server evaluates every reactive attribute and child once and
+//! throws event listeners away. A view rendered with [`render_interactive`]
+//! instead keeps them: its dynamic children and `<For/>` rows are updated by
+//! effects, like in the browser, reactive attributes, classes, and styles are
+//! read again each time it is serialized, and events can be dispatched to it.
+//!
+//! ```no_run
+//! use leptos::*;
+//!
+//! #[component]
+//! fn Accordion() -> impl IntoView {
+//!     let (open, set_open) = create_signal(false);
+//!     view! {
+//!         <section class:open=move || open.get()>
+//!             <button on:click=move |_| set_open.update(|open| *open = !*open)>
+//!                 "Details"
+//!             </button>
+//!             <Show when=move || open.get()>
+//!                 <p>"Hidden until opened."</p>
+//!             </Show>
+//!         </section>
+//!     }
+//! }
+//!
+//! let runtime = create_runtime();
+//! let accordion = ssr::render_interactive(Accordion);
+//! assert!(!accordion.to_html().contains("Hidden until opened."));
+//!
+//! assert!(accordion.dispatch("button", ev::click));
+//! let html = accordion.to_html();
+//! assert!(html.contains("class=\"open\""));
+//! assert!(html.contains("Hidden until opened."));
+//! runtime.dispose();
+//! ```
+//!
+//! [`InteractiveView::js_only_interactions`] lists the parts of the view that
+//! only work with JavaScript, to check that it still works before it is
+//! hydrated, or if it never is.
+//!
+//! Event handlers receive a placeholder event, because there is no browser to
+//! create a real one. A handler that calls a method on it, like
+//! `prevent_default()` or `target()`, is stopped there and skipped with a
+//! warning, and the event goes on to the other listeners, so handlers that
+//! need the event can only be tested in the browser.
+//!
+//! With the `ssr` feature, the `view` macro usually renders elements straight
+//! to HTML strings. When the `interactive-ssr` feature is enabled, it also
+//! generates the code that builds them piece by piece, and chooses between the
+//! two while rendering, which makes server builds larger and slower to compile.
+
+use crate::{
+    ev::EventDescriptor,
+    html::{ElementChildren, StringOrView},
+    macro_helpers::{Attribute, Style},
+    CoreComponent, Element, HydrationCtx, IntoView, View,
+};
+use leptos_reactive::{untrack, Oco};
+use smallvec::SmallVec;
+use std::{
+    cell::{Cell, RefCell},
+    fmt, panic,
+    rc::Rc,
+};
+use wasm_bindgen::{JsCast, JsValue};
+
+thread_local! {
+    static INTERACTIVE: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Whether the view that is being built should stay interactive, because it
+/// is being rendered by [`render_interactive`].
+#[doc(hidden)]
+pub fn is_interactive() -> bool {
+    INTERACTIVE.with(Cell::get)
+}
+
+/// Runs `f` while building an interactive view.
+pub(crate) fn interactive<T>(f: impl FnOnce() -> T) -> T {
+    // restores the previous state even if `f` panics, so that a caught panic
+    // doesn't leave the views built afterwards interactive
+    struct Restore(bool);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            INTERACTIVE.with(|interactive| interactive.set(self.0));
+        }
+    }
+
+    let _restore =
+        Restore(INTERACTIVE.with(|interactive| interactive.replace(true)));
+    f()
+}
+
+/// Renders a view that can be updated by dispatching events to it, and
+/// serialized again afterwards.
+///
+/// Unlike [`render_to_string`](crate::ssr::render_to_string), this does not
+/// create a reactive runtime, because the view is only useful while its
+/// signals are alive: call it inside one, and dispose of the runtime once
+/// you are done with the view.
+pub fn render_interactive<F, N>(f: F) -> InteractiveView
+where
+    F: FnOnce() -> N,
+    N: IntoView,
+{
+    HydrationCtx::reset_id();
+    InteractiveView {
+        view: interactive(|| f().into_view()),
+    }
+}
+
+/// A view rendered with [`render_interactive`].
+#[derive(Clone)]
+pub struct InteractiveView {
+    view: View,
+}
+
+impl InteractiveView {
+    /// Serializes the view in its current state.
+    pub fn to_html(&self) -> String {
+        untrack(|| snapshot(&self.view).render_to_string().into_owned())
+    }
+
+    /// Dispatches an event to the first element that matches a simple
+    /// selector: a tag name like `button`, an id like `#save`, or a class
+    /// like `.primary`. Events that bubble in the browser run the listeners
+    /// of each ancestor afterwards.
+    ///
+    /// Returns `false` if no element matches.
+    pub fn dispatch<E: EventDescriptor>(
+        &self,
+        selector: &str,
+        event: E,
+    ) -> bool {
+        let mut path = Vec::new();
+        if !find(&self.view, selector, &mut path) {
+            return false;
+        }
+        let name = event.name();
+        // clone the listeners out before running any of them, because they
+        // can replace parts of the tree
+        let listeners = path
+            .iter()
+            .rev()
+            .take(if E::BUBBLES { path.len() } else { 1 })
+            .flat_map(|live| live.listeners(&name))
+            .collect::<Vec<_>>();
+        for listener in listeners {
+            run_listener(&name, &listener);
+        }
+        true
+    }
+
+    /// Lists the interactions in the view, in its current state, that only
+    /// work with JavaScript.
+    ///
+    /// Elements with a `click` listener are listed unless the browser does
+    /// something when they are clicked anyway: links with an `href`,
+    /// `<summary>` elements, and the submit buttons of a form with an
+    /// `action`. Forms without an `action` are listed too, because without
+    /// JavaScript they are submitted to the current page, which rarely
+    /// expects it.
+    pub fn js_only_interactions(&self) -> Vec<JsOnlyInteraction> {
+        let mut interactions = Vec::new();
+        untrack(|| audit(&self.view, None, &mut interactions));
+        interactions
+    }
+}
+
+/// An interaction that only works with JavaScript, found by
+/// [`InteractiveView::js_only_interactions`]. Each variant holds the opening
+/// tag of the element, with its `id` and `class`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsOnlyInteraction {
+    /// An element that only does something when clicked through a `click`
+    /// listener.
+    Click(String),
+    /// A `<form>` without an `action`.
+    FormWithoutAction(String),
+}
+
+impl fmt::Display for JsOnlyInteraction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Click(el) => {
+                write!(f, "{el} only responds to clicks with JavaScript")
+            }
+            Self::FormWithoutAction(el) => write!(
+                f,
+                "{el} has no `action`, so it cannot be submitted without \
+                 JavaScript"
+            ),
+        }
+    }
+}
+
+/// Runs an event listener, skipping it if it uses the placeholder event,
+/// whose methods panic outside the browser.
+fn run_listener(name: &str, listener: &Listener) {
+    let run = panic::AssertUnwindSafe(|| (listener.borrow_mut())());
+    if let Err(panic) = panic::catch_unwind(run) {
+        let message = panic
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| panic.downcast_ref::<String>().map(String::as_str));
+        match message {
+            Some(message) if message.contains(JS_ONLY_PANIC) => {
+                crate::debug_warn!(
+                    "a `{name}` listener was skipped, because it uses the \
+                     event, which only exists in the browser"
+                );
+            }
+            _ => panic::resume_unwind(panic),
+        }
+    }
+}
+
+/// The start of the message `wasm-bindgen` panics with when a browser API is
+/// called outside the browser.
+const JS_ONLY_PANIC: &str = "cannot call wasm-bindgen imported functions";
+
+impl fmt::Debug for InteractiveView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_html())
+    }
+}
+
+/// Copies a view, including the contents of its dynamic children, which
+/// rendering would otherwise take out of the view.
+fn snapshot(view: &View) -> View {
+    match view {
+        View::Element(el) => {
+            let mut el = el.clone();
+            if let ElementChildren::Children(children) = &mut el.children {
+                *children = children.iter().map(snapshot).collect();
+            }
+            View::Element(el)
+        }
+        View::Component(component) => {
+            let mut component = component.clone();
+            component.children =
+                component.children.iter().map(snapshot).collect();
+            View::Component(component)
+        }
+        View::CoreComponent(core) => View::CoreComponent(snapshot_core(core)),
+        View::Suspense(id, core) => View::Suspense(*id, snapshot_core(core)),
+        View::Text(_) | View::Transparent(_) => view.clone(),
+    }
+}
+
+fn snapshot_core(core: &CoreComponent) -> CoreComponent {
+    match core {
+        CoreComponent::DynChild(dyn_child) => {
+            let mut dyn_child = dyn_child.clone();
+            let child =
+                dyn_child.child.borrow().as_ref().as_ref().map(snapshot);
+            dyn_child.child = Rc::new(RefCell::new(Box::new(child)));
+            CoreComponent::DynChild(dyn_child)
+        }
+        CoreComponent::Each(each) => {
+            let mut each = each.clone();
+            let rows = each
+                .children
+                .borrow()
+                .iter()
+                .map(|row| {
+                    row.as_ref().map(|row| {
+                        let mut row = row.clone();
+                        row.child = snapshot(&row.child);
+                        row
+                    })
+                })
+                .collect();
+            each.children = Rc::new(RefCell::new(rows));
+            CoreComponent::Each(each)
+        }
+        CoreComponent::Unit(_) => core.clone(),
+    }
+}
+
+/// Calls `f` on each child of `view`, until it returns `true`.
+fn any_child(view: &View, f: &mut dyn FnMut(&View) -> bool) -> bool {
+    match view {
+        View::Element(el) => match &el.children {
+            ElementChildren::Children(children) => children.iter().any(f),
+            ElementChildren::Chunks(chunks) => {
+                chunks.iter().any(|chunk| match chunk {
+                    StringOrView::View(view) => f(&view()),
+                    StringOrView::String(_) => false,
+                })
+            }
+            ElementChildren::Empty | ElementChildren::InnerHtml(_) => false,
+        },
+        View::Component(component) => component.children.iter().any(f),
+        View::CoreComponent(core) | View::Suspense(_, core) => match core {
+            CoreComponent::DynChild(dyn_child) => {
+                let child = dyn_child.child.borrow();
+                child.as_ref().as_ref().is_some_and(f)
+            }
+            CoreComponent::Each(each) => each
+                .children
+                .borrow()
+                .iter()
+                .flatten()
+                .any(|item| f(&item.child)),
+            CoreComponent::Unit(_) => false,
+        },
+        View::Text(_) | View::Transparent(_) => false,
+    }
+}
+
+/// Looks for the first element that matches `selector`, pushing the live
+/// parts of its ancestors and of the element itself onto `path`.
+fn find(view: &View, selector: &str, path: &mut Vec<LiveElement>) -> bool {
+    let View::Element(el) = view else {
+        return any_child(view, &mut |child| find(child, selector, path));
+    };
+    path.push(el.live.clone().unwrap_or_default());
+    let found = matches(el, selector)
+        || any_child(view, &mut |child| find(child, selector, path));
+    if !found {
+        path.pop();
+    }
+    found
+}
+
+/// Collects the interactions that only work with JavaScript. `form` tells
+/// whether the view is inside a form, and whether that form has an `action`.
+fn audit(
+    view: &View,
+    mut form: Option<bool>,
+    interactions: &mut Vec<JsOnlyInteraction>,
+) {
+    if let View::Element(el) = view {
+        let attrs = el.current_attrs();
+        if el.name == "form" {
+            form = Some(attr(&attrs, "action").is_some());
+            if form == Some(false) {
+                interactions.push(JsOnlyInteraction::FormWithoutAction(tag(
+                    el, &attrs,
+                )));
+            }
+        }
+        let has_click = el
+            .live
+            .as_ref()
+            .is_some_and(|live| !live.listeners("click").is_empty());
+        if has_click && !clicks_without_js(el, &attrs, form) {
+            interactions.push(JsOnlyInteraction::Click(tag(el, &attrs)));
+        }
+    }
+    any_child(view, &mut |child| {
+        audit(child, form, interactions);
+        false
+    });
+}
+
+/// Whether the browser does something when the element is clicked, even
+/// without its listeners.
+fn clicks_without_js(el: &Element, attrs: &Attrs, form: Option<bool>) -> bool {
+    let submits = || {
+        form == Some(true)
+            || (form.is_some() && attr(attrs, "formaction").is_some())
+    };
+    match el.name.as_ref() {
+        "a" => attr(attrs, "href").is_some(),
+        "summary" => true,
+        "button" => {
+            matches!(attr(attrs, "type"), None | Some("submit")) && submits()
+        }
+        "input" => {
+            matches!(attr(attrs, "type"), Some("submit" | "image")) && submits()
+        }
+        _ => false,
+    }
+}
+
+/// The opening tag of the element, with only its `id` and `class`.
+fn tag(el: &Element, attrs: &Attrs) -> String {
+    let mut tag = format!("<{}", el.name);
+    for name in ["id", "class"] {
+        if let Some(value) = attr(attrs, name) {
+            tag.push_str(&format!(" {name}=\"{value}\""));
+        }
+    }
+    tag.push('>');
+    tag
+}
+
+fn attr<'a>(attrs: &'a Attrs, name: &str) -> Option<&'a str> {
+    attrs
+        .iter()
+        .find(|(attr, _)| attr == name)
+        .map(|(_, value)| value.as_str())
+}
+
+fn matches(el: &Element, selector: &str) -> bool {
+    let attrs = el.current_attrs();
+    if let Some(id) = selector.strip_prefix('#') {
+        attr(&attrs, "id") == Some(id)
+    } else if let Some(class) = selector.strip_prefix('.') {
+        attr(&attrs, "class").is_some_and(|classes| {
+            classes.split_whitespace().any(|c| c == class)
+        })
+    } else {
+        el.name == selector
+    }
+}
+
+impl Element {
+    /// The element's attributes, with the current values of its live parts.
+    pub(crate) fn current_attrs(&self) -> Attrs {
+        let mut attrs = self.attrs.clone();
+        if let Some(live) = &self.live {
+            live.apply(&mut attrs);
+        }
+        attrs
+    }
+
+    /// Replaces the element's live parts with their current values, before
+    /// it is serialized.
+    pub(crate) fn with_live_attrs(mut self) -> Self {
+        if let Some(live) = self.live.take() {
+            live.apply(&mut self.attrs);
+        }
+        self
+    }
+}
+
+type Attrs = SmallVec<[(Oco<'static, str>, Oco<'static, str>); 4]>;
+type ClassFn = Box<dyn Fn() -> bool>;
+type Listener = Rc<RefCell<Box<dyn FnMut()>>>;
+
+/// The parts of an element in an interactive view that change after it is
+/// built. Clones share the same parts.
+#[derive(Clone, Default)]
+pub(crate) struct LiveElement(Rc<LiveParts>);
+
+#[derive(Default)]
+struct LiveParts {
+    attrs: RefCell<Vec<(Oco<'static, str>, Attribute)>>,
+    classes: RefCell<Vec<(Oco<'static, str>, ClassFn)>>,
+    styles: RefCell<Vec<(Oco<'static, str>, Style)>>,
+    listeners: RefCell<Vec<(Oco<'static, str>, Listener)>>,
+}
+
+impl LiveElement {
+    /// Creates the live parts for a new element, if it is part of an
+    /// interactive view.
+    pub(crate) fn new() -> Option<Self> {
+        is_interactive().then(Self::default)
+    }
+
+    pub(crate) fn add_attr(&self, name: Oco<'static, str>, value: Attribute) {
+        self.0.attrs.borrow_mut().push((name, value));
+    }
+
+    pub(crate) fn add_class(&self, name: Oco<'static, str>, value: ClassFn) {
+        self.0.classes.borrow_mut().push((name, value));
+    }
+
+    pub(crate) fn add_style(&self, name: Oco<'static, str>, value: Style) {
+        self.0.styles.borrow_mut().push((name, value));
+    }
+
+    pub(crate) fn add_listener<E: EventDescriptor>(
+        &self,
+        event: E,
+        mut handler: impl FnMut(E::EventType) + 'static,
+    ) {
+        let handler: Box<dyn FnMut()> =
+            Box::new(move || handler(JsValue::UNDEFINED.unchecked_into()));
+        self.0
+            .listeners
+            .borrow_mut()
+            .push((event.name(), Rc::new(RefCell::new(handler))));
+    }
+
+    fn listeners(&self, name: &str) -> Vec<Listener> {
+        self.0
+            .listeners
+            .borrow()
+            .iter()
+            .filter(|(listener, _)| listener == name)
+            .map(|(_, listener)| Rc::clone(listener))
+            .collect()
+    }
+
+    /// Applies the current values of the live attributes, classes, and styles
+    /// to the element's other attributes.
+    pub(crate) fn apply(&self, attrs: &mut Attrs) {
+        untrack(|| {
+            for (name, value) in self.0.attrs.borrow().iter() {
+                let index = attrs.iter().position(|(attr, _)| attr == name);
+                match (value.as_nameless_value_string(), index) {
+                    (Some(value), Some(index)) => attrs[index].1 = value,
+                    (Some(value), None) => attrs.push((name.clone(), value)),
+                    (None, Some(index)) => {
+                        attrs.remove(index);
+                    }
+                    (None, None) => {}
+                }
+            }
+            for (name, value) in self.0.classes.borrow().iter() {
+                if value() {
+                    append(attrs, "class", name);
+                }
+            }
+            for (name, value) in self.0.styles.borrow().iter() {
+                let mut value = value.clone();
+                while let Style::Fn(f) = value {
+                    value = f();
+                }
+                if let Style::Value(value) | Style::Option(Some(value)) = value
+                {
+                    append(attrs, "style", &format!("{name}: {value};"));
+                }
+            }
+        });
+    }
+}
+
+fn append(attrs: &mut Attrs, name: &'static str, value: &str) {
+    match attrs.iter_mut().find(|(attr, _)| attr == name) {
+        Some((_, old)) if !old.is_empty() => {
+            *old = format!("{old} {value}").into();
+        }
+        Some((_, old)) => *old = value.to_owned().into(),
+        None => attrs.push((name.into(), value.to_owned().into())),
+    }
+}
+
+impl PartialEq for LiveElement {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for LiveElement {}
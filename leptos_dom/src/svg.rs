@@ -58,7 +58,7 @@ macro_rules! generate_svg_tags {
             #[cfg(all(target_arch = "wasm32", feature = "hydrate"))]
             let element = if HydrationCtx::is_hydrating() && id.is_some() {
               let id = id.unwrap();
-              if let Some(el) = crate::hydration::get_element(&id.to_string()) {
+              if let Some(el) = crate::hydration::get_element(&id) {
                 #[cfg(debug_assertions)]
                 assert_eq!(
                   el.node_name().to_ascii_uppercase(),
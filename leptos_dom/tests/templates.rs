@@ -0,0 +1,73 @@
+#![cfg(all(target_arch = "wasm32", feature = "csr"))]
+
+use leptos::*;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn text(parent: &web_sys::Element, selector: &str) -> Option<String> {
+    parent.query_selector(selector).unwrap()?.text_content()
+}
+
+#[component]
+fn Greeting() -> impl IntoView {
+    view! { <em>"Hello"</em> }
+}
+
+#[wasm_bindgen_test]
+fn cloned_views_fill_in_their_dynamic_parts() {
+    let (count, set_count) = create_signal(0);
+    let button = create_node_ref::<html::Button>();
+    let view = view! {
+        <div>
+            <section class="counter" class:odd=move || count.get() % 2 == 1>
+                <h2>"Count"</h2>
+                <p>"Value: " {move || count.get()} "!"</p>
+                <Greeting/>
+                <button node_ref=button on:click=move |_| set_count.update(|n| *n += 1)>
+                    "+1"
+                </button>
+            </section>
+        </div>
+    };
+    // delegated events are only handled in the document
+    document().body().unwrap().append_child(&view).unwrap();
+
+    assert_eq!(
+        text(&view, "section.counter > h2").as_deref(),
+        Some("Count")
+    );
+    assert_eq!(text(&view, "p").as_deref(), Some("Value: 0!"));
+    // the holes are filled in where they were in the view
+    let section = view.first_element_child().unwrap();
+    let children = section.children();
+    let tags = (0..children.length())
+        .map(|i| children.item(i).unwrap().tag_name())
+        .collect::<Vec<_>>();
+    assert_eq!(tags, ["H2", "P", "EM", "BUTTON"]);
+
+    button.get().unwrap().click();
+    assert_eq!(text(&view, "p").as_deref(), Some("Value: 1!"));
+    assert_eq!(section.class_name(), "counter odd");
+    view.remove();
+}
+
+#[wasm_bindgen_test]
+fn views_the_browser_would_parse_differently_are_built_as_written() {
+    // parsing this HTML would close the <p> before the <div>
+    let view = view! {
+        <div>
+            <p class="outer">
+                <div class="inner">"inside"</div>
+                <span>"after"</span>
+            </p>
+        </div>
+    };
+
+    assert_eq!(view.child_element_count(), 1);
+    assert_eq!(
+        text(&view, "p.outer > div.inner").as_deref(),
+        Some("inside")
+    );
+    assert_eq!(text(&view, "p.outer > span").as_deref(), Some("after"));
+}
@@ -0,0 +1,114 @@
+use leptos::{
+    create_runtime, create_signal, IntoAttribute, IntoClass, SignalGet,
+    SignalSet, SignalUpdate,
+};
+use leptos_dom::renderer::{self, Renderer, TestDom};
+
+#[test]
+fn reactive_attributes_classes_and_text() {
+    let runtime = create_runtime();
+    let (disabled, set_disabled) = create_signal(false);
+    let (name, set_name) = create_signal("Alice");
+
+    let button = TestDom::create_element("button");
+    renderer::attribute::<TestDom>(
+        &button,
+        "disabled",
+        (move || disabled.get()).into_attribute(),
+    );
+    renderer::class::<TestDom>(
+        &button,
+        "busy",
+        (move || disabled.get()).into_class(),
+    );
+    let label =
+        renderer::text::<TestDom>(move || format!("Hi, {}", name.get()));
+    TestDom::insert_node(&button, &label, None);
+
+    assert_eq!(button.to_html(), "<button>Hi, Alice</button>");
+
+    set_disabled.set(true);
+    set_name.set("Bob");
+    assert_eq!(button.attribute("disabled").as_deref(), Some(""));
+    assert!(button.has_class("busy"));
+    assert_eq!(button.text_content(), "Hi, Bob");
+
+    set_disabled.set(false);
+    assert_eq!(button.to_html(), "<button>Hi, Bob</button>");
+
+    runtime.dispose();
+}
+
+#[test]
+fn nested_attribute_closures_skip_unchanged_writes() {
+    let runtime = create_runtime();
+    let (count, set_count) = create_signal(1);
+
+    let input = TestDom::create_element("input");
+    renderer::attribute::<TestDom>(
+        &input,
+        "placeholder",
+        (move || move || if count.get() > 0 { "items" } else { "empty" })
+            .into_attribute(),
+    );
+    assert_eq!(input.attribute("placeholder").as_deref(), Some("items"));
+
+    // the attribute is only written when its value changes, even though the
+    // closure returns a new closure each time
+    TestDom::set_attribute(&input, "placeholder", "edited");
+    set_count.set(2);
+    assert_eq!(input.attribute("placeholder").as_deref(), Some("edited"));
+    set_count.set(0);
+    assert_eq!(input.attribute("placeholder").as_deref(), Some("empty"));
+
+    runtime.dispose();
+}
+
+#[test]
+fn events_bubble_to_ancestors() {
+    let runtime = create_runtime();
+    let (clicks, set_clicks) = create_signal(Vec::new());
+
+    let list = TestDom::create_element("ul");
+    let item = TestDom::create_element("li");
+    TestDom::set_attribute(&item, "id", "first");
+    TestDom::insert_node(&list, &item, None);
+
+    TestDom::add_event_listener(
+        &item,
+        "click",
+        Box::new(move |_| set_clicks.update(|c| c.push("li"))),
+    );
+    TestDom::add_event_listener(
+        &list,
+        "click",
+        Box::new(move |ev| {
+            assert_eq!(ev.target().tag().as_deref(), Some("li"));
+            set_clicks.update(|c| c.push("ul"))
+        }),
+    );
+
+    list.query_selector("#first").unwrap().dispatch("click");
+    assert_eq!(clicks.get(), vec!["li", "ul"]);
+
+    runtime.dispose();
+}
+
+#[test]
+fn inserting_and_removing_nodes() {
+    let parent = TestDom::create_element("div");
+    let marker = TestDom::create_placeholder();
+    let a = TestDom::create_text_node("a");
+    let b = TestDom::create_text_node("b");
+
+    TestDom::insert_node(&parent, &marker, None);
+    TestDom::insert_node(&parent, &b, Some(&marker));
+    TestDom::insert_node(&parent, &a, Some(&b));
+    assert_eq!(parent.to_html(), "<div>ab<!----></div>");
+    assert_eq!(TestDom::next_sibling(&a), Some(b.clone()));
+    assert_eq!(TestDom::get_parent(&b), Some(parent.clone()));
+
+    TestDom::remove_node(&a);
+    assert_eq!(TestDom::first_child(&parent), Some(b));
+    assert_eq!(TestDom::get_parent(&a), None);
+}
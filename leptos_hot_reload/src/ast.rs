@@ -0,0 +1,705 @@
+//! A serializable representation of the body of each `view!` macro, for
+//! tools that need to understand views without re-implementing the macro's
+//! parser: formatters, style extractors, i18n string extractors, and the like.
+//!
+//! Unlike [`LNode`](crate::node::LNode), which only keeps what is needed to
+//! diff templates for hot-reloading, this keeps every node and attribute along
+//! with its position in the source file, and records each dynamic "hole"
+//! (a `{block}` child or a non-literal attribute value) as the source code it
+//! contains.
+//!
+//! Views are parsed with the same entry points the macro itself uses
+//! ([`parse_view_nodes`] and [`split_global_class`]), so the tree matches
+//! what the macro sees. Rust comments inside a view never reach the macro at
+//! all, because they are stripped before it runs; they are recovered from the
+//! source text instead, and returned with their positions in
+//! [`ViewMacro::comments`], so that a formatter can print a view and put its
+//! comments back where they were.
+
+use crate::{
+    parsing::{is_component_node, parse_view_nodes, split_global_class},
+    span_to_stable_id, ViewMacroVisitor,
+};
+use anyhow::{anyhow, Result};
+use camino::Utf8PathBuf;
+use proc_macro2::{LineColumn, Span, TokenStream, TokenTree};
+use quote::ToTokens;
+use rstml::node::{KeyedAttribute, Node, NodeAttribute};
+use serde::{Deserialize, Serialize};
+use std::{fs::File, io::Read};
+use syn::{spanned::Spanned, visit::Visit, MacroDelimiter};
+
+/// A position in a source file.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+)]
+pub struct SourcePosition {
+    /// The line, starting at `1`.
+    pub line: usize,
+    /// The column in UTF-8 characters, starting at `0`.
+    pub column: usize,
+}
+
+/// The range of a source file covered by a node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SourceSpan {
+    /// The position of the first character.
+    pub start: SourcePosition,
+    /// The position just after the last character.
+    pub end: SourcePosition,
+}
+
+impl From<LineColumn> for SourcePosition {
+    fn from(position: LineColumn) -> Self {
+        Self {
+            line: position.line,
+            column: position.column,
+        }
+    }
+}
+
+impl From<Span> for SourceSpan {
+    fn from(span: Span) -> Self {
+        Self {
+            start: span.start().into(),
+            end: span.end().into(),
+        }
+    }
+}
+
+/// A piece of Rust code inside a view, which is evaluated when the view is
+/// rendered.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DynamicHole {
+    /// The source code of the expression or block.
+    pub code: String,
+    /// Where the code appears in the file.
+    pub span: SourceSpan,
+}
+
+impl DynamicHole {
+    fn new(tokens: &impl ToTokens) -> Self {
+        let span = tokens.span();
+        Self {
+            // prefer the code exactly as it was written
+            code: span
+                .source_text()
+                .unwrap_or_else(|| tokens.to_token_stream().to_string()),
+            span: span.into(),
+        }
+    }
+}
+
+/// A single `view!` macro invocation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ViewMacro {
+    /// The same stable identifier used for hot-reloading this view.
+    pub id: String,
+    /// The file in which the macro appears.
+    pub file: String,
+    /// The span of the whole macro invocation.
+    pub span: SourceSpan,
+    /// The scoped class given with `view! { class = ..., }`, if any.
+    pub global_class: Option<DynamicHole>,
+    /// The top-level nodes of the view.
+    pub nodes: Vec<ViewNode>,
+    /// The Rust comments inside the view, in source order.
+    pub comments: Vec<ViewComment>,
+}
+
+/// A Rust comment (`// ...` or `/* ... */`) inside a `view!` macro.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ViewComment {
+    /// The comment, including its `//` or `/* */` delimiters.
+    pub text: String,
+    /// Where the comment appears in the file.
+    pub span: SourceSpan,
+}
+
+/// A node inside a `view!` macro.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ViewNode {
+    /// An HTML, SVG, or MathML element.
+    Element {
+        /// The tag name.
+        name: String,
+        /// The element's attributes, in order.
+        attrs: Vec<ViewAttribute>,
+        /// The element's children.
+        children: Vec<ViewNode>,
+        /// Where the element appears in the file.
+        span: SourceSpan,
+    },
+    /// A component.
+    Component {
+        /// The component's name.
+        name: String,
+        /// The props passed to the component, in order.
+        props: Vec<ViewAttribute>,
+        /// The component's children.
+        children: Vec<ViewNode>,
+        /// Where the component appears in the file.
+        span: SourceSpan,
+    },
+    /// A fragment, `<>...</>`.
+    Fragment {
+        /// The fragment's children.
+        children: Vec<ViewNode>,
+        /// Where the fragment appears in the file.
+        span: SourceSpan,
+    },
+    /// Static text.
+    Text {
+        /// The text, as it will be rendered.
+        value: String,
+        /// Where the text appears in the file.
+        span: SourceSpan,
+    },
+    /// A `{block}` child.
+    Dynamic(DynamicHole),
+    /// A comment, `<!-- "..." -->`.
+    Comment {
+        /// The comment text.
+        value: String,
+        /// Where the comment appears in the file.
+        span: SourceSpan,
+    },
+    /// A doctype, `<!DOCTYPE html>`.
+    Doctype {
+        /// The doctype value.
+        value: String,
+        /// Where the doctype appears in the file.
+        span: SourceSpan,
+    },
+}
+
+/// An attribute on an element, or a prop on a component.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ViewAttribute {
+    /// An attribute with a name, like `class="foo"` or `on:click=handler`.
+    Keyed {
+        /// The attribute name, including any prefix like `on:` or `class:`.
+        name: String,
+        /// The attribute value.
+        value: ViewAttributeValue,
+        /// Where the attribute appears in the file.
+        span: SourceSpan,
+    },
+    /// A block in attribute position, like `{..attrs}`.
+    Block(DynamicHole),
+}
+
+/// The value of a [`ViewAttribute`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ViewAttributeValue {
+    /// No value was given, as in `<input disabled/>`.
+    Boolean,
+    /// A literal value, converted to the string that will be rendered.
+    Static(String),
+    /// Any other expression.
+    Dynamic(DynamicHole),
+}
+
+impl ViewMacro {
+    /// Parses every `view!` macro in the given file.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the file cannot be read or its contents cannot be
+    /// parsed.
+    pub fn parse_file(path: &Utf8PathBuf) -> Result<Vec<ViewMacro>> {
+        let mut file = File::open(path)?;
+        let mut content = String::new();
+        file.read_to_string(&mut content)?;
+        Self::parse_str(path, &content)
+    }
+
+    /// Parses every `view!` macro in `content`, which is the source code of
+    /// the file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the contents cannot be parsed.
+    pub fn parse_str(
+        path: &Utf8PathBuf,
+        content: &str,
+    ) -> Result<Vec<ViewMacro>> {
+        let ast = syn::parse_file(content)?;
+        let source = SourceMap::new(content);
+
+        let mut visitor = ViewMacroVisitor::default();
+        visitor.visit_file(&ast);
+        let mut views = Vec::new();
+        for view in visitor.views {
+            let span = view.span();
+            let (global_class, tokens) =
+                split_global_class(view.tokens.clone()).map_err(|span| {
+                    anyhow!(
+                        "expected a `,` after the view's global class at line \
+                         {}",
+                        span.start().line
+                    )
+                })?;
+            let nodes = parse_view_nodes(tokens)
+                .into_result()?
+                .into_iter()
+                .filter_map(ViewNode::parse)
+                .collect();
+            // `view! {}`, `view!()` and `view![]` are all the same macro
+            let delimiter = match &view.delimiter {
+                MacroDelimiter::Brace(brace) => brace.span,
+                MacroDelimiter::Paren(paren) => paren.span,
+                MacroDelimiter::Bracket(bracket) => bracket.span,
+            };
+            let comments = source.comments(
+                delimiter.open(),
+                view.tokens.clone(),
+                delimiter.close(),
+            );
+            views.push(ViewMacro {
+                id: span_to_stable_id(path, span.start().line),
+                file: path.to_string(),
+                span: span.into(),
+                global_class: global_class.as_ref().map(DynamicHole::new),
+                nodes,
+                comments,
+            });
+        }
+        Ok(views)
+    }
+
+    /// Returns every dynamic hole in the view, in source order: the global
+    /// class, then any `{block}` children and non-literal attribute values.
+    #[must_use]
+    pub fn dynamic_holes(&self) -> Vec<&DynamicHole> {
+        let mut holes = self.global_class.iter().collect::<Vec<_>>();
+        for node in &self.nodes {
+            node.collect_holes(&mut holes);
+        }
+        holes
+    }
+}
+
+impl ViewNode {
+    fn parse(node: Node) -> Option<ViewNode> {
+        let span = node.span().into();
+        Some(match node {
+            Node::Element(el) => {
+                let name = el.name().to_string();
+                let is_component = is_component_node(&el);
+                let attrs = el
+                    .open_tag
+                    .attributes
+                    .into_iter()
+                    .map(ViewAttribute::parse)
+                    .collect();
+                let children = el
+                    .children
+                    .into_iter()
+                    .filter_map(ViewNode::parse)
+                    .collect();
+                if is_component {
+                    ViewNode::Component {
+                        name,
+                        props: attrs,
+                        children,
+                        span,
+                    }
+                } else {
+                    ViewNode::Element {
+                        name,
+                        attrs,
+                        children,
+                        span,
+                    }
+                }
+            }
+            Node::Fragment(frag) => ViewNode::Fragment {
+                children: frag
+                    .children
+                    .into_iter()
+                    .filter_map(ViewNode::parse)
+                    .collect(),
+                span,
+            },
+            Node::Text(text) => ViewNode::Text {
+                value: text.value_string(),
+                span,
+            },
+            Node::RawText(text) => {
+                if text.is_empty() {
+                    return None;
+                }
+                ViewNode::Text {
+                    value: text.to_string_best(),
+                    span,
+                }
+            }
+            Node::Block(block) => ViewNode::Dynamic(DynamicHole::new(&block)),
+            Node::Comment(comment) => ViewNode::Comment {
+                value: comment.value.value(),
+                span,
+            },
+            Node::Doctype(doctype) => ViewNode::Doctype {
+                value: doctype.value.to_string_best(),
+                span,
+            },
+        })
+    }
+
+    fn collect_holes<'a>(&'a self, holes: &mut Vec<&'a DynamicHole>) {
+        match self {
+            ViewNode::Element {
+                attrs: props,
+                children,
+                ..
+            }
+            | ViewNode::Component {
+                props, children, ..
+            } => {
+                for prop in props {
+                    match prop {
+                        ViewAttribute::Keyed {
+                            value: ViewAttributeValue::Dynamic(hole),
+                            ..
+                        }
+                        | ViewAttribute::Block(hole) => holes.push(hole),
+                        ViewAttribute::Keyed { .. } => {}
+                    }
+                }
+                for child in children {
+                    child.collect_holes(holes);
+                }
+            }
+            ViewNode::Fragment { children, .. } => {
+                for child in children {
+                    child.collect_holes(holes);
+                }
+            }
+            ViewNode::Dynamic(hole) => holes.push(hole),
+            ViewNode::Text { .. }
+            | ViewNode::Comment { .. }
+            | ViewNode::Doctype { .. } => {}
+        }
+    }
+}
+
+impl ViewAttribute {
+    fn parse(attr: NodeAttribute) -> ViewAttribute {
+        match attr {
+            NodeAttribute::Block(block) => {
+                ViewAttribute::Block(DynamicHole::new(&block))
+            }
+            NodeAttribute::Attribute(attr) => ViewAttribute::Keyed {
+                name: attr.key.to_string(),
+                span: attr.span().into(),
+                value: ViewAttributeValue::parse(&attr),
+            },
+        }
+    }
+}
+
+impl ViewAttributeValue {
+    fn parse(attr: &KeyedAttribute) -> ViewAttributeValue {
+        if let Some(value) = attr.value_literal_string() {
+            ViewAttributeValue::Static(value)
+        } else if let Some(value) = attr.value() {
+            ViewAttributeValue::Dynamic(DynamicHole::new(value))
+        } else {
+            ViewAttributeValue::Boolean
+        }
+    }
+}
+
+/// Maps the positions reported by spans back to the source text.
+struct SourceMap<'a> {
+    content: &'a str,
+    line_starts: Vec<usize>,
+}
+
+impl<'a> SourceMap<'a> {
+    fn new(content: &'a str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(content.match_indices('\n').map(|(idx, _)| idx + 1))
+            .collect();
+        Self {
+            content,
+            line_starts,
+        }
+    }
+
+    fn offset(&self, position: LineColumn) -> usize {
+        let line_start = self.line_starts[position.line - 1];
+        self.content[line_start..]
+            .char_indices()
+            .nth(position.column)
+            .map_or(self.content.len(), |(idx, _)| line_start + idx)
+    }
+
+    fn position(&self, offset: usize) -> SourcePosition {
+        let line = self.line_starts.partition_point(|start| *start <= offset);
+        let line_start = self.line_starts[line - 1];
+        SourcePosition {
+            line,
+            column: self.content[line_start..offset].chars().count(),
+        }
+    }
+
+    /// Finds the comments in between the tokens of a macro body. Every piece
+    /// of source text that isn't part of a token is either whitespace or a
+    /// comment, so only the gaps between tokens need to be searched.
+    fn comments(
+        &self,
+        open: Span,
+        tokens: TokenStream,
+        close: Span,
+    ) -> Vec<ViewComment> {
+        let mut spans = vec![open];
+        flatten_spans(tokens, &mut spans);
+        spans.push(close);
+
+        let mut comments = Vec::new();
+        for pair in spans.windows(2) {
+            let gap_start = self.offset(pair[0].end());
+            let gap_end = self.offset(pair[1].start());
+            if gap_start < gap_end {
+                self.comments_in_gap(gap_start, gap_end, &mut comments);
+            }
+        }
+        comments
+    }
+
+    fn comments_in_gap(
+        &self,
+        mut start: usize,
+        end: usize,
+        comments: &mut Vec<ViewComment>,
+    ) {
+        loop {
+            let gap = &self.content[start..end];
+            let trimmed = gap.trim_start();
+            if trimmed.is_empty() {
+                return;
+            }
+            let comment_start = start + (gap.len() - trimmed.len());
+            let len = if trimmed.starts_with("//") {
+                trimmed.find('\n').unwrap_or(trimmed.len())
+            } else if trimmed.starts_with("/*") {
+                block_comment_len(trimmed)
+            } else {
+                // not a comment; this shouldn't happen between tokens
+                return;
+            };
+            let comment_end = comment_start + len;
+            comments.push(ViewComment {
+                text: self.content[comment_start..comment_end]
+                    .trim_end()
+                    .to_string(),
+                span: SourceSpan {
+                    start: self.position(comment_start),
+                    end: self.position(comment_end),
+                },
+            });
+            start = comment_end;
+        }
+    }
+}
+
+/// The length of the (possibly nested) block comment at the start of `text`.
+fn block_comment_len(text: &str) -> usize {
+    let mut depth = 0;
+    let mut idx = 0;
+    while idx < text.len() {
+        let rest = &text[idx..];
+        if rest.starts_with("/*") {
+            depth += 1;
+            idx += 2;
+        } else if rest.starts_with("*/") {
+            depth -= 1;
+            idx += 2;
+            if depth == 0 {
+                return idx;
+            }
+        } else {
+            idx += rest.chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    text.len()
+}
+
+fn flatten_spans(tokens: TokenStream, spans: &mut Vec<Span>) {
+    for token in tokens {
+        match token {
+            TokenTree::Group(group) => {
+                spans.push(group.span_open());
+                flatten_spans(group.stream(), spans);
+                spans.push(group.span_close());
+            }
+            token => spans.push(token.span()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(source: &str) -> Vec<ViewMacro> {
+        ViewMacro::parse_str(&"src/app.rs".into(), source).unwrap()
+    }
+
+    #[test]
+    fn parses_element_tree_with_positions() {
+        let views = parse(
+            r#"fn app() {
+    view! {
+        <div class="counter">
+            <Button on:click=move |_| {}>"Go"</Button>
+            {move || count.get()}
+        </div>
+    }
+}"#,
+        );
+
+        assert_eq!(views.len(), 1);
+        let view = &views[0];
+        assert_eq!(view.id, "src-app.rs-2");
+        assert_eq!(view.span.start.line, 2);
+
+        let ViewNode::Element {
+            name,
+            attrs,
+            children,
+            span,
+        } = &view.nodes[0]
+        else {
+            panic!("expected an element");
+        };
+        assert_eq!(name, "div");
+        assert_eq!(span.start, SourcePosition { line: 3, column: 8 });
+        assert_eq!(
+            attrs[0],
+            ViewAttribute::Keyed {
+                name: "class".into(),
+                value: ViewAttributeValue::Static("counter".into()),
+                span: SourceSpan {
+                    start: SourcePosition {
+                        line: 3,
+                        column: 13
+                    },
+                    end: SourcePosition {
+                        line: 3,
+                        column: 28
+                    },
+                },
+            }
+        );
+        assert!(matches!(
+            &children[0],
+            ViewNode::Component { name, children, .. }
+                if name == "Button"
+                    && matches!(
+                        &children[0],
+                        ViewNode::Text { value, .. } if value == "Go"
+                    )
+        ));
+
+        let holes = view.dynamic_holes();
+        assert_eq!(holes.len(), 2);
+        assert_eq!(holes[0].code, "move |_| {}");
+        assert_eq!(holes[1].code, "{move || count.get()}");
+        assert_eq!(holes[1].span.start.line, 5);
+    }
+
+    #[test]
+    fn parses_every_macro_delimiter() {
+        let views = parse(
+            r#"fn app() {
+    let a = view!(<p>"parens"</p> /* a */);
+    let b = view![<p>"brackets"</p> /* b */];
+    view! { <p>"braces"</p> /* c */ }
+}"#,
+        );
+
+        assert_eq!(views.len(), 3);
+        for (view, (text, comment)) in views.iter().zip([
+            ("parens", "/* a */"),
+            ("brackets", "/* b */"),
+            ("braces", "/* c */"),
+        ]) {
+            let ViewNode::Element { children, .. } = &view.nodes[0] else {
+                panic!("expected an element");
+            };
+            assert!(matches!(
+                &children[0],
+                ViewNode::Text { value, .. } if value == text
+            ));
+            assert_eq!(view.comments[0].text, comment);
+        }
+    }
+
+    #[test]
+    fn recovers_comments() {
+        let views = parse(
+            r#"fn app() {
+    view! {
+        // the header
+        <h1>"Title"</h1>
+        <p /* inline */ class="a">"Body"</p> // trailing
+    }
+}"#,
+        );
+
+        let comments = &views[0].comments;
+        assert_eq!(
+            comments
+                .iter()
+                .map(|comment| comment.text.as_str())
+                .collect::<Vec<_>>(),
+            ["// the header", "/* inline */", "// trailing"]
+        );
+        assert_eq!(
+            comments[0].span,
+            SourceSpan {
+                start: SourcePosition { line: 3, column: 8 },
+                end: SourcePosition {
+                    line: 3,
+                    column: 21
+                },
+            }
+        );
+        assert_eq!(
+            comments[1].span.start,
+            SourcePosition {
+                line: 5,
+                column: 11
+            }
+        );
+    }
+
+    #[test]
+    fn parses_global_class() {
+        let views = parse(r#"fn app() { view! { class = styles, <p/> } }"#);
+
+        assert_eq!(
+            views[0]
+                .global_class
+                .as_ref()
+                .map(|class| class.code.as_str()),
+            Some("styles")
+        );
+        assert!(matches!(
+            &views[0].nodes[..],
+            [ViewNode::Element { name, .. }] if name == "p"
+        ));
+    }
+}
@@ -20,6 +20,8 @@ use syn::{
 };
 use walkdir::WalkDir;
 
+#[cfg(feature = "view-ast")]
+pub mod ast;
 pub mod diff;
 pub mod node;
 pub mod parsing;
@@ -81,7 +83,7 @@ impl ViewMacros {
             let tokens = view.tokens.clone().into_iter();
             // TODO handle class = ...
             let rsx =
-                rstml::parse2(tokens.collect::<proc_macro2::TokenStream>())?;
+                parsing::parse_view_nodes(tokens.collect()).into_result()?;
             let template = LNode::parse_view(rsx)?;
             views.push(MacroInvocation { id, template });
         }
@@ -1,4 +1,8 @@
-use rstml::node::{NodeElement, NodeName};
+use proc_macro2::{Span, TokenStream, TokenTree};
+use rstml::{
+    node::{Node, NodeElement, NodeName},
+    ParserConfig, ParsingResult,
+};
 
 /// Converts `syn::Block` to simple expression
 ///
@@ -68,3 +72,57 @@ pub fn is_component_tag_name(name: &NodeName) -> bool {
 pub fn is_component_node(node: &NodeElement) -> bool {
     is_component_tag_name(node.name())
 }
+
+/// The `rstml` configuration the `view!` macro uses to parse its body.
+///
+/// Tools that parse views themselves should use [`parse_view_nodes`] together
+/// with [`split_global_class`], so that they accept exactly the same syntax as
+/// the macro.
+#[must_use]
+pub fn view_parser_config() -> ParserConfig {
+    ParserConfig::default().recover_block(true)
+}
+
+/// Parses the nodes of a `view!` body, after its global class, the way the
+/// macro does: errors in blocks are recovered from, so the nodes around them
+/// are still returned along with the errors.
+pub fn parse_view_nodes(tokens: TokenStream) -> ParsingResult<Vec<Node>> {
+    rstml::Parser::new(view_parser_config()).parse_recoverable(tokens)
+}
+
+/// Splits the optional `class = ...,` at the start of a `view!` macro body
+/// from the nodes that follow it.
+///
+/// # Errors
+///
+/// Will return the span of the `=` if the class is not followed by a comma.
+pub fn split_global_class(
+    tokens: TokenStream,
+) -> Result<(Option<TokenTree>, TokenStream), Span> {
+    let mut tokens = tokens.into_iter();
+
+    let first = tokens.next();
+    let second = tokens.next();
+    let third = tokens.next();
+    let fourth = tokens.next();
+    match (&first, &second) {
+        (Some(TokenTree::Ident(first)), Some(TokenTree::Punct(eq)))
+            if *first == "class" && eq.as_char() == '=' =>
+        {
+            match &fourth {
+                Some(TokenTree::Punct(comma)) if comma.as_char() == ',' => {
+                    Ok((third, tokens.collect()))
+                }
+                _ => Err(eq.span()),
+            }
+        }
+        _ => Ok((
+            None,
+            [first, second, third, fourth]
+                .into_iter()
+                .flatten()
+                .chain(tokens)
+                .collect(),
+        )),
+    }
+}
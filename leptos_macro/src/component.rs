@@ -178,6 +178,8 @@ impl ToTokens for Model {
         );
 
         let component_fn_prop_docs = generate_component_fn_prop_docs(props);
+        let prop_metadata = prop_metadata(props);
+        let component_name = name.to_string();
 
         let (
             tracing_instrument_attr,
@@ -232,15 +234,36 @@ impl ToTokens for Model {
         };
 
         let body_name = unmodified_fn_name_from_fn_name(&body_name);
+        let body_call = if body.sig.asyncness.is_some() {
+            quote! {
+                ::leptos::async_component(#body_name(#prop_names))
+            }
+        } else {
+            quote! {
+                #body_name(#prop_names)
+            }
+        };
         let body_expr = if *is_island {
             quote! {
                 ::leptos::SharedContext::with_hydration(move || {
-                    #body_name(#prop_names)
+                    #body_call
                 })
             }
+        } else if body.sig.asyncness.is_some() || *is_transparent {
+            body_call
         } else {
-            quote! {
-                #body_name(#prop_names)
+            // set up twice in strict mode, if the props can be cloned
+            quote::quote! {
+                {
+                    use ::leptos::leptos_dom::{
+                        ComponentSetupOnce as _, StrictComponentSetup as _,
+                    };
+                    (&::leptos::leptos_dom::ComponentSetup::new(
+                        ::std::stringify!(#name),
+                        (#prop_names),
+                    ))
+                    .setup(|(#prop_names)| #body_call)
+                }
             }
         };
 
@@ -267,7 +290,7 @@ impl ToTokens for Model {
                         ::leptos::leptos_dom::html::Custom::new("leptos-island"),
                     )
                     .attr("data-component", #component_id)
-                    .attr("data-hkc", ::leptos::leptos_dom::HydrationCtx::peek_always().to_string())
+                    .attr("data-hkc", ::leptos::leptos_dom::HydrationCtx::peek_always().to_html())
                     #island_serialized_props
                     .child(#component)
                 }
@@ -364,6 +387,7 @@ impl ToTokens for Model {
                 }
             })
             .collect::<TokenStream>();
+        let accepts_dyn_attrs = count > 0;
 
         let body = quote! {
             #destructure_props
@@ -497,11 +521,20 @@ impl ToTokens for Model {
                 }
             }
 
+            impl #impl_generics ::leptos::PropsMetadata for #props_name #generics #where_clause {
+                const COMPONENT: &'static str = #component_name;
+                const PROPS: &'static [::leptos::PropMetadata] = &[#prop_metadata];
+            }
+
             impl #impl_generics ::leptos::DynAttrs for #props_name #generics #where_clause {
                 fn dyn_attrs(mut self, v: Vec<(&'static str, ::leptos::Attribute)>) -> Self {
                     #dyn_attrs_props
                     self
                 }
+
+                fn accepts_dyn_attrs(&self) -> bool {
+                    #accepts_dyn_attrs
+                }
             }
 
             #into_view
@@ -797,6 +830,17 @@ impl Docs {
             .collect()
     }
 
+    /// The text of the doc comments, without the space that usually
+    /// follows `///`.
+    pub fn text(&self) -> String {
+        self.0
+            .iter()
+            .map(|(doc, _)| doc.strip_prefix(' ').unwrap_or(doc))
+            .join("\n")
+            .trim()
+            .to_string()
+    }
+
     pub fn typed_builder(&self) -> String {
         let doc_str = self.0.iter().map(|s| s.0.as_str()).join("\n");
 
@@ -988,6 +1032,47 @@ fn prop_names(props: &[Prop]) -> TokenStream {
         .collect()
 }
 
+fn prop_metadata(props: &[Prop]) -> TokenStream {
+    props
+        .iter()
+        .map(|prop| {
+            let Prop {
+                docs,
+                name,
+                prop_opts,
+                ty,
+            } = prop;
+
+            let name = name.ident.to_string();
+            let ty = pretty_prop_type(ty, prop_opts);
+            let optional = prop_opts.optional
+                || prop_opts.optional_no_strip
+                || prop_opts.default.is_some()
+                || prop_opts.attrs;
+            let default = match &prop_opts.default {
+                Some(default) => {
+                    let default = pretty_expr(default);
+                    quote! { ::core::option::Option::Some(#default) }
+                }
+                None => quote! { ::core::option::Option::None },
+            };
+            let into = prop_opts.into;
+            let docs = docs.text();
+
+            quote! {
+                ::leptos::PropMetadata {
+                    name: #name,
+                    ty: #ty,
+                    optional: #optional,
+                    default: #default,
+                    into: #into,
+                    docs: #docs,
+                },
+            }
+        })
+        .collect()
+}
+
 fn generate_component_fn_prop_docs(props: &[Prop]) -> TokenStream {
     let required_prop_docs = props
         .iter()
@@ -1088,15 +1173,9 @@ enum PropDocStyle {
     Inline,
 }
 
-fn prop_to_doc(
-    Prop {
-        docs,
-        name,
-        ty,
-        prop_opts,
-    }: &Prop,
-    style: PropDocStyle,
-) -> TokenStream {
+/// The type of the values that are passed to the prop, formatted like in the
+/// source, which is the inner type of `Option` props with a stripped setter.
+fn pretty_prop_type(ty: &Type, prop_opts: &PropOpt) -> String {
     let ty = if (prop_opts.optional || prop_opts.strip_option) && is_option(ty)
     {
         unwrap_option(ty)
@@ -1116,7 +1195,37 @@ fn prop_to_doc(
 
     let pretty_ty = prettyplease::unparse(&file);
 
-    let pretty_ty = &pretty_ty[16..&pretty_ty.len() - 2];
+    pretty_ty[16..pretty_ty.len() - 2].to_string()
+}
+
+/// Formats an expression like in the source.
+fn pretty_expr(expr: &syn::Expr) -> String {
+    let const_item: syn::Item = parse_quote! {
+        const SOME_CONST: () = #expr;
+    };
+
+    let file = syn::File {
+        shebang: None,
+        attrs: vec![],
+        items: vec![const_item],
+    };
+
+    let pretty_expr = prettyplease::unparse(&file);
+
+    pretty_expr["const SOME_CONST: () = ".len()..pretty_expr.len() - 2]
+        .to_string()
+}
+
+fn prop_to_doc(
+    Prop {
+        docs,
+        name,
+        ty,
+        prop_opts,
+    }: &Prop,
+    style: PropDocStyle,
+) -> TokenStream {
+    let pretty_ty = &pretty_prop_type(ty, prop_opts);
 
     match style {
         PropDocStyle::List => {
@@ -6,7 +6,7 @@ extern crate proc_macro_error;
 
 use component::DummyModel;
 use proc_macro::TokenStream;
-use proc_macro2::{Span, TokenTree};
+use proc_macro2::Span;
 use quote::ToTokens;
 use rstml::{node::KeyedAttribute, parse};
 use syn::{parse_macro_input, spanned::Spanned, token::Pub, Visibility};
@@ -33,7 +33,9 @@ impl Default for Mode {
 mod params;
 mod view;
 use crate::component::unmodified_fn_name_from_fn_name;
-use view::{client_template::render_template, render_view};
+use view::{
+    client_template::render_template, interpolate_attributes, render_view,
+};
 mod component;
 mod server;
 mod slice;
@@ -114,8 +116,39 @@ mod slot;
 /// # runtime.dispose();
 /// ```
 ///
+///    With the `attribute-interpolation` feature, string attributes on elements can interpolate expressions
+///    in braces. If any of them calls a function (like `.get()`), the attribute is wrapped in a `move ||`
+///    closure so it updates reactively; plain variables are formatted once. Every string attribute on an
+///    element then follows the rules of `format!`, so literal braces, as in JSON or CSS, are written `{{`
+///    and `}}`. Without the feature, strings are used as they are.
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// # if !cfg!(any(feature = "csr", feature = "hydrate")) {
+/// let (id, set_id) = create_signal(1);
+/// let kind = "primary";
+///
+/// view! {
+///   // the same as `href=move || format!("/users/{}", id.get())`
+///   <a href="/users/{id.get()}" class="btn btn-{kind}">"Profile"</a>
+/// }
+/// # ;
+/// # };
+/// # runtime.dispose();
+/// ```
+///
 /// 5. Event handlers can be added with `on:` attributes. In most cases, the events are given the correct type
-///    based on the event name.
+///    based on the event name, from the types in [`leptos::ev`](https://docs.rs/leptos/latest/leptos/ev/index.html).
+///    A name that is a single word has to be one of those events, so that a misspelled event is a
+///    compile error. Names with a `-` or a `.`, like `on:my-event`, are listened to as an
+///    [`ev::Custom`](https://docs.rs/leptos/latest/leptos/ev/struct.Custom.html) event that
+///    receives a `web_sys::Event`; to give a custom event another type, or a name that is a single
+///    word, call `.on(ev::Custom::<E>::new("myevent"), handler)` on the element.
+///
+///    Events that bubble are delegated to a single listener on the window. Add `:undelegated`
+///    after the event name to attach the listener to the element itself, or any of `:capture`,
+///    `:passive` and `:once` to also set those [listener options](https://developer.mozilla.org/en-US/docs/Web/API/EventTarget/addEventListener#options),
+///    as in `on:touchmove:passive=handler`.
 /// ```rust
 /// # use leptos::*;
 /// # let runtime = create_runtime();
@@ -132,6 +165,15 @@ mod slot;
 /// # runtime.dispose();
 /// ```
 ///
+/// ```rust,compile_fail
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// // ❌ there is no `clik` event
+/// view! { <button on:clik=|_| ()>"Click me"</button> }
+/// # ;
+/// # runtime.dispose();
+/// ```
+///
 /// 6. DOM properties can be set with `prop:` attributes, which take any primitive type or `JsValue` (or a signal
 ///    that returns a primitive or JsValue). They can also take an `Option`, in which case `Some` sets the property
 ///    and `None` deletes the property.
@@ -284,6 +326,22 @@ mod slot;
 /// # runtime.dispose();
 /// ```
 ///
+/// 12. You can spread a collection of attributes onto an element or a component with `{..attrs}`,
+///     where `attrs` is an `AttributeMap` or any other iterator of
+///     `(&'static str, Attribute)` pairs. On a component, they are forwarded like `attr:` bindings.
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// # if !cfg!(any(feature = "csr", feature = "hydrate")) {
+/// let attrs = AttributeMap::new().with("id", "main").with("hidden", false);
+/// view! {
+///   <div {..attrs} class="box"/>
+/// }
+/// # ;
+/// # };
+/// # runtime.dispose();
+/// ```
+///
 /// Here’s a simple example that shows off several of these features, put together
 /// ```rust
 /// # use leptos::*;
@@ -319,43 +377,20 @@ mod slot;
 )]
 pub fn view(tokens: TokenStream) -> TokenStream {
     let tokens: proc_macro2::TokenStream = tokens.into();
-    let mut tokens = tokens.into_iter();
-
-    let first = tokens.next();
-    let second = tokens.next();
-    let third = tokens.next();
-    let fourth = tokens.next();
-    let global_class = match (&first, &second) {
-        (Some(TokenTree::Ident(first)), Some(TokenTree::Punct(eq)))
-            if *first == "class" && eq.as_char() == '=' =>
-        {
-            match &fourth {
-                Some(TokenTree::Punct(comma)) if comma.as_char() == ',' => {
-                    third.clone()
-                }
-                _ => {
-                    abort!(
-                        second, "To create a scope class with the view! macro you must put a comma `,` after the value";
-                        help = r#"e.g., view!{ class="my-class", <div>...</div>}"#
-                    )
-                }
-            }
-        }
-        _ => None,
-    };
-    let tokens = if global_class.is_some() {
-        tokens.collect::<proc_macro2::TokenStream>()
-    } else {
-        [first, second, third, fourth]
-            .into_iter()
-            .flatten()
-            .chain(tokens)
-            .collect()
-    };
-    let config = rstml::ParserConfig::default().recover_block(true);
-    let parser = rstml::Parser::new(config);
-    let (nodes, errors) = parser.parse_recoverable(tokens).split_vec();
+    let (global_class, tokens) =
+        match leptos_hot_reload::parsing::split_global_class(tokens) {
+            Ok(split) => split,
+            Err(span) => abort!(
+                span, "To create a scope class with the view! macro you must put a comma `,` after the value";
+                help = r#"e.g., view!{ class="my-class", <div>...</div>}"#
+            ),
+        };
+    let (mut nodes, errors) =
+        leptos_hot_reload::parsing::parse_view_nodes(tokens).split_vec();
     let errors = errors.into_iter().map(|e| e.emit_as_expr_tokens());
+    if cfg!(feature = "attribute-interpolation") {
+        interpolate_attributes(&mut nodes);
+    }
     let nodes_output = render_view(
         &nodes,
         Mode::default(),
@@ -539,6 +574,35 @@ pub fn template(tokens: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// ## Async Components
+/// A component can be an `async fn`, to load some data and then render it without setting up a
+/// resource. Its body runs like a [local resource](https://docs.rs/leptos/latest/leptos/fn.create_local_resource.html):
+/// only in the browser, once, after the component is created. Until it resolves, the component
+/// renders nothing, and the nearest `<Suspense/>` shows its fallback. The body can still use the
+/// context of the component, even after an `.await`.
+///
+/// ```
+/// # use leptos::*;
+/// # async fn fetch_user_name(id: u32) -> String { format!("User {id}") }
+/// #[component]
+/// async fn UserName(id: u32) -> impl IntoView {
+///     let name = fetch_user_name(id).await;
+///     view! { <span>{name}</span> }
+/// }
+///
+/// #[component]
+/// fn Profile() -> impl IntoView {
+///     view! {
+///       <Suspense fallback=|| "Loading...">
+///         <UserName id=42/>
+///       </Suspense>
+///     }
+/// }
+/// ```
+///
+/// Because the body is not run on the server, use a resource instead for data that should be
+/// server-rendered, or that the body should reload when a signal changes.
+///
 /// ## Customizing Properties
 /// You can use the `#[prop]` attribute on individual component properties (function arguments) to
 /// customize the types that component property can receive. You can use the following attributes:
@@ -968,9 +1032,96 @@ pub fn slot(args: proc_macro::TokenStream, s: TokenStream) -> TokenStream {
 #[proc_macro_attribute]
 #[proc_macro_error]
 pub fn server(args: proc_macro::TokenStream, s: TokenStream) -> TokenStream {
+    if !cfg!(feature = "server-fns") {
+        abort!(
+            Span::call_site(),
+            "server functions are not enabled";
+            help = "enable the `server-fns` feature of `leptos`, which is \
+                    on by default"
+        );
+    }
     server::server_impl(args, s)
 }
 
+/// Includes the item it annotates only when the crate is built in `ssr` mode.
+///
+/// This is `#[cfg(feature = "ssr")]`, so your crate needs an `ssr` feature that
+/// enables `leptos/ssr`, like the ones the Leptos templates set up. The three
+/// mode attributes are mutually exclusive even when several of those features
+/// are enabled, for example by `cargo test --workspace`: `ssr` wins over
+/// `hydrate`, which wins over `csr`, like the modes Leptos itself picks.
+/// ```
+/// # use leptos::*;
+/// #[cfg_ssr]
+/// fn database_url() -> String {
+///     std::env::var("DATABASE_URL").unwrap_or_default()
+/// }
+/// ```
+#[proc_macro_attribute]
+#[proc_macro_error]
+pub fn cfg_ssr(args: proc_macro::TokenStream, s: TokenStream) -> TokenStream {
+    mode_cfg("cfg_ssr", quote::quote! { feature = "ssr" }, args, s)
+}
+
+/// Includes the item it annotates only when the crate is built in `csr` mode,
+/// and neither `ssr` nor `hydrate` is enabled.
+///
+/// See [`macro@cfg_ssr`].
+#[proc_macro_attribute]
+#[proc_macro_error]
+pub fn cfg_csr(args: proc_macro::TokenStream, s: TokenStream) -> TokenStream {
+    mode_cfg(
+        "cfg_csr",
+        quote::quote! {
+            all(
+                feature = "csr",
+                not(any(feature = "ssr", feature = "hydrate"))
+            )
+        },
+        args,
+        s,
+    )
+}
+
+/// Includes the item it annotates only when the crate is built in `hydrate`
+/// mode, and `ssr` is not enabled.
+///
+/// See [`macro@cfg_ssr`].
+#[proc_macro_attribute]
+#[proc_macro_error]
+pub fn cfg_hydrate(
+    args: proc_macro::TokenStream,
+    s: TokenStream,
+) -> TokenStream {
+    mode_cfg(
+        "cfg_hydrate",
+        quote::quote! { all(feature = "hydrate", not(feature = "ssr")) },
+        args,
+        s,
+    )
+}
+
+fn mode_cfg(
+    name: &str,
+    predicate: proc_macro2::TokenStream,
+    args: proc_macro::TokenStream,
+    s: TokenStream,
+) -> TokenStream {
+    if !args.is_empty() {
+        abort!(
+            Span::call_site(),
+            "no arguments are supported";
+            help = format!("try just `#[{name}]`")
+        );
+    }
+    let item = proc_macro2::TokenStream::from(s);
+    quote::quote! {
+        #[cfg(#predicate)]
+        #item
+    }
+    .into()
+}
+
 /// Derives a trait that parses a map of string keys and values into a typed
 /// data structure, e.g., for route params.
 #[proc_macro_derive(Params, attributes(params))]
@@ -1,7 +1,7 @@
 use convert_case::{Case, Converter};
 use proc_macro::TokenStream;
 use proc_macro2::Literal;
-use quote::{ToTokens, __private::TokenStream as TokenStream2};
+use quote::{__private::TokenStream as TokenStream2, ToTokens};
 use syn::{
     parse::{Parse, ParseStream},
     Ident, ItemFn, LitStr, Token,
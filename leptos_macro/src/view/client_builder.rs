@@ -1,6 +1,6 @@
 use super::{
     component_builder::component_to_tokens,
-    expr_to_ident, fancy_class_name, fancy_style_name,
+    event_options, expr_to_ident, fancy_class_name, fancy_style_name,
     ide_helper::IdeTagHelper,
     is_ambiguous_element, is_custom_element, is_math_ml_element,
     is_self_closing, is_svg_element, parse_event_name,
@@ -294,47 +294,8 @@ pub(crate) fn element_to_tokens(
         }
 
         let children = node.children.iter().map(|node| {
-            let (child, is_static) = match node {
-                Node::Fragment(fragment) => (
-                    fragment_to_tokens(
-                        Span::call_site(),
-                        &fragment.children,
-                        true,
-                        parent_type,
-                        None,
-                        global_class,
-                        None,
-                    )
-                    .unwrap_or(quote_spanned! {
-                        Span::call_site()=> ::leptos::leptos_dom::Unit
-                    }),
-                    false,
-                ),
-                Node::Text(node) => (quote! { #node }, true),
-                Node::RawText(node) => {
-                    let text = node.to_string_best();
-                    let text = syn::LitStr::new(&text, node.span());
-                    (quote! { #text }, true)
-                }
-                Node::Block(node) => (
-                    quote! {
-                       #node
-                    },
-                    false,
-                ),
-                Node::Element(node) => (
-                    element_to_tokens(
-                        node,
-                        parent_type,
-                        None,
-                        global_class,
-                        None,
-                    )
-                    .unwrap_or_default(),
-                    false,
-                ),
-                Node::Comment(_) | Node::Doctype(_) => (quote! {}, false),
-            };
+            let (child, is_static) =
+                child_to_tokens(node, parent_type, global_class);
             if is_static {
                 quote! {
                     .child(#child)
@@ -367,6 +328,266 @@ pub(crate) fn element_to_tokens(
     }
 }
 
+/// Returns the code that creates a child of an element, and whether it is static text.
+fn child_to_tokens(
+    node: &Node,
+    parent_type: TagType,
+    global_class: Option<&TokenTree>,
+) -> (TokenStream, bool) {
+    match node {
+        Node::Fragment(fragment) => (
+            fragment_to_tokens(
+                Span::call_site(),
+                &fragment.children,
+                true,
+                parent_type,
+                None,
+                global_class,
+                None,
+            )
+            .unwrap_or(quote_spanned! {
+                Span::call_site()=> ::leptos::leptos_dom::Unit
+            }),
+            false,
+        ),
+        Node::Text(node) => (quote! { #node }, true),
+        Node::RawText(node) => {
+            let text = node.to_string_best();
+            let text = syn::LitStr::new(&text, node.span());
+            (quote! { #text }, true)
+        }
+        Node::Block(node) => (
+            quote! {
+               #node
+            },
+            false,
+        ),
+        Node::Element(node) => (
+            static_template_to_tokens(node, parent_type, global_class)
+                .or_else(|| {
+                    element_to_tokens(
+                        node,
+                        parent_type,
+                        None,
+                        global_class,
+                        None,
+                    )
+                })
+                .unwrap_or_default(),
+            false,
+        ),
+        Node::Comment(_) | Node::Doctype(_) => (quote! {}, false),
+    }
+}
+
+/// Creates a child element by cloning its static parts from a `<template>`, if they have at
+/// least two elements: creating the elements one by one is slower for anything larger. Its
+/// dynamic attributes, event listeners and children are then added to the clone, each child
+/// replacing a comment left for it in the HTML.
+///
+/// The HTML is parsed by the browser, which moves elements that are not allowed where they
+/// are, so the view checks that it was parsed into the same nodes, and otherwise creates
+/// every element one by one.
+fn static_template_to_tokens(
+    node: &NodeElement,
+    parent_type: TagType,
+    global_class: Option<&TokenTree>,
+) -> Option<TokenStream> {
+    if global_class.is_some()
+        || matches!(parent_type, TagType::Svg | TagType::Math)
+    {
+        return None;
+    }
+    let mut template = Template::default();
+    if !template.push_element(node) || template.elements < 2 {
+        return None;
+    }
+    let build = element_to_tokens(node, parent_type, None, None, None)?;
+    let Template {
+        html,
+        shape,
+        patches,
+        ..
+    } = template;
+    Some(quote! {
+        ::leptos::leptos_dom::html::from_template(
+            {
+                thread_local! {
+                    static TEMPLATE: ::leptos::leptos_dom::html::StaticTemplate =
+                        ::leptos::leptos_dom::html::StaticTemplate::new(#html, #shape);
+                }
+                &TEMPLATE
+            },
+            |__template| match __template {
+                Some(__template) => {
+                    #(#patches;)*
+                    ::leptos::IntoView::into_view(__template)
+                }
+                None => ::leptos::IntoView::into_view(#build),
+            },
+        )
+    })
+}
+
+/// The HTML of the static parts of a view, with the code that fills in the dynamic parts of
+/// a clone of it, in the order in which they appear.
+#[derive(Default)]
+struct Template {
+    html: String,
+    /// The nodes that the HTML must be parsed into, as checked by `StaticTemplate::new`.
+    shape: String,
+    elements: usize,
+    holes: usize,
+    patches: Vec<TokenStream>,
+}
+
+impl Template {
+    /// Adds an element, or returns `false`, leaving the template as it was, if it can't be
+    /// written as HTML, and must be a hole instead.
+    fn push_element(&mut self, node: &NodeElement) -> bool {
+        let (html, shape) = (self.html.len(), self.shape.len());
+        let (elements, holes) = (self.elements, self.holes);
+        let patches = self.patches.len();
+        let pushed = self.try_push_element(node).is_some();
+        if !pushed {
+            self.html.truncate(html);
+            self.shape.truncate(shape);
+            (self.elements, self.holes) = (elements, holes);
+            self.patches.truncate(patches);
+        }
+        pushed
+    }
+
+    fn try_push_element(&mut self, node: &NodeElement) -> Option<()> {
+        // the contents of these are not parsed as HTML, or the parser adds elements to them
+        const NOT_PARSED_AS_WRITTEN: &[&str] = &[
+            "script",
+            "style",
+            "template",
+            "textarea",
+            "title",
+            "noscript",
+            "pre",
+            "table",
+            "iframe",
+            "xmp",
+            "noembed",
+            "noframes",
+            "plaintext",
+        ];
+
+        let tag = node.name().to_string();
+        if is_component_node(node)
+            || tag.contains("::")
+            || is_svg_element(&tag)
+            || is_math_ml_element(&tag)
+            || NOT_PARSED_AS_WRITTEN.contains(&tag.as_str())
+        {
+            return None;
+        }
+
+        let index = self.elements;
+        self.elements += 1;
+        self.html.push('<');
+        self.html.push_str(&tag);
+        self.shape.push_str(&tag.to_lowercase());
+        self.shape.push('(');
+        let mut patches = Vec::new();
+        for attr in node.attributes() {
+            // spread attributes are only known at run time
+            let NodeAttribute::Attribute(attr) = attr else {
+                return None;
+            };
+            let name = attr.key.to_string();
+            if matches!(
+                name.as_str(),
+                "ref" | "_ref" | "ref_" | "node_ref" | "inner_html"
+            ) {
+                return None;
+            }
+            let is_plain =
+                name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+            let value = match attr.value() {
+                Some(value) => value_to_string(value),
+                None => Some(String::new()),
+            };
+            match value.filter(|_| is_plain) {
+                Some(value) => {
+                    self.html.push(' ');
+                    self.html.push_str(&name);
+                    self.html.push_str("=\"");
+                    self.html.push_str(
+                        &html_escape::encode_double_quoted_attribute(&value),
+                    );
+                    self.html.push('"');
+                }
+                None => patches.push(match fancy_style_name(&name, attr) {
+                    Some((fancy, _, _)) => fancy,
+                    None => attribute_to_tokens(attr, None),
+                }),
+            }
+        }
+        self.html.push('>');
+        if !patches.is_empty() {
+            self.patches.push(quote! {
+                let _ = __template.element(#index) #(#patches)*
+            });
+        }
+        if is_self_closing(node) {
+            self.shape.push(')');
+            return Some(());
+        }
+
+        let mut after_text = false;
+        for child in &node.children {
+            match child {
+                Node::Text(text) => {
+                    after_text |=
+                        self.push_text(&text.value_string(), after_text)
+                }
+                Node::RawText(text) => {
+                    after_text |=
+                        self.push_text(&text.to_string_best(), after_text)
+                }
+                Node::Element(child) if self.push_element(child) => {
+                    after_text = false
+                }
+                Node::Comment(_) | Node::Doctype(_) => {}
+                Node::Element(_) | Node::Block(_) | Node::Fragment(_) => {
+                    let (child, _) =
+                        child_to_tokens(child, TagType::Html, None);
+                    let index = self.holes;
+                    self.holes += 1;
+                    self.html.push_str("<!---->");
+                    self.shape.push('!');
+                    self.patches.push(quote! {
+                        __template.hole(#index, (#child))
+                    });
+                    after_text = false;
+                }
+            }
+        }
+        self.html.push_str("</");
+        self.html.push_str(&tag);
+        self.html.push('>');
+        self.shape.push(')');
+        Some(())
+    }
+
+    /// Adds a text node, unless it is empty, and returns whether it did. Text right after
+    /// other text is parsed into the same node.
+    fn push_text(&mut self, text: &str, after_text: bool) -> bool {
+        if text.is_empty() {
+            return false;
+        }
+        self.html.push_str(&html_escape::encode_text(text));
+        if !after_text {
+            self.shape.push('#');
+        }
+        true
+    }
+}
+
 pub(crate) fn attribute_to_tokens(
     node: &KeyedAttribute,
     global_class: Option<&TokenTree>,
@@ -386,7 +607,7 @@ pub(crate) fn attribute_to_tokens(
         let handler = attribute_value(node);
 
         let (event_type, is_custom, is_force_undelegated) =
-            parse_event_name(name);
+            parse_event_name(name, &node.key);
 
         let event_name_ident = match &node.key {
             NodeName::Punctuated(parts) => {
@@ -425,7 +646,9 @@ pub(crate) fn attribute_to_tokens(
             event_type
         };
 
-        let event_type = if is_force_undelegated {
+        let event_type = if let Some(options) = event_options(name) {
+            quote! { ::leptos::ev::WithOptions::new(::leptos::ev::#event_type, #options) }
+        } else if is_force_undelegated {
             let undelegated = if let Some(undelegated) = undelegated_ident {
                 quote_spanned! {
                     undelegated.span()=> #undelegated
@@ -291,7 +291,7 @@ fn attr_to_tokens(
     let span = node.key.span();
 
     // refs
-    if name == "ref" {
+    if name == "ref" || name == "_ref" || name == "ref_" || name == "node_ref" {
         abort!(span, "node_ref not yet supported in template! macro")
     }
     // Event Handlers
@@ -5,7 +5,7 @@ use super::{
     event_from_attribute_node,
 };
 use crate::view::directive_call_from_attribute_node;
-use proc_macro2::{Ident, TokenStream, TokenTree};
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
 use quote::{format_ident, quote};
 use rstml::node::{NodeAttribute, NodeElement};
 use std::collections::HashMap;
@@ -28,6 +28,48 @@ pub(crate) fn component_to_tokens(
         }
     });
 
+    // `class:name=value` and `style:name=value` are collected into a single
+    // `ClassProp`/`StyleProp`, along with any plain `class`/`style`
+    let has_toggles = attrs.clone().any(|attr| {
+        let name = attr.key.to_string();
+        name.starts_with("class:") || name.starts_with("style:")
+    });
+    let class_and_style = if has_toggles {
+        [
+            ("class", quote! { ::leptos::ClassProp }, quote! { with_class }),
+            ("style", quote! { ::leptos::StyleProp }, quote! { with_style }),
+        ]
+        .into_iter()
+        .filter_map(|(prop, ty, with)| {
+            let prefix = format!("{prop}:");
+            let plain = attrs.clone().find(|attr| attr.key.to_string() == prop);
+            let toggles = attrs
+                .clone()
+                .filter_map(|attr| {
+                    let name = attr.key.to_string();
+                    let name = name.strip_prefix(&prefix)?.to_owned();
+                    let value = attr.value()?;
+                    Some(quote! { .#with(#name, #[allow(unused_braces)] {#value}) })
+                })
+                .collect::<Vec<_>>();
+            let base = match plain.and_then(|attr| attr.value()) {
+                // only a plain attribute, which is passed through as is
+                Some(value) if toggles.is_empty() => {
+                    let name = &plain?.key;
+                    return Some(quote! { .#name(#[allow(unused_braces)] {#value}) });
+                }
+                None if toggles.is_empty() => return None,
+                Some(value) => quote! { #ty::from(#[allow(unused_braces)] {#value}) },
+                None => quote! { <#ty as ::std::default::Default>::default() },
+            };
+            let prop = Ident::new(prop, span);
+            Some(quote! { .#prop(#base #(#toggles)*) })
+        })
+        .collect::<Vec<_>>()
+    } else {
+        Vec::new()
+    };
+
     let props = attrs
         .clone()
         .filter(|attr| {
@@ -36,6 +78,7 @@ pub(crate) fn component_to_tokens(
                 && !attr.key.to_string().starts_with("on:")
                 && !attr.key.to_string().starts_with("attr:")
                 && !attr.key.to_string().starts_with("use:")
+                && !(has_toggles && is_class_or_style(&attr.key.to_string()))
         })
         .map(|attr| {
             let name = &attr.key;
@@ -109,11 +152,33 @@ pub(crate) fn component_to_tokens(
         })
         .collect::<Vec<_>>();
 
-    let dyn_attrs = if dyn_attrs.is_empty() {
-        quote! {}
-    } else {
-        quote! { .dyn_attrs(vec![#(#dyn_attrs),*]) }
-    };
+    // `{..attrs}` spreads a collection of attributes, which are forwarded
+    // like the `attr:` bindings
+    let spread_attrs = node
+        .attributes()
+        .iter()
+        .filter_map(|attr| {
+            use rstml::node::NodeBlock;
+            use syn::{Expr, ExprRange, RangeLimits, Stmt};
+
+            if let NodeAttribute::Block(NodeBlock::ValidBlock(block)) = attr {
+                match block.stmts.first()? {
+                    Stmt::Expr(
+                        Expr::Range(ExprRange {
+                            start: None,
+                            limits: RangeLimits::HalfOpen(_),
+                            end: Some(end),
+                            ..
+                        }),
+                        _,
+                    ) => Some(quote! { #[allow(unused_braces)] {#end} }),
+                    _ => None,
+                }
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
 
     let mut slots = HashMap::new();
     let children = if node.children.is_empty() {
@@ -189,17 +254,45 @@ pub(crate) fn component_to_tokens(
         quote! {}
     };
 
+    let props = quote! {
+        ::leptos::component_props_builder(&#name #generics)
+            #(#props)*
+            #(#class_and_style)*
+            #(#slots)*
+            #children
+            .build()
+    };
+
     #[allow(unused_mut)] // used in debug
-    let mut component = quote! {
-        ::leptos::component_view(
-            &#name,
-            ::leptos::component_props_builder(&#name #generics)
-                #(#props)*
-                #(#slots)*
-                #children
-                .build()
-                #dyn_attrs
-        )
+    let mut component = if dyn_attrs.is_empty() && spread_attrs.is_empty() {
+        quote! {
+            ::leptos::component_view(&#name, #props)
+        }
+    } else {
+        // `attr:` bindings go to `#[prop(attrs)]` if the component has one,
+        // and otherwise to its root element
+        let props_var = Ident::new("props", Span::mixed_site());
+        let attrs_var = Ident::new("attrs", Span::mixed_site());
+        quote! {
+            {
+                let #props_var = #props;
+                #[allow(unused_mut)]
+                let mut #attrs_var: ::std::vec::Vec<(&'static str, ::leptos::Attribute)> =
+                    ::std::vec![#(#dyn_attrs),*];
+                #(::std::iter::Extend::extend(&mut #attrs_var, #spread_attrs);)*
+                if ::leptos::DynAttrs::accepts_dyn_attrs(&#props_var) {
+                    ::leptos::IntoView::into_view(::leptos::component_view(
+                        &#name,
+                        ::leptos::DynAttrs::dyn_attrs(#props_var, #attrs_var),
+                    ))
+                } else {
+                    ::leptos::IntoView::into_view(
+                        ::leptos::component_view(&#name, #props_var),
+                    )
+                    .attrs(#attrs_var)
+                }
+            }
+        }
     };
 
     // (Temporarily?) removed
@@ -216,3 +309,10 @@ pub(crate) fn component_to_tokens(
         }
     }
 }
+
+fn is_class_or_style(name: &str) -> bool {
+    name == "class"
+        || name == "style"
+        || name.starts_with("class:")
+        || name.starts_with("style:")
+}
@@ -0,0 +1,234 @@
+use leptos_hot_reload::parsing::is_component_node;
+use proc_macro2::Span;
+use quote::quote_spanned;
+use rstml::node::{KeyedAttribute, KeyedAttributeValue, Node, NodeAttribute};
+use syn::{Expr, ExprLit, Lit, LitStr};
+
+/// Rewrites string attributes on elements that interpolate expressions, like
+/// `href="/users/{id}"`, into the equivalent `format!` call.
+///
+/// If any of the expressions might read a signal, the `format!` is wrapped in
+/// a `move ||` closure so that the attribute stays reactive. This is only done
+/// with the `attribute-interpolation` feature, because it changes what braces
+/// in existing strings mean.
+pub(crate) fn interpolate_attributes(nodes: &mut [Node]) {
+    for node in nodes {
+        match node {
+            Node::Element(el) => {
+                // components take props of many types, not only strings
+                if !is_component_node(el) {
+                    for attr in &mut el.open_tag.attributes {
+                        if let NodeAttribute::Attribute(attr) = attr {
+                            interpolate_attribute(attr);
+                        }
+                    }
+                }
+                interpolate_attributes(&mut el.children);
+            }
+            Node::Fragment(frag) => interpolate_attributes(&mut frag.children),
+            _ => {}
+        }
+    }
+}
+
+fn interpolate_attribute(attr: &mut KeyedAttribute) {
+    let KeyedAttributeValue::Value(value) = &mut attr.possible_value else {
+        return;
+    };
+    let Expr::Lit(ExprLit {
+        lit: Lit::Str(lit), ..
+    }) = &value.value
+    else {
+        return;
+    };
+
+    match parse_template(&lit.value()) {
+        Ok(segments) => {
+            if let Some(expr) = segments_to_expr(&segments, lit.span()) {
+                value.value = expr;
+            }
+        }
+        Err(message) => proc_macro_error::emit_error!(
+            lit.span(),
+            "{}; use `{{{{` and `}}}}` for literal braces",
+            message
+        ),
+    }
+}
+
+#[derive(Debug)]
+enum Segment {
+    Text(String),
+    Expr(Box<Expr>),
+}
+
+/// Splits a string into text and `{expr}` segments, with the same rules as
+/// `format!`: `{{` and `}}` are escaped braces, and any other brace has to
+/// start or end an expression. Plain text without any braces has nothing to
+/// rewrite, and returns no segments.
+fn parse_template(template: &str) -> Result<Vec<Segment>, String> {
+    if !template.contains(['{', '}']) {
+        return Ok(Vec::new());
+    }
+    let mut segments = Vec::new();
+    let mut text = String::new();
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                text.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                text.push('}');
+            }
+            '{' => {
+                let mut depth = 1;
+                let mut code = String::new();
+                for c in chars.by_ref() {
+                    match c {
+                        '{' => depth += 1,
+                        '}' => depth -= 1,
+                        _ => {}
+                    }
+                    if depth == 0 {
+                        break;
+                    }
+                    code.push(c);
+                }
+                if depth != 0 {
+                    return Err(format!("unclosed `{{` in `{template}`"));
+                }
+                let expr = syn::parse_str::<Expr>(&code).map_err(|_| {
+                    format!("`{{{code}}}` is not a Rust expression")
+                })?;
+                if !text.is_empty() {
+                    segments.push(Segment::Text(std::mem::take(&mut text)));
+                }
+                segments.push(Segment::Expr(Box::new(expr)));
+            }
+            '}' => return Err(format!("unmatched `}}` in `{template}`")),
+            c => text.push(c),
+        }
+    }
+    if !text.is_empty() {
+        segments.push(Segment::Text(text));
+    }
+    Ok(segments)
+}
+
+/// Returns the expression a template expands to, or `None` if there is
+/// nothing to rewrite.
+fn segments_to_expr(segments: &[Segment], span: Span) -> Option<Expr> {
+    match segments {
+        [] => return None,
+        [Segment::Text(text)] => {
+            let lit = LitStr::new(text, span);
+            return Some(syn::parse_quote_spanned! {span=> #lit });
+        }
+        _ => {}
+    }
+
+    let mut format_string = String::new();
+    let mut args = Vec::new();
+    for segment in segments {
+        match segment {
+            Segment::Text(text) => format_string
+                .push_str(&text.replace('{', "{{").replace('}', "}}")),
+            Segment::Expr(expr) => {
+                format_string.push_str("{}");
+                args.push(expr);
+            }
+        }
+    }
+    let format_string = LitStr::new(&format_string, span);
+
+    let tokens = if args.iter().any(|expr| may_be_reactive(expr)) {
+        quote_spanned! {span=>
+            move || ::std::format!(#format_string, #(#args),*)
+        }
+    } else {
+        quote_spanned! {span=>
+            ::std::format!(#format_string, #(#args),*)
+        }
+    };
+    Some(syn::parse2(tokens).expect("format! call to be a valid expression"))
+}
+
+/// Plain variables, fields, and operators on them can't read a signal, so
+/// they can be formatted once. Anything that calls a function might.
+fn may_be_reactive(expr: &Expr) -> bool {
+    match expr {
+        Expr::Path(_) | Expr::Lit(_) => false,
+        Expr::Field(field) => may_be_reactive(&field.base),
+        Expr::Paren(paren) => may_be_reactive(&paren.expr),
+        Expr::Reference(reference) => may_be_reactive(&reference.expr),
+        Expr::Unary(unary) => may_be_reactive(&unary.expr),
+        Expr::Binary(binary) => {
+            may_be_reactive(&binary.left) || may_be_reactive(&binary.right)
+        }
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_template, segments_to_expr, Segment};
+    use proc_macro2::Span;
+    use quote::ToTokens;
+
+    fn expand(template: &str) -> Result<Option<String>, String> {
+        parse_template(template).map(|segments| {
+            segments_to_expr(&segments, Span::call_site())
+                .map(|expr| expr.into_token_stream().to_string())
+        })
+    }
+
+    #[test]
+    fn splits_text_and_expressions() {
+        let segments = parse_template("/users/{id}/posts").unwrap();
+        assert!(matches!(
+            &segments[..],
+            [Segment::Text(a), Segment::Expr(_), Segment::Text(b)]
+                if a == "/users/" && b == "/posts"
+        ));
+    }
+
+    #[test]
+    fn plain_variables_are_formatted_once() {
+        assert_eq!(
+            expand("/users/{id}").unwrap().unwrap(),
+            ":: std :: format ! (\"/users/{}\" , id)"
+        );
+    }
+
+    #[test]
+    fn calls_are_wrapped_in_a_closure() {
+        assert_eq!(
+            expand("btn {{{kind.get()}}}").unwrap().unwrap(),
+            "move | | :: std :: format ! (\"btn {{{}}}\" , kind . get ())"
+        );
+    }
+
+    #[test]
+    fn escaped_braces_are_unescaped_everywhere() {
+        assert_eq!(expand("no braces").unwrap(), None);
+        assert_eq!(
+            expand("{{escaped}}").unwrap().as_deref(),
+            Some("\"{escaped}\"")
+        );
+        assert_eq!(
+            expand("[0-9]{{3}}").unwrap().as_deref(),
+            Some("\"[0-9]{3}\"")
+        );
+    }
+
+    #[test]
+    fn rejects_braces_that_are_not_expressions() {
+        assert!(expand("{ open: false }").is_err());
+        assert!(expand("unclosed {id").is_err());
+        assert!(expand("unmatched }").is_err());
+    }
+}
@@ -9,12 +9,14 @@ pub mod client_builder;
 pub mod client_template;
 pub mod component_builder;
 pub mod ide_helper;
+mod interpolation;
 pub mod server_template;
 pub mod slot_helper;
 #[cfg(test)]
 mod tests;
 
 pub(crate) use ide_helper::*;
+pub(crate) use interpolation::interpolate_attributes;
 
 pub(crate) fn render_view(
     nodes: &[Node],
@@ -30,7 +32,12 @@ pub(crate) fn render_view(
     };
 
     if mode == Mode::Ssr {
-        match nodes.len() {
+        // an interactive view is built from the same pieces as in the
+        // browser, so generate that code too, and choose while rendering
+        let client = cfg!(feature = "interactive-ssr").then(|| {
+            render_view(nodes, Mode::Client, global_class, call_site.clone())
+        });
+        let ssr = match nodes.len() {
             0 => empty,
             1 => server_template::root_node_to_tokens_ssr(
                 &nodes[0],
@@ -43,6 +50,16 @@ pub(crate) fn render_view(
                 global_class,
                 call_site,
             ),
+        };
+        match client {
+            Some(client) => quote! {
+                if ::leptos::leptos_dom::ssr_interactive::is_interactive() {
+                    #client
+                } else {
+                    #ssr
+                }
+            },
+            None => ssr,
         }
     } else {
         match nodes.len() {
@@ -201,7 +218,17 @@ const TYPED_EVENTS: [&str; 126] = [
 
 const CUSTOM_EVENT: &str = "Custom";
 
-pub(crate) fn parse_event_name(name: &str) -> (TokenStream, bool, bool) {
+/// Returns the type of the event listened to by an `on:` attribute, whether it
+/// is a custom event, and whether the listener must be undelegated.
+///
+/// A name that is a single word must be one of the events in `leptos::ev`, so
+/// that a misspelled event is a compile error rather than a listener for an
+/// event that never fires. Custom events are named like `my-event` or
+/// `custom.event`.
+pub(crate) fn parse_event_name(
+    name: &str,
+    key: &NodeName,
+) -> (TokenStream, bool, bool) {
     let (name, is_force_undelegated) = parse_event(name);
 
     let (event_type, is_custom) = TYPED_EVENTS
@@ -209,6 +236,17 @@ pub(crate) fn parse_event_name(name: &str) -> (TokenStream, bool, bool) {
         .map(|_| (name, false))
         .unwrap_or((CUSTOM_EVENT, true));
 
+    if is_custom && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        abort!(
+            key, "unknown event `{}`", name;
+            help = "the events that can be listened to by name are those in \
+                    `leptos::ev`. Custom events must have a `-` or a `.` in \
+                    their name, or be listened to with \
+                    `.on(ev::Custom::new(\"{}\"), handler)`", name
+        );
+    }
+
     let Ok(event_type) = event_type.parse::<TokenStream>() else {
         abort!(event_type, "couldn't parse event name");
     };
@@ -384,12 +422,64 @@ fn is_ambiguous_element(tag: &str) -> bool {
     tag == "a" || tag == "script" || tag == "title"
 }
 
-fn parse_event(event_name: &str) -> (&str, bool) {
-    if let Some(event_name) = event_name.strip_suffix(":undelegated") {
-        (event_name, true)
-    } else {
-        (event_name, false)
+/// The modifiers after an event name, like `on:scroll:passive`.
+#[derive(Default)]
+struct EventModifiers {
+    undelegated: bool,
+    capture: bool,
+    passive: bool,
+    once: bool,
+}
+
+fn parse_event_modifiers(event_name: &str) -> (&str, EventModifiers) {
+    let mut modifiers = EventModifiers::default();
+    let mut event_name = event_name;
+    while let Some((name, modifier)) = event_name.rsplit_once(':') {
+        match modifier {
+            "undelegated" => modifiers.undelegated = true,
+            "capture" => modifiers.capture = true,
+            "passive" => modifiers.passive = true,
+            "once" => modifiers.once = true,
+            _ => break,
+        }
+        event_name = name;
     }
+    (event_name, modifiers)
+}
+
+/// Returns the event name without its modifiers, and whether they force the
+/// listener to be undelegated: listener options only apply to the element's
+/// own listener.
+fn parse_event(event_name: &str) -> (&str, bool) {
+    let (event_name, modifiers) = parse_event_modifiers(event_name);
+    let EventModifiers {
+        undelegated,
+        capture,
+        passive,
+        once,
+    } = modifiers;
+    (event_name, undelegated || capture || passive || once)
+}
+
+/// Returns the `EventOptions` for the `capture`, `passive` and `once` modifiers
+/// of an event, if it has any.
+pub(crate) fn event_options(event_name: &str) -> Option<TokenStream> {
+    let (_, modifiers) = parse_event_modifiers(event_name);
+    let EventModifiers {
+        capture,
+        passive,
+        once,
+        ..
+    } = modifiers;
+    (capture || passive || once).then(|| {
+        quote! {
+            ::leptos::ev::EventOptions {
+                capture: #capture,
+                passive: #passive,
+                once: #once,
+            }
+        }
+    })
 }
 
 fn fancy_class_name<'a>(
@@ -520,9 +610,12 @@ pub(crate) fn event_from_attribute_node(
 
     let handler = attribute_value(attr);
 
-    let (event_type, _, name_undelegated) = parse_event_name(&event_name);
+    let (event_type, _, name_undelegated) =
+        parse_event_name(&event_name, &attr.key);
 
-    let event_type = if force_undelegated || name_undelegated {
+    let event_type = if let Some(options) = event_options(&event_name) {
+        quote! { ::leptos::leptos_dom::ev::WithOptions::new(::leptos::leptos_dom::ev::#event_type, #options) }
+    } else if force_undelegated || name_undelegated {
         quote! { ::leptos::leptos_dom::ev::undelegated(::leptos::leptos_dom::ev::#event_type) }
     } else {
         quote! { ::leptos::leptos_dom::ev::#event_type }
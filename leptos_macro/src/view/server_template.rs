@@ -283,10 +283,9 @@ fn element_to_tokens_ssr(
                     template.push_str(" {}");
                     holes.push(quote! {
                         {#end}.into_iter().filter_map(|(name, attr)| {
-                           Some(::std::format!(
-                                "{}=\"{}\"",
-                                name,
-                                ::leptos::leptos_dom::ssr::escape_attr(&attr.as_nameless_value_string()?)
+                           Some(::leptos::leptos_dom::ssr::attribute_to_string(
+                                &name,
+                                &attr.as_nameless_value_string()?
                             ))
                         }).collect::<::std::vec::Vec<_>>().join(" ")
                     });
@@ -302,14 +301,15 @@ fn element_to_tokens_ssr(
         };
         template.push_str("{}");
         holes.push(quote! {
-            #hydration_id.map(|id| ::std::format!(" data-hk=\"{id}\"")).unwrap_or_default()
+            #hydration_id.map(|id| ::std::format!(" data-hk=\"{}\"", id.to_html())).unwrap_or_default()
         });
 
         set_class_attribute_ssr(node, template, holes, global_class);
         set_style_attribute_ssr(node, template, holes);
 
         if is_self_closing(node) {
-            template.push_str("/>");
+            template.push_str("{}");
+            holes.push(quote! { ::leptos::leptos_dom::ssr::void_tag_end() });
         } else {
             template.push('>');
 
@@ -411,7 +411,7 @@ fn attribute_to_tokens_ssr<'a>(
         // ignore refs on SSR
     } else if let Some(name) = name.strip_prefix("on:") {
         let handler = attribute_value(attr);
-        let (event_type, _, _) = parse_event_name(name);
+        let (event_type, _, _) = parse_event_name(name, &attr.key);
 
         exprs_for_compiler.push(quote! {
             ::leptos::leptos_dom::helpers::ssr_event_listener(::leptos::ev::#event_type, #handler);
@@ -451,22 +451,22 @@ fn attribute_to_tokens_ssr<'a>(
 
             if let Some(value) = attr.value() {
                 if let Some(value) = value_to_string(value) {
+                    let can_omit_quotes = !attribute_needs_quotes(&value);
                     template.push_str(&name);
-                    template.push_str("=\"");
-                    template.push_str(&html_escape::encode_quoted_attribute(
-                        &value,
-                    ));
-                    template.push('"');
+                    template.push('=');
+                    push_attribute_quote(template, holes, can_omit_quotes);
+                    template.push_str(
+                        &html_escape::encode_quoted_attribute(&value)
+                            .replace('{', "\\{")
+                            .replace('}', "\\}"),
+                    );
+                    push_attribute_quote(template, holes, can_omit_quotes);
                 } else {
                     template.push_str("{}");
                     holes.push(quote! {
                         &::leptos::IntoAttribute::into_attribute(#[allow(unused_braces)] {#value})
                             .as_nameless_value_string()
-                            .map(|a| ::std::format!(
-                                "{}=\"{}\"",
-                                #name,
-                                ::leptos::leptos_dom::ssr::escape_attr(&a)
-                            ))
+                            .map(|a| ::leptos::leptos_dom::ssr::attribute_to_string(#name, &a))
                             .unwrap_or_default()
                     })
                 }
@@ -579,15 +579,32 @@ fn set_class_attribute_ssr(
         || !class_attrs.is_empty()
         || dyn_global_class.is_some()
     {
-        template.push_str(" class=\"");
+        let can_omit_quotes = dyn_class_attr.is_empty()
+            && class_attrs.is_empty()
+            && dyn_global_class.is_none()
+            && !attribute_needs_quotes(&static_class_attr);
+        template.push_str(" class=");
+        push_attribute_quote(template, holes, can_omit_quotes);
+
+        template.push_str(
+            &html_escape::encode_quoted_attribute(&static_class_attr)
+                .replace('{', "\\{")
+                .replace('}', "\\}"),
+        );
 
-        template.push_str(&html_escape::encode_quoted_attribute(
-            &static_class_attr,
-        ));
+        // the parts are separated by spaces, without one before the first,
+        // as in the browser, where the class is set to the value itself
+        let mut separator = if static_class_attr.is_empty() {
+            ""
+        } else {
+            " "
+        };
 
         for (_span, value) in dyn_class_attr {
             if let Some(value) = value {
-                template.push_str(" {}");
+                template.push_str(separator);
+                separator = " ";
+                template.push_str("{}");
                 holes.push(quote! {
                   &::leptos::IntoAttribute::into_attribute(#value).as_nameless_value_string()
                     .map(|a| ::leptos::leptos_dom::ssr::escape_attr(&a).to_string())
@@ -597,18 +614,21 @@ fn set_class_attribute_ssr(
         }
 
         for (_span, name, value) in &class_attrs {
-            template.push_str(" {}");
+            template.push_str(separator);
+            separator = " ";
+            template.push_str("{}");
             holes.push(quote! {
                 ::leptos::IntoClass::into_class(#value).as_value_string(#name)
             });
         }
 
         if let Some(dyn_global_class) = dyn_global_class {
-            template.push_str(" {}");
+            template.push_str(separator);
+            template.push_str("{}");
             holes.push(quote! { #dyn_global_class });
         }
 
-        template.push('"');
+        push_attribute_quote(template, holes, can_omit_quotes);
     }
 }
 
@@ -692,9 +712,14 @@ fn set_style_attribute_ssr(
         || !dyn_style_attr.is_empty()
         || !style_attrs.is_empty()
     {
-        template.push_str(" style=\"");
+        let static_style_attr = static_style_attr.unwrap_or_default();
+        let can_omit_quotes = dyn_style_attr.is_empty()
+            && style_attrs.is_empty()
+            && !attribute_needs_quotes(&static_style_attr);
+        template.push_str(" style=");
+        push_attribute_quote(template, holes, can_omit_quotes);
 
-        template.push_str(&static_style_attr.unwrap_or_default());
+        template.push_str(&static_style_attr);
 
         for (_span, value) in dyn_style_attr {
             if let Some(value) = value {
@@ -714,6 +739,28 @@ fn set_style_attribute_ssr(
             });
         }
 
-        template.push('"');
+        push_attribute_quote(template, holes, can_omit_quotes);
     }
 }
+
+/// Writes the quote around an attribute value, which is chosen for each render.
+fn push_attribute_quote(
+    template: &mut String,
+    holes: &mut Vec<TokenStream>,
+    can_omit: bool,
+) {
+    template.push_str("{}");
+    holes.push(quote! {
+        ::leptos::leptos_dom::ssr::attribute_quote(#can_omit)
+    });
+}
+
+/// Whether an attribute value can't be written without quotes, as in
+/// `leptos_dom::ssr::attribute_needs_quotes`.
+fn attribute_needs_quotes(value: &str) -> bool {
+    value.is_empty()
+        || value.contains(|c: char| {
+            c.is_ascii_whitespace()
+                || matches!(c, '"' | '\'' | '=' | '<' | '>' | '`')
+        })
+}
@@ -117,3 +117,17 @@ for_all_modes! {
         <ExternalComponent on:custom.event.clear=move |_: Event| set_value(0) />
     "#
 }
+
+#[test]
+fn static_subtrees_are_cloned_from_a_template() {
+    assert_snapshot!(client_builder(text) => r#"
+        <main>
+            <header class="top" class:dark=dark>
+                <h1>"Tom & Jerry"</h1>
+                <input type="text" disabled on:input=move |_| {}/>
+                <span>"Value: " {value} "!"</span>
+            </header>
+            <p>"Value: " {value}</p>
+        </main>
+    "#)
+}
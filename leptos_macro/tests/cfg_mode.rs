@@ -0,0 +1,27 @@
+use leptos::{cfg_csr, cfg_hydrate, cfg_ssr};
+
+#[cfg_csr]
+const MODE: &str = "csr";
+
+#[cfg_hydrate]
+const MODE: &str = "hydrate";
+
+#[cfg_ssr]
+const MODE: &str = "ssr";
+
+#[cfg(not(any(feature = "csr", feature = "hydrate", feature = "ssr")))]
+const MODE: &str = "none";
+
+#[test]
+fn cfg_macros_follow_the_mode() {
+    let expected = if cfg!(feature = "ssr") {
+        "ssr"
+    } else if cfg!(feature = "hydrate") {
+        "hydrate"
+    } else if cfg!(feature = "csr") {
+        "csr"
+    } else {
+        "none"
+    };
+    assert_eq!(MODE, expected);
+}
@@ -25,3 +25,30 @@ fn component() {
     assert_eq!(cp.default, NonZeroUsize::new(10).unwrap());
     assert_eq!(cp.into, "");
 }
+
+#[test]
+fn component_props_metadata() {
+    assert_eq!(ComponentProps::COMPONENT, "Component");
+    let props = ComponentProps::PROPS
+        .iter()
+        .map(|prop| {
+            (prop.name, prop.ty, prop.optional, prop.default, prop.into)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        props,
+        [
+            ("optional", "bool", true, None, false),
+            ("optional_no_strip", "Option<String>", true, None, false),
+            ("strip_option", "u8", false, None, false),
+            (
+                "default",
+                "NonZeroUsize",
+                true,
+                Some("NonZeroUsize::new(10).unwrap()"),
+                false
+            ),
+            ("into", "String", false, None, true),
+        ]
+    );
+}
@@ -0,0 +1,133 @@
+//! Counts heap allocations, so that the allocations made while effects run
+//! can be reported. Enabled by the `alloc-profiling` feature.
+//!
+//! Apps without a garbage collector, like most WASM apps, can spend much of
+//! their time in the allocator, and the allocations made by a single update
+//! are hard to see from the outside. To count them, wrap the app's allocator
+//! (the system allocator, or one like `wee_alloc`) in a [`CountingAllocator`]:
+//!
+//! ```
+//! use leptos_reactive::{
+//!     alloc_profiling::{AllocationStats, CountingAllocator},
+//!     *,
+//! };
+//!
+//! #[global_allocator]
+//! static ALLOC: CountingAllocator<std::alloc::System> =
+//!     CountingAllocator::new(std::alloc::System);
+//!
+//! # let runtime = create_runtime();
+//! let before = AllocationStats::current();
+//! let list = vec![1, 2, 3];
+//! assert!(AllocationStats::current().since(before).allocations >= 1);
+//! # drop(list);
+//! # runtime.dispose();
+//! ```
+//!
+//! Each time pending effects are flushed, a `tracing` event is then emitted at
+//! the `DEBUG` level with target `leptos_reactive::alloc_profiling`,
+//! recording the number of effects that ran and the allocations and bytes
+//! allocated while they ran. A flush that is triggered from inside an effect
+//! is also counted as part of the flush that ran that effect.
+//!
+//! The counters are shared by all threads, so the counts are only reliable when
+//! nothing else is allocating at the same time, as in a browser or a benchmark.
+
+use std::{
+    alloc::{GlobalAlloc, Layout},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// A global allocator that counts the allocations made through the allocator
+/// it wraps.
+///
+/// Reallocations are counted as allocations, because they usually move the
+/// data to a new allocation.
+#[derive(Debug, Default)]
+pub struct CountingAllocator<A> {
+    inner: A,
+}
+
+impl<A> CountingAllocator<A> {
+    /// Wraps an allocator.
+    pub const fn new(inner: A) -> Self {
+        Self { inner }
+    }
+}
+
+// SAFETY: every method forwards to the wrapped allocator, with the same
+// arguments, and only updates the counters besides.
+unsafe impl<A: GlobalAlloc> GlobalAlloc for CountingAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record(layout.size());
+        self.inner.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        record(layout.size());
+        self.inner.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(
+        &self,
+        ptr: *mut u8,
+        layout: Layout,
+        new_size: usize,
+    ) -> *mut u8 {
+        record(new_size);
+        self.inner.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.inner.dealloc(ptr, layout)
+    }
+}
+
+#[inline(always)]
+fn record(bytes: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    BYTES.fetch_add(bytes, Ordering::Relaxed);
+}
+
+/// The number of allocations made through a [`CountingAllocator`], and the
+/// number of bytes they requested.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AllocationStats {
+    /// The number of allocations and reallocations.
+    pub allocations: usize,
+    /// The total size of those allocations, in bytes.
+    pub bytes: usize,
+}
+
+impl AllocationStats {
+    /// The totals since the program started.
+    pub fn current() -> Self {
+        Self {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed),
+            bytes: BYTES.load(Ordering::Relaxed),
+        }
+    }
+
+    /// The allocations made between `earlier` and these totals.
+    pub fn since(self, earlier: Self) -> Self {
+        Self {
+            allocations: self.allocations.wrapping_sub(earlier.allocations),
+            bytes: self.bytes.wrapping_sub(earlier.bytes),
+        }
+    }
+}
+
+pub(crate) fn report_flush(effects: usize, before: AllocationStats) {
+    let AllocationStats { allocations, bytes } =
+        AllocationStats::current().since(before);
+    tracing::debug!(
+        target: "leptos_reactive::alloc_profiling",
+        effects,
+        allocations,
+        bytes,
+        "flushed effects"
+    );
+}
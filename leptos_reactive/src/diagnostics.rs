@@ -1,6 +1,14 @@
 // The point of these diagnostics is to give useful error messages when someone
 // tries to access a reactive variable outside the reactive scope. They track when
 // you create a signal/memo, and where you access it non-reactively.
+//
+// In debug mode, they also remember which signal, memo, or trigger marked a node
+// dirty, so an effect can ask why it's running with `why_did_this_run()`.
+
+use crate::Oco;
+#[cfg(debug_assertions)]
+use std::any::Any;
+use std::{fmt, panic::Location};
 
 #[cfg(debug_assertions)]
 #[allow(dead_code)] // allowed for SSR
@@ -76,3 +84,143 @@ macro_rules! diagnostics {
         }
     }};
 }
+
+/// Describes the signal, memo, or trigger whose change caused the running effect or memo
+/// to re-run. Returned by [`why_did_this_run`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RunCause {
+    /// The kind of reactive node that changed: `"signal"`, `"memo"`, or `"trigger"`.
+    pub source: &'static str,
+    /// Where the node that changed was created.
+    pub defined_at: &'static Location<'static>,
+    /// The [`Debug`](std::fmt::Debug) representation of the value before the change, if the
+    /// node records its changes. See [`ReadSignal::debug_changes`](crate::ReadSignal::debug_changes).
+    pub before: Option<String>,
+    /// The [`Debug`](std::fmt::Debug) representation of the value after the change, if the
+    /// node records its changes.
+    pub after: Option<String>,
+}
+
+impl fmt::Display for RunCause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} created at {} changed", self.source, self.defined_at)?;
+        match (&self.before, &self.after) {
+            (Some(before), Some(after)) => {
+                write!(f, " from {before} to {after}")
+            }
+            (None, Some(after)) => write!(f, " to {after}"),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Returns the signal, memo, or trigger whose change caused the running effect or memo to
+/// re-run.
+///
+/// Returns `None` the first time an effect or memo runs, outside of any effect or memo, and
+/// in release builds, where the causes are not recorded.
+/// ```
+/// # use leptos_reactive::*;
+/// # let runtime = create_runtime();
+/// let (count, set_count) = create_signal(0);
+/// let count = count.debug_changes();
+///
+/// create_isomorphic_effect(move |_| {
+///     count.track();
+///     if let Some(cause) = why_did_this_run() {
+///         // "signal created at src/app.rs:3:30 changed from 0 to 1"
+///         println!("{cause}");
+///         # if cfg!(debug_assertions) {
+///         #     assert_eq!(cause.before.as_deref(), Some("0"));
+///         #     assert_eq!(cause.after.as_deref(), Some("1"));
+///         # }
+///     }
+/// });
+/// set_count.set(1);
+/// # runtime.dispose();
+/// ```
+pub fn why_did_this_run() -> Option<RunCause> {
+    #[cfg(debug_assertions)]
+    {
+        crate::with_runtime(|runtime| runtime.run_cause.borrow().clone())
+            .ok()
+            .flatten()
+    }
+    #[cfg(not(debug_assertions))]
+    {
+        None
+    }
+}
+
+/// Formats the value of a node, if it has one.
+#[cfg(debug_assertions)]
+pub(crate) type FormatValue = fn(&dyn Any) -> Option<String>;
+
+/// What a [`ReactiveNode`](crate::node::ReactiveNode) remembers in debug mode to explain
+/// why its subscribers re-run.
+#[cfg(debug_assertions)]
+#[derive(Clone)]
+pub(crate) struct NodeDiagnostics {
+    pub defined_at: &'static Location<'static>,
+    pub format_value: Option<FormatValue>,
+    /// The value before and after the node last changed.
+    pub last_change: Option<(Option<String>, Option<String>)>,
+    /// The change that last marked this node dirty.
+    pub dirty_cause: Option<RunCause>,
+    /// The component that created this node, for [`runtime_stats`](crate::stats::runtime_stats).
+    pub component: Option<Oco<'static, str>>,
+}
+
+#[cfg(debug_assertions)]
+impl NodeDiagnostics {
+    pub fn new(defined_at: &'static Location<'static>) -> Self {
+        Self {
+            defined_at,
+            format_value: None,
+            last_change: None,
+            dirty_cause: None,
+            component: CURRENT_COMPONENT
+                .with(|current| current.borrow().clone()),
+        }
+    }
+}
+
+#[cfg(debug_assertions)]
+thread_local! {
+    static CURRENT_COMPONENT: std::cell::RefCell<Option<Oco<'static, str>>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+/// Marks the nodes created until it is dropped as created by a component.
+#[cfg(debug_assertions)]
+pub(crate) struct ComponentGuard(Option<Oco<'static, str>>);
+
+#[cfg(debug_assertions)]
+impl ComponentGuard {
+    pub fn enter(component: Option<Oco<'static, str>>) -> Self {
+        Self(CURRENT_COMPONENT.with(|current| current.replace(component)))
+    }
+}
+
+#[cfg(debug_assertions)]
+impl Drop for ComponentGuard {
+    fn drop(&mut self) {
+        let prev = self.0.take();
+        CURRENT_COMPONENT.with(|current| *current.borrow_mut() = prev);
+    }
+}
+
+/// Runs the body of the component `name`, so that the reactive nodes it creates, and the
+/// nodes created by its effects and memos when they re-run, are counted for it by
+/// [`runtime_stats`](crate::stats::runtime_stats). This does nothing in release builds.
+#[doc(hidden)]
+pub fn with_component_name<T>(
+    name: &Oco<'static, str>,
+    f: impl FnOnce() -> T,
+) -> T {
+    #[cfg(debug_assertions)]
+    let _component = ComponentGuard::enter(Some(name.clone()));
+    #[cfg(not(debug_assertions))]
+    let _ = name;
+    f()
+}
@@ -74,6 +74,11 @@ where
 
             let runtime = Runtime::current();
             let owner = Owner::current();
+            #[cfg(debug_assertions)]
+            let f = crate::strict_mode::run_first_twice(
+                f,
+                std::panic::Location::caller(),
+            );
             let id = runtime.create_effect(f);
 
             queue_microtask(move || {
@@ -250,6 +255,9 @@ where
     T: 'static,
 {
     let runtime = Runtime::current();
+    #[cfg(debug_assertions)]
+    let f =
+        crate::strict_mode::run_first_twice(f, std::panic::Location::caller());
     let id = runtime.create_effect(f);
     //crate::macros::debug_warn!("creating effect {e:?}");
     _ = with_runtime(|runtime| {
@@ -276,6 +284,7 @@ where
     )
 )]
 #[inline(always)]
+#[track_caller]
 pub fn create_render_effect<T>(
     f: impl Fn(Option<T>) -> T + 'static,
 ) -> Effect<T>
@@ -80,6 +80,8 @@ extern crate tracing;
 
 #[macro_use]
 mod signal;
+#[cfg(feature = "alloc-profiling")]
+pub mod alloc_profiling;
 pub mod callback;
 mod context;
 #[macro_use]
@@ -103,16 +105,21 @@ mod serialization;
 mod signal_wrappers_read;
 mod signal_wrappers_write;
 mod slice;
+pub mod slow_effects;
 mod spawn;
 mod spawn_microtask;
+pub mod stats;
 mod stored_value;
+pub mod strict_mode;
 pub mod suspense;
 mod trigger;
 mod watch;
 
 pub use callback::*;
 pub use context::*;
-pub use diagnostics::SpecialNonReactiveZone;
+pub use diagnostics::{
+    why_did_this_run, with_component_name, RunCause, SpecialNonReactiveZone,
+};
 pub use effect::*;
 pub use hydration::{FragmentData, SharedContext};
 pub use memo::*;
@@ -122,7 +129,7 @@ pub use resource::*;
 use runtime::*;
 pub use runtime::{
     as_child_of_current_owner, batch, create_runtime, current_runtime,
-    on_cleanup, run_as_child, set_current_runtime,
+    on_cleanup, queue_after_effects, run_as_child, set_current_runtime,
     spawn_local_with_current_owner, spawn_local_with_owner,
     try_spawn_local_with_current_owner, try_spawn_local_with_owner,
     try_with_owner, untrack, untrack_with_diagnostics, with_current_owner,
@@ -141,9 +148,24 @@ pub use suspense::{GlobalSuspenseContext, SuspenseContext};
 pub use trigger::*;
 pub use watch::*;
 
+#[cfg(debug_assertions)]
+pub(crate) fn console_log(s: &str) {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "slim")] {
+            let _ = s;
+        } else if #[cfg(all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate")))] {
+            web_sys::console::log_1(&wasm_bindgen::JsValue::from_str(s));
+        } else {
+            eprintln!("{s}");
+        }
+    }
+}
+
 pub(crate) fn console_warn(s: &str) {
     cfg_if::cfg_if! {
-        if #[cfg(all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate")))] {
+        if #[cfg(feature = "slim")] {
+            let _ = s;
+        } else if #[cfg(all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate")))] {
             web_sys::console::warn_1(&wasm_bindgen::JsValue::from_str(s));
         } else {
             eprintln!("{s}");
@@ -166,6 +166,22 @@ where
     pub(crate) defined_at: &'static std::panic::Location<'static>,
 }
 
+impl<T: fmt::Debug + 'static> Memo<T> {
+    /// In debug builds, records the [`Debug`](fmt::Debug) representation of the value before
+    /// and after each change, so that [`why_did_this_run`](crate::why_did_this_run) can show
+    /// how it changed. Does nothing in release builds.
+    pub fn debug_changes(self) -> Self {
+        #[cfg(debug_assertions)]
+        self.id.set_format_value(|value| {
+            value
+                .downcast_ref::<Option<T>>()?
+                .as_ref()
+                .map(|value| format!("{value:?}"))
+        });
+        self
+    }
+}
+
 impl<T> Memo<T> {
     /// Creates a new memo from the given function.
     ///
@@ -276,21 +292,22 @@ impl<T: Clone> SignalGetUntracked for Memo<T> {
         )
     )]
     fn get_untracked(&self) -> T {
-        with_runtime(move |runtime| {
-            let f = |maybe_value: &Option<T>| {
-                maybe_value
-                    .clone()
-                    .expect("invariant: must have already been initialized")
-            };
-            match self.id.try_with_no_subscription(runtime, f) {
-                Ok(t) => t,
-                Err(_) => panic_getting_dead_memo(
-                    #[cfg(any(debug_assertions, feature = "ssr"))]
-                    self.defined_at,
-                ),
-            }
+        let f = |maybe_value: &Option<T>| {
+            maybe_value
+                .clone()
+                .expect("invariant: must have already been initialized")
+        };
+        match with_runtime(|runtime| {
+            self.id.try_with_no_subscription(runtime, f)
         })
         .expect("runtime to be alive")
+        {
+            Ok(t) => t,
+            Err(_) => panic_getting_dead_memo(
+                #[cfg(any(debug_assertions, feature = "ssr"))]
+                self.defined_at,
+            ),
+        }
     }
 
     #[cfg_attr(
@@ -329,16 +346,17 @@ impl<T> SignalWithUntracked for Memo<T> {
         )
     )]
     fn with_untracked<O>(&self, f: impl FnOnce(&T) -> O) -> O {
-        with_runtime(|runtime| {
-            match self.id.try_with_no_subscription(runtime, forward_ref_to(f)) {
-                Ok(t) => t,
-                Err(_) => panic_getting_dead_memo(
-                    #[cfg(any(debug_assertions, feature = "ssr"))]
-                    self.defined_at,
-                ),
-            }
+        match with_runtime(|runtime| {
+            self.id.try_with_no_subscription(runtime, forward_ref_to(f))
         })
         .expect("runtime to be alive")
+        {
+            Ok(t) => t,
+            Err(_) => panic_getting_dead_memo(
+                #[cfg(any(debug_assertions, feature = "ssr"))]
+                self.defined_at,
+            ),
+        }
     }
 
     #[cfg_attr(
@@ -582,7 +600,7 @@ fn format_memo_warning(
     let defined_at_msg = {
         #[cfg(any(debug_assertions, feature = "ssr"))]
         {
-            format!("signal created here: {defined_at}\n")
+            format!("memo created here: {defined_at}\n")
         }
 
         #[cfg(not(any(debug_assertions, feature = "ssr")))]
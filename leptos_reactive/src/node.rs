@@ -1,3 +1,5 @@
+#[cfg(debug_assertions)]
+use crate::diagnostics::NodeDiagnostics;
 use crate::{with_runtime, AnyComputation};
 use std::{any::Any, cell::RefCell, rc::Rc};
 
@@ -25,6 +27,8 @@ pub(crate) struct ReactiveNode {
     pub value: Option<Rc<RefCell<dyn Any>>>,
     pub state: ReactiveNodeState,
     pub node_type: ReactiveNodeType,
+    #[cfg(debug_assertions)]
+    pub diagnostics: NodeDiagnostics,
 }
 
 impl ReactiveNode {
@@ -80,6 +80,27 @@ impl<'a, T: ?Sized + ToOwned> Oco<'a, T> {
     pub const fn is_owned(&self) -> bool {
         matches!(self, Oco::Owned(_))
     }
+
+    /// Checks if both values point to the same data, like two
+    /// [`Oco::Borrowed`] values created from the same `&'static str`, or
+    /// two clones of one [`Oco::Counted`].
+    ///
+    /// This is much cheaper than comparing the contents, so it can be used as
+    /// a fast path before a full comparison: if it returns `true`, the values
+    /// are equal, but if it returns `false`, they still might be.
+    /// # Examples
+    /// ```
+    /// # use std::rc::Rc;
+    /// # use leptos_reactive::oco::Oco;
+    /// static CLASS: &str = "active";
+    /// assert!(Oco::<str>::Borrowed(CLASS).ptr_eq(&Oco::Borrowed(CLASS)));
+    /// let counted = Oco::<str>::Counted(Rc::from("active"));
+    /// assert!(counted.ptr_eq(&counted.clone()));
+    /// assert!(!counted.ptr_eq(&Oco::Owned("active".to_string())));
+    /// ```
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        std::ptr::eq::<T>(&**self, &**other)
+    }
 }
 
 impl<T: ?Sized + ToOwned> Deref for Oco<'_, T> {
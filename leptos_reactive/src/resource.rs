@@ -4,19 +4,25 @@ use crate::SharedContext;
 use crate::SpecialNonReactiveZone;
 use crate::{
     create_isomorphic_effect, create_memo, create_render_effect, create_signal,
-    queue_microtask, runtime::with_runtime, serialization::Serializable,
-    signal_prelude::format_signal_warning, spawn::spawn_local,
-    suspense::LocalStatus, use_context, GlobalSuspenseContext, Memo,
+    queue_microtask,
+    runtime::{run_as_child_with_disposer, with_runtime},
+    serialization::Serializable,
+    signal_prelude::format_signal_warning,
+    spawn::spawn_local,
+    suspense::LocalStatus,
+    use_context, with_owner, Disposer, GlobalSuspenseContext, Memo, Owner,
     ReadSignal, ScopeProperty, Signal, SignalDispose, SignalGet,
     SignalGetUntracked, SignalSet, SignalUpdate, SignalWith, SuspenseContext,
     WriteSignal,
 };
+use rustc_hash::FxHashMap;
 use std::{
-    any::Any,
+    any::{Any, TypeId},
     cell::{Cell, RefCell},
     collections::HashSet,
     fmt::Debug,
     future::Future,
+    hash::Hash,
     marker::PhantomData,
     panic::Location,
     pin::Pin,
@@ -94,6 +100,7 @@ use std::{
         )
     )
 )]
+#[track_caller]
 pub fn create_resource<S, T, Fu>(
     source: impl Fn() -> S + 'static,
     fetcher: impl Fn(S) -> Fu + 'static,
@@ -257,6 +264,154 @@ where
     }
 }
 
+/// Creates a _coalesced_ [`Resource`](crate::Resource), which is shared by
+/// every caller that uses the same `key` at the same time.
+///
+/// If several components each create a resource for the same data during a
+/// single server render (or client render), the fetcher would normally run
+/// once per component, and each result would be serialized separately.
+/// Resources created with the same `key` instead share a single [`Resource`]:
+/// the first caller’s `source` and `fetcher` are used to create it, and every
+/// later caller receives the same handle, so the [`Future`] runs once and is
+/// sent to the client in one serialized payload.
+///
+/// Callers with the same `key` are expected to describe the same load. The
+/// shared resource is owned by the root of the reactive tree, so it is not
+/// tied to whichever component created it first. It is disposed once every
+/// component that created it has been cleaned up, and a later call with the
+/// same key will create a new one.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # let runtime = create_runtime();
+/// async fn fetch_user(id: u32) -> String {
+///     format!("user {id}")
+/// }
+///
+/// # if false {
+/// let user_id = 42;
+/// // in the header
+/// let user = create_coalesced_resource(
+///     ("user", user_id),
+///     move || user_id,
+///     fetch_user,
+/// );
+/// // in the sidebar: shares the same future and the same payload
+/// let same_user = create_coalesced_resource(
+///     ("user", user_id),
+///     move || user_id,
+///     fetch_user,
+/// );
+/// assert_eq!(user, same_user);
+/// # }
+/// # runtime.dispose();
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature="ssr"),
+    instrument(
+        level = "debug",
+        skip_all,
+        fields(
+            ty = %std::any::type_name::<T>(),
+            signal_ty = %std::any::type_name::<S>(),
+        )
+    )
+)]
+#[track_caller]
+pub fn create_coalesced_resource<K, S, T, Fu>(
+    key: K,
+    source: impl Fn() -> S + 'static,
+    fetcher: impl Fn(S) -> Fu + 'static,
+) -> Resource<S, T>
+where
+    K: Eq + Hash + Clone + 'static,
+    S: PartialEq + Clone + 'static,
+    T: Serializable + 'static,
+    Fu: Future<Output = T> + 'static,
+{
+    let existing = with_runtime(|runtime| {
+        runtime
+            .coalesced_resources
+            .borrow_mut()
+            .get_mut(&TypeId::of::<CoalescedResources<K, S, T>>())
+            .and_then(|map| map.downcast_mut::<CoalescedResources<K, S, T>>())
+            .and_then(|map| map.get_mut(&key))
+            .map(|entry| {
+                entry.users += 1;
+                entry.resource
+            })
+    })
+    .expect("tried to create a Resource in a Runtime that has been disposed.");
+
+    let resource = match existing {
+        Some(resource) => resource,
+        None => {
+            let root =
+                with_runtime(|runtime| runtime.root_owner()).ok().flatten();
+            let create = move || {
+                run_as_child_with_disposer(move || {
+                    create_resource(source, fetcher)
+                })
+            };
+            let (resource, disposer) = match root {
+                Some(root) => with_owner(Owner(root), create),
+                None => create(),
+            };
+            _ = with_runtime(|runtime| {
+                runtime
+                    .coalesced_resources
+                    .borrow_mut()
+                    .entry(TypeId::of::<CoalescedResources<K, S, T>>())
+                    .or_insert_with(|| {
+                        Box::<CoalescedResources<K, S, T>>::default()
+                    })
+                    .downcast_mut::<CoalescedResources<K, S, T>>()
+                    .expect("coalesced resources to have the right type")
+                    .insert(
+                        key.clone(),
+                        CoalescedResource {
+                            resource,
+                            users: 1,
+                            _disposer: disposer,
+                        },
+                    );
+            });
+            resource
+        }
+    };
+
+    crate::on_cleanup(move || {
+        let released = with_runtime(|runtime| {
+            let mut maps = runtime.coalesced_resources.borrow_mut();
+            let map = maps
+                .get_mut(&TypeId::of::<CoalescedResources<K, S, T>>())
+                .and_then(|map| {
+                    map.downcast_mut::<CoalescedResources<K, S, T>>()
+                })?;
+            let entry = map.get_mut(&key)?;
+            entry.users -= 1;
+            if entry.users == 0 {
+                map.remove(&key)
+            } else {
+                None
+            }
+        });
+        // dropping the entry disposes of the resource, which must happen
+        // after the runtime's map has been released
+        drop(released);
+    });
+
+    resource
+}
+
+type CoalescedResources<K, S, T> = FxHashMap<K, CoalescedResource<S, T>>;
+
+struct CoalescedResource<S: 'static, T: 'static> {
+    resource: Resource<S, T>,
+    users: usize,
+    _disposer: Disposer,
+}
+
 /// Creates a _local_ [`Resource`](crate::Resource), which is a signal that
 /// reflects the current state of an asynchronous task, allowing you to
 /// integrate `async` [`Future`]s into the synchronous reactive system.
@@ -266,7 +421,9 @@ where
 /// value of the `source` changes, a new [`Future`] will be created and run.
 ///
 /// Unlike [`create_resource()`], this [`Future`] is always run on the local system
-/// and therefore its result type does not need to be [`Serializable`].
+/// and therefore its result type does not need to be [`Serializable`]. Neither the
+/// [`Future`] nor its result need to be [`Send`] either, so they can hold `Rc`s or
+/// `web_sys` handles, just like any signal.
 ///
 /// Local resources do not load on the server, only in the client’s browser.
 ///
@@ -303,6 +460,7 @@ where
         )
     )
 )]
+#[track_caller]
 pub fn create_local_resource<S, T, Fu>(
     source: impl Fn() -> S + 'static,
     fetcher: impl Fn(S) -> Fu + 'static,
@@ -336,6 +494,7 @@ where
         )
     )
 )]
+#[track_caller]
 pub fn create_local_resource_with_initial_value<S, T, Fu>(
     source: impl Fn() -> S + 'static,
     fetcher: impl Fn(S) -> Fu + 'static,
@@ -396,6 +555,130 @@ where
     }
 }
 
+/// Creates a _streaming_ [`Resource`](crate::Resource), whose value is
+/// progressively extended as items arrive from a [`Stream`](futures::Stream).
+///
+/// Takes a `fetcher` function that generates a [`Stream`](futures::Stream)
+/// when called and a `source` signal that provides the argument for the
+/// `fetcher`. Each time a new stream is created, the resource’s value is
+/// reset to `initial()` and each item is combined into it with `fold`.
+///
+/// The resource resolves (and any `<Suspense/>` reading it stops showing its
+/// fallback) as soon as the first item has arrived; later items update the
+/// value in place. When the `source` changes, the previous stream is dropped
+/// and a new one is started. This makes it a good fit for chat or LLM-style
+/// interfaces that render partial output as it is produced.
+///
+/// Like [`create_local_resource()`], the stream is always run on the local
+/// system, so its output does not need to be [`Serializable`].
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # let runtime = create_runtime();
+/// # if false {
+/// use futures::stream;
+///
+/// let (prompt, _set_prompt) = create_signal("hello".to_string());
+///
+/// // append each chunk to the response as it arrives
+/// let response = create_streaming_resource(
+///     move || prompt.get(),
+///     |prompt| stream::iter(prompt.chars().collect::<Vec<_>>()),
+///     String::new,
+///     |response: &mut String, chunk| response.push(chunk),
+/// );
+/// # }
+/// # runtime.dispose();
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature="ssr"),
+    instrument(
+        level = "debug",
+        skip_all,
+        fields(
+            ty = %std::any::type_name::<T>(),
+            signal_ty = %std::any::type_name::<S>(),
+        )
+    )
+)]
+#[track_caller]
+pub fn create_streaming_resource<S, T, St>(
+    source: impl Fn() -> S + 'static,
+    fetcher: impl Fn(S) -> St + 'static,
+    initial: impl Fn() -> T + 'static,
+    fold: impl Fn(&mut T, St::Item) + 'static,
+) -> Resource<S, T>
+where
+    S: PartialEq + Clone + 'static,
+    T: 'static,
+    St: futures::Stream + 'static,
+{
+    use futures::StreamExt;
+
+    let fold = Rc::new(fold);
+    let initial = Rc::new(initial);
+    // the resource handle only exists once it has been created; until then,
+    // items are folded into a local value that is returned by the future
+    let resource: Rc<Cell<Option<Resource<S, T>>>> = Default::default();
+    let stream_version = Rc::new(Cell::new(0usize));
+
+    let fetcher = {
+        let resource = Rc::clone(&resource);
+        move |s| {
+            let stream = fetcher(s);
+            let fold = Rc::clone(&fold);
+            let initial = Rc::clone(&initial);
+            let resource = Rc::clone(&resource);
+            let stream_version = Rc::clone(&stream_version);
+            let version = stream_version.get() + 1;
+            stream_version.set(version);
+
+            async move {
+                let mut stream = Box::pin(stream);
+                // holds the value until it can be handed to the resource
+                let mut pending = Some(initial());
+
+                while let Some(item) = stream.next().await {
+                    // a newer stream has replaced this one
+                    if stream_version.get() != version {
+                        break;
+                    }
+
+                    if let Some(value) = pending.as_mut() {
+                        fold(value, item);
+                        // setting a loading resource resolves it, so any
+                        // `<Suspense/>` can show the first item right away
+                        if let Some(r) = resource.get() {
+                            let value = pending.take();
+                            r.try_update(|current| *current = value);
+                        }
+                    } else {
+                        let updated = resource.get().and_then(|r| {
+                            r.try_update(|current| {
+                                if let Some(current) = current.as_mut() {
+                                    fold(current, item);
+                                }
+                            })
+                        });
+                        // the resource has been disposed
+                        if updated.is_none() {
+                            break;
+                        }
+                    }
+                }
+
+                // once the value has been handed over, resolving the resource
+                // bumped its version, so whatever is returned here is ignored
+                pending.unwrap_or_else(|| initial())
+            }
+        }
+    };
+
+    let r = create_local_resource(source, fetcher);
+    resource.set(Some(r));
+    r
+}
+
 #[cfg(not(feature = "hydrate"))]
 fn load_resource<S, T>(id: ResourceId, r: Rc<ResourceState<S, T>>)
 where
@@ -588,6 +871,12 @@ where
     }
 
     /// Re-runs the async function with the current source data.
+    ///
+    /// This is useful to reload data after it has been changed on the server, like
+    /// after a form is submitted. The current value is kept while it reloads. To show
+    /// the expected value before the server has responded, [`set`](SignalSet::set) or
+    /// [`update`](SignalUpdate::update) the resource; this cancels a load that is in
+    /// flight, and ends it in any `<Suspense/>` that it is read in.
     #[cfg_attr(
         any(debug_assertions, feature = "ssr"),
         instrument(level = "trace", skip_all,)
@@ -1385,7 +1674,19 @@ where
                 }
             }
 
-            // run the Future
+            // run the Future, which is cancelled on the server if the runtime is disposed
+            // first, for example because the client disconnected
+            #[cfg(feature = "ssr")]
+            let fut = {
+                let (fut, load) = futures::future::abortable(fut);
+                _ = with_runtime(|runtime| {
+                    runtime.pending_resource_loads.borrow_mut().push(load)
+                });
+                async move { fut.await.ok() }
+            };
+            #[cfg(not(feature = "ssr"))]
+            let fut = async move { Some(fut.await) };
+
             let serializable = self.serializable;
             spawn_local({
                 let resolved = self.resolved.clone();
@@ -1393,7 +1694,9 @@ where
                 let set_loading = self.set_loading;
                 let last_version = self.version.clone();
                 async move {
-                    let res = fut.await;
+                    let Some(res) = fut.await else {
+                        return;
+                    };
 
                     if version == last_version.get() {
                         resolved.set(true);
@@ -1,5 +1,8 @@
 #[cfg(debug_assertions)]
-use crate::SpecialNonReactiveZone;
+use crate::{
+    diagnostics::{FormatValue, NodeDiagnostics, RunCause},
+    SpecialNonReactiveZone,
+};
 use crate::{
     hydration::SharedContext,
     node::{
@@ -75,8 +78,18 @@ pub(crate) struct Runtime {
     pub contexts:
         RefCell<SparseSecondaryMap<NodeId, FxHashMap<TypeId, Box<dyn Any>>>>,
     pub pending_effects: RefCell<Vec<NodeId>>,
+    pub spare_effects: RefCell<Vec<NodeId>>,
+    pub mark_stack: RefCell<Vec<(NodeId, usize)>>,
     pub resources: RefCell<SlotMap<ResourceId, AnyResource>>,
+    pub coalesced_resources: RefCell<FxHashMap<TypeId, Box<dyn Any>>>,
     pub batching: Cell<bool>,
+    pub running_effects: Cell<bool>,
+    pub after_effects: RefCell<Vec<Box<dyn FnOnce()>>>,
+    #[cfg(debug_assertions)]
+    pub run_cause: RefCell<Option<RunCause>>,
+    /// Resource loads that are cancelled if the runtime is disposed before they finish.
+    #[cfg(feature = "ssr")]
+    pub pending_resource_loads: RefCell<Vec<futures::future::AbortHandle>>,
 }
 
 /// The current reactive runtime.
@@ -121,6 +134,93 @@ impl Owner {
 
         Self(NodeId::from(KeyData::from_ffi(ffi)))
     }
+
+    /// Returns the owner of this owner, or `None` if it is the root.
+    pub fn parent(&self) -> Option<Owner> {
+        with_runtime(|runtime| {
+            runtime.node_owners.borrow().get(self.0).copied()
+        })
+        .ok()
+        .flatten()
+        .map(Owner)
+    }
+
+    /// Moves this owner, and everything it owns, so that it belongs to `new_parent`.
+    ///
+    /// Once moved, it is disposed when `new_parent` is disposed instead of when its old
+    /// owner is, and looks up [context](crate::use_context) through `new_parent`.
+    /// This lets components like portals or keep-alive caches move reactive state to
+    /// another part of the tree without recreating it. To run code with a different owner,
+    /// use [`with_owner`].
+    /// ```
+    /// # use leptos_reactive::*;
+    /// # let runtime = create_runtime();
+    /// let cache = run_as_child(|| Owner::current().unwrap());
+    /// let page = run_as_child(|| Owner::current().unwrap());
+    ///
+    /// page.reparent(cache).unwrap();
+    /// assert_eq!(page.parent(), Some(cache));
+    /// # runtime.dispose();
+    /// ```
+    ///
+    /// Returns an error if either owner has been disposed, or if `new_parent` is this
+    /// owner or one of its descendants.
+    pub fn reparent(
+        &self,
+        new_parent: Owner,
+    ) -> Result<(), ReactiveSystemError> {
+        with_runtime(|runtime| {
+            // the property that will tie this owner's lifetime to `new_parent`, if it
+            // is not already tied to a parent
+            let own_property = {
+                let nodes = runtime
+                    .nodes
+                    .try_borrow()
+                    .map_err(ReactiveSystemError::Borrow)?;
+                for owner in [*self, new_parent] {
+                    if !nodes.contains_key(owner.0) {
+                        return Err(ReactiveSystemError::OwnerDisposed(owner));
+                    }
+                }
+                match nodes[self.0].node_type {
+                    ReactiveNodeType::Trigger => ScopeProperty::Trigger(self.0),
+                    ReactiveNodeType::Signal => ScopeProperty::Signal(self.0),
+                    ReactiveNodeType::Memo { .. }
+                    | ReactiveNodeType::Effect { .. } => {
+                        ScopeProperty::Effect(self.0)
+                    }
+                }
+            };
+
+            let mut owners = runtime.node_owners.borrow_mut();
+            let mut ancestor = Some(new_parent.0);
+            while let Some(node) = ancestor {
+                if node == self.0 {
+                    return Err(ReactiveSystemError::OwnerCycle(
+                        *self, new_parent,
+                    ));
+                }
+                ancestor = owners.get(node).copied();
+            }
+
+            // move the property that ties this owner's lifetime to its owner, which a
+            // root owner does not have
+            let mut properties = runtime.node_properties.borrow_mut();
+            if let Some(siblings) = owners
+                .get(self.0)
+                .and_then(|prev_parent| properties.get_mut(*prev_parent))
+            {
+                siblings
+                    .retain(|property| property.to_node_id() != Some(self.0));
+            }
+            if let Some(entry) = properties.entry(new_parent.0) {
+                entry.or_default().push(own_property);
+            }
+            owners.insert(self.0, new_parent.0);
+
+            Ok(())
+        })?
+    }
 }
 
 // This core Runtime impl block handles all the work of marking and updating
@@ -155,20 +255,18 @@ impl Runtime {
 
     pub(crate) fn update_if_necessary(&self, node_id: NodeId) {
         if self.current_state(node_id) == ReactiveNodeState::Check {
-            let sources = {
-                let sources = self.node_sources.borrow();
-
-                // rather than cloning the entire FxIndexSet, only allocate a `Vec` for the node ids
-                sources.get(node_id).map(|n| {
-                    let sources = n.borrow();
-                    // in case Vec::from_iterator specialization doesn't work, do it manually
-                    let mut sources_vec = Vec::with_capacity(sources.len());
-                    sources_vec.extend(sources.iter().cloned());
-                    sources_vec
+            // rather than copying the sources out, look each one up by index,
+            // so that checking a node doesn't allocate; the borrow is released
+            // before updating the source, which may need to borrow them again
+            let source_at = |index: usize| {
+                self.node_sources.borrow().get(node_id).and_then(|sources| {
+                    sources.borrow().get_index(index).copied()
                 })
             };
 
-            for source in sources.into_iter().flatten() {
+            let mut index = 0;
+            while let Some(source) = source_at(index) {
+                index += 1;
                 self.update_if_necessary(source);
                 if self.current_state(node_id) >= ReactiveNodeState::Dirty {
                     // as soon as a single parent has marked us dirty, we can
@@ -218,6 +316,9 @@ impl Runtime {
         };
 
         if let Some(node) = node {
+            #[cfg(debug_assertions)]
+            let mut change = node.diagnostics.last_change.clone();
+
             // memos and effects rerun
             // signals simply have their value
             let changed = match node.node_type {
@@ -225,18 +326,74 @@ impl Runtime {
                 ReactiveNodeType::Memo { ref f }
                 | ReactiveNodeType::Effect { ref f } => {
                     let value = node.value();
+                    #[cfg(debug_assertions)]
+                    let (debug_value, format_value, prev_cause) = (
+                        Rc::clone(&value),
+                        node.diagnostics.format_value,
+                        self.run_cause.replace(self.take_dirty_cause(node_id)),
+                    );
+                    #[cfg(debug_assertions)]
+                    let before = format_value
+                        .and_then(|format| format(&*debug_value.borrow()));
+
+                    #[cfg(debug_assertions)]
+                    let timer = matches!(
+                        node.node_type,
+                        ReactiveNodeType::Effect { .. }
+                    )
+                    .then(crate::slow_effects::EffectTimer::start)
+                    .flatten();
+
+                    #[cfg(debug_assertions)]
+                    let component = node.diagnostics.component.clone();
+
                     // set this node as the observer
-                    self.with_observer(node_id, move || {
+                    let changed = self.with_observer(node_id, move || {
                         // clean up sources of this memo/effect
                         self.cleanup_sources(node_id);
 
+                        // nodes created as it re-runs belong to its component
+                        #[cfg(debug_assertions)]
+                        let _component =
+                            crate::diagnostics::ComponentGuard::enter(
+                                component,
+                            );
+
                         f.run(value)
-                    })
+                    });
+
+                    #[cfg(debug_assertions)]
+                    {
+                        if let Some(timer) = timer {
+                            timer.finish(node.diagnostics.defined_at);
+                        }
+                        self.run_cause.replace(prev_cause);
+                        if let Some(format) = format_value {
+                            change =
+                                Some((before, format(&*debug_value.borrow())));
+                        }
+                    }
+                    changed
                 }
             };
 
             // mark children dirty
             if changed {
+                #[cfg(debug_assertions)]
+                let cause = RunCause {
+                    source: match node.node_type {
+                        ReactiveNodeType::Signal => "signal",
+                        ReactiveNodeType::Trigger => "trigger",
+                        ReactiveNodeType::Memo { .. } => "memo",
+                        ReactiveNodeType::Effect { .. } => "effect",
+                    },
+                    defined_at: node.diagnostics.defined_at,
+                    before: change
+                        .as_ref()
+                        .and_then(|(before, _)| before.clone()),
+                    after: change.and_then(|(_, after)| after),
+                };
+
                 let subs = self.node_subscribers.borrow();
 
                 if let Some(subs) = subs.get(node_id) {
@@ -244,6 +401,11 @@ impl Runtime {
                     for sub_id in subs.borrow().iter() {
                         if let Some(sub) = nodes.get_mut(*sub_id) {
                             sub.state = ReactiveNodeState::Dirty;
+                            #[cfg(debug_assertions)]
+                            {
+                                sub.diagnostics.dirty_cause =
+                                    Some(cause.clone());
+                            }
                         }
                     }
                 }
@@ -254,6 +416,35 @@ impl Runtime {
         }
     }
 
+    #[cfg(debug_assertions)]
+    fn take_dirty_cause(&self, node_id: NodeId) -> Option<RunCause> {
+        self.nodes
+            .borrow_mut()
+            .get_mut(node_id)
+            .and_then(|node| node.diagnostics.dirty_cause.take())
+    }
+
+    /// Records the value of a signal before and after it was updated, if it records its changes.
+    #[cfg(debug_assertions)]
+    pub(crate) fn record_change(
+        &self,
+        node_id: NodeId,
+        before: Option<String>,
+        after: Option<String>,
+    ) {
+        if let Some(node) = self.nodes.borrow_mut().get_mut(node_id) {
+            node.diagnostics.last_change = Some((before, after));
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    pub(crate) fn format_value(&self, node_id: NodeId) -> Option<FormatValue> {
+        self.nodes
+            .borrow()
+            .get(node_id)
+            .and_then(|node| node.diagnostics.format_value)
+    }
+
     pub(crate) fn cleanup_property(&self, property: ScopeProperty) {
         // for signals, triggers, memos, effects, shared node cleanup
         match property {
@@ -363,95 +554,58 @@ impl Runtime {
             );
 
             /*
-             * Depth-first DAG traversal that uses a stack of iterators instead of
-             * buffering the entire to-visit list. Visited nodes are either marked as
+             * Depth-first DAG traversal that uses a stack of nodes, each with
+             * the index of its next subscriber to visit, instead of buffering
+             * the entire to-visit list. Visited nodes are either marked as
              * `Check` or `DirtyMarked`.
              *
-             * Because `RefCell`, borrowing the iterators all at once is difficult,
-             * so a self-referential struct is used instead. self_cell produces safe
-             * code, but it would not be recommended to use this outside of this
-             * algorithm.
+             * The stack holds no borrows, so its buffer is kept in the runtime
+             * and reused, and marking doesn't allocate once it has grown to
+             * the depth of the graph.
              */
+            let mut stack = self.mark_stack.borrow_mut();
+            stack.push((node, 0));
 
-            type Dependent<'a> = indexmap::set::Iter<'a, NodeId>;
-
-            self_cell::self_cell! {
-                struct RefIter<'a> {
-                    owner: std::cell::Ref<'a, FxIndexSet<NodeId>>,
-
-                    #[not_covariant] // avoids extra codegen, harmless to mark it as such
-                    dependent: Dependent,
-                }
-            }
-
-            /// Due to the limitations of self-referencing, we cannot borrow the
-            /// stack and iter simultaneously within the closure or the loop,
-            /// therefore this must be used to command the outside scope
-            /// of what to do.
-            enum IterResult<'a> {
-                Continue,
-                Empty,
-                NewIter(RefIter<'a>),
-            }
-
-            let mut stack = Vec::new();
-
-            if let Some(children) = subscribers.get(node) {
-                stack.push(RefIter::new(children.borrow(), |children| {
-                    children.iter()
-                }));
-            }
+            while let Some((parent, index)) = stack.last_mut() {
+                let next = subscribers.get(*parent).and_then(|children| {
+                    children.borrow().get_index(*index).copied()
+                });
+                let Some(mut child) = next else {
+                    stack.pop();
+                    continue;
+                };
+                *index += 1;
+
+                while let Some(node) = nodes.get_mut(child) {
+                    if node.state == ReactiveNodeState::Check
+                        || node.state == ReactiveNodeState::DirtyMarked
+                    {
+                        break;
+                    }
 
-            while let Some(iter) = stack.last_mut() {
-                let res = iter.with_dependent_mut(|_, iter| {
-                    let Some(mut child) = iter.next().copied() else {
-                        return IterResult::Empty;
-                    };
-
-                    while let Some(node) = nodes.get_mut(child) {
-                        if node.state == ReactiveNodeState::Check
-                            || node.state == ReactiveNodeState::DirtyMarked
-                        {
-                            return IterResult::Continue;
+                    Runtime::mark(
+                        child,
+                        node,
+                        ReactiveNodeState::Check,
+                        &mut pending_effects,
+                        current_observer,
+                    );
+
+                    if let Some(children) = subscribers.get(child) {
+                        let children = children.borrow();
+
+                        // avoid going through the stack in the simple psuedo-recursive case
+                        if children.len() == 1 {
+                            child = children[0];
+                            continue;
                         }
 
-                        Runtime::mark(
-                            child,
-                            node,
-                            ReactiveNodeState::Check,
-                            &mut pending_effects,
-                            current_observer,
-                        );
-
-                        if let Some(children) = subscribers.get(child) {
-                            let children = children.borrow();
-
-                            if !children.is_empty() {
-                                // avoid going through an iterator in the simple psuedo-recursive case
-                                if children.len() == 1 {
-                                    child = children[0];
-                                    continue;
-                                }
-
-                                return IterResult::NewIter(RefIter::new(
-                                    children,
-                                    |children| children.iter(),
-                                ));
-                            }
+                        if !children.is_empty() {
+                            stack.push((child, 0));
                         }
-
-                        break;
                     }
 
-                    IterResult::Continue
-                });
-
-                match res {
-                    IterResult::Continue => continue,
-                    IterResult::NewIter(iter) => stack.push(iter),
-                    IterResult::Empty => {
-                        stack.pop();
-                    }
+                    break;
                 }
             }
         }
@@ -482,9 +636,61 @@ impl Runtime {
 
     pub(crate) fn run_effects(&self) {
         if !self.batching.get() {
-            let effects = self.pending_effects.take();
-            for effect_id in effects {
-                self.update_if_necessary(effect_id);
+            // swap in the spare buffer, so that effects queued while these run
+            // (and the next flush) reuse allocated capacity
+            let mut effects = std::mem::replace(
+                &mut *self.pending_effects.borrow_mut(),
+                self.spare_effects.take(),
+            );
+            if effects.is_empty() {
+                self.spare_effects.replace(effects);
+                return;
+            }
+
+            #[cfg(feature = "alloc-profiling")]
+            let before = crate::alloc_profiling::AllocationStats::current();
+
+            // effects that run while these do are flushed in a nested call,
+            // so only the outermost flush runs the callbacks it queued
+            let outermost = !self.running_effects.replace(true);
+            {
+                // reset even if an effect panics, so that later flushes still
+                // run their callbacks
+                let _running = outermost
+                    .then(|| ResetRunningEffectsOnDrop(&self.running_effects));
+                for &effect_id in &effects {
+                    self.update_if_necessary(effect_id);
+                }
+            }
+            if outermost {
+                self.run_after_effects();
+            }
+
+            #[cfg(feature = "alloc-profiling")]
+            crate::alloc_profiling::report_flush(effects.len(), before);
+
+            effects.clear();
+            let mut pending = self.pending_effects.borrow_mut();
+            if pending.is_empty() {
+                // nothing was queued while these ran, so the next flush can
+                // queue into this buffer directly
+                std::mem::swap(&mut *pending, &mut effects);
+            }
+            let mut spare = self.spare_effects.borrow_mut();
+            if effects.capacity() > spare.capacity() {
+                *spare = effects;
+            }
+        }
+    }
+
+    fn run_after_effects(&self) {
+        loop {
+            let callbacks = self.after_effects.take();
+            if callbacks.is_empty() {
+                break;
+            }
+            for callback in callbacks {
+                callback();
             }
         }
     }
@@ -522,6 +728,16 @@ impl Runtime {
         }
     }
 
+    /// Returns the root of the ownership tree the current owner belongs to.
+    pub(crate) fn root_owner(&self) -> Option<NodeId> {
+        let owners = self.node_owners.borrow();
+        let mut node = self.owner.get()?;
+        while let Some(parent) = owners.get(node) {
+            node = *parent;
+        }
+        Some(node)
+    }
+
     pub(crate) fn get_context<T: Clone + 'static>(
         &self,
         node: NodeId,
@@ -770,6 +986,10 @@ where
                 value: None,
                 state: ReactiveNodeState::Clean,
                 node_type: ReactiveNodeType::Trigger,
+                #[cfg(debug_assertions)]
+                diagnostics: NodeDiagnostics::new(
+                    std::panic::Location::caller(),
+                ),
             });
             runtime.push_scope_property(ScopeProperty::Trigger(id));
             let disposer = Disposer(id);
@@ -835,6 +1055,8 @@ pub enum ReactiveSystemError {
     OwnerDisposed(Owner),
     #[error("Error borrowing runtime.nodes {0:?}")]
     Borrow(std::cell::BorrowError),
+    #[error("Owner {0:?} cannot be moved into its own descendant {1:?}.")]
+    OwnerCycle(Owner, Owner),
 }
 
 /// Runs the given code with the given reactive owner.
@@ -871,9 +1093,19 @@ pub fn try_with_owner<T>(
 
 /// Runs the given function as a child of the current Owner, once.
 pub fn run_as_child<T>(f: impl FnOnce() -> T + 'static) -> T {
+    let (value, disposer) = run_as_child_with_disposer(f);
+    on_cleanup(move || drop(disposer));
+    value
+}
+
+/// Runs the given function as a child of the current Owner, once, returning
+/// a [`Disposer`] that can be used to dispose of the child.
+pub(crate) fn run_as_child_with_disposer<T>(
+    f: impl FnOnce() -> T,
+) -> (T, Disposer) {
     let owner = with_runtime(|runtime| runtime.owner.get())
         .expect("runtime should be alive when created");
-    let (value, disposer) = with_runtime(|runtime| {
+    with_runtime(|runtime| {
         let prev_observer = runtime.observer.take();
         let prev_owner = runtime.owner.take();
 
@@ -884,6 +1116,8 @@ pub fn run_as_child<T>(f: impl FnOnce() -> T + 'static) -> T {
             value: None,
             state: ReactiveNodeState::Clean,
             node_type: ReactiveNodeType::Trigger,
+            #[cfg(debug_assertions)]
+            diagnostics: NodeDiagnostics::new(std::panic::Location::caller()),
         });
         runtime.push_scope_property(ScopeProperty::Trigger(id));
         let disposer = Disposer(id);
@@ -898,9 +1132,7 @@ pub fn run_as_child<T>(f: impl FnOnce() -> T + 'static) -> T {
 
         (v, disposer)
     })
-    .expect("runtime should be alive when run");
-    on_cleanup(move || drop(disposer));
-    value
+    .expect("runtime should be alive when run")
 }
 
 impl RuntimeId {
@@ -926,6 +1158,12 @@ impl RuntimeId {
                     }
                 });
 
+                // any resources still loading can no longer be read, so stop loading them
+                #[cfg(feature = "ssr")]
+                for load in runtime.pending_resource_loads.take() {
+                    load.abort();
+                }
+
                 drop(runtime);
             }
         }
@@ -975,11 +1213,15 @@ impl RuntimeId {
     #[track_caller]
     #[inline(always)] // only because it's placed here to fit in with the other create methods
     pub(crate) fn create_trigger(self) -> Trigger {
+        #[cfg(debug_assertions)]
+        let defined_at = std::panic::Location::caller();
         let id = with_runtime(|runtime| {
             let id = runtime.nodes.borrow_mut().insert(ReactiveNode {
                 value: None,
                 state: ReactiveNodeState::Clean,
                 node_type: ReactiveNodeType::Trigger,
+                #[cfg(debug_assertions)]
+                diagnostics: NodeDiagnostics::new(defined_at),
             });
             runtime.push_scope_property(ScopeProperty::Trigger(id));
             id
@@ -995,15 +1237,20 @@ impl RuntimeId {
         }
     }
 
+    #[track_caller]
     pub(crate) fn create_concrete_signal(
         self,
         value: Rc<RefCell<dyn Any>>,
     ) -> NodeId {
+        #[cfg(debug_assertions)]
+        let defined_at = std::panic::Location::caller();
         with_runtime(|runtime| {
             let id = runtime.nodes.borrow_mut().insert(ReactiveNode {
                 value: Some(value),
                 state: ReactiveNodeState::Clean,
                 node_type: ReactiveNodeType::Signal,
+                #[cfg(debug_assertions)]
+                diagnostics: NodeDiagnostics::new(defined_at),
             });
             runtime.push_scope_property(ScopeProperty::Signal(id));
             id
@@ -1057,11 +1304,14 @@ impl RuntimeId {
         }
     }
 
+    #[track_caller]
     pub(crate) fn create_concrete_effect(
         self,
         value: Rc<RefCell<dyn Any>>,
         effect: Rc<dyn AnyComputation>,
     ) -> NodeId {
+        #[cfg(debug_assertions)]
+        let defined_at = std::panic::Location::caller();
         with_runtime(|runtime| {
             let id = runtime.nodes.borrow_mut().insert(ReactiveNode {
                 value: Some(Rc::clone(&value)),
@@ -1069,6 +1319,8 @@ impl RuntimeId {
                 node_type: ReactiveNodeType::Effect {
                     f: Rc::clone(&effect),
                 },
+                #[cfg(debug_assertions)]
+                diagnostics: NodeDiagnostics::new(defined_at),
             });
             runtime.push_scope_property(ScopeProperty::Effect(id));
             id
@@ -1076,11 +1328,14 @@ impl RuntimeId {
         .expect("tried to create an effect in a runtime that has been disposed")
     }
 
+    #[track_caller]
     pub(crate) fn create_concrete_memo(
         self,
         value: Rc<RefCell<dyn Any>>,
         computation: Rc<dyn AnyComputation>,
     ) -> NodeId {
+        #[cfg(debug_assertions)]
+        let defined_at = std::panic::Location::caller();
         with_runtime(|runtime| {
             let id = runtime.nodes.borrow_mut().insert(ReactiveNode {
                 value: Some(value),
@@ -1088,6 +1343,8 @@ impl RuntimeId {
                 // will be run the first time we ask for it
                 state: ReactiveNodeState::Dirty,
                 node_type: ReactiveNodeType::Memo { f: computation },
+                #[cfg(debug_assertions)]
+                diagnostics: NodeDiagnostics::new(defined_at),
             });
             runtime.push_scope_property(ScopeProperty::Effect(id));
             id
@@ -1221,6 +1478,8 @@ impl Runtime {
             value: None,
             state: ReactiveNodeState::Clean,
             node_type: ReactiveNodeType::Trigger,
+            #[cfg(debug_assertions)]
+            diagnostics: NodeDiagnostics::new(std::panic::Location::caller()),
         };
         let mut nodes: SlotMap<NodeId, ReactiveNode> = SlotMap::default();
         let root_id = nodes.insert(root);
@@ -1409,6 +1668,53 @@ pub fn batch<T>(f: impl FnOnce() -> T) -> T {
     .expect("tried to run a batched update in a runtime that has been disposed")
 }
 
+/// Runs `f` once the effects that are currently running have all finished, or immediately
+/// if no effects are running.
+///
+/// This lets an effect defer work until every other effect triggered by the same update has
+/// run, while still finishing it before the update returns. The renderer uses it to apply
+/// all the attribute changes an update makes to an element together.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # use std::{cell::RefCell, rc::Rc};
+/// # let runtime = create_runtime();
+/// let log = Rc::new(RefCell::new(Vec::new()));
+/// let (count, set_count) = create_signal(0);
+/// for name in ["a", "b"] {
+///     let log = Rc::clone(&log);
+///     create_isomorphic_effect(move |_| {
+///         let count = count.get();
+///         let log = Rc::clone(&log);
+///         queue_after_effects(move || log.borrow_mut().push((name, count)));
+///     });
+/// }
+/// log.borrow_mut().clear();
+///
+/// set_count.set(1);
+/// assert_eq!(*log.borrow(), [("a", 1), ("b", 1)]);
+/// # runtime.dispose();
+/// ```
+pub fn queue_after_effects(f: impl FnOnce() + 'static) {
+    let running = with_runtime(|runtime| runtime.running_effects.get())
+        .unwrap_or_default();
+    if running {
+        _ = with_runtime(|runtime| {
+            runtime.after_effects.borrow_mut().push(Box::new(f));
+        });
+    } else {
+        f();
+    }
+}
+
+struct ResetRunningEffectsOnDrop<'a>(&'a Cell<bool>);
+
+impl Drop for ResetRunningEffectsOnDrop<'_> {
+    fn drop(&mut self) {
+        self.0.set(false);
+    }
+}
+
 struct SetBatchingOnDrop(RuntimeId, bool);
 
 impl Drop for SetBatchingOnDrop {
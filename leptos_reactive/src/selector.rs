@@ -43,6 +43,7 @@ use std::{cell::RefCell, collections::HashMap, hash::Hash, rc::Rc};
 ///  # runtime.dispose()
 /// ```
 #[inline(always)]
+#[track_caller]
 pub fn create_selector<T>(
     source: impl Fn() -> T + Clone + 'static,
 ) -> Selector<T>
@@ -58,6 +59,7 @@ where
 /// **You probably don’t need this,** but it can be a very useful optimization
 /// in certain situations (e.g., “set the class `selected` if `selected() == this_row_index`)
 /// because it reduces them from `O(n)` to `O(1)`.
+#[track_caller]
 pub fn create_selector_with_fn<T>(
     source: impl Fn() -> T + 'static,
     f: impl Fn(&T, &T) -> bool + Clone + 'static,
@@ -24,7 +24,7 @@ pub enum SerializationError {
 ///
 /// This trait is intended to abstract over various serialization crates,
 /// as selected between by the crate features `serde` (default), `serde-lite`,
-/// and `miniserde`.
+/// `miniserde`, `rkyv`, and `bincode`.
 pub trait Serializable
 where
     Self: Sized,
@@ -59,6 +59,27 @@ cfg_if! {
             }
         }
     }
+    // bincode uses the serde traits, so it takes priority over plain serde
+    else if #[cfg(feature = "bincode")] {
+        use serde::{de::DeserializeOwned, Serialize};
+        use base64::Engine as _;
+        use base64::engine::general_purpose::STANDARD_NO_PAD;
+
+        impl<T> Serializable for T
+        where
+            T: DeserializeOwned + Serialize,
+        {
+            fn ser(&self) -> Result<String, SerializationError> {
+                let bytes = bincode::serialize(self).map_err(|e| SerializationError::Serialize(Rc::new(e)))?;
+                Ok(STANDARD_NO_PAD.encode(bytes))
+            }
+
+            fn de(serialized: &str) -> Result<Self, SerializationError> {
+                let bytes = STANDARD_NO_PAD.decode(serialized.as_bytes()).map_err(|e| SerializationError::Deserialize(Rc::new(e)))?;
+                bincode::deserialize(&bytes).map_err(|e| SerializationError::Deserialize(Rc::new(e)))
+            }
+        }
+    }
     // prefer miniserde if it's chosen
     else if #[cfg(feature = "miniserde")] {
         use miniserde::{json, Deserialize, Serialize};
@@ -105,6 +105,34 @@ pub mod prelude {
 
 /// This trait allows getting an owned value of the signals
 /// inner type.
+///
+/// It is implemented by every signal that can be read, like [`ReadSignal`],
+/// [`RwSignal`], [`Memo`](crate::Memo), [`Signal`](crate::Signal) and
+/// [`Resource`](crate::Resource), so functions can take any of them.
+/// [`SignalWith`], [`SignalSet`] and [`SignalUpdate`] do the same for the
+/// other ways of using a signal.
+/// ```
+/// # use leptos_reactive::*;
+/// # let runtime = create_runtime();
+/// fn total(prices: &impl SignalWith<Value = Vec<u32>>) -> u32 {
+///     prices.with(|prices| prices.iter().sum())
+/// }
+///
+/// fn add_one(count: &impl SignalUpdate<Value = u32>) {
+///     count.update(|count| *count += 1);
+/// }
+///
+/// let prices = create_rw_signal(vec![1, 2]);
+/// let doubled =
+///     create_memo(move |_| prices.get().iter().map(|p| p * 2).collect());
+/// assert_eq!(total(&prices), 3);
+/// assert_eq!(total(&doubled), 6);
+///
+/// let count = create_rw_signal(0);
+/// add_one(&count);
+/// assert_eq!(count.get_untracked(), 1);
+/// # runtime.dispose();
+/// ```
 pub trait SignalGet {
     /// The value held by the signal.
     type Value;
@@ -112,6 +140,11 @@ pub trait SignalGet {
     /// Clones and returns the current value of the signal, and subscribes
     /// the running effect to this signal.
     ///
+    /// In debug builds, calling this outside of an effect or memo warns that
+    /// the read is not reactive. The warning is also emitted as a `tracing`
+    /// event with the target `leptos_reactive::untracked`, which records
+    /// where the signal was `defined_at` and `called_at`.
+    ///
     /// # Panics
     /// Panics if you try to access a signal that is owned by a reactive node that has been disposed.
     #[track_caller]
@@ -119,9 +152,42 @@ pub trait SignalGet {
 
     /// Clones and returns the signal value, returning [`Some`] if the signal
     /// is still alive, and [`None`] otherwise.
+    #[track_caller]
     fn try_get(&self) -> Option<Self::Value>;
 }
 
+/// This trait turns a signal into a closure that gets its value, for APIs
+/// that take an `impl Fn() -> T`.
+///
+/// With the `nightly` feature, signals implement [`Fn`] themselves, so
+/// `count()` is the same as `count.get()`, and a signal can be passed anywhere
+/// a closure is expected. On stable Rust, those uses are written
+/// `count.get()` and `count.into_fn()`. Both of these also compile with
+/// `nightly`, so an app can move between the two styles one call site at a
+/// time.
+/// ```
+/// # use leptos_reactive::*;
+/// # let runtime = create_runtime();
+/// fn doubled(value: impl Fn() -> i32) -> i32 {
+///     value() * 2
+/// }
+///
+/// let (count, set_count) = create_signal(1);
+/// let count = count.into_fn();
+/// assert_eq!(doubled(&count), 2);
+/// set_count.set(2);
+/// assert_eq!(doubled(&count), 4);
+/// # runtime.dispose();
+/// ```
+pub trait SignalIntoFn: SignalGet + Sized {
+    /// Returns a closure that calls [`SignalGet::get`].
+    fn into_fn(self) -> impl Fn() -> Self::Value {
+        move || self.get()
+    }
+}
+
+impl<S: SignalGet> SignalIntoFn for S {}
+
 /// This trait allows obtaining an immutable reference to the signal's
 /// inner type.
 pub trait SignalWith {
@@ -139,9 +205,11 @@ pub trait SignalWith {
     /// Applies a function to the current value of the signal, and subscribes
     /// the running effect to this signal. Returns [`Some`] if the signal is
     /// valid and the function ran, otherwise returns [`None`].
+    #[track_caller]
     fn try_with<O>(&self, f: impl FnOnce(&Self::Value) -> O) -> Option<O>;
 
     /// Subscribes to this signal in the current reactive scope without doing anything with its value.
+    #[track_caller]
     fn track(&self) {
         _ = self.try_with(|_| {});
     }
@@ -164,6 +232,7 @@ pub trait SignalSet {
     ///
     /// **Note:** `set()` does not auto-memoize, i.e., it will notify subscribers
     /// even if the value has not actually changed.
+    #[track_caller]
     fn try_set(&self, new_value: Self::Value) -> Option<Self::Value>;
 }
 
@@ -186,6 +255,7 @@ pub trait SignalUpdate {
     ///
     /// **Note:** `update()` does not auto-memoize, i.e., it will notify subscribers
     /// even if the value has not actually changed.
+    #[track_caller]
     fn try_update<O>(&self, f: impl FnOnce(&mut Self::Value) -> O)
         -> Option<O>;
 }
@@ -209,6 +279,7 @@ pub trait SignalGetUntracked {
     /// Gets the signal's value without creating a dependency on the
     /// current scope. Returns [`Some(T)`] if the signal is still
     /// valid, [`None`] otherwise.
+    #[track_caller]
     fn try_get_untracked(&self) -> Option<Self::Value>;
 }
 
@@ -263,6 +334,7 @@ pub trait SignalUpdateUntracked<T> {
     /// Runs the provided closure with a mutable reference to the current
     /// value without notifying dependents and returns
     /// the value the closure returned.
+    #[track_caller]
     fn try_update_untracked<O>(&self, f: impl FnOnce(&mut T) -> O)
         -> Option<O>;
 }
@@ -357,6 +429,7 @@ pub fn create_signal<T>(value: T) -> (ReadSignal<T>, WriteSignal<T>) {
     any(debug_assertions, features = "ssr"),
     instrument(level = "trace", skip_all,)
 )]
+#[track_caller]
 pub fn create_signal_from_stream<T>(
     #[allow(unused_mut)] // allowed because needed for SSR
     mut stream: impl Stream<Item = T> + Unpin + 'static,
@@ -715,6 +788,19 @@ impl<T> SignalDispose for ReadSignal<T> {
     }
 }
 
+impl<T: fmt::Debug + 'static> ReadSignal<T> {
+    /// In debug builds, records the [`Debug`](fmt::Debug) representation of the value before
+    /// and after each change, so that [`why_did_this_run`] can show how it changed. Does
+    /// nothing in release builds.
+    pub fn debug_changes(self) -> Self {
+        #[cfg(debug_assertions)]
+        self.id.set_format_value(|value| {
+            value.downcast_ref::<T>().map(|value| format!("{value:?}"))
+        });
+        self
+    }
+}
+
 impl<T> ReadSignal<T>
 where
     T: 'static,
@@ -1256,6 +1342,60 @@ impl<T> From<T> for RwSignal<T> {
     }
 }
 
+/// Splits an `RwSignal` into its getter and setter, like [`RwSignal::split`]. Both halves
+/// still refer to the same signal.
+/// ```
+/// # use leptos_reactive::*;
+/// # let runtime = create_runtime();
+/// let count = create_rw_signal(0);
+/// let (get_count, set_count): (ReadSignal<i32>, WriteSignal<i32>) =
+///     count.into();
+/// set_count.set(1);
+/// assert_eq!(get_count.get(), 1);
+/// assert_eq!(count.get(), 1);
+/// # runtime.dispose();
+/// ```
+impl<T> From<RwSignal<T>> for (ReadSignal<T>, WriteSignal<T>) {
+    #[track_caller]
+    fn from(value: RwSignal<T>) -> Self {
+        value.split()
+    }
+}
+
+/// Keeps only the getter of an `RwSignal`, like [`RwSignal::read_only`].
+/// ```
+/// # use leptos_reactive::*;
+/// # let runtime = create_runtime();
+/// let count = create_rw_signal(0);
+/// let get_count: ReadSignal<i32> = count.into();
+/// count.set(1);
+/// assert_eq!(get_count.get(), 1);
+/// # runtime.dispose();
+/// ```
+impl<T> From<RwSignal<T>> for ReadSignal<T> {
+    #[track_caller]
+    fn from(value: RwSignal<T>) -> Self {
+        value.read_only()
+    }
+}
+
+/// Keeps only the setter of an `RwSignal`, like [`RwSignal::write_only`].
+/// ```
+/// # use leptos_reactive::*;
+/// # let runtime = create_runtime();
+/// let count = create_rw_signal(0);
+/// let set_count: WriteSignal<i32> = count.into();
+/// set_count.set(1);
+/// assert_eq!(count.get(), 1);
+/// # runtime.dispose();
+/// ```
+impl<T> From<RwSignal<T>> for WriteSignal<T> {
+    #[track_caller]
+    fn from(value: RwSignal<T>) -> Self {
+        value.write_only()
+    }
+}
+
 impl<T: Clone> SignalGetUntracked for RwSignal<T> {
     type Value = T;
 
@@ -1793,6 +1933,19 @@ impl<T> SignalDispose for RwSignal<T> {
     }
 }
 
+impl<T: fmt::Debug + 'static> RwSignal<T> {
+    /// In debug builds, records the [`Debug`](fmt::Debug) representation of the value before
+    /// and after each change, so that [`why_did_this_run`] can show how it changed. Does
+    /// nothing in release builds.
+    pub fn debug_changes(self) -> Self {
+        #[cfg(debug_assertions)]
+        self.id.set_format_value(|value| {
+            value.downcast_ref::<T>().map(|value| format!("{value:?}"))
+        });
+        self
+    }
+}
+
 impl<T> RwSignal<T> {
     /// Creates a reactive signal with the getter and setter unified in one value.
     /// You may prefer this style, or it may be easier to pass around in a context
@@ -1955,6 +2108,15 @@ pub(crate) enum SignalError {
 }
 
 impl NodeId {
+    #[cfg(debug_assertions)]
+    pub(crate) fn set_format_value(&self, format_value: FormatValue) {
+        _ = with_runtime(|runtime| {
+            if let Some(node) = runtime.nodes.borrow_mut().get_mut(*self) {
+                node.diagnostics.format_value = Some(format_value);
+            }
+        });
+    }
+
     #[track_caller]
     pub(crate) fn subscribe(
         &self,
@@ -1983,6 +2145,13 @@ impl NodeId {
                         called_at,
                         defined_at,
                     } = diagnostics;
+                    tracing::warn!(
+                        target: "leptos_reactive::untracked",
+                        %called_at,
+                        %defined_at,
+                        "signal or memo accessed outside a reactive tracking \
+                         context"
+                    );
                     crate::macros::debug_warn!(
                         "At {called_at}, you access a signal or memo (defined \
                          at {defined_at}) outside a reactive tracking \
@@ -2131,7 +2300,12 @@ impl NodeId {
         with_runtime(|runtime| {
             let updated = if let Some(value) = runtime.get_value(*self) {
                 let mut value = value.borrow_mut();
-                if let Some(value) = value.downcast_mut::<T>() {
+                #[cfg(debug_assertions)]
+                let (format_value, before) = {
+                    let format_value = runtime.format_value(*self);
+                    (format_value, format_value.and_then(|f| f(&*value)))
+                };
+                let updated = if let Some(value) = value.downcast_mut::<T>() {
                     Some(f(value))
                 } else {
                     debug_warn!(
@@ -2140,7 +2314,12 @@ impl NodeId {
                         std::any::type_name::<T>()
                     );
                     None
+                };
+                #[cfg(debug_assertions)]
+                if let Some(format_value) = format_value {
+                    runtime.record_change(*self, before, format_value(&*value));
                 }
+                updated
             } else {
                 #[cfg(debug_assertions)]
                 {
@@ -807,6 +807,7 @@ where
             )
         )
     )]
+    #[track_caller]
     pub fn derive(derived_signal: impl Fn() -> T + 'static) -> Self {
         Self::Dynamic(Signal::derive(derived_signal))
     }
@@ -1035,10 +1036,13 @@ impl<T> MaybeProp<T> {
             fields(ty = %std::any::type_name::<T>())
         )
     )]
+    #[track_caller]
     pub fn with<O>(&self, f: impl FnOnce(&T) -> O) -> Option<O> {
-        self.0
-            .as_ref()
-            .and_then(|inner| inner.with(|value| value.as_ref().map(f)))
+        // not `and_then`, so that the closure does not hide the caller's location
+        match &self.0 {
+            Some(inner) => inner.with(|value| value.as_ref().map(f)),
+            None => None,
+        }
     }
 
     /// Applies a function to the current value, returning the result. Returns `None`
@@ -1052,11 +1056,12 @@ impl<T> MaybeProp<T> {
             fields(ty = %std::any::type_name::<T>())
         )
     )]
+    #[track_caller]
     pub fn try_with<O>(&self, f: impl FnOnce(&T) -> O) -> Option<O> {
-        self.0
-            .as_ref()
-            .and_then(|inner| inner.try_with(|value| value.as_ref().map(f)))
-            .flatten()
+        match &self.0 {
+            Some(inner) => inner.try_with(|value| value.as_ref().map(f))?,
+            None => None,
+        }
     }
 
     /// Applies a function to the current value, returning the result, without
@@ -1070,10 +1075,12 @@ impl<T> MaybeProp<T> {
             fields(ty = %std::any::type_name::<T>())
         )
     )]
+    #[track_caller]
     pub fn with_untracked<O>(&self, f: impl FnOnce(&T) -> O) -> Option<O> {
-        self.0.as_ref().and_then(|inner| {
-            inner.with_untracked(|value| value.as_ref().map(f))
-        })
+        match &self.0 {
+            Some(inner) => inner.with_untracked(|value| value.as_ref().map(f)),
+            None => None,
+        }
     }
 
     /// Applies a function to the current value, returning the result, without
@@ -1088,13 +1095,14 @@ impl<T> MaybeProp<T> {
             fields(ty = %std::any::type_name::<T>())
         )
     )]
+    #[track_caller]
     pub fn try_with_untracked<O>(&self, f: impl FnOnce(&T) -> O) -> Option<O> {
-        self.0
-            .as_ref()
-            .and_then(|inner| {
-                inner.try_with_untracked(|value| value.as_ref().map(f))
-            })
-            .flatten()
+        match &self.0 {
+            Some(inner) => {
+                inner.try_with_untracked(|value| value.as_ref().map(f))?
+            }
+            None => None,
+        }
     }
 }
 
@@ -1188,6 +1196,7 @@ where
             )
         )
     )]
+    #[track_caller]
     pub fn derive(derived_signal: impl Fn() -> Option<T> + 'static) -> Self {
         Self(Some(MaybeSignal::derive(derived_signal)))
     }
@@ -186,6 +186,7 @@ where
             )
         )
     )]
+    #[track_caller]
     pub fn set(&self, value: T) {
         match &self.inner {
             SignalSetterTypes::Write(s) => s.set(value),
@@ -0,0 +1,129 @@
+//! Warns about effects that take too long to run, in debug builds.
+//!
+//! Each time an effect runs, it is timed, and a warning with the location where it was
+//! created is logged to the console, and emitted as a `tracing` event with target
+//! `leptos_reactive::slow_effects`, when it takes longer than the budget. A single slow
+//! effect blocks the main thread and makes the page stutter, but is hard to find in a
+//! profile among the effects that run with it.
+//!
+//! The budget defaults to [`DEFAULT_SLOW_EFFECT_BUDGET`], the duration after which browsers
+//! consider a task to be a long task. The time of an effect only counts its own work: the
+//! effects that it creates, which run right away, are timed on their own.
+//!
+//! ```
+//! use leptos_reactive::slow_effects::*;
+//! use std::time::Duration;
+//!
+//! // animations need every frame
+//! set_slow_effect_budget(Some(Duration::from_millis(8)));
+//! # assert_eq!(slow_effect_budget(), if cfg!(debug_assertions) { Some(Duration::from_millis(8)) } else { None });
+//! # set_slow_effect_budget(None);
+//! # assert_eq!(slow_effect_budget(), None);
+//! ```
+//!
+//! Effects are not timed in release builds.
+
+use std::time::Duration;
+#[cfg(debug_assertions)]
+use std::{cell::Cell, panic::Location};
+
+/// How long an effect can run before it is reported, unless
+/// [`set_slow_effect_budget`] is called.
+pub const DEFAULT_SLOW_EFFECT_BUDGET: Duration = Duration::from_millis(50);
+
+#[cfg(debug_assertions)]
+thread_local! {
+    static BUDGET: Cell<Option<Duration>> =
+        const { Cell::new(Some(DEFAULT_SLOW_EFFECT_BUDGET)) };
+    // how long the effects that ran inside the effect being timed took, in milliseconds
+    static NESTED_MS: Cell<f64> = const { Cell::new(0.0) };
+}
+
+/// Sets how long an effect can run on the current thread before it is reported, or turns
+/// the reports off with `None`.
+pub fn set_slow_effect_budget(budget: Option<Duration>) {
+    #[cfg(debug_assertions)]
+    BUDGET.with(|current| current.set(budget));
+    #[cfg(not(debug_assertions))]
+    let _ = budget;
+}
+
+/// How long an effect can run before it is reported. Always `None` in release builds.
+pub fn slow_effect_budget() -> Option<Duration> {
+    #[cfg(debug_assertions)]
+    {
+        BUDGET.with(Cell::get)
+    }
+    #[cfg(not(debug_assertions))]
+    None
+}
+
+/// Times one run of an effect.
+#[cfg(debug_assertions)]
+pub(crate) struct EffectTimer {
+    budget: Duration,
+    started_at: f64,
+    /// The time of the effects timed so far inside the effect that is running this one.
+    outer_nested_ms: f64,
+}
+
+#[cfg(debug_assertions)]
+impl EffectTimer {
+    /// Starts timing, if there is a budget and a clock on this platform.
+    pub(crate) fn start() -> Option<Self> {
+        let budget = slow_effect_budget()?;
+        let started_at = now_ms()?;
+        Some(Self {
+            budget,
+            started_at,
+            outer_nested_ms: NESTED_MS.with(|nested| nested.replace(0.0)),
+        })
+    }
+
+    /// Reports the effect created at `defined_at` if it went over the budget.
+    pub(crate) fn finish(self, defined_at: &'static Location<'static>) {
+        // the wall clock can go backwards
+        let total_ms = now_ms()
+            .map(|now| (now - self.started_at).max(0.0))
+            .unwrap_or_default();
+        let nested_ms = NESTED_MS
+            .with(|nested| nested.replace(self.outer_nested_ms + total_ms));
+        let elapsed =
+            Duration::from_secs_f64((total_ms - nested_ms).max(0.0) / 1000.0);
+        if elapsed > self.budget {
+            tracing::warn!(
+                target: "leptos_reactive::slow_effects",
+                %defined_at,
+                elapsed_ms = elapsed.as_secs_f64() * 1000.0,
+                budget_ms = self.budget.as_secs_f64() * 1000.0,
+                "effect took longer than the budget to run"
+            );
+            crate::console_warn(&format!(
+                "The effect created at {defined_at} took {:.1}ms to run, \
+                 which is more than the budget of {}ms. Consider moving \
+                 expensive work out of the effect, splitting it, or deriving \
+                 less of the UI from it.",
+                elapsed.as_secs_f64() * 1000.0,
+                self.budget.as_millis(),
+            ));
+        }
+    }
+}
+
+/// The current time in milliseconds, from an arbitrary point.
+#[cfg(debug_assertions)]
+fn now_ms() -> Option<f64> {
+    cfg_if::cfg_if! {
+        if #[cfg(all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate")))] {
+            Some(js_sys::Date::now())
+        } else if #[cfg(target_arch = "wasm32")] {
+            // there is no clock without the browser APIs
+            None
+        } else {
+            thread_local! {
+                static START: std::time::Instant = std::time::Instant::now();
+            }
+            Some(START.with(|start| start.elapsed().as_secs_f64() * 1000.0))
+        }
+    }
+}
@@ -0,0 +1,195 @@
+//! Counts the reactive nodes that are alive, grouped by the component that created them, in
+//! debug builds.
+//!
+//! A count that keeps going up as the user navigates back and forth usually means that nodes
+//! are created outside of any owner, or under an owner that is never disposed, like a
+//! signal created in an event listener, which lives as long as the component that added it.
+//!
+//! ```
+//! use leptos_reactive::{stats::*, *};
+//!
+//! # let runtime = create_runtime();
+//! let before = runtime_stats();
+//! let count = create_rw_signal(0);
+//! let double = create_memo(move |_| count.get() * 2);
+//! let after = runtime_stats();
+//!
+//! if cfg!(debug_assertions) {
+//!     assert_eq!(after.total.signals, before.total.signals + 1);
+//!     assert_eq!(after.total.memos, before.total.memos + 1);
+//! }
+//! # runtime.dispose();
+//! ```
+//!
+//! A node is counted for the component whose body created it,
+//! or whose effects and memos created it when they ran. Nodes created outside of any
+//! component, like in the function that mounts the app, are only counted in
+//! [`RuntimeStats::total`]. Components are only known in apps built with `leptos`; with
+//! `leptos_reactive` alone, every node is outside of components.
+//!
+//! [`set_runtime_stats_logging`] logs how the counts have changed each time
+//! [`log_runtime_stats_changes`] is called, which the router does after each navigation.
+//!
+//! Nodes are not counted in release builds, where every count is `0`.
+
+#[cfg(debug_assertions)]
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+
+/// How many reactive nodes of each kind are alive.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NodeCounts {
+    /// Signals, including the signals of resources and stored values.
+    pub signals: usize,
+    /// Memos, including selectors and signal slices.
+    pub memos: usize,
+    /// Effects, including render effects and the effects of the renderer.
+    pub effects: usize,
+    /// Triggers, including the owners created by [`run_as_child`](crate::run_as_child).
+    pub triggers: usize,
+}
+
+impl NodeCounts {
+    /// How many nodes there are, of all kinds.
+    pub fn total(&self) -> usize {
+        self.signals + self.memos + self.effects + self.triggers
+    }
+}
+
+/// A snapshot of the reactive nodes alive in the current runtime, returned by
+/// [`runtime_stats`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RuntimeStats {
+    /// Every node, including the ones created outside of components.
+    pub total: NodeCounts,
+    /// The nodes created by each component, by the name of the component.
+    pub by_component: BTreeMap<String, NodeCounts>,
+}
+
+impl RuntimeStats {
+    /// How many nodes each component has gained, or lost if negative, since `earlier`,
+    /// leaving out the components whose count hasn't changed.
+    pub fn changes_since(
+        &self,
+        earlier: &RuntimeStats,
+    ) -> Vec<(String, isize)> {
+        let count = |stats: &RuntimeStats, name: &str| {
+            stats
+                .by_component
+                .get(name)
+                .map(NodeCounts::total)
+                .unwrap_or_default() as isize
+        };
+        let mut names = self
+            .by_component
+            .keys()
+            .chain(earlier.by_component.keys())
+            .collect::<Vec<_>>();
+        names.sort();
+        names.dedup();
+        names
+            .into_iter()
+            .map(|name| {
+                (name.clone(), count(self, name) - count(earlier, name))
+            })
+            .filter(|(_, change)| *change != 0)
+            .collect()
+    }
+}
+
+/// Counts the reactive nodes alive in the current runtime. This is empty in release builds.
+pub fn runtime_stats() -> RuntimeStats {
+    #[cfg(debug_assertions)]
+    {
+        use crate::node::ReactiveNodeType;
+
+        crate::with_runtime(|runtime| {
+            let mut stats = RuntimeStats::default();
+            for node in runtime.nodes.borrow().values() {
+                let mut counts = vec![&mut stats.total];
+                if let Some(component) = &node.diagnostics.component {
+                    counts.push(
+                        stats
+                            .by_component
+                            .entry(component.to_string())
+                            .or_default(),
+                    );
+                }
+                for counts in counts {
+                    match node.node_type {
+                        ReactiveNodeType::Signal => counts.signals += 1,
+                        ReactiveNodeType::Memo { .. } => counts.memos += 1,
+                        ReactiveNodeType::Effect { .. } => counts.effects += 1,
+                        ReactiveNodeType::Trigger => counts.triggers += 1,
+                    }
+                }
+            }
+            stats
+        })
+        .unwrap_or_default()
+    }
+    #[cfg(not(debug_assertions))]
+    RuntimeStats::default()
+}
+
+#[cfg(debug_assertions)]
+thread_local! {
+    static LOGGING: Cell<bool> = const { Cell::new(false) };
+    static LAST_LOGGED: RefCell<Option<RuntimeStats>> = const { RefCell::new(None) };
+}
+
+/// Turns on or off logging by [`log_runtime_stats_changes`] on the current thread. Logging
+/// is off by default.
+pub fn set_runtime_stats_logging(enabled: bool) {
+    #[cfg(debug_assertions)]
+    {
+        LOGGING.with(|logging| logging.set(enabled));
+        if !enabled {
+            LAST_LOGGED.with(|last| last.take());
+        }
+    }
+    #[cfg(not(debug_assertions))]
+    let _ = enabled;
+}
+
+/// If logging is on, logs how the counts of [`runtime_stats`] have changed since the last
+/// time this was called, to the console and as a `tracing` event with target
+/// `leptos_reactive::stats`. `label` says what happened in between, like the path that was
+/// navigated to.
+///
+/// The first call only takes a snapshot to compare the next one with.
+pub fn log_runtime_stats_changes(label: &str) {
+    #[cfg(debug_assertions)]
+    {
+        if !LOGGING.with(Cell::get) {
+            return;
+        }
+        let stats = runtime_stats();
+        let Some(earlier) =
+            LAST_LOGGED.with(|last| last.borrow_mut().replace(stats.clone()))
+        else {
+            return;
+        };
+
+        let change =
+            stats.total.total() as isize - earlier.total.total() as isize;
+        let by_component = stats
+            .changes_since(&earlier)
+            .into_iter()
+            .map(|(name, change)| format!("\n  {name}: {change:+}"))
+            .collect::<String>();
+        tracing::info!(
+            target: "leptos_reactive::stats",
+            label,
+            total = stats.total.total(),
+            change,
+            "reactive nodes changed"
+        );
+        crate::console_log(&format!(
+            "[{label}] {} reactive nodes ({change:+}){by_component}",
+            stats.total.total(),
+        ));
+    }
+    #[cfg(not(debug_assertions))]
+    let _ = label;
+}
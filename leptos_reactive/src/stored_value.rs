@@ -27,6 +27,8 @@ where
 {
     id: StoredValueId,
     ty: PhantomData<T>,
+    #[cfg(any(debug_assertions, feature = "ssr"))]
+    defined_at: &'static std::panic::Location<'static>,
 }
 
 impl<T: Default> Default for StoredValue<T> {
@@ -45,10 +47,12 @@ impl<T> Copy for StoredValue<T> {}
 
 impl<T> fmt::Debug for StoredValue<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("StoredValue")
-            .field("id", &self.id)
-            .field("ty", &self.ty)
-            .finish()
+        let mut s = f.debug_struct("StoredValue");
+        s.field("id", &self.id);
+        s.field("ty", &self.ty);
+        #[cfg(any(debug_assertions, feature = "ssr"))]
+        s.field("defined_at", &self.defined_at);
+        s.finish()
     }
 }
 
@@ -95,7 +99,14 @@ impl<T> StoredValue<T> {
     where
         T: Clone,
     {
-        self.try_get_value().expect("could not get stored value")
+        match self.try_get_value() {
+            Some(value) => value,
+            None => panic_getting_dead_stored_value(
+                "Attempted to get a stored value after it was disposed.",
+                #[cfg(any(debug_assertions, feature = "ssr"))]
+                self.defined_at,
+            ),
+        }
     }
 
     /// Same as [`StoredValue::get_value`] but will not panic by default.
@@ -130,7 +141,14 @@ impl<T> StoredValue<T> {
     //               track the stored value. This method will also be removed in \
     //               a future version of `leptos`"]
     pub fn with_value<U>(&self, f: impl FnOnce(&T) -> U) -> U {
-        self.try_with_value(f).expect("could not get stored value")
+        match self.try_with_value(f) {
+            Some(value) => value,
+            None => panic_getting_dead_stored_value(
+                "Attempted to get a stored value after it was disposed.",
+                #[cfg(any(debug_assertions, feature = "ssr"))]
+                self.defined_at,
+            ),
+        }
     }
 
     /// Same as [`StoredValue::with_value`] but returns [`Some(O)]` only if
@@ -189,8 +207,13 @@ impl<T> StoredValue<T> {
     /// stored value has been disposed.
     #[track_caller]
     pub fn update_value(&self, f: impl FnOnce(&mut T)) {
-        self.try_update_value(f)
-            .expect("could not set stored value");
+        if self.try_update_value(f).is_none() {
+            panic_getting_dead_stored_value(
+                "Attempted to update a stored value after it was disposed.",
+                #[cfg(any(debug_assertions, feature = "ssr"))]
+                self.defined_at,
+            )
+        }
     }
 
     /// Same as [`Self::update_value`], but returns [`Some(O)`] if the
@@ -318,6 +341,8 @@ where
     StoredValue {
         id,
         ty: PhantomData,
+        #[cfg(any(debug_assertions, feature = "ssr"))]
+        defined_at: std::panic::Location::caller(),
     }
 }
 
@@ -368,3 +393,21 @@ impl<T> StoredValue<T> {
 }
 
 impl_get_fn_traits!(StoredValue(get_value));
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn panic_getting_dead_stored_value(
+    msg: &str,
+    #[cfg(any(debug_assertions, feature = "ssr"))]
+    defined_at: &'static std::panic::Location<'static>,
+) -> ! {
+    let location = std::panic::Location::caller();
+
+    #[cfg(any(debug_assertions, feature = "ssr"))]
+    let defined_at_msg = format!("stored value created here: {defined_at}\n");
+    #[cfg(not(any(debug_assertions, feature = "ssr")))]
+    let defined_at_msg = String::default();
+
+    panic!("{msg}\n{defined_at_msg}warning happened here: {location}")
+}
@@ -0,0 +1,185 @@
+//! Strict mode helps find effects that are not safe to clean up and run again.
+//!
+//! When it is turned on, in debug builds, every [`create_effect`](crate::create_effect) and
+//! [`create_isomorphic_effect`](crate::create_isomorphic_effect) runs twice the first time: it
+//! runs once, its [`on_cleanup`](crate::on_cleanup) callbacks run as if the effect were about
+//! to re-run, and then it runs again. Any interval or window event listener that was added by
+//! the first run but not removed by its cleanup is reported, because it will be added again
+//! each time the effect re-runs, and will outlive the effect when it is disposed, for example
+//! by a keep-alive cache or a hot reload.
+//!
+//! Components whose props can be cloned are set up twice in the same way, except while
+//! hydrating: the first setup is disposed right away, and what it left behind is reported.
+//! Components and effects set up during that first run are only set up once.
+//!
+//! Strict mode does nothing in release builds.
+
+#[cfg(debug_assertions)]
+use std::{
+    cell::{Cell, RefCell},
+    panic::Location,
+};
+
+#[cfg(debug_assertions)]
+struct Resource {
+    seq: u64,
+    kind: &'static str,
+    key: u64,
+    created_at: &'static Location<'static>,
+}
+
+#[cfg(debug_assertions)]
+thread_local! {
+    static STRICT_MODE: Cell<bool> = const { Cell::new(false) };
+    static REHEARSING: Cell<bool> = const { Cell::new(false) };
+    static NEXT_SEQ: Cell<u64> = const { Cell::new(0) };
+    static LIVE_RESOURCES: RefCell<Vec<Resource>> =
+        const { RefCell::new(Vec::new()) };
+}
+
+/// Turns [strict mode](self) on or off for the current thread.
+///
+/// This is meant to be called once, before mounting the app, in development.
+pub fn set_strict_mode(enabled: bool) {
+    #[cfg(debug_assertions)]
+    STRICT_MODE.with(|strict| strict.set(enabled));
+    #[cfg(not(debug_assertions))]
+    let _ = enabled;
+}
+
+/// Whether [strict mode](self) is on. Always `false` in release builds.
+pub fn is_strict_mode() -> bool {
+    #[cfg(debug_assertions)]
+    {
+        STRICT_MODE.with(Cell::get)
+    }
+    #[cfg(not(debug_assertions))]
+    false
+}
+
+/// Records that a side effect of the given `kind`, identified by `key`, has been set up, so
+/// that strict mode can report it if it is still active after the effect that set it up has
+/// been cleaned up.
+#[doc(hidden)]
+#[track_caller]
+pub fn acquire(kind: &'static str, key: u64) {
+    #[cfg(debug_assertions)]
+    if is_strict_mode() {
+        let seq = NEXT_SEQ.with(|seq| seq.replace(seq.get() + 1));
+        LIVE_RESOURCES.with(|live| {
+            live.borrow_mut().push(Resource {
+                seq,
+                kind,
+                key,
+                created_at: Location::caller(),
+            })
+        });
+    }
+    #[cfg(not(debug_assertions))]
+    let _ = (kind, key);
+}
+
+/// Records that a side effect recorded with [`acquire`] has been torn down.
+#[doc(hidden)]
+pub fn release(kind: &'static str, key: u64) {
+    #[cfg(debug_assertions)]
+    LIVE_RESOURCES.with(|live| {
+        live.borrow_mut()
+            .retain(|resource| resource.kind != kind || resource.key != key)
+    });
+    #[cfg(not(debug_assertions))]
+    let _ = (kind, key);
+}
+
+/// Whether something that is being set up should be set up and cleaned up once before it is
+/// set up for real, with [`rehearse`]: strict mode is on, and this is not already happening
+/// inside another rehearsal.
+#[doc(hidden)]
+pub fn should_rehearse() -> bool {
+    #[cfg(debug_assertions)]
+    {
+        is_strict_mode() && !REHEARSING.with(Cell::get)
+    }
+    #[cfg(not(debug_assertions))]
+    false
+}
+
+/// Runs `rehearsal`, which sets something up and cleans it up again, and reports the side
+/// effects it recorded with [`acquire`] that are still active afterwards. `what` describes
+/// the thing that was set up, like `the effect defined at src/app.rs:12:5`.
+#[doc(hidden)]
+pub fn rehearse(what: &dyn std::fmt::Display, rehearsal: impl FnOnce()) {
+    #[cfg(debug_assertions)]
+    {
+        struct Rehearsing(bool);
+
+        impl Drop for Rehearsing {
+            fn drop(&mut self) {
+                REHEARSING.with(|rehearsing| rehearsing.set(self.0));
+            }
+        }
+
+        let first_seq = NEXT_SEQ.with(Cell::get);
+        {
+            let _rehearsing = Rehearsing(
+                REHEARSING.with(|rehearsing| rehearsing.replace(true)),
+            );
+            rehearsal();
+        }
+
+        LIVE_RESOURCES.with(|live| {
+            for resource in live
+                .borrow()
+                .iter()
+                .filter(|resource| resource.seq >= first_seq)
+            {
+                let Resource {
+                    kind, created_at, ..
+                } = resource;
+                tracing::warn!(
+                    target: "leptos_reactive::strict_mode",
+                    %what,
+                    kind,
+                    %created_at,
+                    "side effect not removed when it was cleaned up"
+                );
+                crate::console_warn(&format!(
+                    "[strict mode] {what} added a {kind} at {created_at} that \
+                     was still active after it was cleaned up. It will be \
+                     added again each time it is set up. Remove it in \
+                     `on_cleanup`."
+                ));
+            }
+        });
+    }
+    #[cfg(not(debug_assertions))]
+    {
+        let _ = what;
+        rehearsal();
+    }
+}
+
+/// Wraps an effect function so that, in strict mode, its first run happens twice, with its
+/// cleanups in between, reporting the side effects that the cleanups left behind.
+#[cfg(debug_assertions)]
+pub(crate) fn run_first_twice<T>(
+    f: impl Fn(Option<T>) -> T,
+    defined_at: &'static Location<'static>,
+) -> impl Fn(Option<T>) -> T {
+    move |prev| {
+        if prev.is_some() || !should_rehearse() {
+            return f(prev);
+        }
+
+        rehearse(&format_args!("The effect defined at {defined_at}"), || {
+            drop(f(None));
+            _ = crate::with_runtime(|runtime| {
+                if let Some(effect) = runtime.observer.get() {
+                    runtime.cleanup_node(effect);
+                }
+            });
+        });
+
+        f(None)
+    }
+}
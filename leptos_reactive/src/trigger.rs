@@ -64,6 +64,7 @@ impl Trigger {
     /// ## Panics
     /// Panics if there is no current reactive runtime, or if the
     /// trigger has been disposed.
+    #[track_caller]
     pub fn notify(&self) {
         assert!(self.try_notify(), "Trigger::notify(): runtime not alive")
     }
@@ -71,6 +72,7 @@ impl Trigger {
     /// Attempts to notify any reactive code where this trigger is tracked to rerun.
     ///
     /// Returns `false` if there is no current reactive runtime.
+    #[track_caller]
     pub fn try_notify(&self) -> bool {
         with_runtime(|runtime| {
             runtime.mark_dirty(self.id);
@@ -84,12 +86,14 @@ impl Trigger {
     /// ## Panics
     /// Panics if there is no current reactive runtime, or if the
     /// trigger has been disposed.
+    #[track_caller]
     pub fn track(&self) {
         assert!(self.try_track(), "Trigger::track(): runtime not alive")
     }
 
     /// Attempts to subscribe the running effect to this trigger, returning
     /// `false` if there is no current reactive runtime.
+    #[track_caller]
     pub fn try_track(&self) -> bool {
         let diagnostics = diagnostics!(self);
 
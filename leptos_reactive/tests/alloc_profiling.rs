@@ -0,0 +1,34 @@
+#![cfg(feature = "alloc-profiling")]
+
+use leptos_reactive::{
+    alloc_profiling::{AllocationStats, CountingAllocator},
+    create_isomorphic_effect, create_memo, create_runtime, create_signal,
+    SignalGet, SignalSet,
+};
+
+#[global_allocator]
+static ALLOC: CountingAllocator<std::alloc::System> =
+    CountingAllocator::new(std::alloc::System);
+
+#[test]
+fn propagating_a_change_reuses_buffers() {
+    let runtime = create_runtime();
+
+    let (count, set_count) = create_signal(0);
+    let doubled = create_memo(move |_| count.get() * 2);
+    for offset in 0..10 {
+        let memo = create_memo(move |_| doubled.get() + offset);
+        create_isomorphic_effect(move |_| {
+            memo.get();
+        });
+    }
+
+    // the first update grows the buffers for marking and running effects
+    set_count.set(1);
+
+    let before = AllocationStats::current();
+    set_count.set(2);
+    assert_eq!(AllocationStats::current().since(before).allocations, 0);
+
+    runtime.dispose();
+}
@@ -0,0 +1,72 @@
+// each test binary only uses some of these
+#![allow(dead_code)]
+
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex},
+};
+use tracing::{
+    field::{Field, Visit},
+    span, Event, Metadata, Subscriber,
+};
+
+/// A `tracing` subscriber that keeps the fields of the events sent to one
+/// target, to check what the runtime warns about.
+#[derive(Clone)]
+pub struct Warnings {
+    target: &'static str,
+    events: Arc<Mutex<Vec<HashMap<&'static str, String>>>>,
+}
+
+impl Warnings {
+    pub fn new(target: &'static str) -> Self {
+        Self {
+            target,
+            events: Default::default(),
+        }
+    }
+
+    /// The number of events sent so far.
+    pub fn count(&self) -> usize {
+        self.events.lock().unwrap().len()
+    }
+
+    /// A field of each event sent so far, formatted with `Debug`.
+    pub fn field(&self, name: &str) -> Vec<String> {
+        self.events
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|fields| fields.get(name).cloned())
+            .collect()
+    }
+}
+
+struct Fields<'a>(&'a mut HashMap<&'static str, String>);
+
+impl Visit for Fields<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0.insert(field.name(), format!("{value:?}"));
+    }
+}
+
+impl Subscriber for Warnings {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+    fn new_span(&self, _: &span::Attributes<'_>) -> span::Id {
+        span::Id::from_u64(1)
+    }
+    fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+    fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+    fn event(&self, event: &Event<'_>) {
+        if event.metadata().target() == self.target {
+            let mut fields = HashMap::new();
+            event.record(&mut Fields(&mut fields));
+            self.events.lock().unwrap().push(fields);
+        }
+    }
+    fn enter(&self, _: &span::Id) {}
+    fn exit(&self, _: &span::Id) {}
+}
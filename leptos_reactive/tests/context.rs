@@ -41,3 +41,88 @@ fn context() {
 
     runtime.dispose();
 }
+
+#[test]
+fn reparented_owner_outlives_old_parent_and_uses_new_context() {
+    use leptos_reactive::{
+        as_child_of_current_owner, create_runtime, create_rw_signal,
+        provide_context, use_context, with_owner, Owner, SignalGetUntracked,
+    };
+
+    let runtime = create_runtime();
+
+    let (cache, _cache_disposer) = as_child_of_current_owner(|_| {
+        provide_context("cache");
+        Owner::current().unwrap()
+    })(());
+    // the keep-alive cache holds on to the page's disposer
+    let (((page, signal), _page_disposer), old_parent) =
+        as_child_of_current_owner(|_| {
+            provide_context("old parent");
+            as_child_of_current_owner(|_| {
+                (Owner::current().unwrap(), create_rw_signal(1))
+            })(())
+        })(());
+
+    assert_ne!(page.parent(), Some(cache));
+    page.reparent(cache).unwrap();
+    assert_eq!(page.parent(), Some(cache));
+    assert!(cache.reparent(page).is_err());
+
+    drop(old_parent);
+    assert_eq!(signal.try_get_untracked(), Some(1));
+    assert_eq!(with_owner(page, use_context::<&str>), Some("cache"));
+
+    runtime.dispose();
+}
+
+#[test]
+fn reparented_effect_is_disposed_with_its_new_parent() {
+    use leptos_reactive::{
+        as_child_of_current_owner, create_isomorphic_effect, create_runtime,
+        create_rw_signal, on_cleanup, Owner, SignalGet, SignalSet,
+    };
+    use std::{cell::Cell, rc::Rc};
+
+    let runtime = create_runtime();
+
+    let (cache, cache_disposer) =
+        as_child_of_current_owner(|_| Owner::current().unwrap())(());
+    let signal = create_rw_signal(0);
+    let runs = Rc::new(Cell::new(0));
+    let cleanups = Rc::new(Cell::new(0));
+    let (effect, _old_parent) = as_child_of_current_owner({
+        let runs = Rc::clone(&runs);
+        let cleanups = Rc::clone(&cleanups);
+        move |_| {
+            let effect = Rc::new(Cell::new(None));
+            create_isomorphic_effect({
+                let effect = Rc::clone(&effect);
+                let runs = Rc::clone(&runs);
+                let cleanups = Rc::clone(&cleanups);
+                move |_| {
+                    effect.set(Owner::current());
+                    signal.get();
+                    runs.set(runs.get() + 1);
+                    let cleanups = Rc::clone(&cleanups);
+                    on_cleanup(move || cleanups.set(cleanups.get() + 1));
+                }
+            });
+            effect.get().unwrap()
+        }
+    })(());
+
+    effect.reparent(cache).unwrap();
+    assert_eq!(effect.parent(), Some(cache));
+
+    signal.set(1);
+    assert_eq!((runs.get(), cleanups.get()), (2, 1));
+
+    // the effect is moved as an effect, so it is stopped with its new parent
+    drop(cache_disposer);
+    assert_eq!(cleanups.get(), 2);
+    signal.set(2);
+    assert_eq!(runs.get(), 2);
+
+    runtime.dispose();
+}
@@ -1,6 +1,8 @@
 use leptos_reactive::{
     batch, create_isomorphic_effect, create_memo, create_runtime,
-    create_rw_signal, create_signal, untrack, SignalGet, SignalSet,
+    create_rw_signal, create_signal, create_trigger, queue_after_effects,
+    untrack, why_did_this_run, SignalGet, SignalGetUntracked, SignalSet,
+    SignalSetUntracked, SignalUpdate,
 };
 
 #[test]
@@ -131,3 +133,187 @@ fn batching_actually_batches() {
 
     runtime.dispose();
 }
+
+#[test]
+fn nested_batches_run_effects_once_the_outermost_ends() {
+    use std::{cell::Cell, rc::Rc};
+
+    let runtime = create_runtime();
+
+    let signals = (0..5).map(create_rw_signal).collect::<Vec<_>>();
+    let count = Rc::new(Cell::new(0));
+    let sum = Rc::new(Cell::new(0));
+    create_isomorphic_effect({
+        let signals = signals.clone();
+        let count = Rc::clone(&count);
+        let sum = Rc::clone(&sum);
+        move |_| {
+            sum.set(signals.iter().map(|s| s.get()).sum::<i32>());
+            count.set(count.get() + 1);
+        }
+    });
+    assert_eq!((count.get(), sum.get()), (1, 10));
+
+    let total = batch(|| {
+        for signal in &signals[..3] {
+            signal.update(|n| *n += 1);
+        }
+        batch(|| {
+            signals[3].update(|n| *n += 1);
+            signals[4].update(|n| *n += 1);
+        });
+        // the inner batch doesn't run the effect
+        assert_eq!(count.get(), 1);
+        signals.iter().map(|s| s.get_untracked()).sum::<i32>()
+    });
+    assert_eq!(total, 15);
+    assert_eq!((count.get(), sum.get()), (2, 15));
+
+    // untracked writes inside a batch don't run it either
+    batch(|| signals[0].set_untracked(100));
+    assert_eq!((count.get(), sum.get()), (2, 15));
+
+    runtime.dispose();
+}
+
+#[cfg(debug_assertions)]
+#[test]
+fn effects_know_why_they_ran() {
+    use std::{cell::RefCell, rc::Rc};
+
+    let runtime = create_runtime();
+
+    let causes = Rc::new(RefCell::new(Vec::new()));
+    let a = create_rw_signal(0).debug_changes();
+    let created_a = line!() - 1;
+    let (b, set_b) = create_signal("b");
+    let created_b = line!() - 1;
+    let doubled = create_memo({
+        let causes = Rc::clone(&causes);
+        move |_| {
+            causes.borrow_mut().push(why_did_this_run());
+            a.get() * 2
+        }
+    })
+    .debug_changes();
+    let trigger = create_trigger();
+
+    create_isomorphic_effect({
+        let causes = Rc::clone(&causes);
+        move |_| {
+            doubled.get();
+            b.get();
+            trigger.track();
+            causes.borrow_mut().push(why_did_this_run());
+        }
+    });
+    assert_eq!(*causes.borrow(), [None, None]);
+    assert_eq!(why_did_this_run(), None);
+
+    a.set(1);
+    set_b.set("c");
+    trigger.notify();
+
+    let causes = causes.borrow();
+    assert_eq!(causes.len(), 6);
+
+    let signal = causes[2].clone().unwrap();
+    assert_eq!(signal.source, "signal");
+    assert_eq!(signal.defined_at.file(), file!());
+    assert_eq!(signal.defined_at.line(), created_a);
+    assert_eq!(
+        signal.to_string(),
+        format!(
+            "signal created at {} changed from 0 to 1",
+            signal.defined_at
+        )
+    );
+
+    let memo = causes[3].clone().unwrap();
+    assert_eq!(memo.source, "memo");
+    assert_eq!(
+        (memo.before.as_deref(), memo.after.as_deref()),
+        (Some("0"), Some("2"))
+    );
+
+    // signals that do not record their changes only say where they were created
+    let signal = causes[4].clone().unwrap();
+    assert_eq!(signal.defined_at.line(), created_b);
+    assert_eq!((signal.before, signal.after), (None, None));
+
+    assert_eq!(causes[5].clone().unwrap().source, "trigger");
+
+    runtime.dispose();
+}
+
+#[test]
+fn callbacks_run_after_all_effects_of_an_update() {
+    use std::{cell::RefCell, rc::Rc};
+
+    let runtime = create_runtime();
+
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let (a, set_a) = create_signal(0);
+    let (b, set_b) = create_signal(0);
+
+    // outside a flush, the callback runs right away
+    queue_after_effects({
+        let log = log.clone();
+        move || log.borrow_mut().push("immediate".to_string())
+    });
+    assert_eq!(*log.borrow(), ["immediate"]);
+    log.borrow_mut().clear();
+
+    create_isomorphic_effect({
+        let log = log.clone();
+        move |_| {
+            let a = a.get();
+            log.borrow_mut().push(format!("effect a={a}"));
+            if a > 0 {
+                // a nested update doesn't flush the outer callbacks early
+                set_b.set(a);
+            }
+            let log = log.clone();
+            queue_after_effects(move || {
+                log.borrow_mut().push(format!("after a={a}"))
+            });
+        }
+    });
+    create_isomorphic_effect({
+        let log = log.clone();
+        move |_| log.borrow_mut().push(format!("effect b={}", b.get()))
+    });
+    log.borrow_mut().clear();
+
+    set_a.set(1);
+    assert_eq!(*log.borrow(), ["effect a=1", "effect b=1", "after a=1"]);
+
+    runtime.dispose();
+}
+
+#[test]
+fn callbacks_run_right_away_after_an_effect_panicked() {
+    use std::{cell::Cell, panic::AssertUnwindSafe, rc::Rc};
+
+    let runtime = create_runtime();
+
+    let (a, set_a) = create_signal(0);
+    create_isomorphic_effect(move |_| {
+        if a.get() > 0 {
+            panic!("effect failed");
+        }
+    });
+    let panicked =
+        std::panic::catch_unwind(AssertUnwindSafe(|| set_a.set(1))).is_err();
+    assert!(panicked);
+
+    // the flush that panicked no longer counts as running
+    let ran = Rc::new(Cell::new(false));
+    queue_after_effects({
+        let ran = Rc::clone(&ran);
+        move || ran.set(true)
+    });
+    assert!(ran.get());
+
+    runtime.dispose();
+}
@@ -64,3 +64,236 @@ fn resource_returns_last_future() {
         runtime.dispose();
     }
 }
+
+#[test]
+fn streaming_resource_folds_stream_items() {
+    #[cfg(not(feature = "ssr"))]
+    {
+        use futures::stream;
+        use leptos_reactive::{
+            create_runtime, create_signal, create_streaming_resource,
+            SignalGet, SignalSet,
+        };
+
+        let runtime = create_runtime();
+
+        let (count, set_count) = create_signal(3);
+
+        let resource = create_streaming_resource(
+            move || count.get(),
+            |count| stream::iter(1..=count),
+            Vec::new,
+            |items: &mut Vec<i32>, item| items.push(item),
+        );
+
+        assert_eq!(resource.get(), Some(vec![1, 2, 3]));
+
+        // a new source starts over from the initial value
+        set_count.set(2);
+        assert_eq!(resource.get(), Some(vec![1, 2]));
+
+        runtime.dispose();
+    }
+}
+
+#[test]
+fn coalesced_resources_share_one_fetch() {
+    #[cfg(not(feature = "ssr"))]
+    {
+        use leptos_reactive::{
+            create_coalesced_resource, create_runtime, SignalGet,
+        };
+        use std::{cell::Cell, rc::Rc};
+
+        let runtime = create_runtime();
+
+        let fetches = Rc::new(Cell::new(0));
+        let fetcher = {
+            let fetches = Rc::clone(&fetches);
+            move |id: u32| {
+                fetches.set(fetches.get() + 1);
+                async move { id * 2 }
+            }
+        };
+
+        let a = create_coalesced_resource("a", || 1, fetcher.clone());
+        let b = create_coalesced_resource("a", || 1, fetcher.clone());
+        assert_eq!(a, b);
+        assert_eq!(fetches.get(), 1);
+        assert_eq!(b.get(), Some(2));
+
+        // a different key gets its own resource
+        let c = create_coalesced_resource("c", || 2, fetcher);
+        assert_ne!(a, c);
+        assert_eq!(fetches.get(), 2);
+        assert_eq!(c.get(), Some(4));
+
+        runtime.dispose();
+    }
+}
+
+#[test]
+fn disposing_the_runtime_cancels_pending_loads() {
+    #[cfg(feature = "ssr")]
+    {
+        use futures::channel::oneshot::channel;
+        use leptos_reactive::{create_resource, create_runtime};
+        use std::{cell::Cell, rc::Rc};
+        use tokio::task;
+        use tokio_test::block_on;
+
+        block_on(task::LocalSet::new().run_until(async move {
+            let runtime = create_runtime();
+
+            let (tx, rx) = channel::<()>();
+            let rx = Rc::new(Cell::new(Some(rx)));
+            let finished = Rc::new(Cell::new(false));
+            create_resource(|| (), {
+                let finished = Rc::clone(&finished);
+                move |_| {
+                    let rx = rx.take();
+                    let finished = Rc::clone(&finished);
+                    async move {
+                        _ = rx.unwrap().await;
+                        finished.set(true);
+                    }
+                }
+            });
+            task::yield_now().await;
+
+            // the client went away before the resource loaded
+            runtime.dispose();
+            _ = tx.send(());
+            task::yield_now().await;
+            task::yield_now().await;
+
+            assert!(!finished.get());
+        }));
+    }
+}
+
+#[test]
+fn resources_can_be_set_optimistically_and_refetched() {
+    #[cfg(feature = "ssr")]
+    {
+        use futures::channel::oneshot::{channel, Sender};
+        use leptos_reactive::*;
+        use std::{cell::RefCell, rc::Rc};
+        use tokio::task;
+        use tokio_test::block_on;
+
+        block_on(task::LocalSet::new().run_until(async move {
+            let runtime = create_runtime();
+
+            // every load waits for the value sent by the test
+            let loads = Rc::new(RefCell::new(Vec::<Sender<u32>>::new()));
+            let todos = create_resource(|| (), {
+                let loads = Rc::clone(&loads);
+                move |_| {
+                    let (tx, rx) = channel();
+                    loads.borrow_mut().push(tx);
+                    async move { rx.await.unwrap_or_default() }
+                }
+            });
+            let resolve = |value: u32| {
+                let tx = loads.borrow_mut().remove(0);
+                tx.send(value).unwrap();
+            };
+
+            let suspense = SuspenseContext::new();
+            provide_context(suspense);
+            assert_eq!(todos.get(), None);
+            assert!(!suspense.none_pending());
+
+            // an optimistic value replaces the load that is in flight
+            todos.set(1);
+            assert_eq!(todos.get(), Some(1));
+            assert!(!todos.loading().get_untracked());
+            assert!(suspense.none_pending());
+            resolve(0);
+            task::yield_now().await;
+            assert_eq!(todos.get(), Some(1));
+
+            // refetching keeps the optimistic value until the server responds
+            task::yield_now().await;
+            todos.refetch();
+            assert!(todos.loading().get_untracked());
+            assert!(!suspense.none_pending());
+            assert_eq!(todos.get(), Some(1));
+            resolve(2);
+            task::yield_now().await;
+            assert_eq!(todos.get(), Some(2));
+            assert!(suspense.none_pending());
+
+            runtime.dispose();
+        }));
+    }
+}
+
+#[test]
+fn local_resources_are_not_loaded_or_serialized_on_the_server() {
+    #[cfg(feature = "ssr")]
+    {
+        use leptos_reactive::*;
+        use std::{cell::Cell, rc::Rc};
+
+        // doesn't implement `Serializable`
+        #[derive(Clone)]
+        struct MediaStream;
+
+        let runtime = create_runtime();
+        let fetched = Rc::new(Cell::new(false));
+        let stream = create_local_resource(|| (), {
+            let fetched = Rc::clone(&fetched);
+            move |_| {
+                fetched.set(true);
+                async { MediaStream }
+            }
+        });
+
+        let suspense = SuspenseContext::new();
+        provide_context(suspense);
+        assert!(stream.get().is_none());
+
+        // `<Suspense/>` renders its fallback, and leaves the resource to the client
+        assert!(!fetched.get());
+        assert!(suspense.has_local_only());
+        assert!(!suspense.none_pending());
+        assert!(SharedContext::serialization_resolvers().is_empty());
+        assert!(SharedContext::pending_resources().is_empty());
+
+        runtime.dispose();
+    }
+}
+
+// without `ssr`, local resources run, and are resolved synchronously outside the browser
+#[cfg(not(any(feature = "csr", feature = "hydrate", feature = "ssr")))]
+#[test]
+fn local_resources_and_signals_hold_values_that_are_not_send() {
+    use leptos_reactive::*;
+    use std::{cell::RefCell, rc::Rc};
+
+    let runtime = create_runtime();
+
+    let log = create_rw_signal(Rc::new(RefCell::new(Vec::<u32>::new())));
+    let (page, set_page) = create_signal(1);
+    let resource = create_local_resource(
+        move || page.get(),
+        move |page| {
+            // the future holds an `Rc` across an await point
+            let log = log.get_untracked();
+            async move {
+                async {}.await;
+                log.borrow_mut().push(page);
+                Rc::new(page * 10)
+            }
+        },
+    );
+
+    assert_eq!(resource.get().as_deref(), Some(&10));
+    set_page.set(2);
+    assert_eq!(resource.get().as_deref(), Some(&20));
+    assert_eq!(*log.get_untracked().borrow(), [1, 2]);
+
+    runtime.dispose();
+}
@@ -0,0 +1,27 @@
+#[cfg(not(any(
+    feature = "serde-lite",
+    feature = "miniserde",
+    feature = "rkyv"
+)))]
+#[test]
+fn serializable_round_trips() {
+    use leptos_reactive::Serializable;
+
+    let value = (42_u32, String::from("<script>"), vec![Some(1.5_f64), None]);
+    let serialized = value.ser().unwrap();
+    assert_eq!(
+        <(u32, String, Vec<Option<f64>>)>::de(&serialized).unwrap(),
+        value
+    );
+}
+
+#[cfg(feature = "bincode")]
+#[test]
+fn bincode_payload_is_base64() {
+    use leptos_reactive::Serializable;
+
+    let serialized = vec![1_u64, 2, 3].ser().unwrap();
+    assert!(serialized
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '/'));
+}
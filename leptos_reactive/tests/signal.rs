@@ -12,6 +12,23 @@ fn basic_signal() {
     runtime.dispose();
 }
 
+#[test]
+fn signal_into_fn() {
+    let runtime = create_runtime();
+
+    let (a, set_a) = create_signal(1);
+    let memo = create_memo(move |_| a.get() * 10);
+    let signal = Signal::derive(move || a.get() + 1);
+    let read = a.into_fn();
+    let memo = memo.into_fn();
+    let signal = signal.into_fn();
+    assert_eq!((read(), memo(), signal()), (1, 10, 2));
+    set_a.set(2);
+    assert_eq!((read(), memo(), signal()), (2, 20, 3));
+
+    runtime.dispose();
+}
+
 #[test]
 fn derived_signals() {
     let runtime = create_runtime();
@@ -27,3 +44,165 @@ fn derived_signals() {
 
     runtime.dispose();
 }
+
+#[cfg(debug_assertions)]
+#[test]
+fn disposed_signal_panics_name_creation_and_access_locations() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    fn panic_message(f: impl FnOnce()) -> String {
+        let payload = catch_unwind(AssertUnwindSafe(f)).unwrap_err();
+        payload.downcast_ref::<String>().unwrap().clone()
+    }
+
+    let runtime = create_runtime();
+
+    let (a, _) = create_signal(0);
+    let created_a = line!() - 1;
+    let memo = create_memo(move |_| 0);
+    let created_memo = line!() - 1;
+    let stored = store_value(0);
+    let created_stored = line!() - 1;
+    a.dispose();
+    memo.dispose();
+    stored.dispose();
+
+    let msg = panic_message(|| _ = a.get());
+    let accessed = line!() - 1;
+    assert!(
+        msg.contains(&format!("signal created here: {}:{created_a}:", file!()))
+    );
+    assert!(msg.contains(&format!("happened here: {}:{accessed}:", file!())));
+
+    let msg = panic_message(|| _ = memo.get_untracked());
+    let accessed = line!() - 1;
+    assert!(msg
+        .contains(&format!("memo created here: {}:{created_memo}:", file!())));
+    assert!(msg.contains(&format!("happened here: {}:{accessed}:", file!())));
+
+    let msg = panic_message(|| stored.with_value(|_| ()));
+    let accessed = line!() - 1;
+    assert!(msg.contains(&format!(
+        "stored value created here: {}:{created_stored}:",
+        file!()
+    )));
+    assert!(msg.contains(&format!("happened here: {}:{accessed}:", file!())));
+
+    runtime.dispose();
+}
+
+#[test]
+fn signal_traits_abstract_over_signal_types() {
+    fn read(signal: &impl SignalGet<Value = i32>) -> i32 {
+        signal.get()
+    }
+    fn len(signal: &impl SignalWith<Value = String>) -> usize {
+        signal.with(String::len)
+    }
+    fn double(signal: &impl SignalUpdate<Value = i32>) {
+        signal.update(|value| *value *= 2);
+    }
+    fn reset(signal: &impl SignalSet<Value = i32>) {
+        signal.set(0);
+    }
+
+    let runtime = create_runtime();
+
+    let (count, set_count) = create_signal(1);
+    let rw = create_rw_signal(2);
+    let memo = create_memo(move |_| count.get() + 10);
+    let derived = Signal::derive(move || rw.get() * 100);
+    let maybe = MaybeSignal::from(3);
+    assert_eq!(
+        [
+            read(&count),
+            read(&rw),
+            read(&memo),
+            read(&derived),
+            read(&maybe),
+        ],
+        [1, 2, 11, 200, 3]
+    );
+
+    let name = create_rw_signal("Ada".to_string());
+    let greeting = create_memo(move |_| format!("Hi, {}", name.get()));
+    assert_eq!((len(&name), len(&greeting)), (3, 7));
+
+    double(&set_count);
+    double(&rw);
+    assert_eq!((count.get_untracked(), rw.get_untracked()), (2, 4));
+    reset(&set_count);
+    reset(&rw);
+    assert_eq!((count.get_untracked(), memo.get_untracked()), (0, 10));
+
+    // resources are read as `Option`s, which are `None` while loading
+    let resource = create_local_resource(|| (), |_| async { 1 });
+    fn loaded(signal: &impl SignalGet<Value = Option<i32>>) -> bool {
+        signal.get().is_some()
+    }
+    resource.set(5);
+    assert!(loaded(&resource));
+
+    runtime.dispose();
+}
+
+#[test]
+fn concrete_signals_convert_to_erased_ones() {
+    let runtime = create_runtime();
+
+    fn doubled(value: impl Into<Signal<i32>>) -> Signal<i32> {
+        let value = value.into();
+        Signal::derive(move || value.get() * 2)
+    }
+    fn reset(setter: impl Into<SignalSetter<i32>>) {
+        setter.into().set(0);
+    }
+
+    let rw = create_rw_signal(1);
+    let (count, set_count): (ReadSignal<i32>, WriteSignal<i32>) = rw.into();
+    let memo = create_memo(move |_| count.get() + 10);
+    let from_read = doubled(count);
+    let from_memo = doubled(memo);
+    let from_rw = doubled(rw);
+    assert_eq!(
+        (from_read.get(), from_memo.get(), from_rw.get()),
+        (2, 22, 2)
+    );
+
+    // every view still refers to the same signal
+    set_count.set(2);
+    assert_eq!(
+        (from_read.get(), from_memo.get(), from_rw.get()),
+        (4, 24, 4)
+    );
+    reset(rw);
+    assert_eq!(ReadSignal::from(rw).get(), 0);
+    WriteSignal::from(rw).set(3);
+    reset(set_count);
+    assert_eq!((count.get(), from_memo.get()), (0, 20));
+
+    runtime.dispose();
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn signals_can_be_called_on_nightly() {
+    let runtime = create_runtime();
+
+    let (count, set_count) = create_signal(1);
+    let both = create_rw_signal(10);
+    let doubled = create_memo(move |_| count() * 2);
+    let erased = Signal::derive(move || doubled() + both());
+    let setter = SignalSetter::from(both.write_only());
+    assert_eq!((count(), doubled(), erased()), (1, 2, 12));
+
+    set_count(2);
+    setter(20);
+    assert_eq!((count(), both(), doubled(), erased()), (2, 20, 4, 24));
+
+    // the call syntax is the same as the methods
+    assert_eq!(count(), count.get());
+    assert_eq!(erased(), erased.get());
+
+    runtime.dispose();
+}
@@ -51,3 +51,56 @@ fn slice() {
 
     runtime.dispose();
 }
+
+#[test]
+fn read_and_write_slices_work_as_signal_wrappers() {
+    use leptos_reactive::*;
+    let runtime = create_runtime();
+
+    #[derive(Default)]
+    struct Form {
+        name: String,
+        age: u32,
+    }
+
+    // a component generic over readable and writable signals
+    fn label(value: impl Into<Signal<String>>) -> Signal<String> {
+        let value = value.into();
+        Signal::derive(move || format!("Name: {}", value.get()))
+    }
+    fn clear(setter: impl Into<SignalSetter<String>>) {
+        setter.into().set(String::new());
+    }
+
+    let form = create_rw_signal(Form::default());
+    let name = create_read_slice(form, |form| form.name.clone());
+    let set_name =
+        create_write_slice(form, |form, name: String| form.name = name);
+    let set_age = create_write_slice(form, |form, age| form.age = age);
+
+    let runs = Rc::new(std::cell::Cell::new(0));
+    let label = label(name);
+    create_isomorphic_effect({
+        let runs = Rc::clone(&runs);
+        move |_| {
+            label.track();
+            runs.set(runs.get() + 1);
+        }
+    });
+
+    set_name.set("Ada".into());
+    assert_eq!(label.get(), "Name: Ada");
+    assert_eq!(runs.get(), 2);
+
+    // neither another field nor the same value notifies the slice
+    set_age.set(36);
+    set_name.set("Ada".into());
+    assert_eq!(runs.get(), 2);
+    assert_eq!(form.with(|form| form.age), 36);
+
+    clear(set_name);
+    assert_eq!(label.get(), "Name: ");
+    assert_eq!(runs.get(), 3);
+
+    runtime.dispose();
+}
@@ -0,0 +1,50 @@
+#![cfg(debug_assertions)]
+
+mod common;
+
+use common::Warnings;
+use leptos_reactive::{
+    create_isomorphic_effect, create_runtime, create_signal, slow_effects,
+    SignalGet, SignalSet,
+};
+use std::time::Duration;
+
+#[test]
+fn effects_over_the_budget_are_reported() {
+    let warnings = Warnings::new("leptos_reactive::slow_effects");
+
+    tracing::subscriber::with_default(warnings.clone(), || {
+        let runtime = create_runtime();
+        slow_effects::set_slow_effect_budget(Some(Duration::from_millis(5)));
+
+        let (delay, set_delay) = create_signal(0);
+        create_isomorphic_effect(move |_| {
+            std::thread::sleep(Duration::from_millis(delay.get()));
+        });
+        assert_eq!(warnings.count(), 0);
+
+        set_delay.set(20);
+        assert_eq!(warnings.count(), 1);
+
+        // an effect that creates a slow effect is not slow itself
+        let (nested_delay, set_nested_delay) = create_signal(0);
+        create_isomorphic_effect(move |_| {
+            let delay = nested_delay.get();
+            create_isomorphic_effect(move |_| {
+                std::thread::sleep(Duration::from_millis(delay));
+            });
+        });
+        assert_eq!(warnings.count(), 1);
+        set_nested_delay.set(20);
+        assert_eq!(warnings.count(), 2);
+
+        slow_effects::set_slow_effect_budget(None);
+        set_delay.set(21);
+        assert_eq!(warnings.count(), 2);
+
+        slow_effects::set_slow_effect_budget(Some(
+            slow_effects::DEFAULT_SLOW_EFFECT_BUDGET,
+        ));
+        runtime.dispose();
+    });
+}
@@ -0,0 +1,35 @@
+#![cfg(debug_assertions)]
+
+use leptos_reactive::{
+    create_isomorphic_effect, create_runtime, create_rw_signal, create_signal,
+    stats::runtime_stats, with_component_name, Oco, SignalGet, SignalSet,
+};
+
+#[test]
+fn nodes_are_counted_for_the_component_that_created_them() {
+    let runtime = create_runtime();
+    let outside = create_rw_signal(0);
+    let before = runtime_stats();
+
+    let name: Oco<'static, str> = "Counter".into();
+    let set_count = with_component_name(&name, || {
+        let (count, set_count) = create_signal(0);
+        create_isomorphic_effect(move |_| {
+            // the signal of the previous run is disposed when it runs again
+            create_rw_signal(count.get());
+        });
+        set_count
+    });
+    // an effect keeps its component when it runs again later
+    set_count.set(1);
+    outside.set(1);
+
+    let after = runtime_stats();
+    let counter = after.by_component["Counter"];
+    assert_eq!(counter.signals, 2);
+    assert_eq!(counter.effects, 1);
+    assert_eq!(after.total.total(), before.total.total() + 3);
+    assert_eq!(after.changes_since(&before), [("Counter".to_string(), 3)]);
+
+    runtime.dispose();
+}
@@ -0,0 +1,51 @@
+use leptos_reactive::{as_child_of_current_owner, create_runtime, store_value};
+use std::{cell::Cell, rc::Rc};
+
+// a handle that isn't `Clone`, like an interval or a client
+struct Handle {
+    ticks: u32,
+    dropped: Rc<Cell<bool>>,
+}
+
+impl Drop for Handle {
+    fn drop(&mut self) {
+        self.dropped.set(true);
+    }
+}
+
+#[test]
+fn stored_values_are_shared_by_copy_and_dropped_with_their_owner() {
+    let runtime = create_runtime();
+    let dropped = Rc::new(Cell::new(false));
+
+    let (handle, disposer) = as_child_of_current_owner(|dropped| {
+        store_value(Handle { ticks: 0, dropped })
+    })(Rc::clone(&dropped));
+
+    // the same handle can be moved into several closures
+    let tick = move || handle.update_value(|handle| handle.ticks += 1);
+    let ticks = move || handle.with_value(|handle| handle.ticks);
+    tick();
+    tick();
+    assert_eq!(ticks(), 2);
+
+    handle.set_value(Handle {
+        ticks: 10,
+        dropped: Rc::new(Cell::new(false)),
+    });
+    assert_eq!(ticks(), 10);
+    // the replaced value is dropped right away
+    assert!(dropped.get());
+
+    let replaced = Rc::new(Cell::new(false));
+    handle.update_value(|handle| handle.dropped = Rc::clone(&replaced));
+    assert!(!replaced.get());
+    drop(disposer);
+    assert!(replaced.get());
+    assert_eq!(handle.try_with_value(|handle| handle.ticks), None);
+    assert!(handle
+        .try_update_value(|handle| handle.ticks += 1)
+        .is_none());
+
+    runtime.dispose();
+}
@@ -0,0 +1,62 @@
+#![cfg(debug_assertions)]
+
+mod common;
+
+use common::Warnings;
+use leptos_reactive::{
+    create_isomorphic_effect, create_runtime, create_signal, on_cleanup,
+    strict_mode, SignalGet, SignalSet,
+};
+use std::{cell::Cell, rc::Rc};
+
+#[test]
+fn strict_mode_runs_effects_twice_the_first_time() {
+    let runtime = create_runtime();
+    strict_mode::set_strict_mode(true);
+
+    let (a, set_a) = create_signal(0);
+    let runs = Rc::new(Cell::new(0));
+    let cleanups = Rc::new(Cell::new(0));
+    create_isomorphic_effect({
+        let runs = Rc::clone(&runs);
+        let cleanups = Rc::clone(&cleanups);
+        move |prev: Option<i32>| {
+            assert_eq!(prev.is_some(), runs.get() >= 2);
+            runs.set(runs.get() + 1);
+            let cleanups = Rc::clone(&cleanups);
+            on_cleanup(move || cleanups.set(cleanups.get() + 1));
+            a.get()
+        }
+    });
+    assert_eq!((runs.get(), cleanups.get()), (2, 1));
+
+    set_a.set(1);
+    assert_eq!((runs.get(), cleanups.get()), (3, 2));
+
+    strict_mode::set_strict_mode(false);
+    runtime.dispose();
+}
+
+#[test]
+fn strict_mode_reports_side_effects_that_outlive_cleanup() {
+    let warnings = Warnings::new("leptos_reactive::strict_mode");
+
+    tracing::subscriber::with_default(warnings.clone(), || {
+        let runtime = create_runtime();
+        strict_mode::set_strict_mode(true);
+
+        // removed when the effect is cleaned up
+        create_isomorphic_effect(|_| {
+            strict_mode::acquire("interval", 1);
+            on_cleanup(|| strict_mode::release("interval", 1));
+        });
+        assert_eq!(warnings.count(), 0);
+
+        // never removed
+        create_isomorphic_effect(|_| strict_mode::acquire("interval", 2));
+        assert_eq!(warnings.count(), 1);
+
+        strict_mode::set_strict_mode(false);
+        runtime.dispose();
+    });
+}
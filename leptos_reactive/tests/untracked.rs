@@ -1,3 +1,5 @@
+mod common;
+
 use leptos_reactive::{
     create_isomorphic_effect, create_runtime, create_signal, signal_prelude::*,
     SignalGetUntracked, SignalSetUntracked,
@@ -76,3 +78,28 @@ fn untracked_get_doesnt_trigger_effect() {
 
     runtime.dispose();
 }
+
+#[cfg(all(debug_assertions, not(feature = "ssr")))]
+#[test]
+fn untracked_get_is_reported_to_tracing() {
+    let warnings = common::Warnings::new("leptos_reactive::untracked");
+
+    tracing::subscriber::with_default(warnings.clone(), || {
+        let runtime = create_runtime();
+        let (a, _) = create_signal(0);
+
+        create_isomorphic_effect(move |_| {
+            a.get();
+        });
+        _ = a.get_untracked();
+        assert_eq!(warnings.count(), 0);
+
+        _ = a.get();
+        let line = line!() - 1;
+        let called_at = warnings.field("called_at");
+        assert_eq!(called_at.len(), 1);
+        assert!(called_at[0].starts_with(&format!("{}:{line}:", file!())));
+
+        runtime.dispose();
+    });
+}
@@ -1,3 +1,4 @@
+#[cfg(feature = "server-fns")]
 use crate::{ServerFn, ServerFnError};
 use leptos_reactive::{
     batch, create_rw_signal, is_suppressing_resource_load, signal_prelude::*,
@@ -167,6 +168,7 @@ where
         self.0.with_value(|a| a.pending.read_only())
     }
 
+    #[cfg(feature = "server-fns")]
     /// Create an [Action] to imperatively call a [server_fn::server] function.
     ///
     /// The struct representing your server function's arguments should be
@@ -259,6 +261,7 @@ where
         self.0.with_value(|a| a.url.as_ref().cloned())
     }
 
+    #[cfg(feature = "server-fns")]
     /// Associates the URL of the given server function with this action.
     /// This enables integration with the `ActionForm` component in `leptos_router`.
     #[cfg_attr(
@@ -359,7 +362,7 @@ where
             let pending_dispatches = Rc::clone(&self.pending_dispatches);
             let value = self.value;
             pending.set(true);
-            pending_dispatches.set(pending_dispatches.get().saturating_sub(1));
+            pending_dispatches.set(pending_dispatches.get().wrapping_add(1));
             spawn_local(async move {
                 let new_value = fut.await;
                 batch(move || {
@@ -464,6 +467,7 @@ where
     Action::new(action_fn)
 }
 
+#[cfg(feature = "server-fns")]
 /// Creates an [Action] that can be used to call a server function.
 ///
 /// ```rust
@@ -1,3 +1,4 @@
+#[cfg(feature = "server-fns")]
 use crate::{ServerFn, ServerFnError};
 use leptos_reactive::{
     create_rw_signal, is_suppressing_resource_load, signal_prelude::*,
@@ -126,6 +127,7 @@ where
         self.0.with_value(|a| a.version)
     }
 
+    #[cfg(feature = "server-fns")]
     /// Associates the URL of the given server function with this action.
     /// This enables integration with the `MultiActionForm` component in `leptos_router`.
     #[cfg_attr(
@@ -324,6 +326,7 @@ where
     }))
 }
 
+#[cfg(feature = "server-fns")]
 /// Creates an [MultiAction] that can be used to call a server function.
 ///
 /// ```rust
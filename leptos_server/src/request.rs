@@ -0,0 +1,301 @@
+use leptos_reactive::{
+    create_local_resource, create_resource, provide_context, use_context,
+    Resource, Serializable,
+};
+use serde::{Deserialize, Serialize};
+use std::{fmt::Display, future::Future, pin::Pin, rc::Rc};
+use thiserror::Error;
+
+/// The HTTP method used by a [`ResourceRequest`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Method {
+    /// `GET`
+    #[default]
+    Get,
+    /// `POST`
+    Post,
+    /// `PUT`
+    Put,
+    /// `PATCH`
+    Patch,
+    /// `DELETE`
+    Delete,
+}
+
+impl Method {
+    /// Returns the method name as it appears in an HTTP request line.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Method::Get => "GET",
+            Method::Post => "POST",
+            Method::Put => "PUT",
+            Method::Patch => "PATCH",
+            Method::Delete => "DELETE",
+        }
+    }
+}
+
+impl Display for Method {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A declarative description of a request made by a resource.
+///
+/// This describes *what* should be loaded, not *how*: the request is handed
+/// to whichever [`Transport`] has been provided in context. Because it is
+/// built inside a closure, the URL, headers, and body can all be derived from
+/// signals, and the resource will refetch whenever the request changes.
+///
+/// A GraphQL query is just a `POST` with a JSON body, so GraphQL clients can
+/// build on the same type.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ResourceRequest {
+    /// The HTTP method.
+    pub method: Method,
+    /// The URL to be requested.
+    pub url: String,
+    /// Any additional headers, in order.
+    pub headers: Vec<(String, String)>,
+    /// The request body, if any.
+    pub body: Option<String>,
+}
+
+impl ResourceRequest {
+    /// Creates a request with the given method and URL.
+    pub fn new(method: Method, url: impl Into<String>) -> Self {
+        Self {
+            method,
+            url: url.into(),
+            headers: Vec::new(),
+            body: None,
+        }
+    }
+
+    /// Creates a `GET` request for the given URL.
+    pub fn get(url: impl Into<String>) -> Self {
+        Self::new(Method::Get, url)
+    }
+
+    /// Creates a `POST` request for the given URL.
+    pub fn post(url: impl Into<String>) -> Self {
+        Self::new(Method::Post, url)
+    }
+
+    /// Creates a `PUT` request for the given URL.
+    pub fn put(url: impl Into<String>) -> Self {
+        Self::new(Method::Put, url)
+    }
+
+    /// Creates a `PATCH` request for the given URL.
+    pub fn patch(url: impl Into<String>) -> Self {
+        Self::new(Method::Patch, url)
+    }
+
+    /// Creates a `DELETE` request for the given URL.
+    pub fn delete(url: impl Into<String>) -> Self {
+        Self::new(Method::Delete, url)
+    }
+
+    /// Adds a header to the request.
+    pub fn header(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets the body of the request.
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+}
+
+/// The response returned by a [`Transport`].
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceResponse {
+    /// The HTTP status code.
+    pub status: u16,
+    /// The response headers, in order.
+    pub headers: Vec<(String, String)>,
+    /// The response body.
+    pub body: String,
+}
+
+impl ResourceResponse {
+    /// Whether the status code is in the `2xx` range.
+    pub fn ok(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Returns the value of the first header with the given name, compared
+    /// case-insensitively.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Errors that can occur while loading a [`ResourceRequest`].
+#[derive(Debug, Clone, PartialEq, Eq, Error, Serialize, Deserialize)]
+pub enum RequestError {
+    /// No [`Transport`] was provided in context when the resource was created.
+    #[error(
+        "no Transport found in context; call provide_transport() before \
+         creating a resource from a request"
+    )]
+    MissingTransport,
+    /// The transport failed to complete the request.
+    #[error("error while sending request: {0}")]
+    Transport(String),
+}
+
+/// A pluggable way to send a [`ResourceRequest`], provided in context with
+/// [`provide_transport`].
+///
+/// API-client crates can implement this for their own client, so that apps
+/// can switch between (for example) a `fetch`-based transport in the browser
+/// and a `reqwest`-based transport on the server without changing any of the
+/// code that creates resources.
+///
+/// This is implemented for any function that takes a [`ResourceRequest`] and
+/// returns a [`Future`].
+pub trait Transport {
+    /// Sends the request, resolving to its response.
+    fn send(
+        &self,
+        request: ResourceRequest,
+    ) -> Pin<Box<dyn Future<Output = Result<ResourceResponse, RequestError>>>>;
+}
+
+impl<F, Fu> Transport for F
+where
+    F: Fn(ResourceRequest) -> Fu,
+    Fu: Future<Output = Result<ResourceResponse, RequestError>> + 'static,
+{
+    fn send(
+        &self,
+        request: ResourceRequest,
+    ) -> Pin<Box<dyn Future<Output = Result<ResourceResponse, RequestError>>>>
+    {
+        Box::pin(self(request))
+    }
+}
+
+#[derive(Clone)]
+struct TransportContext(Rc<dyn Transport>);
+
+/// Provides a [`Transport`] that will be used by any resource created from a
+/// [`ResourceRequest`] in this part of the tree.
+pub fn provide_transport(transport: impl Transport + 'static) {
+    provide_context(TransportContext(Rc::new(transport)));
+}
+
+/// Returns the [`Transport`] provided in context, if any.
+pub fn use_transport() -> Option<Rc<dyn Transport>> {
+    use_context::<TransportContext>().map(|transport| transport.0)
+}
+
+/// Extends [`Resource`] with constructors that load a declarative
+/// [`ResourceRequest`] through the [`Transport`] provided in context.
+///
+/// ```rust
+/// # use leptos::*;
+/// use leptos_server::{
+///     provide_transport, RequestError, ResourceRequest, ResourceRequestExt,
+///     ResourceResponse,
+/// };
+/// # let runtime = create_runtime();
+/// # if false {
+/// provide_transport(|_request: ResourceRequest| async move {
+///     // send the request with any HTTP client you like
+///     Ok::<_, RequestError>(ResourceResponse::default())
+/// });
+///
+/// let user_id = create_rw_signal(1);
+/// let token = create_rw_signal(String::from("secret"));
+///
+/// // refetches whenever `user_id` or `token` changes
+/// let user = Resource::from_request(
+///     move || {
+///         ResourceRequest::get(format!("/api/users/{}", user_id.get()))
+///             .header("Authorization", format!("Bearer {}", token.get()))
+///     },
+///     |res| res.map(|res| res.body),
+/// );
+/// # }
+/// # runtime.dispose();
+/// ```
+pub trait ResourceRequestExt<T> {
+    /// Creates a resource that sends the request returned by `request`, and
+    /// converts the response (or error) with `decode`.
+    ///
+    /// Like [`create_resource`], the request is made on the server during
+    /// server-side rendering, and its decoded value is sent to the client.
+    fn from_request(
+        request: impl Fn() -> ResourceRequest + 'static,
+        decode: impl Fn(Result<ResourceResponse, RequestError>) -> T + 'static,
+    ) -> Self
+    where
+        T: Serializable;
+
+    /// Creates a _local_ resource that sends the request returned by
+    /// `request`, and converts the response (or error) with `decode`.
+    ///
+    /// Like [`create_local_resource`], the request is only made in the
+    /// browser, so the decoded value does not need to be [`Serializable`].
+    fn local_from_request(
+        request: impl Fn() -> ResourceRequest + 'static,
+        decode: impl Fn(Result<ResourceResponse, RequestError>) -> T + 'static,
+    ) -> Self;
+}
+
+impl<T> ResourceRequestExt<T> for Resource<ResourceRequest, T>
+where
+    T: 'static,
+{
+    #[track_caller]
+    fn from_request(
+        request: impl Fn() -> ResourceRequest + 'static,
+        decode: impl Fn(Result<ResourceResponse, RequestError>) -> T + 'static,
+    ) -> Self
+    where
+        T: Serializable,
+    {
+        create_resource(request, request_fetcher(decode))
+    }
+
+    #[track_caller]
+    fn local_from_request(
+        request: impl Fn() -> ResourceRequest + 'static,
+        decode: impl Fn(Result<ResourceResponse, RequestError>) -> T + 'static,
+    ) -> Self {
+        create_local_resource(request, request_fetcher(decode))
+    }
+}
+
+fn request_fetcher<T>(
+    decode: impl Fn(Result<ResourceResponse, RequestError>) -> T + 'static,
+) -> impl Fn(ResourceRequest) -> Pin<Box<dyn Future<Output = T>>> {
+    // read the transport when the resource is created, because the fetcher
+    // may run later outside of the component that provided it
+    let transport = use_transport();
+    let decode = Rc::new(decode);
+    move |request| {
+        let transport = transport.clone();
+        let decode = Rc::clone(&decode);
+        Box::pin(async move {
+            let res = match transport {
+                Some(transport) => transport.send(request).await,
+                None => Err(RequestError::MissingTransport),
+            };
+            decode(res)
+        })
+    }
+}
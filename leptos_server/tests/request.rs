@@ -0,0 +1,84 @@
+use leptos::{
+    create_runtime, create_rw_signal, Resource, SignalGet, SignalSet,
+};
+use leptos_server::{
+    provide_transport, Method, RequestError, ResourceRequest,
+    ResourceRequestExt, ResourceResponse,
+};
+use std::{cell::RefCell, rc::Rc};
+
+fn block_on(test: impl std::future::Future<Output = ()>) {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    tokio::task::LocalSet::new().block_on(&rt, test);
+}
+
+#[test]
+fn resources_send_their_request_through_the_transport() {
+    block_on(async {
+        let runtime = create_runtime();
+        let sent = Rc::new(RefCell::new(Vec::new()));
+        provide_transport({
+            let sent = Rc::clone(&sent);
+            move |request: ResourceRequest| {
+                sent.borrow_mut().push(request.clone());
+                async move {
+                    Ok(ResourceResponse {
+                        status: 200,
+                        headers: vec![("Content-Type".into(), "text".into())],
+                        body: format!("user at {}", request.url),
+                    })
+                }
+            }
+        });
+
+        let user_id = create_rw_signal(1);
+        let user = Resource::from_request(
+            move || {
+                ResourceRequest::post(format!("/api/users/{}", user_id.get()))
+                    .header("Authorization", "Bearer secret")
+                    .body("{}")
+            },
+            |res| {
+                let res = res.unwrap();
+                assert!(res.ok());
+                assert_eq!(res.header("content-type"), Some("text"));
+                res.body
+            },
+        );
+        tokio::task::yield_now().await;
+        assert_eq!(user.get().as_deref(), Some("user at /api/users/1"));
+
+        // the request is made again when a signal it reads changes
+        user_id.set(2);
+        tokio::task::yield_now().await;
+        assert_eq!(user.get().as_deref(), Some("user at /api/users/2"));
+
+        let sent = sent.borrow();
+        assert_eq!(sent.len(), 2);
+        assert_eq!(sent[1].method, Method::Post);
+        assert_eq!(
+            sent[1].headers,
+            [("Authorization".into(), "Bearer secret".into())]
+        );
+        assert_eq!(sent[1].body.as_deref(), Some("{}"));
+
+        runtime.dispose();
+    });
+}
+
+#[test]
+fn resources_without_a_transport_fail_to_load() {
+    block_on(async {
+        let runtime = create_runtime();
+        let user = Resource::from_request(
+            || ResourceRequest::get("/api/users/1"),
+            |res| res.err(),
+        );
+        tokio::task::yield_now().await;
+        assert_eq!(user.get(), Some(Some(RequestError::MissingTransport)));
+
+        runtime.dispose();
+    });
+}
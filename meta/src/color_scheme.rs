@@ -0,0 +1,193 @@
+use crate::use_head;
+use leptos::{nonce::use_nonce, *};
+
+/// The `localStorage` key under which [`use_color_scheme`] saves the chosen scheme.
+pub const COLOR_SCHEME_STORAGE_KEY: &str = "leptos-color-scheme";
+
+/// A color scheme the user can choose.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ColorScheme {
+    /// Always light.
+    Light,
+    /// Always dark.
+    Dark,
+    /// Follows the preference of the operating system or browser.
+    #[default]
+    System,
+}
+
+impl ColorScheme {
+    /// The name of the scheme, as it is saved in `localStorage`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Light => "light",
+            Self::Dark => "dark",
+            Self::System => "system",
+        }
+    }
+
+    /// Parses a saved scheme, falling back to [`ColorScheme::System`].
+    pub fn from_saved(value: &str) -> Self {
+        match value {
+            "light" => Self::Light,
+            "dark" => Self::Dark,
+            _ => Self::System,
+        }
+    }
+}
+
+/// The signals returned by [`use_color_scheme`].
+#[derive(Clone, Copy, Debug)]
+pub struct UseColorScheme {
+    /// The scheme chosen by the user. Setting it saves it to `localStorage`.
+    pub scheme: RwSignal<ColorScheme>,
+    /// Whether the page is dark, following the system preference when the scheme is
+    /// [`ColorScheme::System`].
+    pub is_dark: Signal<bool>,
+}
+
+/// Manages a light and a dark theme, with a choice that is remembered across visits.
+///
+/// The `<html>` element gets a `dark` or a `light` class, which stylesheets can select on.
+/// Its other classes are left alone, so don't also set `class` on [`<Html/>`](crate::Html),
+/// which replaces the whole attribute.
+///
+/// - The chosen scheme is saved in `localStorage` under [`COLOR_SCHEME_STORAGE_KEY`].
+/// - With [`ColorScheme::System`], the page follows the system preference, even when it
+///   changes while the page is open.
+/// - The server cannot know either, so a small inline script in the `<head>` sets the class
+///   before the page is first painted, and dark pages don't flash white. On the server and until
+///   the page has been hydrated, `scheme` is [`ColorScheme::System`] and `is_dark` is `false`,
+///   so that the server and the browser render the same view.
+///
+/// Every component that calls it shares the same signals, which belong to the root of the app,
+/// where [`provide_meta_context`](crate::provide_meta_context) was called, so they keep working
+/// after the first component that asked for them unmounts.
+///
+/// ```
+/// use leptos::*;
+/// use leptos_meta::*;
+///
+/// #[component]
+/// fn ThemeToggle() -> impl IntoView {
+///     let UseColorScheme { scheme, is_dark } = use_color_scheme();
+///     let toggle = move |_| {
+///         scheme.set(if is_dark.get() {
+///             ColorScheme::Light
+///         } else {
+///             ColorScheme::Dark
+///         })
+///     };
+///
+///     view! {
+///       <button on:click=toggle>{move || if is_dark.get() { "Light mode" } else { "Dark mode" }}</button>
+///       <button on:click=move |_| scheme.set(ColorScheme::System)>"Use the system theme"</button>
+///     }
+/// }
+/// ```
+pub fn use_color_scheme() -> UseColorScheme {
+    use_head().color_scheme(create_color_scheme)
+}
+
+fn create_color_scheme() -> UseColorScheme {
+    register_script();
+
+    let scheme = create_rw_signal(ColorScheme::System);
+    let (prefers_dark, set_prefers_dark) = create_signal(false);
+    let is_dark = Signal::derive(move || match scheme.get() {
+        ColorScheme::Light => false,
+        ColorScheme::Dark => true,
+        ColorScheme::System => prefers_dark.get(),
+    });
+
+    cfg_if::cfg_if! {
+        if #[cfg(all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate")))] {
+            use wasm_bindgen::{closure::Closure, JsCast, UnwrapThrowExt};
+
+            // effects only run once the page has been hydrated, and these run in order:
+            // the saved scheme is read before the class is first set
+            create_effect(move |_| {
+                let saved = window()
+                    .local_storage()
+                    .ok()
+                    .flatten()
+                    .and_then(|storage| storage.get_item(COLOR_SCHEME_STORAGE_KEY).ok().flatten());
+                if let Some(saved) = saved {
+                    scheme.set(ColorScheme::from_saved(&saved));
+                }
+
+                if let Ok(Some(query)) = window().match_media("(prefers-color-scheme: dark)") {
+                    set_prefers_dark.set(query.matches());
+                    let on_change = Closure::<dyn Fn()>::new({
+                        let query = query.clone();
+                        move || set_prefers_dark.set(query.matches())
+                    })
+                    .into_js_value();
+                    _ = query.add_event_listener_with_callback("change", on_change.unchecked_ref());
+                    on_cleanup(move || {
+                        _ = query.remove_event_listener_with_callback(
+                            "change",
+                            on_change.unchecked_ref(),
+                        );
+                    });
+                }
+            });
+
+            create_effect(move |_| {
+                let dark = is_dark.get();
+                let classes = document().document_element().unwrap_throw().class_list();
+                _ = classes.toggle_with_force("dark", dark);
+                _ = classes.toggle_with_force("light", !dark);
+            });
+
+            create_effect(move |prev: Option<ColorScheme>| {
+                let scheme = scheme.get();
+                // the first run happens before the saved scheme has been read
+                if prev.is_some() {
+                    if let Ok(Some(storage)) = window().local_storage() {
+                        _ = match scheme {
+                            ColorScheme::System => storage.remove_item(COLOR_SCHEME_STORAGE_KEY),
+                            _ => storage.set_item(COLOR_SCHEME_STORAGE_KEY, scheme.as_str()),
+                        };
+                    }
+                }
+                scheme
+            });
+        } else {
+            _ = set_prefers_dark;
+        }
+    }
+
+    UseColorScheme { scheme, is_dark }
+}
+
+/// Adds the script that sets the class of the `<html>` before the page is painted.
+fn register_script() {
+    let meta = use_head();
+    let id: Oco<'static, str> =
+        format!("leptos-script-{}", meta.tags.get_next_id().0).into();
+    let script = format!(
+        r#"(function () {{
+  try {{
+    var saved = localStorage.getItem("{COLOR_SCHEME_STORAGE_KEY}");
+    var dark = saved === "dark" || (saved !== "light" && matchMedia("(prefers-color-scheme: dark)").matches);
+    document.documentElement.classList.toggle("dark", dark);
+    document.documentElement.classList.toggle("light", !dark);
+  }} catch (e) {{}}
+}})();"#
+    );
+    let builder_el = leptos::leptos_dom::html::as_meta_tag({
+        let id = id.clone();
+        move || {
+            leptos::leptos_dom::html::script()
+                .attr("id", id)
+                .attr("nonce", use_nonce())
+                .child(script)
+        }
+    });
+    meta.tags.register_with_key(
+        Some("color-scheme-script".into()),
+        id,
+        builder_el.into_any(),
+    );
+}
@@ -0,0 +1,82 @@
+use crate::{use_head, TextProp};
+use leptos::{component, untrack, IntoView, Oco};
+
+/// Sets the icon of the page, shown in its browser tab.
+///
+/// The `href` can change, to add an unread count to the icon or follow the color
+/// scheme. Most browsers ignore changes to the `href` of an icon that is already in the
+/// document, so each new value is set on a new `<link>` that replaces the old one. The
+/// server renders the initial value.
+///
+/// An icon replaces any earlier icon with the same `sizes`, and the earlier one comes
+/// back once it unmounts.
+///
+/// ```
+/// use leptos::*;
+/// use leptos_meta::*;
+///
+/// #[component]
+/// fn MyApp() -> impl IntoView {
+///     provide_meta_context();
+///     let (unread, set_unread) = create_signal(0);
+///
+///     view! {
+///       <Favicon
+///         href=move || {
+///             if unread.get() > 0 { "/icon-unread.svg" } else { "/icon.svg" }.to_string()
+///         }
+///         type_="image/svg+xml"
+///       />
+///       <button on:click=move |_| set_unread.update(|n| *n += 1)>"New message"</button>
+///     }
+/// }
+/// ```
+#[component(transparent)]
+pub fn Favicon(
+    /// The URL of the icon.
+    #[prop(into)]
+    href: TextProp,
+    /// The [`type`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/link#type) of the icon,
+    /// like `image/svg+xml`.
+    #[prop(optional, into)]
+    type_: Option<Oco<'static, str>>,
+    /// The [`sizes`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/link#sizes) of
+    /// the icon, like `32x32`.
+    #[prop(optional, into)]
+    sizes: Option<Oco<'static, str>>,
+) -> impl IntoView {
+    let meta = use_head();
+    let next_id = meta.tags.get_next_id();
+    let id: Oco<'static, str> = format!("leptos-link-{}", next_id.0).into();
+    let key = format!("icon:{}", sizes.as_deref().unwrap_or_default());
+
+    let initial = untrack(|| href.get());
+    let builder_el = leptos::leptos_dom::html::as_meta_tag({
+        let id = id.clone();
+        move || {
+            leptos::leptos_dom::html::link()
+                .attr("id", id)
+                .attr("rel", "icon")
+                .attr("href", initial)
+                .attr("type", type_)
+                .attr("sizes", sizes)
+        }
+    });
+
+    meta.tags.register_with_key(
+        Some(key.into()),
+        id.clone(),
+        builder_el.into_any(),
+    );
+
+    #[cfg(any(feature = "csr", feature = "hydrate"))]
+    leptos::create_render_effect(move |prev: Option<Oco<'static, str>>| {
+        let href = href.get();
+        if prev.is_some_and(|prev| prev != href) {
+            meta.tags.replace_attribute(&id, "href", &href);
+        }
+        href
+    });
+    #[cfg(not(any(feature = "csr", feature = "hydrate")))]
+    let _ = href;
+}
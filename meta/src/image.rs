@@ -0,0 +1,308 @@
+use crate::Link;
+use leptos::*;
+use std::{fmt, rc::Rc};
+
+/// The widths an [`<Image/>`](Image) with `sizes` offers the browser by default.
+pub const DEFAULT_IMAGE_WIDTHS: &[u32] =
+    &[640, 750, 828, 1080, 1200, 1920, 2048, 3840];
+
+/// The quality an [`<Image/>`](Image) asks for by default, from 1 to 100.
+pub const DEFAULT_IMAGE_QUALITY: u8 = 75;
+
+/// Builds the URL of a resized version of an image, for the `srcset` of an [`<Image/>`](Image).
+///
+/// It is called with the `src` of the image, the width in pixels, and the quality, and
+/// usually points at an image CDN or at a resizing endpoint on your server. Provide one for
+/// the whole app with [`provide_image_loader`], or pass one to a single image.
+///
+/// ```
+/// use leptos_meta::ImageLoader;
+///
+/// let loader = ImageLoader::query("/_image");
+/// assert_eq!(
+///     loader.url("/photos/cat 1.jpg", 640, 75),
+///     "/_image?url=%2Fphotos%2Fcat%201.jpg&w=640&q=75"
+/// );
+///
+/// let cdn = ImageLoader::new(|src, width, quality| {
+///     format!("https://cdn.example.com/w_{width},q_{quality}{src}")
+/// });
+/// assert_eq!(
+///     cdn.url("/cat.jpg", 640, 75),
+///     "https://cdn.example.com/w_640,q_75/cat.jpg"
+/// );
+/// ```
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub struct ImageLoader(Rc<dyn Fn(&str, u32, u8) -> String>);
+
+impl ImageLoader {
+    /// Creates a loader from a function of the `src`, the width and the quality.
+    pub fn new(f: impl Fn(&str, u32, u8) -> String + 'static) -> Self {
+        Self(Rc::new(f))
+    }
+
+    /// A loader for an endpoint that takes the image as `url`, the width as `w` and the
+    /// quality as `q` in its query string, like `/_image?url=%2Fcat.jpg&w=640&q=75`.
+    pub fn query(endpoint: impl Into<String>) -> Self {
+        let endpoint = endpoint.into();
+        Self::new(move |src, width, quality| {
+            format!("{endpoint}?url={}&w={width}&q={quality}", encode(src))
+        })
+    }
+
+    /// The URL of the image at `src`, resized to `width` pixels with the given `quality`.
+    pub fn url(&self, src: &str, width: u32, quality: u8) -> String {
+        (self.0)(src, width, quality)
+    }
+}
+
+impl fmt::Debug for ImageLoader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ImageLoader").finish()
+    }
+}
+
+/// Provides the [`ImageLoader`] used by every [`<Image/>`](Image) below this point that
+/// doesn't have its own.
+pub fn provide_image_loader(loader: ImageLoader) {
+    provide_context(loader);
+}
+
+/// Renders an `<img>` that doesn't shift the layout while it loads, and that lets the browser
+/// download a size that fits the screen.
+///
+/// - `width` and `height` are always set, so the browser can reserve the space.
+/// - With an [`ImageLoader`], a `srcset` is generated: for each of `widths` if `sizes` is set,
+///   or for 1x and 2x pixel densities otherwise.
+/// - Images load lazily, unless `priority` is set: then the image is preloaded from the
+///   `<head>` with a [`<Link/>`](Link) and fetched with a high priority. Use it for the largest
+///   image above the fold.
+/// - A `placeholder`, usually a tiny version of the image as a data URL, is shown behind the
+///   image until it has loaded. With `blur`, it is blurred, so that a placeholder only a few
+///   pixels wide is shown smoothly: the integrations' `blur_placeholder` makes one from the
+///   image on the server.
+///
+/// ```
+/// use leptos::*;
+/// use leptos_meta::*;
+///
+/// #[component]
+/// fn Hero() -> impl IntoView {
+///     provide_meta_context();
+///     provide_image_loader(ImageLoader::query("/_image"));
+///
+///     view! {
+///       <Image
+///         src="/hero.jpg"
+///         alt="The view from the office"
+///         width=1200
+///         height=600
+///         sizes="100vw"
+///         priority=true
+///       />
+///     }
+/// }
+/// ```
+#[component]
+pub fn Image(
+    /// The URL of the original image.
+    #[prop(into)]
+    src: Oco<'static, str>,
+    /// A description of the image, for people who cannot see it.
+    #[prop(into)]
+    alt: Oco<'static, str>,
+    /// The width the image is displayed at, in CSS pixels.
+    width: u32,
+    /// The height the image is displayed at, in CSS pixels.
+    height: u32,
+    /// The [`sizes`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/img#sizes)
+    /// attribute, for images whose displayed width depends on the viewport.
+    #[prop(optional, into)]
+    sizes: Option<Oco<'static, str>>,
+    /// The widths offered in the `srcset` when `sizes` is set. Defaults to
+    /// [`DEFAULT_IMAGE_WIDTHS`].
+    #[prop(optional, into)]
+    widths: Option<Vec<u32>>,
+    /// The quality asked from the loader. Defaults to [`DEFAULT_IMAGE_QUALITY`].
+    #[prop(optional)]
+    quality: Option<u8>,
+    /// Whether to preload the image and fetch it eagerly. Defaults to `false`.
+    #[prop(optional)]
+    priority: bool,
+    /// An image shown behind this one while it loads.
+    #[prop(optional, into)]
+    placeholder: Option<Oco<'static, str>>,
+    /// Whether to blur the `placeholder`, which must then be a data URL. Defaults to `false`.
+    #[prop(optional)]
+    blur: bool,
+    /// The loader that builds the `srcset`. Defaults to the one provided with
+    /// [`provide_image_loader`]; without one, only `src` is used.
+    #[prop(optional)]
+    loader: Option<ImageLoader>,
+    /// The `class` attribute.
+    #[prop(optional, into)]
+    class: Option<Oco<'static, str>>,
+) -> impl IntoView {
+    let loader = loader.or_else(use_context::<ImageLoader>);
+    let quality = quality.unwrap_or(DEFAULT_IMAGE_QUALITY);
+    let (src, srcset) = match &loader {
+        Some(loader) => {
+            let candidates = image_candidates(
+                width,
+                sizes.is_some(),
+                widths.as_deref().unwrap_or(DEFAULT_IMAGE_WIDTHS),
+            );
+            let srcset = candidates
+                .iter()
+                .map(|(width, descriptor)| {
+                    format!(
+                        "{} {descriptor}",
+                        loader.url(&src, *width, quality)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            let largest = candidates.last().map(|(width, _)| *width);
+            let src = largest
+                .map(|largest| loader.url(&src, largest, quality).into())
+                .unwrap_or(src);
+            (src, Some(Oco::from(srcset)))
+        }
+        None => (src, None),
+    };
+
+    if priority {
+        // the srcset and sizes are passed as attributes, which are left out when they are `None`
+        _ = view! {
+            <Link
+                rel="preload"
+                as_="image"
+                fetchpriority="high"
+                href=src.clone()
+                attr:imagesrcset=srcset.clone()
+                attr:imagesizes=sizes.clone()
+            />
+        };
+    }
+
+    let (loaded, set_loaded) = create_signal(false);
+    let placeholder_style = placeholder.map(|placeholder| {
+        let placeholder = if blur {
+            blurred(&placeholder, width, height)
+        } else {
+            placeholder.to_string()
+        };
+        format!(
+            "background-image:url(\"{}\");background-size:cover;\
+             background-position:50% 50%;background-repeat:no-repeat",
+            css_string(&placeholder)
+        )
+    });
+    let style = move || placeholder_style.clone().filter(|_| !loaded.get());
+
+    let img_ref = create_node_ref::<html::Img>();
+    // an image rendered on the server may have loaded before hydration
+    create_effect(move |_| {
+        if img_ref.get().is_some_and(|img| img.complete()) {
+            set_loaded.set(true);
+        }
+    });
+
+    view! {
+        <img
+            node_ref=img_ref
+            src=src
+            srcset=srcset
+            sizes=sizes
+            alt=alt
+            width=width
+            height=height
+            loading=if priority { "eager" } else { "lazy" }
+            decoding="async"
+            fetchpriority=priority.then_some("high")
+            class=class
+            style=style
+            on:load=move |_| set_loaded.set(true)
+        />
+    }
+}
+
+/// The widths in the `srcset` of an image, with their descriptors.
+fn image_candidates(
+    width: u32,
+    has_sizes: bool,
+    widths: &[u32],
+) -> Vec<(u32, String)> {
+    if has_sizes {
+        let mut widths = widths.to_vec();
+        widths.sort_unstable();
+        widths.dedup();
+        widths
+            .into_iter()
+            .map(|width| (width, format!("{width}w")))
+            .collect()
+    } else {
+        vec![
+            (width, "1x".to_string()),
+            (width.saturating_mul(2), "2x".to_string()),
+        ]
+    }
+}
+
+/// An SVG that displays `placeholder` blurred, as a data URL. Images used as CSS backgrounds
+/// can't load other resources, so the placeholder has to be a data URL itself.
+fn blurred(placeholder: &str, width: u32, height: u32) -> String {
+    let svg = format!(
+        "<svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 {width} \
+         {height}'><filter id='b' \
+         color-interpolation-filters='sRGB'><feGaussianBlur \
+         stdDeviation='20'/><feComponentTransfer><feFuncA type='discrete' \
+         tableValues='1 1'/></feComponentTransfer></filter><image \
+         width='100%' height='100%' preserveAspectRatio='none' \
+         filter='url(#b)' href='{}'/></svg>",
+        placeholder
+            .replace('&', "&amp;")
+            .replace('\'', "&apos;")
+            .replace('<', "&lt;")
+    );
+    format!("data:image/svg+xml,{}", encode(&svg))
+}
+
+/// Escapes a value for a double-quoted CSS string, so that a placeholder can't end the
+/// `url("...")` it is put in.
+fn css_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' | '\\' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            // newlines can't be escaped with a backslash, only as code points
+            '\n' | '\r' | '\u{c}' => {
+                escaped.push_str(&format!("\\{:x} ", c as u32));
+            }
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Percent-encodes everything but unreserved characters, for a query string value.
+fn encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'-'
+            | b'.'
+            | b'_'
+            | b'~' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
@@ -0,0 +1,103 @@
+use crate::use_head;
+use leptos::*;
+use serde::Serialize;
+
+/// Injects [structured data](https://developers.google.com/search/docs/appearance/structured-data/intro-structured-data)
+/// into the document head as an `<script type="application/ld+json">` tag, which search engines use to show
+/// rich results for products, articles, recipes, and so on.
+///
+/// `data` can be any value that implements [`Serialize`]. With the `serde` feature of `leptos`, signals implement it
+/// too, so when `data` is a signal, or contains one, the script is updated in the browser whenever it changes. The
+/// JSON is escaped so that strings in it can never close the `<script>` tag. The tag is removed from the head when the component unmounts.
+///
+/// ```
+/// use leptos::*;
+/// use leptos_meta::*;
+/// use serde::Serialize;
+///
+/// #[derive(Clone, Serialize)]
+/// struct Product {
+///     #[serde(rename = "@context")]
+///     context: &'static str,
+///     #[serde(rename = "@type")]
+///     kind: &'static str,
+///     name: String,
+/// }
+///
+/// #[component]
+/// fn ProductPage() -> impl IntoView {
+///     provide_meta_context();
+///     let (name, set_name) = create_signal("Leptos T-Shirt".to_string());
+///     let product = Signal::derive(move || Product {
+///         context: "https://schema.org",
+///         kind: "Product",
+///         name: name.get(),
+///     });
+///
+///     view! {
+///       <JsonLd data=product/>
+///       <h1>{name}</h1>
+///     }
+/// }
+/// ```
+#[component(transparent)]
+pub fn JsonLd<T>(
+    /// An ID for the `<script>` tag.
+    #[prop(optional, into)]
+    id: Option<Oco<'static, str>>,
+    /// The structured data.
+    data: T,
+) -> impl IntoView
+where
+    T: Serialize + 'static,
+{
+    let meta = use_head();
+    let next_id = meta.tags.get_next_id();
+    let mut id: Oco<'static, str> =
+        id.unwrap_or_else(|| format!("leptos-script-{}", next_id.0).into());
+
+    let json = untrack(|| to_json(&data));
+
+    let builder_el = leptos::leptos_dom::html::as_meta_tag({
+        let id = id.clone_inplace();
+        move || {
+            leptos::leptos_dom::html::script()
+                .attr("id", id)
+                .attr("type", "application/ld+json")
+                .inner_html(json)
+        }
+    });
+
+    #[cfg(any(feature = "csr", feature = "hydrate"))]
+    create_render_effect({
+        let id = id.clone_inplace();
+        move |prev: Option<()>| {
+            let json = to_json(&data);
+            // the first value is already in the tag
+            if prev.is_some() {
+                if let Some(el) = document().get_element_by_id(&id) {
+                    el.set_text_content(Some(&json));
+                }
+            }
+        }
+    });
+
+    meta.tags.register(id, builder_el.into_any());
+}
+
+/// Serializes the data, escaping the characters that could end the `<script>` tag or start an HTML comment.
+/// They can only appear in JSON strings, where the escaped forms mean the same thing.
+fn to_json<T: Serialize>(data: &T) -> String {
+    match serde_json::to_string(data) {
+        Ok(json) => json
+            .replace('<', "\\u003c")
+            .replace('>', "\\u003e")
+            .replace('&', "\\u0026"),
+        Err(e) => {
+            leptos::logging::error!(
+                "Could not serialize the data of a <JsonLd/> tag: {e}"
+            );
+            "null".to_string()
+        }
+    }
+}
@@ -51,7 +51,8 @@ use leptos::{
     *,
 };
 use std::{
-    cell::{Cell, RefCell},
+    cell::{Cell, OnceCell, RefCell},
+    collections::HashMap,
     fmt::Debug,
     rc::Rc,
 };
@@ -59,20 +60,34 @@ use std::{
 use wasm_bindgen::{JsCast, UnwrapThrowExt};
 
 mod body;
+mod color_scheme;
+mod favicon;
 mod html;
+mod image;
+mod json_ld;
 mod link;
 mod meta_tags;
 mod script;
+mod script_loader;
+mod social;
 mod style;
 mod stylesheet;
+mod theme_color;
 mod title;
 pub use body::*;
+pub use color_scheme::*;
+pub use favicon::*;
 pub use html::*;
+pub use image::*;
+pub use json_ld::*;
 pub use link::*;
 pub use meta_tags::*;
 pub use script::*;
+pub use script_loader::*;
+pub use social::*;
 pub use style::*;
 pub use stylesheet::*;
+pub use theme_color::*;
 pub use title::*;
 
 /// Contains the current state of meta tags. To access it, you can use [`use_head`].
@@ -89,21 +104,40 @@ pub struct MetaContext {
     pub body: BodyContext,
     /// Other metadata tags.
     pub tags: MetaTagsContext,
+    /// The owner in which the context was created, which owns the state that is shared
+    /// by the whole app, like the signals of [`use_color_scheme`].
+    owner: Option<Owner>,
+    color_scheme: Rc<OnceCell<UseColorScheme>>,
 }
 
 /// Manages all of the element created by components.
+///
+/// Tags that share a deduplication key, like two `<Meta name="description"/>`
+/// tags, replace one another: only the one that was registered last is in the
+/// document, and once it is removed, the one before it comes back. This lets
+/// a route override a default that is set at the root of the app.
+///
+/// Each tag is removed when the component that created it unmounts, both on
+/// the server and in the browser.
 #[derive(Clone, Default)]
 pub struct MetaTagsContext {
     next_id: Rc<Cell<MetaTagId>>,
+    els: Rc<RefCell<IndexMap<Oco<'static, str>, RegisteredTag>>>,
+    /// The ids of the tags that share each deduplication key, in the order in
+    /// which they were registered.
     #[allow(clippy::type_complexity)]
-    els: Rc<
-        RefCell<
-            IndexMap<
-                Oco<'static, str>,
-                (HtmlElement<AnyElement>, Option<web_sys::Element>),
-            >,
-        >,
-    >,
+    keyed: Rc<RefCell<HashMap<Oco<'static, str>, Vec<Oco<'static, str>>>>>,
+}
+
+struct RegisteredTag {
+    #[cfg_attr(not(feature = "ssr"), allow(dead_code))]
+    builder_el: HtmlElement<AnyElement>,
+    #[cfg_attr(
+        not(any(feature = "csr", feature = "hydrate")),
+        allow(dead_code)
+    )]
+    el: Option<web_sys::Element>,
+    key: Option<Oco<'static, str>>,
 }
 
 impl core::fmt::Debug for MetaTagsContext {
@@ -119,8 +153,9 @@ impl MetaTagsContext {
         self.els
             .borrow()
             .iter()
-            .map(|(_, (builder_el, _))| {
-                builder_el.clone().into_view().render_to_string()
+            .filter(|(id, tag)| !self.is_replaced(id, &tag.key))
+            .map(|(_, tag)| {
+                tag.builder_el.clone().into_view().render_to_string()
             })
             .collect()
     }
@@ -132,6 +167,33 @@ impl MetaTagsContext {
         id: Oco<'static, str>,
         builder_el: HtmlElement<AnyElement>,
     ) {
+        self.register_with_key(None, id, builder_el);
+    }
+
+    /// Registers a tag that replaces any earlier tag with the same `key`.
+    #[doc(hidden)]
+    pub fn register_with_key(
+        &self,
+        key: Option<Oco<'static, str>>,
+        id: Oco<'static, str>,
+        builder_el: HtmlElement<AnyElement>,
+    ) {
+        if let Some(key) = &key {
+            let replaced = self
+                .keyed
+                .borrow()
+                .get(key)
+                .and_then(|ids| ids.last().cloned());
+            if let Some(replaced) = replaced {
+                self.detach(&replaced);
+            }
+            self.keyed
+                .borrow_mut()
+                .entry(key.clone())
+                .or_default()
+                .push(id.clone());
+        }
+
         cfg_if! {
             if #[cfg(any(feature = "csr", feature = "hydrate"))] {
                 use leptos::document;
@@ -150,27 +212,116 @@ impl MetaTagsContext {
                         (*builder_el).clone().unchecked_into()
                     }
                 });
+                let el = Some(el);
+            } else {
+                let el = None;
+            }
+        }
 
-                on_cleanup({
-                    let el = el.clone();
-                    let els = self.els.clone();
-                    let id = id.clone();
-                    move || {
-                        let head = document().head().unwrap_throw();
-                        _ = head.remove_child(&el);
-                        els.borrow_mut().remove(&id);
-                    }
-                });
+        self.els.borrow_mut().insert(
+            id.clone(),
+            RegisteredTag {
+                builder_el,
+                el,
+                key,
+            },
+        );
 
-                self
-                    .els
-                    .borrow_mut()
-                    .insert(id, (builder_el.into_any(), Some(el)));
+        on_cleanup({
+            let tags = self.clone();
+            move || tags.remove(&id)
+        });
+    }
 
-            } else {
-                self.els.borrow_mut().insert(id, (builder_el, None));
+    /// Removes the tag with the given `id`, which is the `id` attribute of the
+    /// element, if it is still registered. If it replaced an earlier tag with
+    /// the same deduplication key, that tag is restored.
+    ///
+    /// Tags are removed automatically when the component that created them
+    /// unmounts, so this is only needed to clear a tag early.
+    pub fn remove(&self, id: &str) {
+        self.detach(id);
+        let Some(tag) = self.els.borrow_mut().shift_remove(id) else {
+            return;
+        };
+        let Some(key) = tag.key else { return };
+
+        let mut keyed = self.keyed.borrow_mut();
+        let Some(ids) = keyed.get_mut(&key) else {
+            return;
+        };
+        let was_current = ids.last().is_some_and(|last| last == id);
+        ids.retain(|other| other != id);
+        let restored = if was_current {
+            ids.last().cloned()
+        } else {
+            None
+        };
+        if ids.is_empty() {
+            keyed.remove(&key);
+        }
+        drop(keyed);
+
+        #[cfg(any(feature = "csr", feature = "hydrate"))]
+        if let Some(restored) = restored {
+            if let Some(el) = self
+                .els
+                .borrow()
+                .get(&restored)
+                .and_then(|tag| tag.el.as_ref())
+            {
+                let head = document().head().unwrap_throw();
+                _ = head.append_child(el);
             }
         }
+        #[cfg(not(any(feature = "csr", feature = "hydrate")))]
+        let _ = restored;
+    }
+
+    /// Takes a tag out of the document, while keeping it registered.
+    fn detach(&self, id: &str) {
+        #[cfg(any(feature = "csr", feature = "hydrate"))]
+        if let Some(el) =
+            self.els.borrow().get(id).and_then(|tag| tag.el.as_ref())
+        {
+            let head = document().head().unwrap_throw();
+            _ = head.remove_child(el);
+        }
+        #[cfg(not(any(feature = "csr", feature = "hydrate")))]
+        let _ = id;
+    }
+
+    /// Swaps the element of a tag for a copy with a new value for one attribute,
+    /// for tags like icons that browsers only read when they are inserted.
+    #[cfg(any(feature = "csr", feature = "hydrate"))]
+    pub(crate) fn replace_attribute(&self, id: &str, name: &str, value: &str) {
+        let mut els = self.els.borrow_mut();
+        let Some(el) = els.get_mut(id).and_then(|tag| tag.el.as_mut()) else {
+            return;
+        };
+        let Ok(copy) = el.clone_node() else {
+            return;
+        };
+        let copy = copy.unchecked_into::<web_sys::Element>();
+        _ = copy.set_attribute(name, value);
+        // a tag that has been replaced by one with the same key is detached,
+        // and only its element needs updating
+        if el.is_connected() {
+            _ = el.replace_with_with_node_1(&copy);
+        }
+        *el = copy;
+    }
+
+    /// Whether a later tag with the same key has replaced this one.
+    #[cfg(any(feature = "ssr", docs))]
+    fn is_replaced(&self, id: &str, key: &Option<Oco<'static, str>>) -> bool {
+        key.as_ref().is_some_and(|key| {
+            self.keyed
+                .borrow()
+                .get(key)
+                .and_then(|ids| ids.last())
+                .is_some_and(|last| last != id)
+        })
     }
 }
 
@@ -227,7 +378,22 @@ pub fn use_head() -> MetaContext {
 impl MetaContext {
     /// Creates an empty [`MetaContext`].
     pub fn new() -> Self {
-        Default::default()
+        Self {
+            owner: Owner::current(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the state shared by the whole app, creating it in the owner of the context
+    /// the first time, so that it outlives the component that asked for it.
+    pub(crate) fn color_scheme(
+        &self,
+        init: impl FnOnce() -> UseColorScheme,
+    ) -> UseColorScheme {
+        *self.color_scheme.get_or_init(|| match self.owner {
+            Some(owner) => with_owner(owner, init),
+            None => init(),
+        })
     }
 
     #[cfg(feature = "ssr")]
@@ -1,9 +1,13 @@
 use crate::{use_head, TextProp};
-use leptos::{component, Attribute, IntoView};
+use leptos::{component, untrack, Attribute, IntoView, Oco};
 
 /// Injects an [`HTMLMetaElement`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMetaElement) into the document
 /// head to set metadata
 ///
+/// A tag with the same `charset`, `name`, `property`, or `http_equiv` as an earlier one replaces it, so a page
+/// can override a description set at the root of the app, and the earlier tag comes back once the page unmounts.
+/// Only the initial value of these attributes is used to find the tag to replace.
+///
 /// ```
 /// use leptos::*;
 /// use leptos_meta::*;
@@ -23,6 +27,10 @@ use leptos::{component, Attribute, IntoView};
 /// ```
 #[component(transparent)]
 pub fn Meta(
+    /// The [`id`](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/id) attribute, which can be
+    /// passed to [`MetaTagsContext::remove`](crate::MetaTagsContext::remove).
+    #[prop(optional, into)]
+    id: Option<Oco<'static, str>>,
     /// The [`charset`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/meta#attr-charset) attribute.
     #[prop(optional, into)]
     charset: Option<TextProp>,
@@ -44,20 +52,41 @@ pub fn Meta(
 ) -> impl IntoView {
     let meta = use_head();
     let next_id = meta.tags.get_next_id();
-    let id = format!("leptos-link-{}", next_id.0);
+    let mut id: Oco<'static, str> =
+        id.unwrap_or_else(|| format!("leptos-link-{}", next_id.0).into());
 
-    let builder_el = leptos::leptos_dom::html::as_meta_tag(move || {
-        attrs
-            .into_iter()
-            .fold(leptos::leptos_dom::html::meta(), |el, (name, value)| {
-                el.attr(name, value)
+    let key = untrack(|| {
+        if charset.is_some() {
+            Some("charset".into())
+        } else if let Some(name) = &name {
+            Some(format!("name={}", name.get()).into())
+        } else if let Some(property) = &property {
+            Some(format!("property={}", property.get()).into())
+        } else {
+            http_equiv.as_ref().map(|http_equiv| {
+                format!("http-equiv={}", http_equiv.get()).into()
             })
-            .attr("charset", move || charset.as_ref().map(|v| v.get()))
-            .attr("name", move || name.as_ref().map(|v| v.get()))
-            .attr("property", move || property.as_ref().map(|v| v.get()))
-            .attr("http-equiv", move || http_equiv.as_ref().map(|v| v.get()))
-            .attr("content", move || content.as_ref().map(|v| v.get()))
+        }
+    });
+
+    let builder_el = leptos::leptos_dom::html::as_meta_tag({
+        let id = id.clone_inplace();
+        move || {
+            attrs
+                .into_iter()
+                .fold(leptos::leptos_dom::html::meta(), |el, (name, value)| {
+                    el.attr(name, value)
+                })
+                .attr("id", id)
+                .attr("charset", move || charset.as_ref().map(|v| v.get()))
+                .attr("name", move || name.as_ref().map(|v| v.get()))
+                .attr("property", move || property.as_ref().map(|v| v.get()))
+                .attr("http-equiv", move || {
+                    http_equiv.as_ref().map(|v| v.get())
+                })
+                .attr("content", move || content.as_ref().map(|v| v.get()))
+        }
     });
 
-    meta.tags.register(id.into(), builder_el.into_any());
+    meta.tags.register_with_key(key, id, builder_el.into_any());
 }
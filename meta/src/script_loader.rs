@@ -0,0 +1,367 @@
+use crate::use_head;
+use leptos::{nonce::use_nonce, *};
+
+/// When a [`<ScriptLoader/>`](ScriptLoader) loads its script.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ScriptStrategy {
+    /// As soon as the page has been hydrated, or mounted with `csr`. A preload hint
+    /// lets the browser download the script while the page loads.
+    #[default]
+    AfterHydration,
+    /// Once the page has finished loading and the browser is idle, for scripts like
+    /// analytics or chat widgets that nothing on the page waits for. The hint is a
+    /// low-priority prefetch.
+    Lazy,
+    /// In a dedicated [`Worker`](https://developer.mozilla.org/en-US/docs/Web/API/Worker),
+    /// off the main thread, once the page has been hydrated. The script cannot touch the
+    /// DOM, and is only [`Loaded`](ScriptStatus::Loaded) once it has posted a first
+    /// message to the page. The hint is a low-priority prefetch.
+    Worker,
+}
+
+/// Whether a script loaded with [`use_script`] or [`<ScriptLoader/>`](ScriptLoader) is ready.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ScriptStatus {
+    /// The script has not been loaded yet. This is always the case on the server.
+    #[default]
+    Loading,
+    /// The script has run, or its worker has posted its first message.
+    Loaded,
+    /// The script could not be loaded, or its worker failed before posting a message.
+    Failed,
+}
+
+impl ScriptStatus {
+    /// Whether the script has run, or its worker has posted its first message.
+    pub fn is_loaded(self) -> bool {
+        self == Self::Loaded
+    }
+}
+
+/// Loads the script at `src` in the browser with the given strategy, and returns its
+/// status.
+///
+/// Each `src` is only loaded once: when several components ask for the same script,
+/// they all follow the first one. Nothing is rendered, in the `<head>` or anywhere else.
+///
+/// ```
+/// use leptos::*;
+/// use leptos_meta::*;
+///
+/// #[component]
+/// fn Map() -> impl IntoView {
+///     let status = use_script(
+///         "https://maps.example.com/sdk.js",
+///         ScriptStrategy::AfterHydration,
+///     );
+///
+///     view! {
+///       <Show when=move || status.get().is_loaded() fallback=|| "Loading the map…">
+///         <div id="map"></div>
+///       </Show>
+///     }
+/// }
+/// ```
+pub fn use_script(
+    src: impl Into<Oco<'static, str>>,
+    strategy: ScriptStrategy,
+) -> Signal<ScriptStatus> {
+    load(src.into(), strategy, None, None)
+}
+
+/// Loads a third-party script at the time chosen by its [`ScriptStrategy`], instead of
+/// blocking the page with a `<script>` tag.
+///
+/// - Each `src` is only loaded once, however many loaders ask for it.
+/// - `on_load` and `on_error` are called once the script has run or failed to load.
+///   Use [`use_script`] to read its status as a signal instead.
+/// - During server rendering, nothing is rendered, except a hint in the `<head>` when
+///   `preload` is set, so the browser can download the script early.
+///
+/// ```
+/// use leptos::*;
+/// use leptos_meta::*;
+///
+/// #[component]
+/// fn MyApp() -> impl IntoView {
+///     provide_meta_context();
+///     let (ready, set_ready) = create_signal(false);
+///
+///     view! {
+///       <ScriptLoader
+///         src="https://widgets.example.com/chat.js"
+///         strategy=ScriptStrategy::Lazy
+///         preload=true
+///         on_load=move |_| set_ready.set(true)
+///       />
+///       <p>{move || if ready.get() { "Chat is online" } else { "" }}</p>
+///     }
+/// }
+/// ```
+#[component(transparent)]
+pub fn ScriptLoader(
+    /// The URL of the script.
+    #[prop(into)]
+    src: Oco<'static, str>,
+    /// When to load the script. Defaults to [`ScriptStrategy::AfterHydration`].
+    #[prop(optional)]
+    strategy: ScriptStrategy,
+    /// Whether to add a hint for the script to the `<head>`. Defaults to `false`.
+    #[prop(optional)]
+    preload: bool,
+    /// The [`integrity`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/script#integrity) attribute.
+    #[prop(optional, into)]
+    integrity: Option<Oco<'static, str>>,
+    /// The [`crossorigin`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/script#crossorigin) attribute.
+    #[prop(optional, into)]
+    crossorigin: Option<Oco<'static, str>>,
+    /// Called once the script has run, or its worker has posted its first message.
+    #[prop(optional, into)]
+    on_load: Option<Callback<()>>,
+    /// Called if the script could not be loaded.
+    #[prop(optional, into)]
+    on_error: Option<Callback<()>>,
+) -> impl IntoView {
+    if preload {
+        let meta = use_head();
+        let id: Oco<'static, str> =
+            format!("leptos-script-hint-{}", meta.tags.get_next_id().0).into();
+        let (rel, as_) = match strategy {
+            ScriptStrategy::AfterHydration => ("preload", Some("script")),
+            ScriptStrategy::Lazy | ScriptStrategy::Worker => ("prefetch", None),
+        };
+        let builder_el = leptos::leptos_dom::html::as_meta_tag({
+            let id = id.clone();
+            let src = src.clone();
+            let integrity = integrity.clone();
+            let crossorigin = crossorigin.clone();
+            move || {
+                leptos::leptos_dom::html::link()
+                    .attr("id", id)
+                    .attr("rel", rel)
+                    .attr("as", as_)
+                    .attr("href", src)
+                    .attr("integrity", integrity)
+                    .attr("crossorigin", crossorigin)
+                    .attr("nonce", use_nonce())
+            }
+        });
+        // loaders of the same script share a single hint
+        let key = format!("script-loader:{src}").into();
+        meta.tags
+            .register_with_key(Some(key), id, builder_el.into_any());
+    }
+
+    let status = load(src, strategy, integrity, crossorigin);
+    if on_load.is_some() || on_error.is_some() {
+        create_effect(move |_| match status.get() {
+            ScriptStatus::Loading => {}
+            ScriptStatus::Loaded => {
+                if let Some(on_load) = on_load {
+                    on_load.call(());
+                }
+            }
+            ScriptStatus::Failed => {
+                if let Some(on_error) = on_error {
+                    on_error.call(());
+                }
+            }
+        });
+    }
+}
+
+fn load(
+    src: Oco<'static, str>,
+    strategy: ScriptStrategy,
+    integrity: Option<Oco<'static, str>>,
+    crossorigin: Option<Oco<'static, str>>,
+) -> Signal<ScriptStatus> {
+    let (status, set_status) = create_signal(ScriptStatus::Loading);
+
+    cfg_if::cfg_if! {
+        if #[cfg(all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate")))] {
+            let nonce = use_nonce();
+            // effects only run in the browser, once the page has been hydrated
+            create_effect(move |_| {
+                let notify = move |status| _ = set_status.try_set(status);
+                if client::subscribe(&src, Box::new(notify)) {
+                    let script = client::PendingScript {
+                        src: src.to_string(),
+                        integrity: integrity.as_deref().map(str::to_string),
+                        crossorigin: crossorigin.as_deref().map(str::to_string),
+                        nonce: nonce.as_ref().map(ToString::to_string),
+                    };
+                    match strategy {
+                        ScriptStrategy::AfterHydration => script.inject(),
+                        ScriptStrategy::Lazy => script.inject_when_idle(),
+                        ScriptStrategy::Worker => script.spawn_worker(),
+                    }
+                }
+            });
+        } else {
+            _ = (src, strategy, integrity, crossorigin, set_status);
+        }
+    }
+
+    status.into()
+}
+
+#[cfg(all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate")))]
+mod client {
+    use super::ScriptStatus;
+    use leptos::{document, request_idle_callback, window};
+    use std::{
+        cell::RefCell,
+        collections::{hash_map::Entry, HashMap},
+    };
+    use wasm_bindgen::{closure::Closure, JsCast, UnwrapThrowExt};
+
+    type Notify = Box<dyn FnOnce(ScriptStatus)>;
+
+    enum Injected {
+        Loading(Vec<Notify>),
+        Done(ScriptStatus),
+    }
+
+    thread_local! {
+        static SCRIPTS: RefCell<HashMap<String, Injected>> = Default::default();
+        // a worker that nothing refers to could be garbage-collected
+        static WORKERS: RefCell<Vec<web_sys::Worker>> = Default::default();
+    }
+
+    /// Calls `notify` once the script at `src` has loaded or failed, returning whether
+    /// it has not been requested before and should be loaded now.
+    pub(super) fn subscribe(src: &str, notify: Notify) -> bool {
+        let (is_new, done) = SCRIPTS.with(|scripts| {
+            match scripts.borrow_mut().entry(src.to_string()) {
+                Entry::Vacant(entry) => {
+                    entry.insert(Injected::Loading(vec![notify]));
+                    (true, None)
+                }
+                Entry::Occupied(mut entry) => match entry.get_mut() {
+                    Injected::Loading(waiting) => {
+                        waiting.push(notify);
+                        (false, None)
+                    }
+                    Injected::Done(status) => (false, Some((*status, notify))),
+                },
+            }
+        });
+        if let Some((status, notify)) = done {
+            notify(status);
+        }
+        is_new
+    }
+
+    /// Records the status of the script at `src`, unless it already has one: a worker
+    /// keeps the status of its first message or error.
+    fn finish(src: &str, status: ScriptStatus) {
+        let waiting = SCRIPTS.with(|scripts| {
+            let mut scripts = scripts.borrow_mut();
+            let Some(Injected::Loading(waiting)) = scripts.get_mut(src) else {
+                return Vec::new();
+            };
+            let waiting = std::mem::take(waiting);
+            scripts.insert(src.to_string(), Injected::Done(status));
+            waiting
+        });
+        // the signals are set outside of the borrow, as their effects may load
+        // other scripts
+        for notify in waiting {
+            notify(status);
+        }
+    }
+
+    pub(super) struct PendingScript {
+        pub src: String,
+        pub integrity: Option<String>,
+        pub crossorigin: Option<String>,
+        pub nonce: Option<String>,
+    }
+
+    impl PendingScript {
+        pub fn inject(self) {
+            let script = document()
+                .create_element("script")
+                .unwrap_throw()
+                .unchecked_into::<web_sys::HtmlScriptElement>();
+            script.set_src(&self.src);
+            script.set_async(true);
+            if let Some(integrity) = &self.integrity {
+                script.set_integrity(integrity);
+            }
+            if let Some(crossorigin) = &self.crossorigin {
+                script.set_cross_origin(Some(crossorigin));
+            }
+            if let Some(nonce) = &self.nonce {
+                _ = script.set_attribute("nonce", nonce);
+            }
+
+            let src = self.src.clone();
+            let on_load = Closure::once_into_js(move || {
+                finish(&src, ScriptStatus::Loaded)
+            });
+            script.set_onload(Some(on_load.unchecked_ref()));
+            let src = self.src;
+            let on_error = Closure::once_into_js(move || {
+                finish(&src, ScriptStatus::Failed)
+            });
+            script.set_onerror(Some(on_error.unchecked_ref()));
+
+            document()
+                .head()
+                .unwrap_throw()
+                .append_child(&script)
+                .unwrap_throw();
+        }
+
+        pub fn inject_when_idle(self) {
+            let script = RefCell::new(Some(self));
+            let inject_when_idle = move || {
+                request_idle_callback(move || {
+                    if let Some(script) = script.take() {
+                        script.inject();
+                    }
+                })
+            };
+
+            if document().ready_state() == "complete" {
+                inject_when_idle();
+            } else {
+                let options = web_sys::AddEventListenerOptions::new();
+                options.set_once(true);
+                let on_load = Closure::once_into_js(inject_when_idle);
+                _ = window()
+                    .add_event_listener_with_callback_and_add_event_listener_options(
+                        "load",
+                        on_load.unchecked_ref(),
+                        &options,
+                    );
+            }
+        }
+
+        pub fn spawn_worker(self) {
+            let worker = match web_sys::Worker::new(&self.src) {
+                Ok(worker) => worker,
+                Err(_) => return finish(&self.src, ScriptStatus::Failed),
+            };
+
+            // a worker that could not be fetched or failed to start only reports it
+            // with an `error` event, so it is loaded once it has sent a first message
+            let src = self.src.clone();
+            let on_message = Closure::<dyn Fn()>::new(move || {
+                finish(&src, ScriptStatus::Loaded)
+            })
+            .into_js_value();
+            worker.set_onmessage(Some(on_message.unchecked_ref()));
+            let src = self.src;
+            let on_error = Closure::<dyn Fn()>::new(move || {
+                leptos::logging::error!("The worker for {src} failed.");
+                finish(&src, ScriptStatus::Failed)
+            })
+            .into_js_value();
+            worker.set_onerror(Some(on_error.unchecked_ref()));
+            WORKERS.with(|workers| workers.borrow_mut().push(worker));
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{Meta, MetaProps, TextProp};
+use leptos::{component, leptos_dom::debug_warn, untrack, IntoView};
+
+/// Sets the [Open Graph](https://ogp.me/) title of the page, which is shown in link previews on
+/// social networks and chat apps.
+///
+/// ```
+/// use leptos::*;
+/// use leptos_meta::*;
+///
+/// #[component]
+/// fn MyApp() -> impl IntoView {
+///     provide_meta_context();
+///
+///     view! {
+///       <OgTitle text="Leptos"/>
+///       <OgImage url="https://leptos.dev/logo.png" alt="The Leptos logo" width=1200 height=630/>
+///       <TwitterCard card=TwitterCardKind::SummaryLargeImage site="@leptos_rs"/>
+///     }
+/// }
+/// ```
+#[component(transparent)]
+pub fn OgTitle(
+    /// The title, without any branding or site name.
+    #[prop(into)]
+    text: TextProp,
+) -> impl IntoView {
+    property("og:title", text);
+}
+
+/// Sets the [Open Graph](https://ogp.me/#structured) image of the page, along with its optional
+/// structured properties.
+///
+/// Social networks only fetch images from absolute URLs, so a warning is logged in debug builds
+/// when `url` is relative.
+#[component(transparent)]
+pub fn OgImage(
+    /// The absolute URL of the image.
+    #[prop(into)]
+    url: TextProp,
+    /// The URL of the image over HTTPS, if `url` uses HTTP.
+    #[prop(optional, into)]
+    secure_url: Option<TextProp>,
+    /// The MIME type of the image, like `image/png`.
+    #[prop(optional, into)]
+    mime_type: Option<TextProp>,
+    /// The width of the image, in pixels.
+    #[prop(optional)]
+    width: Option<u32>,
+    /// The height of the image, in pixels.
+    #[prop(optional)]
+    height: Option<u32>,
+    /// A description of what is in the image, for people who cannot see it.
+    #[prop(optional, into)]
+    alt: Option<TextProp>,
+) -> impl IntoView {
+    warn_if_relative("og:image", &url);
+    property("og:image", url);
+    if let Some(secure_url) = secure_url {
+        warn_if_relative("og:image:secure_url", &secure_url);
+        property("og:image:secure_url", secure_url);
+    }
+    if let Some(mime_type) = mime_type {
+        property("og:image:type", mime_type);
+    }
+    if let Some(width) = width {
+        property("og:image:width", width.to_string());
+    }
+    if let Some(height) = height {
+        property("og:image:height", height.to_string());
+    }
+    if let Some(alt) = alt {
+        property("og:image:alt", alt);
+    }
+}
+
+/// The kinds of [Twitter/X cards](https://developer.x.com/en/docs/twitter-for-websites/cards/overview/abouts-cards).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TwitterCardKind {
+    /// A small square image next to the title and description.
+    Summary,
+    /// A large image above the title and description.
+    SummaryLargeImage,
+    /// A video or audio player, which requires the `player`, `player_width`, and `player_height`
+    /// properties.
+    Player,
+}
+
+impl TwitterCardKind {
+    /// The value of the `twitter:card` tag.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Summary => "summary",
+            Self::SummaryLargeImage => "summary_large_image",
+            Self::Player => "player",
+        }
+    }
+}
+
+/// Sets the tags for a [Twitter/X card](https://developer.x.com/en/docs/twitter-for-websites/cards/overview/markup).
+///
+/// The title, description, and image fall back to the Open Graph tags of the page, so they only
+/// need to be set here to show something different on X. A `Player` card without all of its
+/// `player` properties is not shown, so a warning is logged in debug builds when one is missing.
+#[component(transparent)]
+pub fn TwitterCard(
+    /// The kind of card.
+    card: TwitterCardKind,
+    /// The `@username` of the website.
+    #[prop(optional, into)]
+    site: Option<TextProp>,
+    /// The `@username` of the author of the content.
+    #[prop(optional, into)]
+    creator: Option<TextProp>,
+    /// The title, if it differs from `og:title`.
+    #[prop(optional, into)]
+    title: Option<TextProp>,
+    /// The description, if it differs from `og:description`.
+    #[prop(optional, into)]
+    description: Option<TextProp>,
+    /// The absolute URL of the image, if it differs from `og:image`.
+    #[prop(optional, into)]
+    image: Option<TextProp>,
+    /// A description of what is in the image, for people who cannot see it.
+    #[prop(optional, into)]
+    image_alt: Option<TextProp>,
+    /// The HTTPS URL of the player's iframe, for `Player` cards.
+    #[prop(optional, into)]
+    player: Option<TextProp>,
+    /// The width of the player, in pixels.
+    #[prop(optional)]
+    player_width: Option<u32>,
+    /// The height of the player, in pixels.
+    #[prop(optional)]
+    player_height: Option<u32>,
+) -> impl IntoView {
+    if card == TwitterCardKind::Player {
+        for (property, value) in [
+            ("twitter:player", player.is_some()),
+            ("twitter:player:width", player_width.is_some()),
+            ("twitter:player:height", player_height.is_some()),
+        ] {
+            if !value {
+                debug_warn!(
+                    "A <TwitterCard/> player card is missing `{property}`, so \
+                     it will not be shown."
+                );
+            }
+        }
+    }
+
+    name("twitter:card", card.as_str());
+    let tags = [
+        ("twitter:site", site),
+        ("twitter:creator", creator),
+        ("twitter:title", title),
+        ("twitter:description", description),
+    ];
+    for (tag, value) in tags {
+        if let Some(value) = value {
+            name(tag, value);
+        }
+    }
+    if let Some(image) = image {
+        warn_if_relative("twitter:image", &image);
+        name("twitter:image", image);
+    }
+    if let Some(image_alt) = image_alt {
+        name("twitter:image:alt", image_alt);
+    }
+    if let Some(player) = player {
+        name("twitter:player", player);
+    }
+    if let Some(width) = player_width {
+        name("twitter:player:width", width.to_string());
+    }
+    if let Some(height) = player_height {
+        name("twitter:player:height", height.to_string());
+    }
+}
+
+fn property(property: &'static str, content: impl Into<TextProp>) {
+    _ = Meta(
+        MetaProps::builder()
+            .property(property)
+            .content(content)
+            .build(),
+    );
+}
+
+fn name(name: &'static str, content: impl Into<TextProp>) {
+    _ = Meta(MetaProps::builder().name(name).content(content).build());
+}
+
+fn warn_if_relative(property: &str, url: &TextProp) {
+    if cfg!(debug_assertions) {
+        let url = untrack(|| url.get());
+        if !(url.starts_with("https://") || url.starts_with("http://")) {
+            debug_warn!(
+                "`{property}` should be an absolute URL, but it is {url:?}."
+            );
+        }
+    }
+}
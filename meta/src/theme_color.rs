@@ -0,0 +1,58 @@
+use crate::{use_head, TextProp};
+use leptos::{component, IntoView, Oco};
+
+/// Sets the [`theme-color`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/meta/name/theme-color)
+/// of the page, which browsers use to tint their toolbar or the title bar of an installed app.
+///
+/// The `color` can change, and the `<meta>` tag is updated in place. With a `media` query, a
+/// color only applies when it matches, so a light and a dark color can be set side by side. A
+/// color replaces any earlier one with the same `media`, including a
+/// `<Meta name="theme-color"/>` when there is no `media`, and the earlier one comes back once
+/// it unmounts.
+///
+/// ```
+/// use leptos::*;
+/// use leptos_meta::*;
+///
+/// #[component]
+/// fn MyApp() -> impl IntoView {
+///     provide_meta_context();
+///
+///     view! {
+///       <ThemeColor color="#ffffff" media="(prefers-color-scheme: light)"/>
+///       <ThemeColor color="#1a1a1a" media="(prefers-color-scheme: dark)"/>
+///     }
+/// }
+/// ```
+#[component(transparent)]
+pub fn ThemeColor(
+    /// A CSS color.
+    #[prop(into)]
+    color: TextProp,
+    /// The [`media`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/meta#media) query
+    /// that must match for the color to apply.
+    #[prop(optional, into)]
+    media: Option<Oco<'static, str>>,
+) -> impl IntoView {
+    let meta = use_head();
+    let next_id = meta.tags.get_next_id();
+    let id: Oco<'static, str> = format!("leptos-meta-{}", next_id.0).into();
+    let key = match &media {
+        Some(media) => format!("name=theme-color;media={media}"),
+        None => "name=theme-color".to_string(),
+    };
+
+    let builder_el = leptos::leptos_dom::html::as_meta_tag({
+        let id = id.clone();
+        move || {
+            leptos::leptos_dom::html::meta()
+                .attr("id", id)
+                .attr("name", "theme-color")
+                .attr("content", move || color.get())
+                .attr("media", media)
+        }
+    });
+
+    meta.tags
+        .register_with_key(Some(key.into()), id, builder_el.into_any());
+}
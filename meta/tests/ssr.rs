@@ -0,0 +1,312 @@
+#![cfg(feature = "ssr")]
+
+use leptos::*;
+use leptos_meta::*;
+
+#[test]
+fn later_meta_tags_replace_earlier_ones() {
+    let runtime = create_runtime();
+    provide_meta_context();
+
+    _ = view! {
+        <Meta name="description" content="Default description"/>
+        <Meta property="og:type" content="website"/>
+        <Meta name="description" content="Page description"/>
+    }
+    .into_view()
+    .render_to_string();
+
+    let head = use_head().dehydrate();
+    assert!(head.contains("Page description"));
+    assert!(!head.contains("Default description"));
+    assert!(head.contains("og:type"));
+
+    runtime.dispose();
+}
+
+#[test]
+fn meta_tags_can_be_removed_by_id() {
+    let runtime = create_runtime();
+    provide_meta_context();
+
+    _ = view! {
+        <Meta name="description" content="Default description"/>
+        <Meta id="override" name="description" content="Other description"/>
+    }
+    .into_view()
+    .render_to_string();
+
+    assert!(use_head().dehydrate().contains("id=\"override\""));
+    use_head().tags.remove("override");
+    let head = use_head().dehydrate();
+    assert!(head.contains("Default description"));
+    assert!(!head.contains("Other description"));
+
+    runtime.dispose();
+}
+
+#[test]
+fn social_components_emit_their_tags() {
+    let runtime = create_runtime();
+    provide_meta_context();
+
+    _ = view! {
+        <OgTitle text="Leptos"/>
+        <OgImage url="https://leptos.dev/logo.png" alt="The Leptos logo" width=1200/>
+        <TwitterCard card=TwitterCardKind::SummaryLargeImage site="@leptos_rs"/>
+    }
+    .into_view()
+    .render_to_string();
+
+    let head = use_head().dehydrate();
+    for (attr, value) in [
+        ("property=\"og:title\"", "Leptos"),
+        ("property=\"og:image\"", "https://leptos.dev/logo.png"),
+        ("property=\"og:image:alt\"", "The Leptos logo"),
+        ("property=\"og:image:width\"", "1200"),
+        ("name=\"twitter:card\"", "summary_large_image"),
+        ("name=\"twitter:site\"", "@leptos_rs"),
+    ] {
+        assert!(
+            head.contains(&format!("{attr} content=\"{value}\"")),
+            "missing {attr} in {head}"
+        );
+    }
+    assert!(!head.contains("og:image:height"));
+
+    runtime.dispose();
+}
+
+#[test]
+fn json_ld_is_escaped() {
+    #[derive(serde::Serialize)]
+    struct Article {
+        headline: &'static str,
+    }
+
+    let runtime = create_runtime();
+    provide_meta_context();
+
+    _ = view! {
+        <JsonLd data=Article { headline: "</script><script>alert(1)</script>" }/>
+    }
+    .into_view()
+    .render_to_string();
+
+    let head = use_head().dehydrate();
+    assert!(head.contains("type=\"application/ld+json\""));
+    assert!(head.contains(
+        r#"{"headline":"\u003c/script\u003e\u003cscript\u003ealert(1)\u003c/script\u003e"}</script>"#
+    ));
+
+    runtime.dispose();
+}
+
+#[test]
+fn json_ld_is_removed_when_it_unmounts() {
+    let runtime = create_runtime();
+    provide_meta_context();
+
+    let (show, set_show) = create_signal(true);
+    let page = create_memo(move |_| {
+        show.get().then(|| {
+            view! { <JsonLd data=["first", "second"]/> }
+                .into_view()
+                .render_to_string()
+        })
+    });
+
+    page.track();
+    assert!(use_head().dehydrate().contains(r#"["first","second"]"#));
+
+    set_show.set(false);
+    page.track();
+    assert!(!use_head().dehydrate().contains("application/ld+json"));
+
+    runtime.dispose();
+}
+
+#[test]
+fn image_has_a_srcset_and_can_be_preloaded() {
+    let runtime = create_runtime();
+    provide_meta_context();
+    provide_image_loader(ImageLoader::query("/_image"));
+
+    let html = view! {
+        <Image src="/hero.jpg" alt="Hero" width=600 height=300 priority=true/>
+        <Image
+            src="/thumb.jpg"
+            alt="Thumbnail"
+            width=100
+            height=100
+            sizes="50vw"
+            widths=vec![320, 160]
+            placeholder="data:image/png;base64,AAAA"
+        />
+    }
+    .into_view()
+    .render_to_string();
+
+    assert!(html.contains(r#"width="600" height="300""#));
+    assert!(html.contains(
+        r#"srcset="/_image?url=%2Fhero.jpg&amp;w=600&amp;q=75 1x, /_image?url=%2Fhero.jpg&amp;w=1200&amp;q=75 2x""#
+    ));
+    assert!(html.contains(r#"loading="eager""#));
+    assert!(html.contains(
+        r#"srcset="/_image?url=%2Fthumb.jpg&amp;w=160&amp;q=75 160w, /_image?url=%2Fthumb.jpg&amp;w=320&amp;q=75 320w""#
+    ));
+    assert!(html.contains(r#"loading="lazy""#));
+    assert!(html.contains("background-image:url("));
+
+    let head = use_head().dehydrate();
+    assert!(head.contains(r#"rel="preload""#));
+    assert!(
+        head.contains(r#"href="/_image?url=%2Fhero.jpg&amp;w=1200&amp;q=75""#)
+    );
+    assert!(head.contains(r#"imagesrcset="/_image?url=%2Fhero.jpg"#));
+    assert!(!head.contains("imagesizes"));
+
+    runtime.dispose();
+}
+
+#[test]
+fn image_widths_do_not_overflow() {
+    let runtime = create_runtime();
+    provide_meta_context();
+    provide_image_loader(ImageLoader::query("/_image"));
+
+    let html =
+        view! { <Image src="/wide.jpg" alt="Wide" width=u32::MAX height=1/> }
+            .into_view()
+            .render_to_string();
+    assert!(
+        html.contains(&format!("w={}&amp;q=75 2x", u32::MAX)),
+        "{html}"
+    );
+
+    runtime.dispose();
+}
+
+#[test]
+fn script_loader_renders_nothing_but_a_shared_hint() {
+    let runtime = create_runtime();
+    provide_meta_context();
+
+    let html = view! {
+        <div>
+            <ScriptLoader src="/chat.js" preload=true/>
+            <ScriptLoader src="/chat.js" preload=true/>
+            <ScriptLoader src="/analytics.js" strategy=ScriptStrategy::Lazy preload=true/>
+            <ScriptLoader src="/sdk.js"/>
+        </div>
+    }
+    .into_view()
+    .render_to_string();
+    assert!(!html.contains("<script"));
+
+    let head = use_head().dehydrate();
+    assert_eq!(head.matches(r#"href="/chat.js""#).count(), 1);
+    assert!(head.contains(r#"rel="preload" as="script" href="/chat.js""#));
+    assert!(head.contains(r#"rel="prefetch" href="/analytics.js""#));
+    assert!(!head.contains("/sdk.js"));
+
+    runtime.dispose();
+}
+
+#[test]
+fn favicon_and_theme_color_render_their_initial_values() {
+    let runtime = create_runtime();
+    provide_meta_context();
+
+    let (unread, _) = create_signal(3);
+    _ = view! {
+        <Favicon href="/icon.svg" type_="image/svg+xml"/>
+        <Favicon href=move || {
+            if unread.get() > 0 { "/icon-unread.svg" } else { "/icon.svg" }.to_string()
+        }/>
+        <Meta name="theme-color" content="#000000"/>
+        <ThemeColor color="#ffffff"/>
+        <ThemeColor color="#1a1a1a" media="(prefers-color-scheme: dark)"/>
+    }
+    .into_view()
+    .render_to_string();
+
+    let head = use_head().dehydrate();
+    assert!(head.contains(r#"rel="icon" href="/icon-unread.svg""#));
+    assert!(!head.contains(r#"href="/icon.svg""#));
+    assert!(!head.contains("#000000"));
+    assert!(head.contains(r##"name="theme-color" content="#ffffff""##));
+    assert_eq!(head.matches(r#"<meta id="leptos-meta-"#).count(), 2);
+    assert!(head.contains(
+        r##"content="#1a1a1a" media="(prefers-color-scheme: dark)""##
+    ));
+
+    runtime.dispose();
+}
+
+#[test]
+fn color_scheme_is_set_before_hydration_by_a_script() {
+    let runtime = create_runtime();
+    provide_meta_context();
+
+    // the signals belong to the meta root, so that they outlive the first component
+    // that asked for them
+    let (color_scheme, disposer) =
+        as_child_of_current_owner(|_| use_color_scheme())(());
+    drop(disposer);
+    let again = use_color_scheme();
+    assert_eq!(color_scheme.scheme, again.scheme);
+    again.scheme.set(ColorScheme::Dark);
+    assert!(color_scheme.is_dark.get());
+    again.scheme.set(ColorScheme::System);
+    assert_eq!(color_scheme.scheme.get(), ColorScheme::System);
+    assert!(!color_scheme.is_dark.get());
+
+    let head = use_head().dehydrate();
+    assert_eq!(head.matches("<script").count(), 1);
+    assert!(head.contains(r#"<script id="leptos-script-"#));
+    assert!(head.contains(r#"localStorage.getItem("leptos-color-scheme")"#));
+    assert!(head.contains(r#"classList.toggle("dark", dark)"#));
+
+    runtime.dispose();
+}
+
+#[test]
+fn image_placeholders_cannot_end_their_css_url() {
+    let runtime = create_runtime();
+    provide_meta_context();
+
+    let html = view! {
+        <Image
+            src="/cat.jpg"
+            alt="Cat"
+            width=100
+            height=100
+            placeholder="data:x\");background:red;(\""
+        />
+    }
+    .into_view()
+    .render_to_string();
+    assert!(html.contains(
+        r#"url(&quot;data:x\&quot;);background:red;(\&quot;&quot;)"#
+    ));
+
+    // a blurred placeholder is wrapped in an SVG that blurs it
+    let html = view! {
+        <Image
+            src="/cat.jpg"
+            alt="Cat"
+            width=100
+            height=50
+            placeholder="data:image/png;base64,AAAA"
+            blur=true
+        />
+    }
+    .into_view()
+    .render_to_string();
+    assert!(html.contains("background-image:url(&quot;data:image/svg+xml,"));
+    assert!(html.contains("feGaussianBlur"));
+    assert!(html.contains("data%3Aimage%2Fpng%3Bbase64%2CAAAA"));
+
+    runtime.dispose();
+}
@@ -0,0 +1,91 @@
+use crate::{
+    generate_route_list_inner, resolve_get_paths, RouterIntegrationContext,
+    ServerIntegration,
+};
+use leptos::{
+    create_runtime, provide_context,
+    ssr::{render_interactive, JsOnlyInteraction},
+    IntoView,
+};
+use leptos_meta::MetaContext;
+use std::fmt;
+
+/// The interactions that only work with JavaScript on one page of the app, found by
+/// [`audit_progressive_enhancement`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RouteAudit {
+    /// The path of the page.
+    pub path: String,
+    /// The interactions that only work with JavaScript.
+    pub interactions: Vec<JsOnlyInteraction>,
+}
+
+impl fmt::Display for RouteAudit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:", self.path)?;
+        for interaction in &self.interactions {
+            write!(f, "\n  - {interaction}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Renders every page of the app on the server, and reports the interactions on each one that
+/// only work with JavaScript, like buttons that only have an `on:click` listener and forms
+/// without an `action`. See [`InteractiveView::js_only_interactions`](leptos::ssr::InteractiveView::js_only_interactions)
+/// for the exact rules.
+///
+/// This helps apps that must work before they are hydrated, or without JavaScript at all, to
+/// use links and forms for their interactions. Only the pages that respond to `GET` requests are
+/// rendered, once for each set of their `static_params` if they have params, and resources are not
+/// loaded, so the fallbacks of `<Suspense/>` are audited instead of its children.
+///
+/// The audit is meant to run in development, in a test or behind a command-line flag:
+/// ```ignore
+/// # use leptos_router::*;
+/// #[test]
+/// fn app_works_without_javascript() {
+///     let audits = futures::executor::block_on(audit_progressive_enhancement(App));
+///     for audit in &audits {
+///         eprintln!("{audit}");
+///     }
+///     assert!(audits.is_empty());
+/// }
+/// ```
+pub async fn audit_progressive_enhancement<IV>(
+    app_fn: impl Fn() -> IV + 'static + Clone,
+) -> Vec<RouteAudit>
+where
+    IV: IntoView + 'static,
+{
+    let (routes, static_data_map) = generate_route_list_inner(app_fn.clone());
+    let mut audits = Vec::new();
+    for path in resolve_get_paths(&routes, &static_data_map).await {
+        let interactions = audit_path(&path, app_fn.clone());
+        if !interactions.is_empty() {
+            audits.push(RouteAudit { path, interactions });
+        }
+    }
+    audits
+}
+
+fn audit_path<IV>(
+    path: &str,
+    app_fn: impl Fn() -> IV + 'static,
+) -> Vec<JsOnlyInteraction>
+where
+    IV: IntoView + 'static,
+{
+    let runtime = create_runtime();
+    provide_context(RouterIntegrationContext::new(ServerIntegration {
+        path: format!("http://leptos.dev{path}"),
+    }));
+    provide_context(MetaContext::new());
+
+    leptos::suppress_resource_load(true);
+    let interactions = render_interactive(app_fn).js_only_interactions();
+    leptos::suppress_resource_load(false);
+
+    runtime.dispose();
+    interactions
+}
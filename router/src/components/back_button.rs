@@ -0,0 +1,65 @@
+use crate::{use_navigate, use_router, NavigateOptions};
+use leptos::{leptos_dom::IntoView, *};
+
+/// A button that goes back to the previous page, if the user got to the current page by
+/// navigating within the app.
+///
+/// If they didn't (for example, because they followed a link from another site or opened
+/// the page in a new tab), going back in the browser's history would leave the app, so it
+/// navigates to `fallback` instead, replacing the current page in the history.
+///
+/// ```rust
+/// # use leptos::*;
+/// # use leptos_router::*;
+/// # if false {
+/// view! {
+///   <BackButton fallback="/posts">"Back to posts"</BackButton>
+/// }
+/// # ;}
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "info", skip_all,)
+)]
+#[component]
+pub fn BackButton(
+    /// Where to go if there is no previous page in the app. Defaults to `/`.
+    #[prop(optional, into)]
+    fallback: Option<Oco<'static, str>>,
+    /// Sets the `class` attribute on the underlying `<button>` tag, making it easier to style.
+    #[prop(optional, into)]
+    class: Option<AttributeValue>,
+    /// The nodes or elements to be shown inside the button.
+    children: Children,
+) -> impl IntoView {
+    let router = use_router();
+    let navigate = use_navigate();
+    let fallback = fallback.unwrap_or(Oco::Borrowed("/"));
+
+    let on_click = move |_| {
+        let has_previous = router
+            .inner
+            .path_stack
+            .try_with_value(|stack| stack.len() > 1)
+            .unwrap_or(false);
+        if has_previous {
+            if let Ok(history) = window().history() {
+                _ = history.back();
+            }
+        } else {
+            navigate(
+                &fallback,
+                NavigateOptions {
+                    replace: true,
+                    ..Default::default()
+                },
+            );
+        }
+    };
+
+    view! {
+        <button type="button" class=class on:click=on_click>
+            {children()}
+        </button>
+    }
+}
@@ -3,9 +3,11 @@ use crate::{
     ToHref, Url,
 };
 use leptos::{html::form, logging::*, *};
+#[cfg(feature = "server-fns")]
 use serde::{de::DeserializeOwned, Serialize};
 use std::{error::Error, rc::Rc};
 use wasm_bindgen::{JsCast, UnwrapThrowExt};
+#[cfg(feature = "server-fns")]
 use wasm_bindgen_futures::JsFuture;
 use web_sys::RequestRedirect;
 
@@ -406,6 +408,7 @@ fn current_window_origin() -> String {
 ///     Ok(())
 /// }
 /// ```
+#[cfg(feature = "server-fns")]
 #[cfg_attr(
     any(debug_assertions, feature = "ssr"),
     tracing::instrument(level = "trace", skip_all,)
@@ -596,6 +599,7 @@ where
 /// Automatically turns a server [MultiAction](leptos_server::MultiAction) into an HTML
 /// [`form`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/form)
 /// progressively enhanced to use client-side routing.
+#[cfg(feature = "server-fns")]
 #[cfg_attr(
     any(debug_assertions, feature = "ssr"),
     tracing::instrument(level = "trace", skip_all,)
@@ -1,4 +1,4 @@
-use crate::{use_location, use_resolved_path, State};
+use crate::{use_locale, use_location, use_resolved_path, RoutePath, State};
 use leptos::{leptos_dom::IntoView, *};
 use std::borrow::Cow;
 
@@ -38,6 +38,13 @@ impl ToHref for Oco<'_, str> {
     }
 }
 
+impl ToHref for RoutePath {
+    fn to_href(&self) -> Box<dyn Fn() -> String + '_> {
+        let s = self.to_string();
+        Box::new(move || s.clone())
+    }
+}
+
 impl<F> ToHref for F
 where
     F: Fn() -> String + 'static,
@@ -90,6 +97,10 @@ pub fn A<H>(
     /// will skip this page.)
     #[prop(optional)]
     replace: bool,
+    /// If `true`, the linked page is displayed as a modal over the current page, which stays
+    /// displayed behind it. See [`use_navigate_modal`](crate::use_navigate_modal).
+    #[prop(optional)]
+    modal: bool,
     /// Sets the `class` attribute on the underlying `<a>` tag, making it easier to style.
     #[prop(optional, into)]
     class: Option<AttributeValue>,
@@ -116,6 +127,7 @@ where
         exact: bool,
         #[allow(unused)] state: Option<State>,
         #[allow(unused)] replace: bool,
+        #[allow(unused)] modal: bool,
         class: Option<AttributeValue>,
         #[allow(unused)] active_class: Option<Oco<'static, str>>,
         id: Option<Oco<'static, str>>,
@@ -126,6 +138,7 @@ where
         {
             _ = state;
             _ = replace;
+            _ = modal;
         }
 
         let location = use_location();
@@ -211,12 +224,18 @@ where
         // DRY here to avoid WASM binary size bloat
         #[cfg(not(feature = "ssr"))]
         {
+            // a modal opened from a modal keeps the same background
+            let background = crate::use_router().background();
+            let pathname = use_location().pathname;
             let mut a = view! {
                 <a
                     href=move || href.get().unwrap_or_default()
                     target=target
                     prop:state=state.map(|s| s.to_js_value())
                     prop:replace=replace
+                    prop:background=move || modal.then(|| {
+                        background.get().unwrap_or_else(|| pathname.get())
+                    })
                     aria-current=move || if is_active.get() { Some("page") } else { None }
                     class=class
                     id=id
@@ -235,17 +254,25 @@ where
                 a = a.attr(attr_name, attr_value);
             }
 
-            a.into_view()
+            crate::prefetch::prefetch_link(a, href).into_view()
         }
     }
 
-    let href = use_resolved_path(move || href.to_href()());
+    let locale = use_locale();
+    let href = use_resolved_path(move || {
+        let href = href.to_href()();
+        match locale {
+            Some(locale) => locale.localize_href(href),
+            None => href,
+        }
+    });
     inner(
         href,
         target,
         exact,
         state,
         replace,
+        modal,
         class,
         active_class,
         id,
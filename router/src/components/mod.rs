@@ -1,3 +1,4 @@
+mod back_button;
 mod form;
 mod link;
 mod outlet;
@@ -8,6 +9,7 @@ mod router;
 mod routes;
 mod static_render;
 
+pub use back_button::*;
 pub use form::*;
 pub use link::*;
 pub use outlet::*;
@@ -4,9 +4,61 @@ use crate::{
     SetIsRouting,
 };
 use leptos::{leptos_dom::HydrationCtx, *};
-use std::{cell::Cell, rc::Rc};
+use std::{any::Any, cell::Cell, rc::Rc};
 use web_sys::AnimationEvent;
 
+/// A value that a parent route passes to the child route displayed in its outlet, using the
+/// `context` prop of [`<Outlet/>`](Outlet) or [`<AnimatedOutlet/>`](AnimatedOutlet). The child
+/// route reads it with [`use_outlet_context`](crate::use_outlet_context).
+///
+/// ```rust
+/// # use leptos::*;
+/// # use leptos_router::*;
+/// #[derive(Clone)]
+/// struct Org {
+///     name: String,
+/// }
+///
+/// #[component]
+/// fn OrgLayout() -> impl IntoView {
+///     let org = Org {
+///         name: "leptos-rs".into(),
+///     };
+///     view! { <Outlet context=OutletContext::new(org)/> }
+/// }
+///
+/// #[component]
+/// fn OrgRepos() -> impl IntoView {
+///     let org =
+///         use_outlet_context::<Org>().expect("rendered in <OrgLayout/>");
+///     view! { <h1>{org.name}</h1> }
+/// }
+/// ```
+#[derive(Clone)]
+pub struct OutletContext(Rc<dyn Any>);
+
+impl OutletContext {
+    /// Wraps a value to pass to the child route.
+    pub fn new<T: Clone + 'static>(value: T) -> Self {
+        Self(Rc::new(value))
+    }
+
+    pub(crate) fn get<T: Clone + 'static>(&self) -> Option<T> {
+        self.0.downcast_ref::<T>().cloned()
+    }
+}
+
+impl core::fmt::Debug for OutletContext {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("OutletContext").finish_non_exhaustive()
+    }
+}
+
+/// The [`OutletContext`] of the outlet the current route is displayed in, if any. Always
+/// provided, so a route can't see the context of an outlet further up the tree.
+#[derive(Clone, Debug)]
+pub(crate) struct ParentOutletContext(pub Option<OutletContext>);
+
 /// Displays the child route nested in a parent route, allowing you to control exactly where
 /// that child route is displayed. Renders nothing if there is no nested child.
 #[cfg_attr(
@@ -14,7 +66,12 @@ use web_sys::AnimationEvent;
     tracing::instrument(level = "info", skip_all,)
 )]
 #[component]
-pub fn Outlet() -> impl IntoView {
+pub fn Outlet(
+    /// A value to pass to the child route, which can read it with
+    /// [`use_outlet_context`](crate::use_outlet_context).
+    #[prop(optional)]
+    context: Option<OutletContext>,
+) -> impl IntoView {
     _ = HydrationCtx::next_outlet();
     let id = HydrationCtx::id();
     let route = use_route();
@@ -30,8 +87,9 @@ pub fn Outlet() -> impl IntoView {
 
     let is_showing = Rc::new(Cell::new(None::<usize>));
     let (outlet, set_outlet) = create_signal(None::<View>);
-    let build_outlet = as_child_of_current_owner(|child: RouteContext| {
+    let build_outlet = as_child_of_current_owner(move |child: RouteContext| {
         provide_context(child.clone());
+        provide_context(ParentOutletContext(context.clone()));
         child.outlet().into_view()
     });
     create_isomorphic_effect(move |prev_disposer| {
@@ -115,6 +173,10 @@ pub fn Outlet() -> impl IntoView {
 ///
 /// Each of these properties is optional, and the router will transition to the next correct state
 /// whenever an `animationend` event fires.
+///
+/// Like [`<AnimatedRoutes/>`](crate::AnimatedRoutes), it only animates when the child route
+/// changes. Navigating to a URL that matches the same child route, with different params or a
+/// different wildcard, updates its params without an animation.
 #[component]
 pub fn AnimatedOutlet(
     /// Base classes to be applied to the `<div>` wrapping the outlet during any animation state.
@@ -138,13 +200,18 @@ pub fn AnimatedOutlet(
     /// CSS class added after other animations have completed.
     #[prop(optional)]
     finally: Option<&'static str>,
+    /// A value to pass to the child route, which can read it with
+    /// [`use_outlet_context`](crate::use_outlet_context).
+    #[prop(optional)]
+    context: Option<OutletContext>,
 ) -> impl IntoView {
     let pathname = use_location().pathname;
     let route = use_route();
     let is_showing = Rc::new(Cell::new(None::<usize>));
     let (outlet, set_outlet) = create_signal(None::<View>);
-    let build_outlet = as_child_of_current_owner(|child: RouteContext| {
+    let build_outlet = as_child_of_current_owner(move |child: RouteContext| {
         provide_context(child.clone());
+        provide_context(ParentOutletContext(context.clone()));
         child.outlet().into_view()
     });
 
@@ -196,9 +263,8 @@ pub fn AnimatedOutlet(
             (Some(child), Some(is_showing_val))
                 if child.id() == *is_showing_val =>
             {
-                trigger_animation.set(());
-
-                // do nothing: we don't need to rerender the component, because it's the same
+                // do nothing: only the params of the route have changed, so we don't need to
+                // animate or rerender the component, because it's the same
                 // returning the disposer keeps it alive until the next iteration
                 prev_disposer.flatten()
             }
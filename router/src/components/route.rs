@@ -41,6 +41,10 @@ pub enum Method {
 
 /// Describes a portion of the nested layout of the app, specifying the route it should match,
 /// the element it should display, and data that should be loaded alongside the route.
+///
+/// Navigating between two URLs that match the same route, like `/docs/intro` and
+/// `/docs/guide/routing` for `docs/*any`, does not create its view again: only its params
+/// change, so its state is kept and [`use_params`](crate::use_params) updates reactively.
 #[cfg_attr(
     any(debug_assertions, feature = "ssr"),
     tracing::instrument(level = "info", skip_all,)
@@ -65,6 +69,11 @@ pub fn Route<E, F, P>(
     /// accessed with [`use_route_data`](crate::use_route_data).
     #[prop(optional, into)]
     data: Option<Loader>,
+    /// The path fragment of this route in other locales, as `(locale, path)` pairs, when the
+    /// [`<Router/>`](crate::Router) has [`Locales`](crate::Locales). The locales that aren't
+    /// listed use `path`.
+    #[prop(optional)]
+    translations: &'static [(&'static str, &'static str)],
     /// `children` may be empty or include nested routes.
     #[prop(optional)]
     children: Option<Children>,
@@ -74,16 +83,19 @@ where
     F: Fn() -> E + 'static,
     P: core::fmt::Display,
 {
-    define_route(
-        children,
-        path.to_string(),
-        Rc::new(move || view().into_view()),
-        ssr,
-        methods,
-        data,
-        None,
-        None,
-    )
+    RouteDefinition {
+        translations,
+        ..define_route(
+            children,
+            path.to_string(),
+            Rc::new(move || view().into_view()),
+            ssr,
+            methods,
+            data,
+            None,
+            None,
+        )
+    }
 }
 
 /// Describes a route that is guarded by a certain condition. This works the same way as
@@ -242,6 +254,7 @@ pub(crate) fn define_route(
         data,
         static_mode,
         static_params,
+        translations: &[],
     }
 }
 
@@ -264,6 +277,7 @@ impl RouteContext {
     )]
     pub(crate) fn new(
         router: &RouterContext,
+        parent: Option<RouteContext>,
         child: impl Fn() -> Option<RouteContext> + 'static,
         matcher: impl Fn() -> Option<RouteMatch> + 'static,
     ) -> Option<Self> {
@@ -277,28 +291,42 @@ impl RouteContext {
             data,
             ..
         } = route.key;
-        let params = create_memo(move |_| {
+        let matcher = Rc::new(matcher);
+        let params = create_memo({
+            let matcher = Rc::clone(&matcher);
+            move |_| {
+                matcher()
+                    .map(|matched| matched.path_match.params)
+                    .unwrap_or_default()
+            }
+        });
+        let raw_params = create_memo(move |_| {
             matcher()
-                .map(|matched| matched.path_match.params)
+                .map(|matched| matched.path_match.raw_params)
                 .unwrap_or_default()
         });
 
+        let matched_path = path.clone();
         let inner = Rc::new(RouteContextInner {
             id,
             base_path: base,
+            parent,
             child: Box::new(child),
             path: create_rw_signal(path),
             original_path: route.original_path.to_string(),
             params,
+            raw_params,
             outlet: Box::new(move || Some(element())),
             data: RefCell::new(None),
         });
         if let Some(loader) = data {
-            let data = {
-                let inner = Rc::clone(&inner);
-                provide_context(RouteContext { inner });
-                (loader.data)()
-            };
+            let data = router
+                .take_prefetched(id, &matched_path, params)
+                .unwrap_or_else(|| {
+                    let inner = Rc::clone(&inner);
+                    provide_context(RouteContext { inner });
+                    (loader.data)()
+                });
             *inner.data.borrow_mut() = Some(data);
         }
 
@@ -342,19 +370,40 @@ impl RouteContext {
     }
 
     /// A reactive wrapper for the route parameters that are currently matched.
+    ///
+    /// These include the params matched by the route's ancestors. If the route declares a
+    /// param with the same name as one of its ancestors, its own value is used; the
+    /// ancestor's can be read from the [`parent`](Self::parent)'s params.
     pub fn params(&self) -> Memo<ParamsMap> {
         self.inner.params
     }
 
+    /// A reactive wrapper for the route parameters that are currently matched, as they
+    /// appear in the URL.
+    ///
+    /// These differ from the [`params`](Self::params) only if the
+    /// [`<Router/>`](crate::Router) decodes params, in which case they are still
+    /// percent-encoded.
+    pub fn raw_params(&self) -> Memo<ParamsMap> {
+        self.inner.raw_params
+    }
+
+    /// The route this route is nested in, if any.
+    pub fn parent(&self) -> Option<RouteContext> {
+        self.inner.parent.clone()
+    }
+
     pub(crate) fn base(path: &str, fallback: Option<fn() -> View>) -> Self {
         Self {
             inner: Rc::new(RouteContextInner {
                 id: 0,
                 base_path: path.to_string(),
+                parent: None,
                 child: Box::new(|| None),
                 path: create_rw_signal(path.to_string()),
                 original_path: path.to_string(),
                 params: create_memo(|_| ParamsMap::new()),
+                raw_params: create_memo(|_| ParamsMap::new()),
                 outlet: Box::new(move || fallback.as_ref().map(move |f| f())),
                 data: Default::default(),
             }),
@@ -389,11 +438,13 @@ impl RouteContext {
 
 pub(crate) struct RouteContextInner {
     base_path: String,
+    pub(crate) parent: Option<RouteContext>,
     pub(crate) id: usize,
     pub(crate) child: Box<dyn Fn() -> Option<RouteContext>>,
     pub(crate) path: RwSignal<String>,
     pub(crate) original_path: String,
     pub(crate) params: Memo<ParamsMap>,
+    pub(crate) raw_params: Memo<ParamsMap>,
     pub(crate) outlet: Box<dyn Fn() -> Option<View>>,
     pub(crate) data: RefCell<Option<Rc<dyn Any>>>,
 }
@@ -1,6 +1,9 @@
 use crate::{
-    create_location, matching::resolve_path, scroll_to_el, Branch, History,
-    Location, LocationChange, RouteContext, RouterIntegrationContext, State,
+    create_location,
+    matching::{resolve_path, MatchOptions},
+    prefetch::Prefetcher,
+    scroll_to_el, Branch, History, Locale, Locales, Location, LocationChange,
+    PrefetchPolicy, RouteContext, RouterIntegrationContext, State,
 };
 #[cfg(not(feature = "ssr"))]
 use crate::{unescape, Url};
@@ -8,7 +11,7 @@ use cfg_if::cfg_if;
 use leptos::*;
 #[cfg(feature = "transition")]
 use leptos_reactive::use_transition;
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 use thiserror::Error;
 #[cfg(not(feature = "ssr"))]
 use wasm_bindgen::JsCast;
@@ -26,13 +29,58 @@ pub fn Router(
     /// A signal that will be set while the navigation process is underway.
     #[prop(optional, into)]
     set_is_routing: Option<SignalSetter<bool>>,
+    /// If set, links start loading the data for the routes they point to before they are
+    /// clicked, as described by the [`PrefetchPolicy`].
+    #[prop(optional)]
+    prefetch: Option<PrefetchPolicy>,
+    /// If set, the routes are served under a path prefix for each of these locales, as
+    /// described by [`Locales`].
+    #[prop(optional)]
+    locales: Option<Locales>,
+    /// If `true`, the static segments of route paths match the URL regardless of their
+    /// case, so that `/About` matches `<Route path="/about"/>`. Params keep the case they
+    /// have in the URL.
+    ///
+    /// This only applies to matching in the app. The route lists that the server
+    /// integrations generate, such as `generate_route_list`, keep the paths as they are
+    /// written, so on the server a URL in a different case is not handled by its route but
+    /// by the fallback, without the route's [`SsrMode`](crate::SsrMode) or methods.
+    #[prop(optional)]
+    case_insensitive: bool,
+    /// If `true`, params are percent-decoded, so that `/caf%C3%A9` gives `"café"` for
+    /// `/:name`, and a URL whose segments are not valid UTF-8 once decoded matches no
+    /// route. The params as they appear in the URL can still be read with
+    /// [`use_raw_params_map`](crate::use_raw_params_map).
+    #[prop(optional)]
+    decode_params: bool,
     /// The `<Router/>` should usually wrap your whole page. It can contain
     /// any elements, and should include a [`Routes`](crate::Routes) component somewhere
     /// to define and display [`Route`](crate::Route)s.
     children: Children,
 ) -> impl IntoView {
     // create a new RouterContext and provide it to every component beneath the router
-    let router = RouterContext::new(base, fallback);
+    let match_options = MatchOptions {
+        case_insensitive,
+        decode_params,
+    };
+    let router = RouterContext::new(base, fallback, prefetch, match_options);
+
+    // logs the reactive nodes that each navigation leaves behind, once turned on with
+    // `stats::set_runtime_stats_logging`
+    #[cfg(debug_assertions)]
+    {
+        let pathname = router.pathname();
+        create_effect(move |_| {
+            let path = pathname.get();
+            queue_after_effects(move || {
+                stats::log_runtime_stats_changes(&path)
+            });
+        });
+    }
+
+    if let Some(locales) = locales {
+        provide_context(Locale::new(locales, router.pathname(), match_options));
+    }
     provide_context(router);
     provide_context(GlobalSuspenseContext::new());
     if let Some(set_is_routing) = set_is_routing {
@@ -64,6 +112,11 @@ pub(crate) struct RouterContextInner {
     set_state: WriteSignal<State>,
     pub(crate) is_back: RwSignal<bool>,
     pub(crate) path_stack: StoredValue<Vec<String>>,
+    pub(crate) prefetcher: Option<Prefetcher>,
+    pub(crate) match_options: MatchOptions,
+    /// The background page of each path that was navigated to as a modal.
+    backgrounds: StoredValue<HashMap<String, String>>,
+    background: Memo<Option<String>>,
 }
 
 impl core::fmt::Debug for RouterContextInner {
@@ -89,6 +142,8 @@ impl RouterContext {
     pub(crate) fn new(
         base: Option<&'static str>,
         fallback: Option<fn() -> View>,
+        prefetch: Option<PrefetchPolicy>,
+        match_options: MatchOptions,
     ) -> Self {
         cfg_if! {
             if #[cfg(any(feature = "csr", feature = "hydrate"))] {
@@ -164,6 +219,14 @@ impl RouterContext {
             });
         });
 
+        let backgrounds = store_value(HashMap::<String, String>::new());
+        let background = create_memo(move |_| {
+            location.pathname.with(|path| {
+                backgrounds
+                    .with_value(|backgrounds| backgrounds.get(path).cloned())
+            })
+        });
+
         let inner = Rc::new(RouterContextInner {
             base_path: base_path.into_owned(),
             path_stack: store_value(vec![location.pathname.get_untracked()]),
@@ -178,6 +241,10 @@ impl RouterContext {
             set_state,
             possible_routes: Default::default(),
             is_back: create_rw_signal(false),
+            prefetcher: prefetch.map(Prefetcher::new),
+            match_options,
+            backgrounds,
+            background,
         });
 
         // handle all click events on anchor tags
@@ -198,6 +265,13 @@ impl RouterContext {
         self.inner.location.pathname
     }
 
+    /// The path of the page displayed behind the current one, if the current page was
+    /// navigated to as a modal, with [`use_navigate_modal`](crate::use_navigate_modal) or
+    /// `<A modal=true>`.
+    pub fn background(&self) -> Memo<Option<String>> {
+        self.inner.background
+    }
+
     /// The [`RouteContext`] of the base route.
     pub fn base(&self) -> RouteContext {
         self.inner.base.clone()
@@ -222,6 +296,7 @@ impl RouterContextInner {
         self: Rc<Self>,
         to: &str,
         options: &NavigateOptions,
+        background: Option<String>,
     ) -> Result<(), NavigationError> {
         let this = Rc::clone(&self);
 
@@ -264,6 +339,20 @@ impl RouterContextInner {
 
                         let resolved = resolved_to.to_string();
                         let state = options.state.clone();
+                        let path = path_only(&resolved).to_string();
+                        self.backgrounds.update_value(|backgrounds| {
+                            match background {
+                                Some(background) => {
+                                    backgrounds.insert(
+                                        path,
+                                        path_only(&background).to_string(),
+                                    );
+                                }
+                                None => {
+                                    backgrounds.remove(&path);
+                                }
+                            }
+                        });
                         set_reference.update(move |r| *r = resolved);
 
                         set_state.update({
@@ -312,6 +401,12 @@ impl RouterContextInner {
         })
     }
 
+    /// The background page that `to` was last navigated to with, if any.
+    pub(crate) fn background_of(&self, to: &str) -> Option<String> {
+        self.backgrounds
+            .with_value(|backgrounds| backgrounds.get(path_only(to)).cloned())
+    }
+
     pub(crate) fn navigate_end(self: Rc<Self>, mut next: LocationChange) {
         let first = self.referrers.borrow().first().cloned();
         if let Some(first) = first {
@@ -408,6 +503,12 @@ impl RouterContextInner {
                     .ok()
                     .and_then(|value| value.as_bool())
                     .unwrap_or(false);
+            let background = leptos_dom::helpers::get_property(
+                a.unchecked_ref(),
+                "background",
+            )
+            .ok()
+            .and_then(|value| value.as_string());
             if let Err(e) = self.navigate_from_route(
                 &to,
                 &NavigateOptions {
@@ -416,6 +517,7 @@ impl RouterContextInner {
                     scroll: !a.has_attribute("noscroll"),
                     state: State(state),
                 },
+                background,
             ) {
                 leptos::logging::error!("{e:#?}");
             }
@@ -423,6 +525,10 @@ impl RouterContextInner {
     }
 }
 
+fn path_only(path: &str) -> &str {
+    path.split(['?', '#']).next().unwrap_or_default()
+}
+
 /// An error that occurs during navigation.
 #[derive(Debug, Error)]
 pub enum NavigationError {
@@ -460,3 +566,58 @@ impl Default for NavigateOptions {
         }
     }
 }
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+    use crate::{use_query_param, use_router, ServerIntegration};
+    use std::cell::Cell;
+
+    #[test]
+    fn query_param_subscribers_ignore_changes_to_other_keys() {
+        let runtime = create_runtime();
+        provide_context(RouterIntegrationContext::new(ServerIntegration {
+            path: "http://leptos.rs/?page=2&utm_source=mail".to_string(),
+        }));
+        let runs = Rc::new(Cell::new(0));
+        let subscriber = Rc::new(RefCell::new(None));
+        let subscribe = {
+            let runs = Rc::clone(&runs);
+            let subscriber = Rc::clone(&subscriber);
+            move || {
+                let page = use_query_param("page");
+                let runs = Rc::clone(&runs);
+                let page = create_memo(move |_| {
+                    runs.set(runs.get() + 1);
+                    page.get()
+                });
+                *subscriber.borrow_mut() = Some((use_router(), page));
+            }
+        };
+        _ = view! { <Router>{subscribe}</Router> }.into_view();
+        let (router, page) = subscriber.borrow_mut().take().unwrap();
+        assert_eq!(page.get_untracked().as_deref(), Some("2"));
+        assert_eq!(runs.get(), 1);
+
+        router
+            .inner
+            .set_reference
+            .set("/?page=2&utm_source=feed".into());
+        let query = router.inner.location.query;
+        assert_eq!(
+            query.with_untracked(|query| query.get("utm_source").cloned()),
+            Some("feed".to_string())
+        );
+        assert_eq!(page.get_untracked().as_deref(), Some("2"));
+        assert_eq!(runs.get(), 1);
+
+        router
+            .inner
+            .set_reference
+            .set("/?page=3&utm_source=feed".into());
+        assert_eq!(page.get_untracked().as_deref(), Some("3"));
+        assert_eq!(runs.get(), 2);
+
+        runtime.dispose();
+    }
+}
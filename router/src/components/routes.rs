@@ -4,7 +4,8 @@ use crate::{
         expand_optionals, get_route_matches, join_paths, Branch, Matcher,
         RouteDefinition, RouteMatch,
     },
-    use_is_back_navigation, RouteContext, RouterContext, SetIsRouting,
+    use_is_back_navigation, use_locale, Locales, RouteContext, RouterContext,
+    SetIsRouting,
 };
 use leptos::{leptos_dom::HydrationCtx, *};
 use std::{
@@ -63,6 +64,13 @@ use std::{
 /// }
 /// # ;}
 /// ```
+///
+/// ## Modal routes
+/// When a page is navigated to as a modal, with `<A href="/photos/1" modal=true>` or
+/// [`use_navigate_modal`](crate::use_navigate_modal), `<Routes/>` keeps displaying the
+/// background page and displays the routes matching the new page after it, so the new page
+/// can be styled as a modal over the background. Going back closes the modal and leaves the background page
+/// as it was. Loading the modal's URL directly displays it as a normal page.
 #[cfg_attr(
     any(debug_assertions, feature = "ssr"),
     tracing::instrument(level = "info", skip_all,)
@@ -80,7 +88,14 @@ pub fn Routes(
     let base_route = router.base();
     let base = base.unwrap_or_default();
 
-    Branches::initialize(&base, children());
+    let locale = use_locale();
+    Branches::initialize(&base, children(), locale.map(|l| l.locales()));
+    if let Some(prefetcher) = &router.inner.prefetcher {
+        prefetcher.set_routes_base(&base);
+    }
+    if let Some(locale) = locale {
+        locale.set_routes_base(&base);
+    }
 
     #[cfg(feature = "ssr")]
     if let Some(context) = use_context::<crate::PossibleBranchContext>() {
@@ -89,11 +104,16 @@ pub fn Routes(
         });
     }
 
+    // while a modal is open, the page behind it keeps being displayed
     let next_route = router.pathname();
-    let current_route = next_route;
+    let background = router.background();
+    let current_route = create_memo(move |_| {
+        background.get().unwrap_or_else(|| next_route.get())
+    });
 
     let root_equal = Rc::new(Cell::new(true));
-    let route_states = route_states(base, &router, current_route, &root_equal);
+    let route_states =
+        route_states(base.clone(), &router, current_route, &root_equal);
     provide_context(route_states);
 
     let id = HydrationCtx::id();
@@ -104,8 +124,47 @@ pub fn Routes(
     let (root, dis) = root_route((base_route, root_equal));
     on_cleanup(move || drop(dis));
 
-    leptos::leptos_dom::DynChild::new_with_id(id, move || root.get())
-        .into_view()
+    let modal = modal_view(base, &router);
+
+    leptos::leptos_dom::DynChild::new_with_id(id, move || {
+        with_modal(root.get(), modal.get())
+    })
+    .into_view()
+}
+
+/// The routes matching the current page, while it is displayed as a modal over the background
+/// page that `<Routes/>` or `<AnimatedRoutes/>` displays instead of them.
+fn modal_view(base: String, router: &RouterContext) -> Memo<Option<View>> {
+    let pathname = router.pathname();
+    let background = router.background();
+    let is_modal = create_memo(move |_| background.with(Option::is_some));
+    let router = router.clone();
+
+    // the modal's routes are owned by the memo, so closing it disposes of them
+    create_memo(move |_| {
+        is_modal.get().then(|| {
+            untrack(|| {
+                let root_equal = Rc::new(Cell::new(true));
+                let route_states =
+                    route_states(base.clone(), &router, pathname, &root_equal);
+                // shadows the route states of the background page for the modal's outlets
+                provide_context(route_states);
+
+                let root = root_route(router.base(), route_states, root_equal);
+                leptos::leptos_dom::DynChild::new(move || root.get())
+                    .into_view()
+            })
+        })
+    })
+}
+
+/// Displays the modal after the background page, if there is one, so that pages displayed
+/// without one render exactly as they would if modals didn't exist.
+fn with_modal(page: Option<View>, modal: Option<View>) -> View {
+    match modal {
+        None => page.into_view(),
+        Some(modal) => Fragment::new(vec![page.into_view(), modal]).into_view(),
+    }
 }
 
 /// Contains route definitions and manages the actual routing process, with animated transitions
@@ -125,6 +184,9 @@ pub fn Routes(
 /// Each of these properties is optional, and the router will transition to the next correct state
 /// whenever an `animationend` event fires.
 ///
+/// Modal routes are displayed after the background page, as with [`<Routes/>`](Routes), and
+/// are not animated.
+///
 /// **Note:** Your application should only include one `<AnimatedRoutes/>` or `<Routes/>` component.
 #[component]
 pub fn AnimatedRoutes(
@@ -160,7 +222,14 @@ pub fn AnimatedRoutes(
     let base_route = router.base();
     let base = base.unwrap_or_default();
 
-    Branches::initialize(&base, children());
+    let locale = use_locale();
+    Branches::initialize(&base, children(), locale.map(|l| l.locales()));
+    if let Some(prefetcher) = &router.inner.prefetcher {
+        prefetcher.set_routes_base(&base);
+    }
+    if let Some(locale) = locale {
+        locale.set_routes_base(&base);
+    }
 
     #[cfg(feature = "ssr")]
     if let Some(context) = use_context::<crate::PossibleBranchContext>() {
@@ -180,7 +249,13 @@ pub fn AnimatedRoutes(
     let is_back = use_is_back_navigation();
     let (animation_state, set_animation_state) =
         create_signal(AnimationState::Finally);
-    let next_route = router.pathname();
+    // while a modal is open, the page behind it keeps being displayed
+    let pathname = router.pathname();
+    let background = router.background();
+    let next_route = create_memo(move |_| {
+        background.get().unwrap_or_else(|| pathname.get())
+    });
+    let options = router.inner.match_options;
 
     let is_complete = Rc::new(Cell::new(true));
     let animation_and_route = create_memo({
@@ -193,8 +268,8 @@ pub fn AnimatedRoutes(
             let prev_matches = prev
                 .map(|(_, r)| r)
                 .cloned()
-                .map(|location| get_route_matches(&base, location));
-            let matches = get_route_matches(&base, next_route.clone());
+                .map(|location| get_route_matches(&base, location, options));
+            let matches = get_route_matches(&base, next_route.clone(), options);
             let same_route = prev_matches
                 .and_then(|p| p.first().as_ref().map(|r| r.route.key.clone()))
                 == matches.first().as_ref().map(|r| r.route.key.clone());
@@ -221,9 +296,11 @@ pub fn AnimatedRoutes(
     let current_route = create_memo(move |_| animation_and_route.get().1);
 
     let root_equal = Rc::new(Cell::new(true));
-    let route_states = route_states(base, &router, current_route, &root_equal);
+    let route_states =
+        route_states(base.clone(), &router, current_route, &root_equal);
 
     let root = root_route(base_route, route_states, root_equal);
+    let modal = modal_view(base, &router);
     let node_ref = create_node_ref::<html::Div>();
 
     html::div()
@@ -260,7 +337,7 @@ pub fn AnimatedRoutes(
                 }
             }
         })
-        .child(move || root.get())
+        .child(move || with_modal(root.get(), modal.get()))
         .into_view()
 }
 
@@ -271,7 +348,11 @@ thread_local! {
 }
 
 impl Branches {
-    pub fn initialize(base: &str, children: Fragment) {
+    pub fn initialize(
+        base: &str,
+        children: Fragment,
+        locales: Option<Locales>,
+    ) {
         BRANCHES.with(|branches| {
             #[cfg(debug_assertions)]
             {
@@ -308,14 +389,42 @@ impl Branches {
                     })
                     .cloned()
                     .collect::<Vec<_>>();
-                create_branches(
-                    &children,
-                    base,
-                    &mut Vec::new(),
-                    &mut branches,
-                    true,
-                    base,
-                );
+                match locales {
+                    None => create_branches(
+                        &children,
+                        base,
+                        &mut Vec::new(),
+                        &mut branches,
+                        true,
+                        base,
+                        None,
+                    ),
+                    Some(locales) => {
+                        // every route is available under each locale's prefix, and the root
+                        // redirects to the locale of the user
+                        create_branches(
+                            &[crate::locale::root_redirect(base)],
+                            base,
+                            &mut Vec::new(),
+                            &mut branches,
+                            true,
+                            base,
+                            None,
+                        );
+                        for locale in locales.supported {
+                            let base = join_paths(base, locale);
+                            create_branches(
+                                &children,
+                                &base,
+                                &mut Vec::new(),
+                                &mut branches,
+                                true,
+                                &base,
+                                Some(locale),
+                            );
+                        }
+                    }
+                }
                 current.insert(base.to_string(), branches);
             }
         })
@@ -340,8 +449,10 @@ fn route_states(
     root_equal: &Rc<Cell<bool>>,
 ) -> Memo<RouterState> {
     // whenever path changes, update matches
-    let matches =
-        create_memo(move |_| get_route_matches(&base, current_route.get()));
+    let options = router.inner.match_options;
+    let matches = create_memo(move |_| {
+        get_route_matches(&base, current_route.get(), options)
+    });
 
     // iterate over the new matches, reusing old routes when they are the same
     // and replacing them with new routes when they differ
@@ -397,8 +508,12 @@ fn route_states(
                             let next = Rc::clone(&next);
                             let router = Rc::clone(&router);
                             move || {
+                                let parent = i.checked_sub(1).and_then(|i| {
+                                    next.borrow().get(i).cloned()
+                                });
                                 RouteContext::new(
                                     &RouterContext { inner: router },
+                                    parent,
                                     move || {
                                         if let Some(route_states) =
                                             use_context::<Memo<RouterState>>()
@@ -567,6 +682,7 @@ impl RouteData {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn create_branches(
     route_defs: &[RouteDefinition],
     base: &str,
@@ -574,6 +690,7 @@ fn create_branches(
     branches: &mut Vec<Branch>,
     static_valid: bool,
     parents_path: &str,
+    locale: Option<&str>,
 ) {
     for def in route_defs {
         let routes = create_routes(
@@ -581,6 +698,7 @@ fn create_branches(
             base,
             static_valid && def.static_mode.is_some(),
             parents_path,
+            locale,
         );
         for route in routes {
             stack.push(route.clone());
@@ -595,7 +713,8 @@ fn create_branches(
                     stack,
                     branches,
                     static_valid && route.key.static_mode.is_some(),
-                    &format!("{}{}", parents_path, def.path),
+                    &format!("{}{}", parents_path, def.path_in(locale)),
+                    locale,
                 );
             }
 
@@ -624,6 +743,7 @@ fn create_routes(
     base: &str,
     static_valid: bool,
     parents_path: &str,
+    locale: Option<&str>,
 ) -> Vec<RouteData> {
     let RouteDefinition { children, .. } = route_def;
     let is_leaf = children.is_empty();
@@ -635,7 +755,7 @@ fn create_routes(
         );
     }
     let mut acc = Vec::new();
-    for original_path in expand_optionals(&route_def.path) {
+    for original_path in expand_optionals(route_def.path_in(locale)) {
         let path = join_paths(base, &original_path);
         let pattern = if is_leaf {
             path
@@ -655,3 +775,117 @@ fn create_routes(
     }
     acc
 }
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use crate::*;
+    use leptos::*;
+
+    /// Navigates to `to` while rendering, before the routes are set up.
+    #[component]
+    fn Navigate(to: &'static str, modal: bool) -> impl IntoView {
+        let router = use_router();
+        let background = modal.then(|| router.pathname().get_untracked());
+        router
+            .inner
+            .navigate_from_route(
+                to,
+                &NavigateOptions {
+                    resolve: false,
+                    ..Default::default()
+                },
+                background,
+            )
+            .unwrap();
+    }
+
+    fn render(path: &str, view: impl FnOnce() -> View + 'static) -> String {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        tokio::task::LocalSet::new().block_on(&rt, async move {
+            let runtime = create_runtime();
+            provide_context(RouterIntegrationContext::new(ServerIntegration {
+                path: format!("http://leptos.rs{path}"),
+            }));
+            let html = view().render_to_string().to_string();
+            runtime.dispose();
+            html
+        })
+    }
+
+    #[test]
+    fn modal_routes_are_displayed_after_their_background() {
+        let html = render("/", || {
+            view! {
+                <Router>
+                    <Navigate to="/photos/1" modal=true/>
+                    <Routes>
+                        <Route path="/" view=|| view! { <main>"Gallery"</main> }/>
+                        <Route path="/photos/:id" view=|| view! { <dialog>"Photo"</dialog> }/>
+                    </Routes>
+                </Router>
+            }
+        });
+        let gallery = html.find("<main").expect("background is displayed");
+        let photo = html.find("<dialog").expect("modal is displayed");
+        assert!(gallery < photo);
+
+        let html = render("/", || {
+            view! {
+                <Router>
+                    <Navigate to="/photos/1" modal=true/>
+                    <AnimatedRoutes>
+                        <Route path="/" view=|| view! { <main>"Gallery"</main> }/>
+                        <Route path="/photos/:id" view=|| view! { <dialog>"Photo"</dialog> }/>
+                    </AnimatedRoutes>
+                </Router>
+            }
+        });
+        let gallery = html.find("<main").expect("background is displayed");
+        let photo = html.find("<dialog").expect("modal is displayed");
+        assert!(gallery < photo);
+    }
+
+    #[test]
+    fn pages_without_a_background_are_displayed_alone() {
+        let direct = render("/photos/1", || {
+            view! {
+                <Router>
+                    <Routes>
+                        <Route path="/" view=|| view! { <main>"Gallery"</main> }/>
+                        <Route path="/photos/:id" view=|| view! { <dialog>"Photo"</dialog> }/>
+                    </Routes>
+                </Router>
+            }
+        });
+        assert!(direct.contains("<dialog"));
+
+        // navigating away from a modal without one closes it
+        let navigated = render("/", || {
+            view! {
+                <Router>
+                    <Navigate to="/photos/2" modal=true/>
+                    <Navigate to="/photos/1" modal=false/>
+                    <Routes>
+                        <Route path="/" view=|| view! { <main>"Gallery"</main> }/>
+                        <Route path="/photos/:id" view=|| view! { <dialog>"Photo"</dialog> }/>
+                    </Routes>
+                </Router>
+            }
+        });
+        for html in [direct, navigated] {
+            // no markers are added for modals that aren't displayed
+            let routes = routes_markup(&html);
+            assert_eq!(routes.matches("leptos-dyn-child-start").count(), 1);
+            assert!(!routes.contains("leptos-<>-start"));
+            assert!(!routes.contains("<main"));
+        }
+    }
+
+    fn routes_markup(html: &str) -> &str {
+        let start = html.find("leptos-routes-start").unwrap();
+        let end = html.find("leptos-routes-end").unwrap();
+        &html[start..end]
+    }
+}
@@ -1,13 +1,13 @@
 #[cfg(feature = "ssr")]
-use crate::{RouteListing, RouterIntegrationContext, ServerIntegration};
+use crate::{
+    Method, RouteListing, RouterIntegrationContext, ServerIntegration,
+};
 #[cfg(feature = "ssr")]
 use leptos::{provide_context, IntoView, LeptosOptions};
 #[cfg(feature = "ssr")]
 use leptos_meta::MetaContext;
 use linear_map::LinearMap;
 use serde::{Deserialize, Serialize};
-#[cfg(feature = "ssr")]
-use std::path::Path;
 use std::{
     collections::HashMap,
     fmt::Display,
@@ -17,6 +17,8 @@ use std::{
     pin::Pin,
     sync::Arc,
 };
+#[cfg(feature = "ssr")]
+use std::{collections::HashSet, path::Path};
 
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct StaticParamsMap(pub LinearMap<String, Vec<String>>);
@@ -124,6 +126,31 @@ impl<'b, 'a: 'b> StaticPath<'b, 'a> {
         paths
     }
 
+    /// Adds the params of this path and all of its parents from the resolved static data.
+    pub(crate) fn add_all_params(
+        &mut self,
+        static_data: &'b HashMap<&str, StaticParamsMap>,
+    ) {
+        for p in self.parents().into_iter().rev() {
+            if let Some(data) = static_data.get(p.path()) {
+                self.add_params(data);
+            }
+        }
+        if let Some(data) = static_data.get(self.path()) {
+            self.add_params(data);
+        }
+    }
+
+    /// Whether a value has been added for every param, so that
+    /// [`into_paths`](Self::into_paths) will not panic.
+    pub(crate) fn has_all_params(&self) -> bool {
+        use StaticPathSegment::*;
+        self.segments.iter().all(|segment| match segment {
+            Static(_) => true,
+            Param(name) | Wildcard(name) => self.params.contains_key(name),
+        })
+    }
+
     pub fn parent(&self) -> Option<StaticPath<'b, 'a>> {
         if self.path == "/" || self.path.is_empty() {
             return None;
@@ -246,13 +273,7 @@ pub async fn build_static_routes_with_additional_context<IV>(
 where
     IV: IntoView + 'static,
 {
-    let mut static_data: HashMap<&str, StaticParamsMap> = HashMap::new();
-    for (key, value) in static_data_map {
-        match value {
-            Some(value) => static_data.insert(key, value.as_ref()().await),
-            None => static_data.insert(key, StaticParamsMap::default()),
-        };
-    }
+    let static_data = resolve_static_data(static_data_map).await;
     let static_routes = routes
         .iter()
         .filter(|route| route.static_mode().is_some())
@@ -260,14 +281,7 @@ where
     // TODO: maybe make this concurrent in some capacity
     for route in static_routes {
         let mut path = StaticPath::new(route.leptos_path());
-        for p in path.parents().into_iter().rev() {
-            if let Some(data) = static_data.get(p.path()) {
-                path.add_params(data);
-            }
-        }
-        if let Some(data) = static_data.get(path.path()) {
-            path.add_params(data);
-        }
+        path.add_all_params(&static_data);
         for path in path.into_paths() {
             println!("building static route: {}", path);
             path.write(options, app_fn.clone(), additional_context.clone())
@@ -277,6 +291,55 @@ where
     Ok(())
 }
 
+/// Loads the static params of every route.
+#[cfg(feature = "ssr")]
+pub(crate) async fn resolve_static_data(
+    static_data_map: &StaticDataMap,
+) -> HashMap<&str, StaticParamsMap> {
+    let mut static_data: HashMap<&str, StaticParamsMap> = HashMap::new();
+    for (key, value) in static_data_map {
+        match value {
+            Some(value) => static_data.insert(key, value.as_ref()().await),
+            None => static_data.insert(key, StaticParamsMap::default()),
+        };
+    }
+    static_data
+}
+
+/// Lists the concrete paths of the routes that handle `GET` requests: routes without params
+/// once, and routes with params once for each set of their static params, leaving out those
+/// that are missing some. Each path is listed once, with `/` for the root.
+#[cfg(feature = "ssr")]
+pub(crate) async fn resolve_get_paths(
+    routes: &[RouteListing],
+    static_data_map: &StaticDataMap,
+) -> Vec<String> {
+    let static_data = resolve_static_data(static_data_map).await;
+    let mut seen = HashSet::new();
+    let mut paths = Vec::new();
+    for route in routes {
+        if !route.methods().any(|method| method == Method::Get) {
+            continue;
+        }
+        let mut path = StaticPath::new(route.leptos_path());
+        path.add_all_params(&static_data);
+        if !path.has_all_params() {
+            continue;
+        }
+        for path in path.into_paths() {
+            let path = if path.0.is_empty() {
+                "/".to_string()
+            } else {
+                path.0
+            };
+            if seen.insert(path.clone()) {
+                paths.push(path);
+            }
+        }
+    }
+    paths
+}
+
 #[doc(hidden)]
 #[cfg(feature = "ssr")]
 pub fn purge_dir_of_static_files(path: PathBuf) -> Result<(), std::io::Error> {
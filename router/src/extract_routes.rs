@@ -166,3 +166,134 @@ where
     runtime.dispose();
     (routes, static_data_map)
 }
+
+/// A server function that this application can serve.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ServerFnListing {
+    path: String,
+    method: Method,
+}
+
+impl ServerFnListing {
+    /// Create a server function listing from its parts.
+    pub fn new(path: impl ToString, method: Method) -> Self {
+        Self {
+            path: path.to_string(),
+            method,
+        }
+    }
+
+    /// The path the server function is called at, including its prefix, like `/api/add_todo`.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The method the server function is called with: `GET` for the `GetJson` and `GetCbor`
+    /// encodings, and `POST` for the others.
+    pub fn method(&self) -> Method {
+        self.method
+    }
+}
+
+/// Everything that an application can serve: its routes and its server functions. Returned by
+/// [`generate_route_list`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RouteList {
+    routes: Vec<RouteListing>,
+    server_fns: Vec<ServerFnListing>,
+}
+
+impl RouteList {
+    /// The routes of the application, with their paths in the leptos_router format (like
+    /// `/users/:id` or `/docs/*any`), the methods they accept, and their rendering modes.
+    pub fn routes(&self) -> &[RouteListing] {
+        &self.routes
+    }
+
+    /// The server functions registered by the application.
+    pub fn server_fns(&self) -> &[ServerFnListing] {
+        &self.server_fns
+    }
+}
+
+/// Generates a list of all routes and server functions this application could possibly serve,
+/// so that a server that doesn't have a Leptos integration can route requests to the app, or a
+/// tool can generate the configuration of a proxy in front of it.
+///
+/// This renders the app once without loading any resources, to find its `<Route/>`s. The paths
+/// are in the leptos_router format, so they may need to be translated to the syntax of the
+/// server's router. The root route's path is `/`.
+///
+/// The integrations' own `generate_route_list()` functions return routes with paths already
+/// translated for their router.
+/// ```rust,ignore
+/// let list = leptos_router::generate_route_list(App);
+/// for route in list.routes() {
+///     for method in route.methods() {
+///         server.route(method, route.path(), render_app(route.mode()));
+///     }
+/// }
+/// for server_fn in list.server_fns() {
+///     server.route(server_fn.method(), server_fn.path(), handle_server_fns());
+/// }
+/// ```
+pub fn generate_route_list<IV>(
+    app_fn: impl Fn() -> IV + 'static + Clone,
+) -> RouteList
+where
+    IV: IntoView + 'static,
+{
+    let (routes, _) = generate_route_list_inner(app_fn);
+    let routes = routes
+        .into_iter()
+        .map(|listing| {
+            if listing.path.is_empty() {
+                RouteListing {
+                    path: "/".to_string(),
+                    ..listing
+                }
+            } else {
+                listing
+            }
+        })
+        .collect();
+
+    RouteList {
+        routes,
+        server_fns: server_fn_listings(),
+    }
+}
+
+/// The server functions registered by the application, sorted by path.
+#[cfg(all(feature = "server-fns", feature = "ssr"))]
+fn server_fn_listings() -> Vec<ServerFnListing> {
+    use leptos::leptos_server::{
+        server_fn_by_path, server_fns_by_path, Encoding,
+    };
+
+    let mut server_fns = server_fns_by_path()
+        .into_iter()
+        .filter_map(server_fn_by_path)
+        .map(|server_fn| {
+            let method = match server_fn.encoding() {
+                Encoding::GetJSON | Encoding::GetCBOR => Method::Get,
+                Encoding::Url | Encoding::Cbor => Method::Post,
+            };
+            let path = format!(
+                "{}/{}",
+                server_fn.prefix().trim_end_matches('/'),
+                server_fn.url().trim_start_matches('/')
+            );
+            ServerFnListing { path, method }
+        })
+        .collect::<Vec<_>>();
+    server_fns.sort_by(|a, b| a.path.cmp(&b.path));
+    server_fns
+}
+
+/// Without the `server-fns` feature, the application has no server functions, and they are
+/// only registered on the server.
+#[cfg(not(all(feature = "server-fns", feature = "ssr")))]
+fn server_fn_listings() -> Vec<ServerFnListing> {
+    Vec::new()
+}
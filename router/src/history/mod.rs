@@ -79,6 +79,8 @@ impl History for BrowserIntegration {
                 request_animation_frame(move || {
                     is_back.set(false);
                 });
+                // going back or forward to a modal keeps its background
+                let background = router.inner.background_of(&change.value);
                 if let Err(e) = router.inner.navigate_from_route(
                     &change.value,
                     &NavigateOptions {
@@ -87,6 +89,7 @@ impl History for BrowserIntegration {
                         scroll: change.scroll,
                         state: change.state,
                     },
+                    background,
                 ) {
                     leptos::logging::error!("{e:#?}");
                 }
@@ -93,7 +93,10 @@ impl TryFrom<&str> for Url {
     type Error = String;
 
     fn try_from(url: &str) -> Result<Self, Self::Error> {
-        let url = url::Url::parse(url).map_err(|e| e.to_string())?;
+        // paths navigated to are relative, as in the browser
+        let base =
+            url::Url::parse("http://leptos").map_err(|e| e.to_string())?;
+        let url = base.join(url).map_err(|e| e.to_string())?;
         Ok(Self {
             origin: url.origin().unicode_serialization(),
             pathname: url.path().to_string(),
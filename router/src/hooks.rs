@@ -1,6 +1,6 @@
 use crate::{
-    Location, NavigateOptions, Params, ParamsError, ParamsMap, RouteContext,
-    RouterContext,
+    Location, NavigateOptions, Params, ParamsError, ParamsMap,
+    ParentOutletContext, RouteContext, RouterContext,
 };
 use leptos::{
     create_memo, request_animation_frame, signal_prelude::*, use_context, Memo,
@@ -119,6 +119,17 @@ pub fn use_route_data<T: Clone + 'static>() -> Option<T> {
     downcast
 }
 
+/// Returns the value passed to the `context` prop of the [`<Outlet/>`](crate::Outlet) the
+/// current route is displayed in, if it is of type `T`.
+///
+/// This lets a parent layout route share data it has loaded with whichever child route it
+/// displays, without loading it again. Only the outlet the current route is directly
+/// displayed in is used; contexts of outlets further up the tree are not visible.
+#[track_caller]
+pub fn use_outlet_context<T: Clone + 'static>() -> Option<T> {
+    use_context::<ParentOutletContext>()?.0?.get()
+}
+
 /// Returns the current [`Location`], which contains reactive variables
 #[track_caller]
 pub fn use_location() -> Location {
@@ -126,13 +137,30 @@ pub fn use_location() -> Location {
 }
 
 /// Returns a raw key-value map of route params.
+///
+/// This includes the params matched by the routes the current route is nested in. If a
+/// nested route declares a param with the same name as one of its ancestors, the nested
+/// route's value is used.
 #[track_caller]
 pub fn use_params_map() -> Memo<ParamsMap> {
     let route = use_route();
     route.params()
 }
 
+/// Returns a raw key-value map of route params, as they appear in the URL.
+///
+/// This is the same as [`use_params_map`], except that the values are still
+/// percent-encoded if the [`<Router/>`](crate::Router) decodes params.
+#[track_caller]
+pub fn use_raw_params_map() -> Memo<ParamsMap> {
+    let route = use_route();
+    route.raw_params()
+}
+
 /// Returns the current route params, parsed into the given type, or an error.
+///
+/// Like [`use_params_map`], this includes the params of the routes the current route is
+/// nested in, so a route at `/:org_id/:repo_id` nested in `/:org_id` can read both.
 #[track_caller]
 pub fn use_params<T: Params>() -> Memo<Result<T, ParamsError>>
 where
@@ -142,12 +170,59 @@ where
     create_memo(move |_| route.params().with(T::from_map))
 }
 
+/// Returns a raw key-value map of the params matched by the route the current route is
+/// nested in, which is empty for a top-level route.
+///
+/// This is useful to read an ancestor's param when the current route declares a param
+/// with the same name.
+#[track_caller]
+pub fn use_parent_params_map() -> Memo<ParamsMap> {
+    let route = use_route();
+    route
+        .parent()
+        .unwrap_or_else(|| use_router().base())
+        .params()
+}
+
+/// Returns the params matched by the route the current route is nested in, parsed into
+/// the given type, or an error.
+#[track_caller]
+pub fn use_parent_params<T>() -> Memo<Result<T, ParamsError>>
+where
+    T: Params + PartialEq,
+{
+    let params = use_parent_params_map();
+    create_memo(move |_| params.with(T::from_map))
+}
+
 /// Returns a raw key-value map of the URL search query.
 #[track_caller]
 pub fn use_query_map() -> Memo<ParamsMap> {
     use_router().inner.location.query
 }
 
+/// Returns the value of a single key of the URL search query, if it is set.
+///
+/// Unlike [`use_query_map`], this only notifies its subscribers when the value of `key`
+/// changes, so that a view reading `?page=` isn't rendered again when another key of the
+/// query, like `utm_source`, changes.
+///
+/// ```rust
+/// # use leptos::*;
+/// # use leptos_router::*;
+/// # #[component]
+/// # fn Pagination() -> impl IntoView {
+/// let page = use_query_param("page");
+/// view! { <p>"Page " {move || page.get().unwrap_or_else(|| "1".into())}</p> }
+/// # }
+/// ```
+#[track_caller]
+pub fn use_query_param(key: impl Into<String>) -> Memo<Option<String>> {
+    let key = key.into();
+    let query = use_router().inner.location.query;
+    create_memo(move |_| query.with(|query| query.get(&key).cloned()))
+}
+
 /// Returns the current URL search query, parsed into the given type, or an error.
 #[track_caller]
 pub fn use_query<T: Params>() -> Memo<Result<T, ParamsError>>
@@ -180,34 +255,82 @@ pub fn use_resolved_path(
 /// This should only be called on the client; it does nothing during
 /// server rendering.
 ///
+/// It takes either a string path or a typed route path built by a function generated with
+/// [`routes!`](crate::routes), so that renaming a route's params can't silently break
+/// navigation to it.
+///
 /// ```rust
 /// # use leptos::{request_animation_frame, create_runtime};
 /// # let runtime = create_runtime();
+/// leptos_router::routes! {
+///     user(id: u32) = "/users/:id";
+/// }
+///
 /// # if false { // can't actually navigate, no <Router/>
 /// let navigate = leptos_router::use_navigate();
 /// navigate("/", Default::default());
+/// navigate(&user(42), Default::default());
 /// # }
 /// # runtime.dispose();
 /// ```
 #[track_caller]
 pub fn use_navigate() -> impl Fn(&str, NavigateOptions) {
+    let router = use_router();
+    move |to, options| navigate(&router, to, options, None)
+}
+
+/// Returns a function that can be used to navigate to a new route, which is then displayed
+/// as a modal over the current page by [`<Routes/>`](crate::Routes), like with
+/// `<A modal=true>`. Going back closes the modal.
+///
+/// This should only be called on the client; it does nothing during
+/// server rendering.
+///
+/// ```rust
+/// # use leptos::create_runtime;
+/// # let runtime = create_runtime();
+/// # if false { // can't actually navigate, no <Router/>
+/// let open_photo = leptos_router::use_navigate_modal();
+/// open_photo("/photos/1", Default::default());
+/// # }
+/// # runtime.dispose();
+/// ```
+#[track_caller]
+pub fn use_navigate_modal() -> impl Fn(&str, NavigateOptions) {
     let router = use_router();
     move |to, options| {
-        let router = Rc::clone(&router.inner);
-        let to = to.to_string();
-        if cfg!(any(feature = "csr", feature = "hydrate")) {
-            request_animation_frame(move || {
-                #[allow(unused_variables)]
-                if let Err(e) = router.navigate_from_route(&to, &options) {
-                    leptos::logging::debug_warn!("use_navigate error: {e:?}");
-                }
-            });
-        } else {
-            leptos::logging::warn!(
-                "The navigation function returned by `use_navigate` should \
-                 not be called during server rendering."
-            );
-        }
+        // a modal opened from a modal keeps the same background
+        let background = router
+            .background()
+            .get_untracked()
+            .unwrap_or_else(|| router.pathname().get_untracked());
+        navigate(&router, to, options, Some(background))
+    }
+}
+
+fn navigate(
+    router: &RouterContext,
+    to: &str,
+    options: NavigateOptions,
+    background: Option<String>,
+) {
+    let router = Rc::clone(&router.inner);
+    let to = to.to_string();
+    if cfg!(any(feature = "csr", feature = "hydrate")) {
+        request_animation_frame(move || {
+            #[allow(unused_variables)]
+            if let Err(e) =
+                router.navigate_from_route(&to, &options, background)
+            {
+                leptos::logging::debug_warn!("use_navigate error: {e:?}");
+            }
+        });
+    } else {
+        _ = background;
+        leptos::logging::warn!(
+            "The navigation function returned by `use_navigate` should not be \
+             called during server rendering."
+        );
     }
 }
 
@@ -177,6 +177,11 @@
 //! - `csr` Client-side rendering: Generate DOM nodes in the browser
 //! - `ssr` Server-side rendering: Generate an HTML string (typically on the server)
 //! - `hydrate` Hydration: use this to add interactivity to an SSRed Leptos app
+//! - `interactive-ssr` Adds `audit_progressive_enhancement`, which reports the interactions of each
+//!   page that only work with JavaScript. Implies `ssr`.
+//! - `server-fns` (on by default): Adds [`ActionForm`] and [`MultiActionForm`], and lists the
+//!   app's server functions in [`generate_route_list`]. Turn off default features to build without
+//!   server functions.
 //! - `nightly`: On `nightly` Rust, enables the function-call syntax for signal getters and setters.
 //!
 //! **Important Note:** You must enable one of `csr`, `hydrate`, or `ssr` to tell Leptos
@@ -189,19 +194,35 @@
 #![cfg_attr(feature = "nightly", feature(type_name_of_val))]
 
 mod animation;
+#[cfg(feature = "interactive-ssr")]
+mod audit;
 mod components;
 #[cfg(any(feature = "ssr", doc))]
 mod extract_routes;
 mod history;
 mod hooks;
+mod locale;
 #[doc(hidden)]
 pub mod matching;
+mod plural;
+mod prefetch;
 mod render_mode;
+#[cfg(feature = "ssr")]
+mod sitemap;
+mod typed_routes;
+#[cfg(feature = "interactive-ssr")]
+pub use audit::*;
 pub use components::*;
 #[cfg(any(feature = "ssr", doc))]
 pub use extract_routes::*;
 pub use history::*;
 pub use hooks::*;
+pub use locale::{use_locale, AcceptLanguage, Locale, Locales};
 pub use matching::{RouteDefinition, *};
+pub use plural::*;
+pub use prefetch::PrefetchPolicy;
 pub use render_mode::*;
+#[cfg(feature = "ssr")]
+pub use sitemap::*;
+pub use typed_routes::*;
 extern crate tracing;
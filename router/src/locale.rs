@@ -0,0 +1,352 @@
+use crate::{
+    define_route,
+    matching::{get_route_matches, join_paths, MatchOptions},
+    Method, NavigateOptions, Redirect, RouteDefinition, SsrMode,
+};
+use cfg_if::cfg_if;
+use leptos::*;
+use std::rc::Rc;
+
+/// The locales an app is available in. Passed to the `locales` prop of the
+/// [`<Router/>`](crate::Router).
+///
+/// When it is set, every route is served under a prefix for each supported locale, like
+/// `/en/about` and `/fr/about`, and the root of the [`<Routes/>`](crate::Routes) redirects to
+/// the locale that best matches the user's language. A [`<Route/>`](crate::Route) can give
+/// its path in other locales with its `translations` prop, so that `/fr/a-propos` is the
+/// French version of `/en/about`, and links rendered with [`<A/>`](crate::A) to absolute
+/// paths without a locale are prefixed with the current one.
+///
+/// The current locale can be read with [`use_locale`], for example to set the `lang`
+/// attribute of the page with `<Html lang=move || locale.get()/>` from `leptos_meta`.
+///
+/// ```rust
+/// # use leptos::*;
+/// # use leptos_router::*;
+/// # if false {
+/// view! {
+///   <Router locales=Locales { supported: &["en", "fr"], default: "en" }>
+///     <Routes>
+///       <Route path="" view=|| "Home"/>
+///       <Route path="about" translations=&[("fr", "a-propos")] view=|| "About"/>
+///     </Routes>
+///   </Router>
+/// }
+/// # ;}
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Locales {
+    /// The locales the app is available in, as language tags like `"en"` or `"pt-BR"`.
+    /// These are also the path prefixes of the routes.
+    pub supported: &'static [&'static str],
+    /// The locale used when none of the user's languages are supported, and for paths that
+    /// don't start with a locale.
+    pub default: &'static str,
+}
+
+/// The locale of the current page, provided by a [`<Router/>`](crate::Router) that has
+/// [`Locales`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Locale {
+    locales: Locales,
+    routes_base: StoredValue<String>,
+    current: Memo<&'static str>,
+    match_options: MatchOptions,
+}
+
+impl Locale {
+    pub(crate) fn new(
+        locales: Locales,
+        pathname: Memo<String>,
+        match_options: MatchOptions,
+    ) -> Self {
+        let routes_base = store_value(String::new());
+        let current = create_memo(move |_| {
+            pathname.with(|pathname| {
+                routes_base.with_value(|base| {
+                    locale_segment(&locales, strip_base(base, pathname))
+                        .unwrap_or(locales.default)
+                })
+            })
+        });
+        Self {
+            locales,
+            routes_base,
+            current,
+            match_options,
+        }
+    }
+
+    /// The locale of the current page: the locale its path starts with, or the default
+    /// locale if it doesn't start with a supported one. Tracks the location.
+    pub fn get(&self) -> &'static str {
+        self.current.get()
+    }
+
+    /// The locale of the current page, without tracking the location.
+    pub fn get_untracked(&self) -> &'static str {
+        self.current.get_untracked()
+    }
+
+    /// The locales the app is available in.
+    pub fn locales(&self) -> Locales {
+        self.locales
+    }
+
+    /// Translates the path of a page to the given locale, using the `translations` of the
+    /// routes it matches. This is useful for a language switcher:
+    /// `locale.localize(&pathname, "fr")` turns `/en/about?tab=1` into `/fr/a-propos?tab=1`.
+    ///
+    /// Paths that don't match any route only get their locale prefix replaced.
+    pub fn localize(&self, path: &str, locale: &str) -> String {
+        let base = self.routes_base.get_value();
+        let (path, rest) = split_query_and_hash(path);
+        let path = strip_base(&base, path);
+        let path = match locale_segment(&self.locales, path) {
+            Some(_) => path.to_string(),
+            None => join_paths(self.locales.default, path),
+        };
+
+        let matches = get_route_matches(
+            &base,
+            join_paths(&base, &path),
+            self.match_options,
+        );
+        let localized = if matches.is_empty() {
+            let (_, unprefixed) = split_first_segment(&path);
+            join_paths(locale, unprefixed)
+        } else {
+            let mut localized = normalize_segment(locale);
+            for route_match in matches.iter() {
+                localized.push_str(&fill_params(
+                    route_match.route.key.path_in(Some(locale)),
+                    &route_match.path_match.raw_params,
+                ));
+            }
+            localized
+        };
+
+        join_paths(&base, &localized) + rest
+    }
+
+    pub(crate) fn set_routes_base(&self, base: &str) {
+        self.routes_base.set_value(base.to_string());
+    }
+
+    /// Prefixes an absolute `href` with the current locale, if it doesn't already start
+    /// with a supported locale.
+    pub(crate) fn localize_href(&self, href: String) -> String {
+        if !href.starts_with('/') {
+            return href;
+        }
+        let base = self.routes_base.get_value();
+        let (path, _) = split_query_and_hash(&href);
+        if !path.starts_with(base.as_str())
+            || locale_segment(&self.locales, strip_base(&base, path)).is_some()
+        {
+            return href;
+        }
+        self.localize(&href, self.get())
+    }
+
+    /// The supported locale that best matches the languages of the user, from the browser
+    /// on the client or from the `Accept-Language` header of the request on the server.
+    pub fn detect(&self) -> &'static str {
+        negotiate(&self.locales, &preferred_languages())
+    }
+}
+
+/// Returns the [`Locale`] of the current page, if the [`<Router/>`](crate::Router) has
+/// [`Locales`].
+pub fn use_locale() -> Option<Locale> {
+    use_context::<Locale>()
+}
+
+/// The `Accept-Language` header of the request being rendered, provided by the server
+/// integrations so that the router can pick the user's locale.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AcceptLanguage(pub String);
+
+/// The root route added to the routes when the router has [`Locales`], which redirects to
+/// the locale of the user.
+pub(crate) fn root_redirect(base: &str) -> RouteDefinition {
+    let base = base.to_string();
+    define_route(
+        None,
+        String::new(),
+        Rc::new(move || {
+            let locale = use_locale()
+                .map(|locale| locale.detect())
+                .unwrap_or_default();
+            view! {
+                <Redirect
+                    path=join_paths(&base, locale)
+                    options=NavigateOptions {
+                        replace: true,
+                        ..Default::default()
+                    }
+                />
+            }
+            .into_view()
+        }),
+        SsrMode::default(),
+        &[Method::Get],
+        None,
+        None,
+        None,
+    )
+}
+
+fn locale_segment(locales: &Locales, path: &str) -> Option<&'static str> {
+    let (first, _) = split_first_segment(path);
+    locales
+        .supported
+        .iter()
+        .find(|locale| locale.eq_ignore_ascii_case(first))
+        .copied()
+}
+
+fn split_first_segment(path: &str) -> (&str, &str) {
+    let path = path.trim_start_matches('/');
+    match path.find('/') {
+        Some(index) => path.split_at(index),
+        None => (path, ""),
+    }
+}
+
+fn split_query_and_hash(path: &str) -> (&str, &str) {
+    match path.find(['?', '#']) {
+        Some(index) => path.split_at(index),
+        None => (path, ""),
+    }
+}
+
+fn strip_base<'a>(base: &str, path: &'a str) -> &'a str {
+    let base = base.trim_end_matches('/');
+    path.strip_prefix(base).unwrap_or(path)
+}
+
+fn normalize_segment(segment: &str) -> String {
+    format!("/{}", segment.trim_matches('/'))
+}
+
+/// Rebuilds the path of a route from its pattern and the params it matched.
+fn fill_params(pattern: &str, params: &crate::ParamsMap) -> String {
+    let mut path = String::new();
+    for segment in pattern.split('/').filter(|s| !s.is_empty()) {
+        let value = if let Some(name) = segment.strip_prefix(':') {
+            params.get(name.trim_end_matches('?')).map(String::as_str)
+        } else if let Some(name) = segment.strip_prefix('*') {
+            params.get(name).map(String::as_str)
+        } else {
+            Some(segment)
+        };
+        if let Some(value) = value.filter(|value| !value.is_empty()) {
+            path.push('/');
+            path.push_str(value.trim_start_matches('/'));
+        }
+    }
+    path
+}
+
+/// The languages the user prefers, most preferred first.
+fn preferred_languages() -> Vec<String> {
+    cfg_if! {
+        if #[cfg(feature = "ssr")] {
+            use_context::<AcceptLanguage>()
+                .map(|AcceptLanguage(header)| parse_accept_language(&header))
+                .unwrap_or_default()
+        } else if #[cfg(any(feature = "csr", feature = "hydrate"))] {
+            window()
+                .navigator()
+                .languages()
+                .iter()
+                .filter_map(|language| language.as_string())
+                .collect()
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "ssr"), allow(unused))]
+fn parse_accept_language(header: &str) -> Vec<String> {
+    let mut languages = header
+        .split(',')
+        .filter_map(|language| {
+            let mut parts = language.split(';');
+            let tag = parts.next()?.trim();
+            let quality = parts
+                .find_map(|param| param.trim().strip_prefix("q="))
+                .and_then(|q| q.parse::<f32>().ok())
+                .unwrap_or(1.0);
+            (!tag.is_empty() && tag != "*" && quality > 0.0)
+                .then(|| (tag.to_string(), quality))
+        })
+        .collect::<Vec<_>>();
+    // the sort is stable, so languages with the same quality keep their order
+    languages.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+    languages.into_iter().map(|(tag, _)| tag).collect()
+}
+
+/// Picks the supported locale that best matches the preferred languages, matching either
+/// the whole tag or its primary language (`fr-CA` matches `fr`).
+fn negotiate(locales: &Locales, languages: &[String]) -> &'static str {
+    fn primary(tag: &str) -> &str {
+        tag.split(['-', '_']).next().unwrap_or_default()
+    }
+    languages
+        .iter()
+        .find_map(|language| {
+            locales
+                .supported
+                .iter()
+                .find(|locale| locale.eq_ignore_ascii_case(language))
+                .or_else(|| {
+                    locales.supported.iter().find(|locale| {
+                        primary(locale).eq_ignore_ascii_case(primary(language))
+                    })
+                })
+        })
+        .copied()
+        .unwrap_or(locales.default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOCALES: Locales = Locales {
+        supported: &["en", "fr", "pt-BR"],
+        default: "en",
+    };
+
+    #[test]
+    fn accept_language_is_sorted_by_quality() {
+        assert_eq!(
+            parse_accept_language("fr;q=0.5, de-CH, en;q=0.8, *;q=0.1"),
+            vec!["de-CH", "en", "fr"]
+        );
+    }
+
+    #[test]
+    fn negotiate_matches_primary_language() {
+        let languages = |tags: &[&str]| {
+            tags.iter().map(|tag| tag.to_string()).collect::<Vec<_>>()
+        };
+        assert_eq!(negotiate(&LOCALES, &languages(&["fr-CA", "en"])), "fr");
+        assert_eq!(negotiate(&LOCALES, &languages(&["pt-br"])), "pt-BR");
+        assert_eq!(negotiate(&LOCALES, &languages(&["pt-PT"])), "pt-BR");
+        assert_eq!(negotiate(&LOCALES, &languages(&["de"])), "en");
+        assert_eq!(negotiate(&LOCALES, &[]), "en");
+    }
+
+    #[test]
+    fn fill_params_drops_missing_optionals() {
+        let mut params = crate::ParamsMap::new();
+        params.insert("id".into(), "42".into());
+        params.insert("rest".into(), "a/b".into());
+        assert_eq!(fill_params("/users/:id/*rest", &params), "/users/42/a/b");
+        assert_eq!(fill_params("/posts/:page?", &params), "/posts");
+    }
+}
@@ -2,12 +2,27 @@
 // see <https://github.com/solidjs/solid-router/blob/main/src/utils.ts>
 
 use crate::ParamsMap;
+use percent_encoding::percent_decode_str;
+use std::borrow::Cow;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[doc(hidden)]
 pub struct PathMatch {
     pub path: String,
     pub params: ParamsMap,
+    /// The params as they appear in the URL, before any percent-decoding.
+    pub raw_params: ParamsMap,
+}
+
+/// How a [`Matcher`] compares the segments of a location with those of its path.
+#[doc(hidden)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MatchOptions {
+    /// Static segments match regardless of their case.
+    pub case_insensitive: bool,
+    /// Segments are percent-decoded before they are compared or stored as params, and a
+    /// location whose segments are not valid UTF-8 once decoded doesn't match.
+    pub decode_params: bool,
 }
 
 #[doc(hidden)]
@@ -15,6 +30,8 @@ pub struct PathMatch {
 pub struct Matcher {
     splat: Option<String>,
     segments: Vec<String>,
+    /// The segments in lowercase, for case-insensitive matching.
+    lowercase_segments: Vec<String>,
     len: usize,
     partial: bool,
 }
@@ -37,11 +54,14 @@ impl Matcher {
             .map(|n| n.to_string())
             .collect::<Vec<_>>();
 
+        let lowercase_segments =
+            segments.iter().map(|n| n.to_lowercase()).collect();
         let len = segments.len();
 
         Self {
             splat,
             segments,
+            lowercase_segments,
             len,
             partial,
         }
@@ -49,6 +69,15 @@ impl Matcher {
 
     #[doc(hidden)]
     pub fn test(&self, location: &str) -> Option<PathMatch> {
+        self.test_with_options(location, MatchOptions::default())
+    }
+
+    #[doc(hidden)]
+    pub fn test_with_options(
+        &self,
+        location: &str,
+        options: MatchOptions,
+    ) -> Option<PathMatch> {
         let loc_segments = location
             .split('/')
             .filter(|n| !n.is_empty())
@@ -72,13 +101,20 @@ impl Matcher {
         else {
             let mut path = String::new();
             let mut params = ParamsMap::new();
+            let mut raw_params = ParamsMap::new();
 
-            for (segment, loc_segment) in
-                self.segments.iter().zip(loc_segments.iter())
+            for ((segment, lowercase), loc_segment) in self
+                .segments
+                .iter()
+                .zip(&self.lowercase_segments)
+                .zip(loc_segments.iter())
             {
+                let value = decode_segment(loc_segment, options)?;
                 if let Some(param_name) = segment.strip_prefix(':') {
-                    params.insert(param_name.into(), (*loc_segment).into());
-                } else if segment != loc_segment {
+                    params.insert(param_name.into(), value.into_owned());
+                    raw_params.insert(param_name.into(), (*loc_segment).into());
+                } else if !segment_matches(segment, lowercase, &value, options)
+                {
                     // if any segment doesn't match and isn't a param, there's no path match
                     return None;
                 }
@@ -89,22 +125,61 @@ impl Matcher {
 
             if let Some(splat) = &self.splat {
                 if !splat.is_empty() {
-                    let mut value = if len_diff > 0 {
-                        loc_segments[self.len..].join("/")
+                    let rest = if len_diff > 0 {
+                        &loc_segments[self.len..]
                     } else {
-                        "".into()
+                        &[]
                     };
+                    let mut raw_value = rest.join("/");
+                    let mut value = rest
+                        .iter()
+                        .map(|loc_segment| decode_segment(loc_segment, options))
+                        .collect::<Option<Vec<_>>>()?
+                        .join("/");
 
                     // add trailing slashes to splat
                     let trailing_slashes =
                         trailing_iter.skip(1).collect::<String>();
                     value.push_str(&trailing_slashes);
+                    raw_value.push_str(&trailing_slashes);
 
                     params.insert(splat.into(), value);
+                    raw_params.insert(splat.into(), raw_value);
                 }
             }
 
-            Some(PathMatch { path, params })
+            Some(PathMatch {
+                path,
+                params,
+                raw_params,
+            })
         }
     }
 }
+
+fn decode_segment(
+    loc_segment: &str,
+    options: MatchOptions,
+) -> Option<Cow<'_, str>> {
+    if options.decode_params {
+        percent_decode_str(loc_segment).decode_utf8().ok()
+    } else {
+        Some(Cow::Borrowed(loc_segment))
+    }
+}
+
+fn segment_matches(
+    segment: &str,
+    lowercase_segment: &str,
+    loc_segment: &str,
+    options: MatchOptions,
+) -> bool {
+    if options.case_insensitive {
+        loc_segment
+            .chars()
+            .flat_map(char::to_lowercase)
+            .eq(lowercase_segment.chars())
+    } else {
+        segment == loc_segment
+    }
+}
@@ -19,31 +19,38 @@ pub(crate) struct RouteMatch {
 pub(crate) fn get_route_matches(
     base: &str,
     location: String,
+    options: MatchOptions,
 ) -> Rc<Vec<RouteMatch>> {
     #[cfg(feature = "ssr")]
     {
         use lru::LruCache;
         use std::{cell::RefCell, num::NonZeroUsize};
+        type RouteMatchCache =
+            LruCache<(String, MatchOptions), Rc<Vec<RouteMatch>>>;
         thread_local! {
-            static ROUTE_MATCH_CACHE: RefCell<LruCache<String, Rc<Vec<RouteMatch>>>> = RefCell::new(LruCache::new(NonZeroUsize::new(32).unwrap()));
+            static ROUTE_MATCH_CACHE: RefCell<RouteMatchCache> = RefCell::new(LruCache::new(NonZeroUsize::new(32).unwrap()));
         }
 
         ROUTE_MATCH_CACHE.with(|cache| {
             let mut cache = cache.borrow_mut();
-            Rc::clone(cache.get_or_insert(location.clone(), || {
-                build_route_matches(base, location)
+            Rc::clone(cache.get_or_insert((location.clone(), options), || {
+                build_route_matches(base, location, options)
             }))
         })
     }
 
     #[cfg(not(feature = "ssr"))]
-    build_route_matches(base, location)
+    build_route_matches(base, location, options)
 }
 
-fn build_route_matches(base: &str, location: String) -> Rc<Vec<RouteMatch>> {
+fn build_route_matches(
+    base: &str,
+    location: String,
+    options: MatchOptions,
+) -> Rc<Vec<RouteMatch>> {
     Rc::new(Branches::with(base, |branches| {
         for branch in branches {
-            if let Some(matches) = branch.matcher(&location) {
+            if let Some(matches) = branch.matcher(&location, options) {
                 return matches;
             }
         }
@@ -61,10 +68,14 @@ pub struct Branch {
 }
 
 impl Branch {
-    fn matcher<'a>(&'a self, location: &'a str) -> Option<Vec<RouteMatch>> {
+    fn matcher<'a>(
+        &'a self,
+        location: &'a str,
+        options: MatchOptions,
+    ) -> Option<Vec<RouteMatch>> {
         let mut matches = Vec::new();
         for route in self.routes.iter().rev() {
-            match route.matcher.test(location) {
+            match route.matcher.test_with_options(location, options) {
                 None => return None,
                 Some(m) => matches.push(RouteMatch {
                     path_match: m,
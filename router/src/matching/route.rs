@@ -25,6 +25,8 @@ pub struct RouteDefinition {
     pub static_mode: Option<StaticMode>,
     /// The data required to fill any dynamic segments in the path during static rendering.
     pub static_params: Option<StaticData>,
+    /// The path in other locales, as `(locale, path)` pairs.
+    pub translations: &'static [(&'static str, &'static str)],
 }
 
 impl core::fmt::Debug for RouteDefinition {
@@ -38,6 +40,20 @@ impl core::fmt::Debug for RouteDefinition {
     }
 }
 
+impl RouteDefinition {
+    /// The path of the route in the given locale.
+    pub(crate) fn path_in(&self, locale: Option<&str>) -> &str {
+        locale
+            .and_then(|locale| {
+                self.translations
+                    .iter()
+                    .find(|(translated, _)| *translated == locale)
+            })
+            .map(|(_, path)| *path)
+            .unwrap_or(&self.path)
+    }
+}
+
 impl PartialEq for RouteDefinition {
     fn eq(&self, other: &Self) -> bool {
         self.path == other.path && self.children == other.children
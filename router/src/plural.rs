@@ -0,0 +1,422 @@
+use crate::use_locale;
+use leptos::*;
+
+/// The plural categories of the [CLDR](https://cldr.unicode.org/index/cldr-spec/plural-rules),
+/// one of which is picked for a count by the rules of each language.
+///
+/// English only uses [`One`](PluralCategory::One) and [`Other`](PluralCategory::Other), but
+/// Polish uses `Few` for 2, 3 and 4, and Arabic uses all of them. `Zero` is only used by
+/// the languages whose rules have it, like Arabic or Latvian, and not for `0` in English.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    /// For `0` in some languages, like Arabic.
+    Zero,
+    /// For `1` in most languages, and other numbers in some.
+    One,
+    /// For `2` in some languages, like Arabic or Slovenian.
+    Two,
+    /// For small numbers in some languages, like `2` to `4` in Czech.
+    Few,
+    /// For large numbers or fractions in some languages, like `5` in Russian.
+    Many,
+    /// For every other count. Every language uses it.
+    Other,
+}
+
+/// The parts of a number that plural rules look at. It is created from integer and floating
+/// point counts; a float keeps the digits it is displayed with, so `1.5` has a fraction
+/// while `1.0` doesn't.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PluralOperands {
+    /// The absolute value of the number.
+    n: f64,
+    /// Its integer digits.
+    i: u64,
+    /// How many fraction digits it has.
+    v: usize,
+    /// Its fraction digits, as an integer.
+    f: u64,
+    /// Its fraction digits without trailing zeros, as an integer.
+    t: u64,
+}
+
+macro_rules! operands_from_integers {
+    ($($ty:ty),*) => {
+        $(impl From<$ty> for PluralOperands {
+            fn from(value: $ty) -> Self {
+                let i = value.unsigned_abs() as u64;
+                Self { n: i as f64, i, v: 0, f: 0, t: 0 }
+            }
+        })*
+    };
+}
+
+operands_from_integers!(i8, i16, i32, i64, isize);
+
+macro_rules! operands_from_unsigned {
+    ($($ty:ty),*) => {
+        $(impl From<$ty> for PluralOperands {
+            fn from(value: $ty) -> Self {
+                let i = value as u64;
+                Self { n: i as f64, i, v: 0, f: 0, t: 0 }
+            }
+        })*
+    };
+}
+
+operands_from_unsigned!(u8, u16, u32, u64, usize);
+
+impl From<f64> for PluralOperands {
+    fn from(value: f64) -> Self {
+        let n = value.abs();
+        let digits = n.to_string();
+        let (integer, fraction) =
+            digits.split_once('.').unwrap_or((&digits, ""));
+        Self {
+            n,
+            i: integer.parse().unwrap_or_default(),
+            v: fraction.len(),
+            f: fraction.parse().unwrap_or_default(),
+            t: fraction.trim_end_matches('0').parse().unwrap_or_default(),
+        }
+    }
+}
+
+impl From<f32> for PluralOperands {
+    fn from(value: f32) -> Self {
+        // through its decimal digits, so that `0.1_f32` doesn't become `0.10000000149011612`
+        value.to_string().parse::<f64>().unwrap_or_default().into()
+    }
+}
+
+impl PluralOperands {
+    /// Whether `n` is an integer in `range`.
+    fn n_in(&self, range: std::ops::RangeInclusive<u64>) -> bool {
+        self.n.fract() == 0.0 && range.contains(&(self.n as u64))
+    }
+
+    /// `n % modulo`, which keeps the fraction of `n`.
+    fn n_mod(&self, modulo: u64) -> f64 {
+        self.n % modulo as f64
+    }
+}
+
+/// Whether a value compared with `n_mod` is an integer in `range`.
+fn is_in(value: f64, range: std::ops::RangeInclusive<u64>) -> bool {
+    value.fract() == 0.0 && range.contains(&(value as u64))
+}
+
+/// Returns the plural category that the rules of `locale` pick for `count`. `locale` is a
+/// language tag like `"en"` or `"pt-BR"`; languages without known rules only use
+/// [`PluralCategory::Other`].
+///
+/// ```
+/// # use leptos_router::*;
+/// assert_eq!(plural_category("en", 1), PluralCategory::One);
+/// assert_eq!(plural_category("en", 1.5), PluralCategory::Other);
+/// assert_eq!(plural_category("fr", 1.5), PluralCategory::One);
+/// assert_eq!(plural_category("pl", 22), PluralCategory::Few);
+/// assert_eq!(plural_category("ja", 1), PluralCategory::Other);
+/// ```
+pub fn plural_category(
+    locale: &str,
+    count: impl Into<PluralOperands>,
+) -> PluralCategory {
+    use PluralCategory::*;
+
+    let op = count.into();
+    let PluralOperands { n, i, v, f, t } = op;
+    let locale = locale.to_ascii_lowercase().replace('_', "-");
+    let language = locale.split('-').next().unwrap_or_default();
+    // the "many" of Romance languages, for millions
+    let millions = i != 0 && i % 1_000_000 == 0 && v == 0;
+    let one_if = |one: bool| if one { One } else { Other };
+
+    match language {
+        "am" | "as" | "bn" | "doi" | "fa" | "gu" | "hi" | "kn" | "pcm"
+        | "zu" => one_if(i == 0 || n == 1.0),
+        "ff" | "hy" | "kab" => one_if(i <= 1),
+        "ast" | "de" | "en" | "et" | "fi" | "fy" | "gl" | "ia" | "io"
+        | "ji" | "lij" | "nl" | "sc" | "sv" | "sw" | "ur" | "yi" => {
+            one_if(i == 1 && v == 0)
+        }
+        "si" => one_if(op.n_in(0..=1) || (i == 0 && f == 1)),
+        "ak" | "bho" | "guw" | "ln" | "mg" | "nso" | "pa" | "ti" | "wa" => {
+            one_if(op.n_in(0..=1))
+        }
+        "af" | "an" | "asa" | "az" | "bal" | "bem" | "bez" | "bg" | "brx"
+        | "ce" | "cgg" | "chr" | "ckb" | "dv" | "ee" | "el" | "eo" | "eu"
+        | "fo" | "fur" | "gsw" | "ha" | "haw" | "hu" | "jgo" | "jmc" | "ka"
+        | "kaj" | "kcg" | "kk" | "kkj" | "kl" | "ks" | "ksb" | "ku" | "ky"
+        | "lb" | "lg" | "mas" | "mgo" | "ml" | "mn" | "mr" | "nah" | "nb"
+        | "nd" | "ne" | "nn" | "nnh" | "no" | "nr" | "ny" | "nyn" | "om"
+        | "or" | "os" | "pap" | "ps" | "rm" | "rof" | "rwk" | "saq" | "sd"
+        | "sdh" | "seh" | "sn" | "so" | "sq" | "ss" | "ssy" | "st" | "syr"
+        | "ta" | "te" | "teo" | "tig" | "tk" | "tn" | "tr" | "ts" | "ug"
+        | "uz" | "ve" | "vo" | "vun" | "wae" | "xh" | "xog" => one_if(n == 1.0),
+        "da" => one_if(n == 1.0 || (t != 0 && i <= 1)),
+        "is" => one_if(
+            (t == 0 && i % 10 == 1 && i % 100 != 11)
+                || (t % 10 == 1 && t % 100 != 11),
+        ),
+        "mk" => one_if(
+            (v == 0 && i % 10 == 1 && i % 100 != 11)
+                || (f % 10 == 1 && f % 100 != 11),
+        ),
+        "ceb" | "fil" | "tl" => one_if(if v == 0 {
+            (1..=3).contains(&i) || ![4, 6, 9].contains(&(i % 10))
+        } else {
+            ![4, 6, 9].contains(&(f % 10))
+        }),
+        "fr" if i <= 1 => One,
+        "fr" if millions => Many,
+        "pt" if locale == "pt-pt" && i == 1 && v == 0 => One,
+        "pt" if locale != "pt-pt" && i <= 1 => One,
+        "pt" if millions => Many,
+        "es" if n == 1.0 => One,
+        "ca" | "it" | "vec" if i == 1 && v == 0 => One,
+        "es" | "ca" | "it" | "vec" if millions => Many,
+        "lv" | "prg" => {
+            if is_in(op.n_mod(10), 0..=0)
+                || is_in(op.n_mod(100), 11..=19)
+                || (v == 2 && (11..=19).contains(&(f % 100)))
+            {
+                Zero
+            } else if (is_in(op.n_mod(10), 1..=1)
+                && !is_in(op.n_mod(100), 11..=11))
+                || (f % 10 == 1 && (v != 2 || f % 100 != 11))
+            {
+                One
+            } else {
+                Other
+            }
+        }
+        "lag" if n == 0.0 => Zero,
+        "lag" => one_if(i <= 1),
+        "ksh" if n == 0.0 => Zero,
+        "ksh" => one_if(n == 1.0),
+        "he" | "iw" if (i == 1 && v == 0) || (i == 0 && v != 0) => One,
+        "he" | "iw" if i == 2 && v == 0 => Two,
+        "iu" | "naq" | "sat" | "se" | "sma" | "smi" | "smj" | "smn" | "sms"
+            if n == 1.0 =>
+        {
+            One
+        }
+        "iu" | "naq" | "sat" | "se" | "sma" | "smi" | "smj" | "smn" | "sms"
+            if n == 2.0 =>
+        {
+            Two
+        }
+        "shi" if i == 0 || n == 1.0 => One,
+        "shi" if op.n_in(2..=10) => Few,
+        "mo" | "ro" if i == 1 && v == 0 => One,
+        "mo" | "ro" if v != 0 || n == 0.0 || is_in(op.n_mod(100), 2..=19) => {
+            Few
+        }
+        "bs" | "hr" | "sh" | "sr" => {
+            if (v == 0 && i % 10 == 1 && i % 100 != 11)
+                || (f % 10 == 1 && f % 100 != 11)
+            {
+                One
+            } else if (v == 0
+                && (2..=4).contains(&(i % 10))
+                && !(12..=14).contains(&(i % 100)))
+                || ((2..=4).contains(&(f % 10))
+                    && !(12..=14).contains(&(f % 100)))
+            {
+                Few
+            } else {
+                Other
+            }
+        }
+        "gd" if op.n_in(1..=1) || op.n_in(11..=11) => One,
+        "gd" if op.n_in(2..=2) || op.n_in(12..=12) => Two,
+        "gd" if op.n_in(3..=10) || op.n_in(13..=19) => Few,
+        "sl" if v == 0 && i % 100 == 1 => One,
+        "sl" if v == 0 && i % 100 == 2 => Two,
+        "sl" if v != 0 || (3..=4).contains(&(i % 100)) => Few,
+        "dsb" | "hsb" if (v == 0 && i % 100 == 1) || f % 100 == 1 => One,
+        "dsb" | "hsb" if (v == 0 && i % 100 == 2) || f % 100 == 2 => Two,
+        "dsb" | "hsb"
+            if (v == 0 && (3..=4).contains(&(i % 100)))
+                || (3..=4).contains(&(f % 100)) =>
+        {
+            Few
+        }
+        "cs" | "sk" if i == 1 && v == 0 => One,
+        "cs" | "sk" if (2..=4).contains(&i) && v == 0 => Few,
+        "cs" | "sk" if v != 0 => Many,
+        "pl" if i == 1 && v == 0 => One,
+        "pl" | "ru" | "uk" if v != 0 => Other,
+        "pl" | "ru" | "uk"
+            if (2..=4).contains(&(i % 10))
+                && !(12..=14).contains(&(i % 100)) =>
+        {
+            Few
+        }
+        "ru" | "uk" if i % 10 == 1 && i % 100 != 11 => One,
+        "pl" | "ru" | "uk" => Many,
+        "be" => {
+            let (n10, n100) = (op.n_mod(10), op.n_mod(100));
+            if is_in(n10, 1..=1) && !is_in(n100, 11..=11) {
+                One
+            } else if is_in(n10, 2..=4) && !is_in(n100, 12..=14) {
+                Few
+            } else if is_in(n10, 0..=0)
+                || is_in(n10, 5..=9)
+                || is_in(n100, 11..=14)
+            {
+                Many
+            } else {
+                Other
+            }
+        }
+        "lt" => {
+            let (n10, n100) = (op.n_mod(10), op.n_mod(100));
+            if f != 0 {
+                Many
+            } else if is_in(n10, 1..=1) && !is_in(n100, 11..=19) {
+                One
+            } else if is_in(n10, 2..=9) && !is_in(n100, 11..=19) {
+                Few
+            } else {
+                Other
+            }
+        }
+        "br" => {
+            let (n10, n100) = (op.n_mod(10), op.n_mod(100));
+            let tens = |ranges: &[std::ops::RangeInclusive<u64>]| {
+                ranges.iter().any(|range| is_in(n100, range.clone()))
+            };
+            if is_in(n10, 1..=1) && !tens(&[11..=11, 71..=71, 91..=91]) {
+                One
+            } else if is_in(n10, 2..=2) && !tens(&[12..=12, 72..=72, 92..=92]) {
+                Two
+            } else if (is_in(n10, 3..=4) || is_in(n10, 9..=9))
+                && !tens(&[10..=19, 70..=79, 90..=99])
+            {
+                Few
+            } else if n != 0.0 && is_in(op.n_mod(1_000_000), 0..=0) {
+                Many
+            } else {
+                Other
+            }
+        }
+        "mt" if n == 1.0 => One,
+        "mt" if n == 2.0 => Two,
+        "mt" if n == 0.0 || is_in(op.n_mod(100), 3..=10) => Few,
+        "mt" if is_in(op.n_mod(100), 11..=19) => Many,
+        "ga" if n == 1.0 => One,
+        "ga" if n == 2.0 => Two,
+        "ga" if op.n_in(3..=6) => Few,
+        "ga" if op.n_in(7..=10) => Many,
+        "gv" if v == 0 && i % 10 == 1 => One,
+        "gv" if v == 0 && i % 10 == 2 => Two,
+        "gv" if v == 0 && i % 20 == 0 => Few,
+        "gv" if v != 0 => Many,
+        "ar" | "ars" => {
+            if n == 0.0 {
+                Zero
+            } else if n == 1.0 {
+                One
+            } else if n == 2.0 {
+                Two
+            } else if is_in(op.n_mod(100), 3..=10) {
+                Few
+            } else if is_in(op.n_mod(100), 11..=99) {
+                Many
+            } else {
+                Other
+            }
+        }
+        "cy" if n == 0.0 => Zero,
+        "cy" if n == 1.0 => One,
+        "cy" if n == 2.0 => Two,
+        "cy" if n == 3.0 => Few,
+        "cy" if n == 6.0 => Many,
+        _ => Other,
+    }
+}
+
+/// Shows the form of a text that matches a reactive `count`, following the plural rules of
+/// the current [`Locale`](crate::Locale) of the [`<Router/>`](crate::Router), or of its
+/// `locale` prop. This avoids writing "1 items" by hand.
+///
+/// It takes one form for each [`PluralCategory`] that the languages of the app use; a
+/// category without a form shows `other`. The form is picked again when `count` or the
+/// locale changes, and is rendered on the server like in the browser.
+///
+/// ```rust
+/// # use leptos::*;
+/// # use leptos_router::*;
+/// # if false {
+/// let (count, set_count) = create_signal(1);
+/// view! {
+///     <Plural
+///         count=move || count.get()
+///         one=move || view! { {count} " item" }
+///         other=move || view! { {count} " items" }
+///     />
+/// }
+/// # ;}
+/// ```
+///
+/// `0` is in the `other` category in English, so to write "No items" instead of "0 items",
+/// check for it before using `<Plural/>`.
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "trace", skip_all,)
+)]
+#[component]
+pub fn Plural<F, N>(
+    /// The count the form is picked for.
+    count: F,
+    /// The form for every other count.
+    #[prop(into)]
+    other: ViewFn,
+    /// The form for the [`Zero`](PluralCategory::Zero) category.
+    #[prop(optional, into)]
+    zero: Option<ViewFn>,
+    /// The form for the [`One`](PluralCategory::One) category.
+    #[prop(optional, into)]
+    one: Option<ViewFn>,
+    /// The form for the [`Two`](PluralCategory::Two) category.
+    #[prop(optional, into)]
+    two: Option<ViewFn>,
+    /// The form for the [`Few`](PluralCategory::Few) category.
+    #[prop(optional, into)]
+    few: Option<ViewFn>,
+    /// The form for the [`Many`](PluralCategory::Many) category.
+    #[prop(optional, into)]
+    many: Option<ViewFn>,
+    /// The locale whose rules are used, instead of the current locale of the router. Without
+    /// either, the rules of English are used.
+    #[prop(optional, into)]
+    locale: Option<TextProp>,
+) -> impl IntoView
+where
+    F: Fn() -> N + 'static,
+    N: Into<PluralOperands>,
+{
+    let router_locale = use_locale();
+    let category = create_memo(move |_| {
+        let locale = match (&locale, router_locale) {
+            (Some(locale), _) => locale.get(),
+            (None, Some(locale)) => locale.get().into(),
+            (None, None) => "en".into(),
+        };
+        plural_category(&locale, count())
+    });
+
+    move || {
+        let form = match category.get() {
+            PluralCategory::Zero => zero.as_ref(),
+            PluralCategory::One => one.as_ref(),
+            PluralCategory::Two => two.as_ref(),
+            PluralCategory::Few => few.as_ref(),
+            PluralCategory::Many => many.as_ref(),
+            PluralCategory::Other => None,
+        };
+        form.unwrap_or(&other).run()
+    }
+}
@@ -0,0 +1,415 @@
+use crate::{
+    matching::{get_route_matches, PathMatch, RouteMatch},
+    ParamsMap, RouteContext, RouterContext,
+};
+use leptos::*;
+use std::{
+    any::Any, cell::RefCell, collections::VecDeque, rc::Rc, time::Duration,
+};
+
+/// The number of prefetched routes that are kept until they are navigated to.
+const MAX_PREFETCHED: usize = 16;
+
+/// Controls when an [`<A/>`](crate::A) starts loading the route it links to, before it is
+/// clicked. Passed to the `prefetch` prop of the [`<Router/>`](crate::Router).
+///
+/// Prefetching runs the [`data`](crate::Route) loaders of the routes that match the link,
+/// so any resources they create start loading early. When the link is followed, the router
+/// uses the data that was prefetched rather than calling the loaders again. The app is
+/// shipped as a single WASM bundle, so there is no route code to load separately.
+///
+/// ```rust
+/// # use leptos::*;
+/// # use leptos_router::*;
+/// # use std::time::Duration;
+/// # if false {
+/// view! {
+///   <Router prefetch=PrefetchPolicy {
+///     hover_delay: Duration::from_millis(50),
+///     ..Default::default()
+///   }>
+///     <Routes>
+///       <Route path="/" view=|| "Home"/>
+///     </Routes>
+///   </Router>
+/// }
+/// # ;}
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrefetchPolicy {
+    /// How long the pointer has to stay over a link before its route is prefetched.
+    /// Defaults to 100ms.
+    pub hover_delay: Duration,
+    /// Whether to prefetch as soon as a link receives focus. Defaults to `true`.
+    pub on_focus: bool,
+    /// Whether to prefetch as soon as a link is scrolled into view. Defaults to `false`.
+    pub on_visible: bool,
+    /// Whether to skip prefetching when the user has asked the browser to reduce data usage
+    /// (i.e., when it would send a `Save-Data` header). Defaults to `true`.
+    pub respect_save_data: bool,
+}
+
+impl Default for PrefetchPolicy {
+    fn default() -> Self {
+        Self {
+            hover_delay: Duration::from_millis(100),
+            on_focus: true,
+            on_visible: false,
+            respect_save_data: true,
+        }
+    }
+}
+
+pub(crate) struct Prefetcher {
+    #[allow(unused)] // used in CSR/hydrate
+    pub(crate) policy: PrefetchPolicy,
+    #[allow(unused)] // used in CSR/hydrate
+    owner: Owner,
+    routes_base: RefCell<String>,
+    prefetched: RefCell<VecDeque<PrefetchedRoute>>,
+}
+
+struct PrefetchedRoute {
+    id: usize,
+    path: String,
+    data: Rc<dyn Any>,
+    owner: Owner,
+    disposer: Disposer,
+    params: RwSignal<ParamsMap>,
+}
+
+impl Prefetcher {
+    pub(crate) fn new(policy: PrefetchPolicy) -> Self {
+        Self {
+            policy,
+            owner: Owner::current()
+                .expect("<Router/> created outside reactive system."),
+            routes_base: Default::default(),
+            prefetched: Default::default(),
+        }
+    }
+
+    pub(crate) fn set_routes_base(&self, base: &str) {
+        *self.routes_base.borrow_mut() = base.to_string();
+    }
+}
+
+impl RouterContext {
+    /// Runs the data loaders of the routes that match `href`, unless they are already
+    /// displayed or have already been prefetched.
+    #[cfg(not(feature = "ssr"))]
+    pub(crate) fn prefetch(&self, href: &str) {
+        let Some(prefetcher) = &self.inner.prefetcher else {
+            return;
+        };
+        if prefetcher.policy.respect_save_data && save_data() {
+            return;
+        }
+        self.prefetch_data(href);
+    }
+
+    /// Runs the data loaders of the routes that match `href`, whatever the policy says.
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    fn prefetch_data(&self, href: &str) {
+        let Some(prefetcher) = &self.inner.prefetcher else {
+            return;
+        };
+
+        let path = href.split(['?', '#']).next().unwrap_or_default();
+        let base = prefetcher.routes_base.borrow().clone();
+        let options = self.inner.match_options;
+        let current =
+            get_route_matches(&base, self.pathname().get_untracked(), options);
+        let is_cached = |id: usize, path: &str| {
+            current
+                .iter()
+                .any(|m| m.route.key.id == id && m.path_match.path == path)
+                || prefetcher.prefetched.borrow().iter().any(|prefetched| {
+                    prefetched.id == id && prefetched.path == path
+                })
+        };
+
+        for route_match in
+            get_route_matches(&base, path.to_string(), options).iter()
+        {
+            let id = route_match.route.key.id;
+            if route_match.route.key.data.is_none()
+                || is_cached(id, &route_match.path_match.path)
+            {
+                continue;
+            }
+
+            let router = self.clone();
+            let route_match = route_match.clone();
+            let path = route_match.path_match.path.clone();
+            let (prefetched, disposer) = with_owner(prefetcher.owner, || {
+                as_child_of_current_owner(move |route_match: RouteMatch| {
+                    let RouteMatch {
+                        path_match:
+                            PathMatch {
+                                path,
+                                params,
+                                raw_params,
+                            },
+                        route,
+                    } = route_match;
+                    let params = create_rw_signal(params);
+                    let context = RouteContext::new(
+                        &router,
+                        None,
+                        || None,
+                        move || {
+                            Some(RouteMatch {
+                                path_match: PathMatch {
+                                    path: path.clone(),
+                                    params: params.get(),
+                                    raw_params: raw_params.clone(),
+                                },
+                                route: route.clone(),
+                            })
+                        },
+                    );
+                    let data = context.and_then(|context| {
+                        context.inner.data.borrow().clone()
+                    });
+                    (data, Owner::current(), params)
+                })(route_match)
+            });
+            let (Some(data), Some(owner), params) = prefetched else {
+                continue;
+            };
+
+            let mut prefetched = prefetcher.prefetched.borrow_mut();
+            if prefetched.len() >= MAX_PREFETCHED {
+                prefetched.pop_front();
+            }
+            prefetched.push_back(PrefetchedRoute {
+                id,
+                path,
+                data,
+                owner,
+                disposer,
+                params,
+            });
+        }
+    }
+
+    /// Takes the data prefetched for the route with the given `id` at `path`, if any, and
+    /// moves it under the current owner. `params` keeps its route params up to date.
+    pub(crate) fn take_prefetched(
+        &self,
+        id: usize,
+        path: &str,
+        params: Memo<ParamsMap>,
+    ) -> Option<Rc<dyn Any>> {
+        let prefetcher = self.inner.prefetcher.as_ref()?;
+        let PrefetchedRoute {
+            data,
+            owner,
+            disposer,
+            params: prefetched_params,
+            ..
+        } = {
+            let mut prefetched = prefetcher.prefetched.borrow_mut();
+            let index = prefetched.iter().position(|prefetched| {
+                prefetched.id == id && prefetched.path == path
+            })?;
+            prefetched.remove(index)?
+        };
+
+        // the data now belongs to the current owner, so that it looks up context through
+        // the route, and is disposed with it
+        if let Some(current) = Owner::current() {
+            _ = owner.reparent(current);
+        }
+        on_cleanup(move || drop(disposer));
+        create_render_effect(move |_| prefetched_params.set(params.get()));
+
+        Some(data)
+    }
+}
+
+/// Adds the event listeners that prefetch the route `href` links to, as configured by
+/// the router's [`PrefetchPolicy`].
+#[cfg(not(feature = "ssr"))]
+pub(crate) fn prefetch_link(
+    a: HtmlElement<html::A>,
+    href: Memo<Option<String>>,
+) -> HtmlElement<html::A> {
+    use std::cell::Cell;
+    use wasm_bindgen::{closure::Closure, JsCast};
+
+    let router = crate::use_router();
+    let Some(policy) = router
+        .inner
+        .prefetcher
+        .as_ref()
+        .map(|prefetcher| prefetcher.policy.clone())
+    else {
+        return a;
+    };
+
+    let prefetch = {
+        let router = router.clone();
+        move || {
+            if let Some(href) = href.get_untracked() {
+                router.prefetch(&href);
+            }
+        }
+    };
+
+    let hover_timeout = Rc::new(Cell::new(
+        None::<leptos::leptos_dom::helpers::TimeoutHandle>,
+    ));
+    let mut a = a
+        .on(ev::mouseenter, {
+            let prefetch = prefetch.clone();
+            let hover_timeout = Rc::clone(&hover_timeout);
+            move |_| {
+                let timeout = set_timeout_with_handle(
+                    prefetch.clone(),
+                    policy.hover_delay,
+                );
+                hover_timeout.set(timeout.ok());
+            }
+        })
+        .on(ev::mouseleave, move |_| {
+            if let Some(timeout) = hover_timeout.take() {
+                timeout.clear();
+            }
+        });
+
+    if policy.on_focus {
+        a = a.on(ev::focus, {
+            let prefetch = prefetch.clone();
+            move |_| prefetch()
+        });
+    }
+
+    if policy.on_visible {
+        let observer = Rc::new(RefCell::new(None));
+        on_cleanup({
+            let observer = Rc::clone(&observer);
+            move || {
+                if let Some(observer) = observer.take() {
+                    web_sys::IntersectionObserver::disconnect(&observer);
+                }
+            }
+        });
+        a = a.on_mount(move |a| {
+            let callback = Closure::<dyn Fn(js_sys::Array, web_sys::IntersectionObserver)>::new(
+                move |entries: js_sys::Array,
+                      observer: web_sys::IntersectionObserver| {
+                    let is_visible = entries.iter().any(|entry| {
+                        entry
+                            .unchecked_into::<web_sys::IntersectionObserverEntry>()
+                            .is_intersecting()
+                    });
+                    if is_visible {
+                        observer.disconnect();
+                        prefetch();
+                    }
+                },
+            )
+            .into_js_value();
+            if let Ok(new_observer) =
+                web_sys::IntersectionObserver::new(callback.unchecked_ref())
+            {
+                new_observer.observe(&a);
+                *observer.borrow_mut() = Some(new_observer);
+            }
+        });
+    }
+
+    a
+}
+
+#[cfg(not(feature = "ssr"))]
+fn save_data() -> bool {
+    let connection = js_sys::Reflect::get(
+        &window().navigator(),
+        &wasm_bindgen::JsValue::from_str("connection"),
+    );
+    connection
+        .ok()
+        .filter(|connection| connection.is_object())
+        .and_then(|connection| {
+            js_sys::Reflect::get(
+                &connection,
+                &wasm_bindgen::JsValue::from_str("saveData"),
+            )
+            .ok()
+        })
+        .is_some_and(|save_data| save_data.is_truthy())
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use crate::*;
+    use leptos::*;
+    use std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+    };
+
+    #[test]
+    fn prefetched_data_is_taken_once_and_disposed_with_its_route() {
+        let runtime = create_runtime();
+        provide_context(RouterIntegrationContext::new(ServerIntegration {
+            path: "http://leptos.rs/".to_string(),
+        }));
+
+        let loads = Rc::new(Cell::new(0));
+        let disposals = Rc::new(Cell::new(0));
+        let router = Rc::new(RefCell::new(None));
+        let data = {
+            let loads = Rc::clone(&loads);
+            let disposals = Rc::clone(&disposals);
+            move || {
+                loads.set(loads.get() + 1);
+                let disposals = Rc::clone(&disposals);
+                on_cleanup(move || disposals.set(disposals.get() + 1));
+                42
+            }
+        };
+        let home = {
+            let router = Rc::clone(&router);
+            move || {
+                *router.borrow_mut() = Some(use_router());
+                "Home"
+            }
+        };
+        _ = view! {
+            <Router prefetch=PrefetchPolicy::default()>
+                <Routes>
+                    <Route path="/" view=home/>
+                    <Route path="/users/:id" view=|| "User" data/>
+                </Routes>
+            </Router>
+        }
+        .into_view()
+        .render_to_string();
+        let router = router.take().unwrap();
+
+        router.prefetch_data("/users/1");
+        router.prefetch_data("/users/1?tab=posts");
+        // the route that is displayed already has its data
+        router.prefetch_data("/");
+        assert_eq!(loads.get(), 1);
+
+        let prefetcher = router.inner.prefetcher.as_ref().unwrap();
+        let id = prefetcher.prefetched.borrow()[0].id;
+        let params = create_memo(|_| ParamsMap::new());
+        let (taken, route) = as_child_of_current_owner(|_| {
+            router.take_prefetched(id, "/users/1", params)
+        })(());
+        assert_eq!(taken.unwrap().downcast_ref::<i32>(), Some(&42));
+        assert!(router.take_prefetched(id, "/users/1", params).is_none());
+
+        assert_eq!(disposals.get(), 0);
+        drop(route);
+        assert_eq!(disposals.get(), 1);
+
+        runtime.dispose();
+    }
+}
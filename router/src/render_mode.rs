@@ -18,10 +18,16 @@
 /// 5. **`Async`**: Load all resources on the server. Wait until all data are loaded, and render HTML in one sweep.
 ///     - *Pros*: Better handling for meta tags (because you know async data even before you render the `<head>`). Faster complete load than **synchronous** because async resources begin loading on server.
 ///     - *Cons*: Slower load time/TTFB: you need to wait for all async resources to load before displaying anything on the client.
+/// 6. **Client-only** (`Client`): Don't render the app on the server at all. Serve an HTML shell with an empty `<body>`, and render the whole page in the browser once the WASM bundle has loaded.
+///     - *Pros*: No server rendering cost, and no need for the page's code and data to work on the server (useful for dashboards behind a login).
+///     - *Cons*: Nothing is displayed until JS + Wasm have loaded, and the page has no content for crawlers or users without JS.
 ///
 /// The mode defaults to out-of-order streaming. For a path that includes multiple nested routes, the most
 /// restrictive mode will be used: i.e., if even a single nested route asks for `Async` rendering, the whole initial
-/// request will be rendered `Async`. (`Async` is the most restricted requirement, followed by `InOrder`, `PartiallyBlocked`, and `OutOfOrder`.)
+/// request will be rendered `Async`. (`Client` is the most restricted requirement, followed by `Async`, `InOrder`, `PartiallyBlocked`, and `OutOfOrder`.)
+///
+/// The mode is only used for the initial request: the server integrations pick the renderer for each route
+/// from the route list generated from your app. Navigating between routes in the browser always renders on the client.
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum SsrMode {
     #[default]
@@ -29,4 +35,5 @@ pub enum SsrMode {
     PartiallyBlocked,
     InOrder,
     Async,
+    Client,
 }
@@ -0,0 +1,113 @@
+use crate::{resolve_get_paths, RouteListing, StaticDataMap};
+use leptos::LeptosOptions;
+use std::path::Path;
+
+/// Generates the contents of a [`sitemap.xml`](https://www.sitemaps.org/protocol.html) file
+/// that lists every page of the app.
+///
+/// Routes without params are always listed. Routes with params are listed once for each set
+/// of params returned by their `static_params` function, as they would be built by the static
+/// site generation, and are left out if they have none, because the pages they could render
+/// are not known ahead of time. Routes that do not handle `GET` requests are left out too.
+///
+/// `base_url` is the origin the site is served from, like `https://leptos.dev`.
+///
+/// ```
+/// # use leptos_router::*;
+/// # let routes = vec![
+/// #     RouteListing::new("/", "", SsrMode::default(), [Method::Get], None),
+/// #     RouteListing::new("/about", "/about", SsrMode::default(), [Method::Get], None),
+/// #     RouteListing::new("/post/:id", "/post/:id", SsrMode::default(), [Method::Get], None),
+/// # ];
+/// # let static_data_map = StaticDataMap::new();
+/// # futures::executor::block_on(async {
+/// let sitemap =
+///     generate_sitemap("https://leptos.dev", &routes, &static_data_map).await;
+/// assert!(sitemap.contains("<loc>https://leptos.dev/about</loc>"));
+/// assert!(!sitemap.contains("/post/"));
+/// # });
+/// ```
+pub async fn generate_sitemap(
+    base_url: &str,
+    routes: &[RouteListing],
+    static_data_map: &StaticDataMap,
+) -> String {
+    let base_url = base_url.trim_end_matches('/');
+    let mut sitemap = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<urlset \
+         xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+    );
+    for path in resolve_get_paths(routes, static_data_map).await {
+        sitemap.push_str("  <url><loc>");
+        escape_xml(&mut sitemap, base_url);
+        escape_xml(&mut sitemap, &path);
+        sitemap.push_str("</loc></url>\n");
+    }
+    sitemap.push_str("</urlset>\n");
+    sitemap
+}
+
+/// Generates the contents of a [`robots.txt`](https://www.rfc-editor.org/rfc/rfc9309) file
+/// that allows every crawler to visit the site, except for the `disallow`ed path prefixes, and
+/// points them to the `sitemap.xml` at the root of `base_url`.
+///
+/// ```
+/// # use leptos_router::*;
+/// let robots = generate_robots_txt("https://leptos.dev", &["/admin"]);
+/// assert_eq!(
+///     robots,
+///     "User-agent: *\nDisallow: /admin\n\nSitemap: https://leptos.dev/sitemap.xml\n"
+/// );
+/// ```
+pub fn generate_robots_txt(
+    base_url: &str,
+    disallow: &[impl AsRef<str>],
+) -> String {
+    let mut robots = String::from("User-agent: *\n");
+    if disallow.is_empty() {
+        robots.push_str("Disallow:\n");
+    }
+    for path in disallow {
+        robots.push_str("Disallow: ");
+        robots.push_str(path.as_ref());
+        robots.push('\n');
+    }
+    robots.push_str("\nSitemap: ");
+    robots.push_str(base_url.trim_end_matches('/'));
+    robots.push_str("/sitemap.xml\n");
+    robots
+}
+
+/// Writes the [`generate_sitemap`] and [`generate_robots_txt`] files to the `site_root`, so that
+/// they are served next to the statically built routes.
+pub async fn build_sitemap(
+    options: &LeptosOptions,
+    base_url: &str,
+    routes: &[RouteListing],
+    static_data_map: &StaticDataMap,
+    disallow: &[impl AsRef<str>],
+) -> Result<(), std::io::Error> {
+    let site_root = Path::new(&options.site_root);
+    std::fs::create_dir_all(site_root)?;
+    std::fs::write(
+        site_root.join("sitemap.xml"),
+        generate_sitemap(base_url, routes, static_data_map).await,
+    )?;
+    std::fs::write(
+        site_root.join("robots.txt"),
+        generate_robots_txt(base_url, disallow),
+    )
+}
+
+fn escape_xml(buf: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => buf.push_str("&amp;"),
+            '<' => buf.push_str("&lt;"),
+            '>' => buf.push_str("&gt;"),
+            '"' => buf.push_str("&quot;"),
+            '\'' => buf.push_str("&apos;"),
+            c => buf.push(c),
+        }
+    }
+}
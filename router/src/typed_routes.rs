@@ -0,0 +1,303 @@
+use leptos::Oco;
+use std::{fmt::Display, ops::Deref};
+
+/// A URL path built from a route pattern by a function generated with [`routes!`](crate::routes).
+///
+/// It dereferences to a `&str`, so it can be passed anywhere a path is expected, like the
+/// function returned by [`use_navigate`](crate::use_navigate) or the `href` of an
+/// [`<A/>`](crate::A).
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RoutePath(String);
+
+impl RoutePath {
+    /// Fills in the params of a route pattern like `/users/:id/*rest`, percent-encoding their
+    /// values. Optional params (`:id?`) that are not given are left out.
+    ///
+    /// # Panics
+    /// Panics if a required param in the pattern isn't given, or if a param is given that
+    /// doesn't appear in the pattern. Both mean that the pattern was changed without
+    /// updating its params.
+    #[doc(hidden)]
+    pub fn from_pattern(
+        pattern: &'static str,
+        params: &[(&'static str, &dyn Display)],
+    ) -> Self {
+        let find = |name: &str| {
+            params
+                .iter()
+                .find(|(param, _)| *param == name)
+                .map(|(_, value)| value.to_string())
+        };
+
+        let mut path = String::with_capacity(pattern.len());
+        let mut used = 0;
+        for segment in pattern.split('/').filter(|s| !s.is_empty()) {
+            let value = if let Some(name) = segment.strip_prefix(':') {
+                let (name, optional) = match name.strip_suffix('?') {
+                    Some(name) => (name, true),
+                    None => (name, false),
+                };
+                match find(name) {
+                    Some(value) => Some(encode(&value, false)),
+                    None if optional => None,
+                    None => panic!(
+                        "the route `{pattern}` has a param `{name}` that was \
+                         not given"
+                    ),
+                }
+            } else if let Some(name) = segment.strip_prefix('*') {
+                find(name).map(|value| encode(&value, true))
+            } else {
+                path.push('/');
+                path.push_str(segment);
+                continue;
+            };
+
+            if let Some(value) = value {
+                used += 1;
+                path.push('/');
+                path.push_str(value.trim_start_matches('/'));
+            }
+        }
+
+        if used != params.len() {
+            let (unused, _) = params
+                .iter()
+                .find(|(name, _)| {
+                    !pattern.split('/').any(|segment| {
+                        segment
+                            .trim_start_matches([':', '*'])
+                            .trim_end_matches('?')
+                            == *name
+                    })
+                })
+                .expect("unused route param");
+            panic!("the route `{pattern}` has no param `{unused}`");
+        }
+
+        if path.is_empty() {
+            path.push('/');
+        }
+        Self(path)
+    }
+}
+
+/// Checks at compile time that the params of a route generated by [`routes!`](crate::routes)
+/// are those of its pattern, the same way as [`RoutePath::from_pattern`] does when the path is
+/// built: every required param in the pattern is a param of the route, and every param of
+/// the route is in the pattern.
+#[doc(hidden)]
+pub const fn check_route_params(pattern: &str, params: &[&str]) {
+    let pattern = pattern.as_bytes();
+
+    let mut start = 0;
+    while start < pattern.len() {
+        let end = segment_end(pattern, start);
+        if end > start && pattern[start] == b':' && pattern[end - 1] != b'?' {
+            let mut given = false;
+            let mut i = 0;
+            while i < params.len() {
+                given |=
+                    range_eq(pattern, start + 1, end, params[i].as_bytes());
+                i += 1;
+            }
+            if !given {
+                panic!(
+                    "the route has a param in its pattern that is not given"
+                );
+            }
+        }
+        start = end + 1;
+    }
+
+    let mut i = 0;
+    while i < params.len() {
+        let param = params[i].as_bytes();
+        let mut found = false;
+        let mut start = 0;
+        while start < pattern.len() {
+            let end = segment_end(pattern, start);
+            if end > start && (pattern[start] == b':' || pattern[start] == b'*')
+            {
+                let name_end = if pattern[end - 1] == b'?' {
+                    end - 1
+                } else {
+                    end
+                };
+                found |= range_eq(pattern, start + 1, name_end, param);
+            }
+            start = end + 1;
+        }
+        if !found {
+            panic!("the route has a param that is not in its pattern");
+        }
+        i += 1;
+    }
+}
+
+/// The end of the path segment that begins at `start`.
+const fn segment_end(pattern: &[u8], start: usize) -> usize {
+    let mut end = start;
+    while end < pattern.len() && pattern[end] != b'/' {
+        end += 1;
+    }
+    end
+}
+
+/// Whether `pattern[start..end]` is `name`.
+const fn range_eq(
+    pattern: &[u8],
+    start: usize,
+    end: usize,
+    name: &[u8],
+) -> bool {
+    if end < start || end - start != name.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < name.len() {
+        if pattern[start + i] != name[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+fn encode(value: &str, keep_slashes: bool) -> String {
+    const SEGMENT: &percent_encoding::AsciiSet = &percent_encoding::CONTROLS
+        .add(b' ')
+        .add(b'"')
+        .add(b'#')
+        .add(b'%')
+        .add(b'/')
+        .add(b'<')
+        .add(b'>')
+        .add(b'?')
+        .add(b'`')
+        .add(b'{')
+        .add(b'}');
+
+    if keep_slashes {
+        value
+            .split('/')
+            .map(|part| encode(part, false))
+            .collect::<Vec<_>>()
+            .join("/")
+    } else {
+        percent_encoding::utf8_percent_encode(value, SEGMENT).to_string()
+    }
+}
+
+impl Deref for RoutePath {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for RoutePath {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for RoutePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<RoutePath> for String {
+    fn from(path: RoutePath) -> Self {
+        path.0
+    }
+}
+
+impl From<RoutePath> for Oco<'static, str> {
+    fn from(path: RoutePath) -> Self {
+        Oco::Owned(path.0)
+    }
+}
+
+/// Generates typed functions that build the paths of your routes, so that links and
+/// navigation can't silently go out of sync with the route definitions.
+///
+/// Each route becomes a function that takes its params and returns a [`RoutePath`], and a
+/// module of the same name with the route's pattern as `PATH`, which can be passed to
+/// [`<Route/>`](crate::Route). If the pattern and the params of a route don't match, because
+/// a param was renamed in one but not the other, the macro fails to compile.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_router::*;
+/// mod paths {
+///     leptos_router::routes! {
+///         pub home = "/";
+///         pub user(id: u32) = "/users/:id";
+///         pub post(user: u32, slug: String) = "/users/:user/posts/:slug";
+///     }
+/// }
+///
+/// assert_eq!(&*paths::home(), "/");
+/// assert_eq!(&*paths::user(42), "/users/42");
+/// assert_eq!(paths::post::PATH, "/users/:user/posts/:slug");
+/// assert_eq!(
+///     &*paths::post(42, "hello world".into()),
+///     "/users/42/posts/hello%20world"
+/// );
+///
+/// # if false {
+/// view! {
+///   <Router>
+///     <Routes>
+///       <Route path=paths::user::PATH view=|| view! { <A href=paths::home()>"Home"</A> }/>
+///     </Routes>
+///   </Router>
+/// }
+/// # ;}
+/// ```
+///
+/// ```compile_fail
+/// leptos_router::routes! {
+///     // the param was renamed in the pattern only
+///     pub user(id: u32) = "/users/:user_id";
+/// }
+/// ```
+///
+/// ```compile_fail
+/// leptos_router::routes! {
+///     pub user = "/users/:id";
+/// }
+/// ```
+#[macro_export]
+macro_rules! routes {
+    ($(
+        $(#[$attr:meta])*
+        $vis:vis $name:ident $(( $($param:ident : $ty:ty),* $(,)? ))? = $path:literal;
+    )*) => {
+        $(
+            $(#[$attr])*
+            #[doc = concat!("Builds the path of the `", $path, "` route.")]
+            #[allow(clippy::too_many_arguments)]
+            $vis fn $name($($($param: $ty),*)?) -> $crate::RoutePath {
+                $crate::RoutePath::from_pattern(
+                    $path,
+                    &[$($((stringify!($param), &$param as &dyn ::core::fmt::Display)),*)?],
+                )
+            }
+
+            #[doc = concat!("The pattern of the `", $path, "` route.")]
+            $vis mod $name {
+                #[doc = concat!("`", $path, "`")]
+                pub const PATH: &str = $path;
+
+                const _: () = $crate::check_route_params(
+                    $path,
+                    &[$($(stringify!($param)),*)?],
+                );
+            }
+        )*
+    };
+}
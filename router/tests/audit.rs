@@ -0,0 +1,70 @@
+#![cfg(feature = "interactive-ssr")]
+
+use leptos::{ssr::JsOnlyInteraction, *};
+use leptos_router::*;
+
+#[component]
+fn App() -> impl IntoView {
+    view! {
+        <Router>
+            <Routes>
+                <Route path="" view=Home/>
+                <Route path="/counter" view=Counter/>
+                <Route path="/search" view=Search/>
+            </Routes>
+        </Router>
+    }
+}
+
+#[component]
+fn Home() -> impl IntoView {
+    view! { <A href="/counter">"Counter"</A> }
+}
+
+#[component]
+fn Counter() -> impl IntoView {
+    let (count, set_count) = create_signal(0);
+    view! {
+        <button on:click=move |_| set_count.update(|n| *n += 1)>
+            {count}
+        </button>
+    }
+}
+
+#[component]
+fn Search() -> impl IntoView {
+    view! {
+        <Form action="/search">
+            <input name="q"/>
+        </Form>
+        <form class="legacy">
+            <input name="q"/>
+        </form>
+    }
+}
+
+#[test]
+fn audit_reports_js_only_interactions_per_route() {
+    let audits =
+        futures::executor::block_on(audit_progressive_enhancement(App));
+
+    assert_eq!(
+        audits,
+        [
+            RouteAudit {
+                path: "/counter".into(),
+                interactions: vec![JsOnlyInteraction::Click("<button>".into())],
+            },
+            RouteAudit {
+                path: "/search".into(),
+                interactions: vec![JsOnlyInteraction::FormWithoutAction(
+                    "<form class=\"legacy\">".into()
+                )],
+            },
+        ]
+    );
+    assert_eq!(
+        audits[0].to_string(),
+        "/counter:\n  - <button> only responds to clicks with JavaScript"
+    );
+}
@@ -0,0 +1,13 @@
+use leptos::*;
+use leptos_router::*;
+
+/// Renders the view returned by `app` as if the server were handling a request for `path`.
+pub fn render<V: IntoView>(path: &str, app: impl FnOnce() -> V) -> String {
+    let runtime = create_runtime();
+    provide_context(RouterIntegrationContext::new(ServerIntegration {
+        path: format!("http://leptos.rs{path}"),
+    }));
+    let html = app().into_view().render_to_string().to_string();
+    runtime.dispose();
+    html
+}
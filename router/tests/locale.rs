@@ -0,0 +1,78 @@
+#![cfg(feature = "ssr")]
+
+mod common;
+
+use leptos::*;
+use leptos_router::*;
+
+const LOCALES: Locales = Locales {
+    supported: &["en", "fr"],
+    default: "en",
+};
+
+#[component]
+fn App() -> impl IntoView {
+    view! {
+        <Router locales=LOCALES>
+            <Routes>
+                <Route path="" view=|| view! { <A href="/about">"About"</A> }/>
+                <Route
+                    path="about"
+                    translations=&[("fr", "a-propos")]
+                    view=|| {
+                        let locale = use_locale().unwrap();
+                        view! {
+                            <p>{locale.get()}</p>
+                            <a href=locale.localize("/fr/a-propos?tab=1", "en")/>
+                            <A href="/">"Home"</A>
+                        }
+                    }
+                />
+                <Route path="users/:id" view=|| "User"/>
+            </Routes>
+        </Router>
+    }
+}
+
+fn render(path: &str) -> String {
+    common::render(path, App)
+}
+
+#[test]
+fn routes_are_prefixed_with_each_locale() {
+    let mut paths = generate_route_list(App)
+        .routes()
+        .iter()
+        .map(|route| route.path().to_string())
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    assert_eq!(
+        paths,
+        [
+            "/",
+            "/en",
+            "/en/about",
+            "/en/users/:id",
+            "/fr",
+            "/fr/a-propos",
+            "/fr/users/:id"
+        ]
+    );
+}
+
+#[test]
+fn locale_is_read_from_the_path() {
+    let html = render("/fr/a-propos");
+
+    assert!(html.contains(">fr</p>"));
+    assert!(html.contains(r#"href="/en/about?tab=1""#));
+    assert!(html.contains(r#"href="/fr""#));
+}
+
+#[test]
+fn links_are_localized_to_the_current_locale() {
+    let html = render("/fr");
+
+    assert!(html.contains(r#"href="/fr/a-propos""#));
+}
@@ -5,7 +5,7 @@ use cfg_if::cfg_if;
 
 cfg_if! {
     if #[cfg(feature = "ssr")] {
-        use leptos_router::{params_map, Matcher, PathMatch};
+        use leptos_router::{params_map, MatchOptions, Matcher, PathMatch};
 
         #[test]
         fn create_matcher_should_return_no_params_when_location_matches_exactly() {
@@ -15,7 +15,8 @@ cfg_if! {
                 matched,
                 Some(PathMatch {
                     path: "/foo/bar".into(),
-                    params: params_map!()
+                    params: params_map!(),
+                    raw_params: params_map!()
                 })
             );
         }
@@ -37,6 +38,9 @@ cfg_if! {
                     path: "/foo/abc-123".into(),
                     params: params_map!(
                         "id" => "abc-123"
+                    ),
+                    raw_params: params_map!(
+                        "id" => "abc-123"
                     )
                 })
             );
@@ -50,7 +54,8 @@ cfg_if! {
                 matched,
                 Some(PathMatch {
                     path: "/foo/bar".into(),
-                    params: params_map!()
+                    params: params_map!(),
+                    raw_params: params_map!()
                 })
             );
         }
@@ -73,6 +78,9 @@ cfg_if! {
                     path: "/foo/bar".into(),
                     params: params_map!(
                         "something" => "baz/qux"
+                    ),
+                    raw_params: params_map!(
+                        "something" => "baz/qux"
                     )
                 })
             );
@@ -89,6 +97,9 @@ cfg_if! {
                     path: "/foo".into(),
                     params: params_map!(
                         "something" => "baz/qux"
+                    ),
+                    raw_params: params_map!(
+                        "something" => "baz/qux"
                     )
                 })
             );
@@ -104,6 +115,9 @@ cfg_if! {
                     path: "/foo/bar".into(),
                     params: params_map!(
                         "something" => ""
+                    ),
+                    raw_params: params_map!(
+                        "something" => ""
                     )
                 })
             );
@@ -119,6 +133,9 @@ cfg_if! {
                     path: "".into(),
                     params: params_map!(
                         "any" => "///"
+                    ),
+                    raw_params: params_map!(
+                        "any" => "///"
                     )
                 })
             );
@@ -134,9 +151,126 @@ cfg_if! {
                     path: "/foo/bar".into(),
                     params: params_map!(
                         "any" => "///"
+                    ),
+                    raw_params: params_map!(
+                        "any" => "///"
                     )
                 })
             );
         }
+
+        #[test]
+        fn matcher_should_only_change_params_when_splat_changes() {
+            // the matched path identifies the route that is displayed, so a route whose
+            // wildcard changes is updated rather than created again
+            let matcher = Matcher::new("/docs/*any");
+            let intro = matcher.test("/docs/intro").unwrap();
+            let routing = matcher.test("/docs/guide/routing").unwrap();
+            assert_eq!(intro.path, routing.path);
+            assert_eq!(intro.params, params_map!("any" => "intro"));
+            assert_eq!(routing.params, params_map!("any" => "guide/routing"));
+        }
+
+        #[test]
+        fn nested_matcher_should_include_params_of_parent_segments() {
+            let parent = Matcher::new_with_partial("/:org_id", true);
+            let child = Matcher::new("/:org_id/:repo_id");
+            assert_eq!(
+                parent.test("/leptos-rs/leptos").map(|m| m.params),
+                Some(params_map!("org_id" => "leptos-rs"))
+            );
+            assert_eq!(
+                child.test("/leptos-rs/leptos").map(|m| m.params),
+                Some(params_map!(
+                    "org_id" => "leptos-rs",
+                    "repo_id" => "leptos"
+                ))
+            );
+        }
+
+        #[test]
+        fn nested_matcher_param_should_shadow_parent_param_with_same_name() {
+            let parent = Matcher::new_with_partial("/users/:id", true);
+            let child = Matcher::new("/users/:id/posts/:id");
+            assert_eq!(
+                parent.test("/users/1/posts/2").map(|m| m.params),
+                Some(params_map!("id" => "1"))
+            );
+            assert_eq!(
+                child.test("/users/1/posts/2").map(|m| m.params),
+                Some(params_map!("id" => "2"))
+            );
+        }
+
+        #[test]
+        fn matcher_should_decode_params_when_asked_to() {
+            let decode = MatchOptions {
+                decode_params: true,
+                ..Default::default()
+            };
+            let matcher = Matcher::new("/caf%C3%A9/:name/*rest");
+            let location = "/caf%C3%A9/cr%C3%A8me%20br%C3%BBl%C3%A9e/a%2Fb/c";
+            assert_eq!(
+                matcher.test(location).map(|m| m.params),
+                Some(params_map!(
+                    "name" => "cr%C3%A8me%20br%C3%BBl%C3%A9e",
+                    "rest" => "a%2Fb/c"
+                ))
+            );
+            assert_eq!(
+                Matcher::new("/café/:name/*rest").test_with_options(location, decode),
+                Some(PathMatch {
+                    path: "/caf%C3%A9/cr%C3%A8me%20br%C3%BBl%C3%A9e".into(),
+                    params: params_map!(
+                        "name" => "crème brûlée",
+                        "rest" => "a/b/c"
+                    ),
+                    raw_params: params_map!(
+                        "name" => "cr%C3%A8me%20br%C3%BBl%C3%A9e",
+                        "rest" => "a%2Fb/c"
+                    )
+                })
+            );
+        }
+
+        #[test]
+        fn matcher_should_not_match_params_that_are_not_utf8_once_decoded() {
+            let decode = MatchOptions {
+                decode_params: true,
+                ..Default::default()
+            };
+            let matcher = Matcher::new("/foo/:id");
+            assert!(matcher.test("/foo/%FF").is_some());
+            assert_eq!(matcher.test_with_options("/foo/%FF", decode), None);
+            assert_eq!(
+                Matcher::new("/foo/*any").test_with_options("/foo/bar/%C3", decode),
+                None
+            );
+        }
+
+        #[test]
+        fn matcher_should_ignore_case_of_static_segments_when_asked_to() {
+            let case_insensitive = MatchOptions {
+                case_insensitive: true,
+                ..Default::default()
+            };
+            let matcher = Matcher::new("/Users/:id");
+            assert_eq!(matcher.test("/users/Ada"), None);
+            assert_eq!(
+                matcher
+                    .test_with_options("/USERS/Ada", case_insensitive)
+                    .map(|m| (m.path, m.params)),
+                Some(("/USERS/Ada".into(), params_map!("id" => "Ada")))
+            );
+            assert_eq!(
+                Matcher::new("/été")
+                    .test_with_options("/%C3%89T%C3%89", MatchOptions {
+                        case_insensitive: true,
+                        decode_params: true
+                    })
+                    .map(|m| m.path),
+                Some("/%C3%89T%C3%89".into())
+            );
+        }
     }
 }
@@ -0,0 +1,57 @@
+#![cfg(feature = "ssr")]
+
+mod common;
+
+use leptos::*;
+use leptos_router::*;
+
+#[derive(Clone)]
+struct Org(&'static str);
+
+#[component]
+fn App() -> impl IntoView {
+    view! {
+        <Router>
+            <Routes>
+                <Route
+                    path="orgs/:org"
+                    view=|| view! { <Outlet context=OutletContext::new(Org("leptos-rs"))/> }
+                >
+                    <Route
+                        path="repos"
+                        view=|| {
+                            let org = use_outlet_context::<Org>().map(|org| org.0);
+                            view! { <p>"repos of " {org}</p> <Outlet/> }
+                        }
+                    >
+                        <Route
+                            path=":repo"
+                            view=|| {
+                                let org = use_outlet_context::<Org>().map(|org| org.0);
+                                view! { <p>"repo in " {org.unwrap_or("no org")}</p> }
+                            }
+                        />
+                        <Route path="" view=|| ()/>
+                    </Route>
+                </Route>
+            </Routes>
+        </Router>
+    }
+}
+
+fn render(path: &str) -> String {
+    common::render(path, App)
+}
+
+#[test]
+fn child_route_reads_the_context_of_its_outlet() {
+    let html = render("/orgs/leptos/repos");
+    assert!(html.contains("repos of leptos-rs"), "{html}");
+}
+
+#[test]
+fn outlet_context_is_not_seen_further_down_the_tree() {
+    let html = render("/orgs/leptos/repos/leptos");
+    assert!(html.contains("repos of leptos-rs"), "{html}");
+    assert!(html.contains("repo in no org"), "{html}");
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "ssr")]
+
+mod common;
+
+use leptos::*;
+use leptos_router::*;
+
+fn render(path: &str, case_insensitive: bool, decode_params: bool) -> String {
+    common::render(path, || {
+        view! {
+            <Router case_insensitive decode_params>
+                <Routes>
+                    <Route
+                        path="/posts/:slug"
+                        view=|| {
+                            let slug = |params: Memo<ParamsMap>| {
+                                params.with_untracked(|p| p.get("slug").cloned())
+                                    .unwrap_or_default()
+                            };
+                            format!(
+                                "slug={} raw={}",
+                                slug(use_params_map()),
+                                slug(use_raw_params_map()),
+                            )
+                        }
+                    />
+                    <Route path="/*any" view=|| "Not found"/>
+                </Routes>
+            </Router>
+        }
+    })
+}
+
+#[test]
+fn params_are_only_decoded_when_the_router_asks_to() {
+    let html = render("/posts/caf%C3%A9", false, false);
+    assert!(html.contains("slug=caf%C3%A9 raw=caf%C3%A9"), "{html}");
+
+    let html = render("/posts/caf%C3%A9", false, true);
+    assert!(html.contains("slug=café raw=caf%C3%A9"), "{html}");
+}
+
+#[test]
+fn params_that_are_not_utf8_once_decoded_match_no_route() {
+    assert!(render("/posts/caf%E9", false, false).contains("slug="));
+    let html = render("/posts/caf%E9", false, true);
+    assert!(!html.contains("slug="), "{html}");
+}
+
+#[test]
+fn case_insensitive_router_matches_static_segments_of_any_case() {
+    let html = render("/Posts/Hello", false, false);
+    assert!(html.contains("Not found"), "{html}");
+
+    let html = render("/Posts/Hello", true, false);
+    assert!(html.contains("slug=Hello"), "{html}");
+}
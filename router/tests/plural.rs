@@ -0,0 +1,129 @@
+use leptos_router::{plural_category, PluralCategory::*};
+
+#[test]
+fn categories_follow_the_rules_of_each_language() {
+    let cases = [
+        ("en", 0.0, Other),
+        ("en", 1.0, One),
+        ("en", 2.0, Other),
+        ("en-GB", 1.0, One),
+        ("fr", 0.0, One),
+        ("fr", 1.9, One),
+        ("fr", 2.0, Other),
+        ("fr", 1_000_000.0, Many),
+        ("pt", 0.0, One),
+        ("pt-PT", 0.0, Other),
+        ("ru", 1.0, One),
+        ("ru", 3.0, Few),
+        ("ru", 5.0, Many),
+        ("ru", 11.0, Many),
+        ("ru", 21.0, One),
+        ("ru", 22.0, Few),
+        ("ru", 1.5, Other),
+        ("pl", 1.0, One),
+        ("pl", 12.0, Many),
+        ("pl", 21.0, Many),
+        ("pl", 24.0, Few),
+        ("cs", 3.0, Few),
+        ("cs", 0.5, Many),
+        ("ar", 0.0, Zero),
+        ("ar", 2.0, Two),
+        ("ar", 103.0, Few),
+        ("ar", 111.0, Many),
+        ("ar", 100.0, Other),
+        ("cy", 6.0, Many),
+        ("lv", 10.0, Zero),
+        ("lv", 21.0, One),
+        ("sl", 102.0, Two),
+        ("ja", 1.0, Other),
+        ("unknown", 1.0, Other),
+    ];
+    for (locale, count, category) in cases {
+        assert_eq!(
+            plural_category(locale, count),
+            category,
+            "{count} in {locale}"
+        );
+    }
+}
+
+#[test]
+fn floats_keep_their_fraction_digits() {
+    assert_eq!(plural_category("en", 1), One);
+    assert_eq!(plural_category("en", 1.0), One);
+    assert_eq!(plural_category("en", 1.5_f32), Other);
+    assert_eq!(plural_category("ru", -2), Few);
+    assert_eq!(plural_category("da", 0.1), One);
+}
+
+#[cfg(feature = "ssr")]
+mod ssr {
+    use leptos::*;
+    use leptos_router::*;
+
+    fn items(count: usize) -> impl IntoView {
+        view! {
+            <Plural
+                count=move || count
+                one=|| "one item"
+                other=|| "several items"
+            />
+        }
+    }
+
+    #[test]
+    fn plural_renders_the_form_for_the_locale() {
+        let runtime = create_runtime();
+        let render = |view: View| view.render_to_string().to_string();
+
+        assert!(render(items(1).into_view()).contains("one item"));
+        assert!(render(items(2).into_view()).contains("several items"));
+
+        let polish = |count: usize| {
+            view! {
+                <Plural
+                    count=move || count
+                    locale="pl"
+                    one=|| "przedmiot"
+                    few=|| "przedmioty"
+                    many=|| "przedmiotów"
+                    other=|| "przedmiotu"
+                />
+            }
+            .into_view()
+        };
+        assert!(render(polish(3)).contains("przedmioty"));
+        assert!(render(polish(5)).contains("przedmiotów"));
+
+        runtime.dispose();
+    }
+
+    #[test]
+    fn plural_reads_the_locale_of_the_router() {
+        let runtime = create_runtime();
+        provide_context(RouterIntegrationContext::new(ServerIntegration {
+            path: "http://leptos.rs/fr".to_string(),
+        }));
+        let html = view! {
+            <Router locales=Locales { supported: &["en", "fr"], default: "en" }>
+                <Routes>
+                    <Route
+                        path=""
+                        view=|| {
+                            view! {
+                                <Plural count=|| 0 one=|| "un" other=|| "plusieurs"/>
+                            }
+                        }
+                    />
+                </Routes>
+            </Router>
+        }
+        .into_view()
+        .render_to_string()
+        .to_string();
+        runtime.dispose();
+
+        assert!(html.contains("un"), "{html}");
+        assert!(!html.contains("plusieurs"), "{html}");
+    }
+}
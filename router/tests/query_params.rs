@@ -50,3 +50,33 @@ cfg_if! {
         }
     }
 }
+
+#[cfg(feature = "ssr")]
+#[test]
+fn query_param_reads_a_single_key_of_the_query() {
+    use leptos::*;
+    use leptos_router::*;
+
+    let runtime = create_runtime();
+    provide_context(RouterIntegrationContext::new(ServerIntegration {
+        path: "http://leptos.rs/?page=2&utm_source=mail".to_string(),
+    }));
+    let html = view! {
+        <Router>
+            {move || {
+                let page = use_query_param("page");
+                let sort = use_query_param(String::from("sort"));
+                format!("page={:?} sort={:?}", page.get(), sort.get())
+            }}
+        </Router>
+    }
+    .into_view()
+    .render_to_string()
+    .to_string();
+    runtime.dispose();
+
+    assert!(
+        html.contains("page=Some(&quot;2&quot;) sort=None"),
+        "{html}"
+    );
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "ssr")]
+
+use leptos::*;
+use leptos_router::*;
+
+#[component]
+fn App() -> impl IntoView {
+    view! {
+        <Router>
+            <Routes>
+                <Route path="" view=|| "Home"/>
+                <Route path="/dashboard" view=|| "Dashboard" ssr=SsrMode::Client/>
+                <Route
+                    path="/users/:id"
+                    view=|| view! { <Outlet/> }
+                    ssr=SsrMode::InOrder
+                >
+                    <Route path="" view=|| "User"/>
+                    <Route
+                        path="edit"
+                        view=|| "Edit"
+                        ssr=SsrMode::Async
+                        methods=&[Method::Get, Method::Post]
+                    />
+                </Route>
+            </Routes>
+        </Router>
+    }
+}
+
+#[test]
+fn route_list_includes_paths_methods_and_modes() {
+    let list = generate_route_list(App);
+    let mut routes = list
+        .routes()
+        .iter()
+        .map(|route| {
+            let mut methods = route
+                .methods()
+                .map(|method| format!("{method:?}"))
+                .collect::<Vec<_>>();
+            methods.sort();
+            (route.path().to_string(), route.mode(), methods)
+        })
+        .collect::<Vec<_>>();
+    routes.sort_by(|a, b| a.0.cmp(&b.0));
+
+    assert_eq!(
+        routes,
+        [
+            (
+                "/".to_string(),
+                SsrMode::OutOfOrder,
+                vec!["Get".to_string()]
+            ),
+            (
+                "/dashboard".to_string(),
+                SsrMode::Client,
+                vec!["Get".to_string()]
+            ),
+            (
+                "/users/:id".to_string(),
+                SsrMode::InOrder,
+                vec!["Get".to_string()]
+            ),
+            (
+                "/users/:id/edit".to_string(),
+                SsrMode::Async,
+                vec!["Get".to_string(), "Post".to_string()]
+            ),
+        ]
+    );
+    assert!(list.server_fns().is_empty());
+}
@@ -0,0 +1,61 @@
+use cfg_if::cfg_if;
+
+cfg_if! {
+    if #[cfg(feature = "ssr")] {
+        use leptos_router::{
+            generate_sitemap, Method, RouteListing, SsrMode, StaticData,
+            StaticDataMap, StaticMode, StaticParamsMap,
+        };
+        use std::sync::Arc;
+
+        #[test]
+        fn sitemap_lists_static_routes_and_their_params() {
+            let route = |path: &str, methods: &[Method]| {
+                RouteListing::new(
+                    path,
+                    path,
+                    SsrMode::default(),
+                    methods.iter().copied(),
+                    Some(StaticMode::Upfront),
+                )
+            };
+            let routes = vec![
+                route("", &[Method::Get]),
+                route("/posts", &[Method::Get]),
+                route("/posts/:id", &[Method::Get]),
+                route("/users/:id", &[Method::Get]),
+                route("/submit", &[Method::Post]),
+            ];
+            let post_params: StaticData = Arc::new(|| {
+                Box::pin(async {
+                    let mut params = StaticParamsMap::new();
+                    params.insert("id", vec!["1".into(), "a&b".into()]);
+                    params
+                })
+            });
+            let mut static_data_map = StaticDataMap::new();
+            static_data_map.insert("/posts/:id".into(), Some(post_params));
+            static_data_map.insert("/users/:id".into(), None);
+
+            let sitemap = futures::executor::block_on(generate_sitemap(
+                "https://leptos.dev/",
+                &routes,
+                &static_data_map,
+            ));
+            let locs = sitemap
+                .lines()
+                .filter_map(|line| line.strip_prefix("  <url><loc>"))
+                .filter_map(|line| line.strip_suffix("</loc></url>"))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                locs,
+                [
+                    "https://leptos.dev/",
+                    "https://leptos.dev/posts",
+                    "https://leptos.dev/posts/1",
+                    "https://leptos.dev/posts/a&amp;b",
+                ]
+            );
+        }
+    }
+}
@@ -0,0 +1,42 @@
+mod paths {
+    leptos_router::routes! {
+        pub home = "/";
+        pub user(id: u32) = "/users/:id";
+        pub post(user: u32, slug: String) = "/users/:user/posts/:slug/";
+        pub page(page: u32) = "/posts/:page?";
+        pub posts = "/posts/:page?";
+        pub file(path: &str) = "/files/*path";
+    }
+}
+
+#[test]
+fn typed_routes_fill_in_params() {
+    assert_eq!(&*paths::home(), "/");
+    assert_eq!(&*paths::user(42), "/users/42");
+    assert_eq!(paths::user::PATH, "/users/:id");
+    assert_eq!(
+        &*paths::post(1, "a b/c?".into()),
+        "/users/1/posts/a%20b%2Fc%3F"
+    );
+    assert_eq!(&*paths::page(2), "/posts/2");
+    assert_eq!(&*paths::posts(), "/posts");
+    assert_eq!(&*paths::file("docs/a b.txt"), "/files/docs/a%20b.txt");
+}
+
+#[test]
+#[should_panic(expected = "the route `/users/:user_id` has a param `user_id` \
+                           that was not given")]
+fn route_path_panics_if_pattern_was_renamed() {
+    leptos_router::RoutePath::from_pattern(
+        "/users/:user_id",
+        &[("id", &1 as &dyn std::fmt::Display)],
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "the route `/users/:id` has a param `id` that was not given"
+)]
+fn route_path_panics_if_param_is_missing() {
+    leptos_router::RoutePath::from_pattern("/users/:id", &[]);
+}